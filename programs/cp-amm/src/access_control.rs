@@ -1,6 +1,6 @@
 use crate::assert_eq_admin;
-use crate::state::Operator;
 use crate::state::OperatorPermission;
+use crate::state::{Operator, OperatorUsageLimit};
 use crate::PoolError;
 use anchor_lang::prelude::*;
 
@@ -15,10 +15,48 @@ pub fn is_valid_operator_role<'info>(
     permission: OperatorPermission,
 ) -> Result<()> {
     let operator = operator.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
 
-    if operator.whitelisted_address.eq(signer) && operator.is_permission_allow(permission) {
+    if operator.whitelisted_address.eq(signer)
+        && operator.is_permission_allow(permission, current_time)
+    {
         Ok(())
     } else {
         err!(PoolError::InvalidPermission)
     }
 }
+
+/// Record the call and `amount` in the usage limit of the operator when the permission is usage limited. The
+/// operator usage limit can be passed at any position of the remaining accounts
+pub fn consume_operator_usage<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    operator_key: Pubkey,
+    operator: &Operator,
+    permission: OperatorPermission,
+    current_timestamp: u64,
+    amount: u64,
+) -> Result<()> {
+    if !operator.is_usage_limited(permission) {
+        return Ok(());
+    }
+
+    for account in remaining_accounts {
+        if account.owner != &crate::ID {
+            continue;
+        }
+        let Ok(loader) = AccountLoader::<OperatorUsageLimit>::try_from(account) else {
+            continue;
+        };
+        if loader.load()?.operator != operator_key {
+            continue;
+        }
+
+        loader
+            .load_mut()?
+            .get_limit_mut(permission)
+            .consume(current_timestamp, amount)?;
+        return Ok(());
+    }
+
+    err!(PoolError::MissingOperatorUsageLimit)
+}
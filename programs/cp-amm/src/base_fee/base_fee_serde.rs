@@ -1,8 +1,12 @@
+use crate::base_fee::fee_asymmetric_time_scheduler::{
+    BorshAsymmetricFeeTimeScheduler, PodAlignedAsymmetricFeeTimeScheduler,
+};
 use crate::base_fee::fee_market_cap_scheduler::{
     BorshFeeMarketCapScheduler, PodAlignedFeeMarketCapScheduler,
 };
 use crate::base_fee::fee_rate_limiter::{BorshFeeRateLimiter, PodAlignedFeeRateLimiter};
 use crate::base_fee::fee_time_scheduler::{BorshFeeTimeScheduler, PodAlignedFeeTimeScheduler};
+use crate::base_fee::fee_volume_tier::{BorshFeeVolumeTier, PodAlignedFeeVolumeTier};
 use crate::base_fee::BaseFeeHandler;
 use crate::state::fee::BaseFeeMode;
 use crate::state::BaseFeeInfo;
@@ -78,6 +82,19 @@ impl BaseFeeHandlerBuilder for BaseFeeInfo {
                         .map_err(|_| PoolError::UndeterminedError)?;
                 Ok(Box::new(fee_market_cap_scheduler))
             }
+            BaseFeeMode::FeeVolumeTier => {
+                let fee_volume_tier =
+                    *bytemuck::try_from_bytes::<PodAlignedFeeVolumeTier>(&self.data)
+                        .map_err(|_| PoolError::UndeterminedError)?;
+                Ok(Box::new(fee_volume_tier))
+            }
+            BaseFeeMode::AsymmetricFeeTimeSchedulerLinear
+            | BaseFeeMode::AsymmetricFeeTimeSchedulerExponential => {
+                let asymmetric_fee_time_scheduler =
+                    *bytemuck::try_from_bytes::<PodAlignedAsymmetricFeeTimeScheduler>(&self.data)
+                        .map_err(|_| PoolError::UndeterminedError)?;
+                Ok(Box::new(asymmetric_fee_time_scheduler))
+            }
         }
     }
 }
@@ -99,6 +116,16 @@ pub fn base_fee_parameters_to_base_fee_info(from: &BaseFeeParameters) -> Result<
                 BorshFeeMarketCapScheduler::try_from_slice(from.data.as_slice())?;
             borsh_serde_struct.to_pod_aligned_bytes()?
         }
+        BaseFeeMode::FeeVolumeTier => {
+            let borsh_serde_struct = BorshFeeVolumeTier::try_from_slice(from.data.as_slice())?;
+            borsh_serde_struct.to_pod_aligned_bytes()?
+        }
+        BaseFeeMode::AsymmetricFeeTimeSchedulerLinear
+        | BaseFeeMode::AsymmetricFeeTimeSchedulerExponential => {
+            let borsh_serde_struct =
+                BorshAsymmetricFeeTimeScheduler::try_from_slice(from.data.as_slice())?;
+            borsh_serde_struct.to_pod_aligned_bytes()?
+        }
     };
     Ok(BaseFeeInfo { data })
 }
@@ -125,6 +152,19 @@ pub fn base_fee_info_to_base_fee_parameters(from: &BaseFeeInfo) -> Result<BaseFe
                     .map_err(|_| PoolError::UndeterminedError)?;
             pod_aligned_struct.to_borsh_bytes()?
         }
+        BaseFeeMode::FeeVolumeTier => {
+            let pod_aligned_struct =
+                bytemuck::try_from_bytes::<PodAlignedFeeVolumeTier>(&from.data)
+                    .map_err(|_| PoolError::UndeterminedError)?;
+            pod_aligned_struct.to_borsh_bytes()?
+        }
+        BaseFeeMode::AsymmetricFeeTimeSchedulerLinear
+        | BaseFeeMode::AsymmetricFeeTimeSchedulerExponential => {
+            let pod_aligned_struct =
+                bytemuck::try_from_bytes::<PodAlignedAsymmetricFeeTimeScheduler>(&from.data)
+                    .map_err(|_| PoolError::UndeterminedError)?;
+            pod_aligned_struct.to_borsh_bytes()?
+        }
     };
     Ok(BaseFeeParameters { data })
 }
@@ -156,6 +196,23 @@ impl UpdateCliffFeeNumerator for BaseFeeInfo {
                     bytemuck::try_from_bytes_mut::<PodAlignedFeeMarketCapScheduler>(&mut self.data)
                         .map_err(|_| PoolError::UndeterminedError)?;
 
+                pod_aligned_struct.cliff_fee_numerator = new_cliff_fee_numerator;
+            }
+            BaseFeeMode::FeeVolumeTier => {
+                let pod_aligned_struct =
+                    bytemuck::try_from_bytes_mut::<PodAlignedFeeVolumeTier>(&mut self.data)
+                        .map_err(|_| PoolError::UndeterminedError)?;
+
+                pod_aligned_struct.cliff_fee_numerator = new_cliff_fee_numerator;
+            }
+            // only the scheduled direction is updated, the constant fee of the opposite direction stays as is
+            BaseFeeMode::AsymmetricFeeTimeSchedulerLinear
+            | BaseFeeMode::AsymmetricFeeTimeSchedulerExponential => {
+                let pod_aligned_struct = bytemuck::try_from_bytes_mut::<
+                    PodAlignedAsymmetricFeeTimeScheduler,
+                >(&mut self.data)
+                .map_err(|_| PoolError::UndeterminedError)?;
+
                 pod_aligned_struct.cliff_fee_numerator = new_cliff_fee_numerator;
             }
         };
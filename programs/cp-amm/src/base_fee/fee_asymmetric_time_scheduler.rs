@@ -0,0 +1,217 @@
+use super::BaseFeeHandler;
+use crate::{
+    activation_handler::ActivationType,
+    base_fee::{
+        fee_time_scheduler::PodAlignedFeeTimeScheduler, BorshBaseFeeSerde, PodAlignedBaseFeeSerde,
+    },
+    constants::fee::{
+        get_max_fee_numerator, CURRENT_POOL_VERSION, FEE_DENOMINATOR, MIN_FEE_NUMERATOR,
+    },
+    params::{
+        fee_parameters::{validate_fee_fraction, BaseFeeParameters},
+        swap::TradeDirection,
+    },
+    state::{fee::BaseFeeMode, BaseFeeInfo, CollectFeeMode},
+    PoolError,
+};
+use anchor_lang::prelude::*;
+
+/// Swaps in scheduled_trade_direction follow a fee time scheduler starting from cliff_fee_numerator,
+/// swaps in the opposite direction are charged constant_fee_numerator.
+/// Base fee data is 26 bytes, so only one direction can have a scheduler. To fit, period_frequency and
+/// reduction_factor are u32 instead of u64 of [PodAlignedFeeTimeScheduler], which still covers
+/// ~136 years of seconds or ~54 years of slots per period, and any reduction below FEE_DENOMINATOR
+#[derive(
+    Copy, Clone, Debug, AnchorSerialize, AnchorDeserialize, InitSpace, Default, PartialEq, Eq,
+)]
+pub struct BorshAsymmetricFeeTimeScheduler {
+    pub cliff_fee_numerator: u64,
+    pub number_of_period: u16,
+    pub period_frequency: u32,
+    pub reduction_factor: u32,
+    pub constant_fee_numerator: u32,
+    pub scheduled_trade_direction: u8,
+    pub padding: [u8; 3],
+    // Must at offset 26 (without memory alignment padding)
+    pub base_fee_mode: u8,
+}
+
+static_assertions::const_assert_eq!(
+    BaseFeeParameters::INIT_SPACE,
+    BorshAsymmetricFeeTimeScheduler::INIT_SPACE
+);
+
+impl BorshBaseFeeSerde for BorshAsymmetricFeeTimeScheduler {
+    fn to_pod_aligned_bytes(&self) -> Result<[u8; BaseFeeInfo::INIT_SPACE]> {
+        let pod_aligned_struct = PodAlignedAsymmetricFeeTimeScheduler {
+            cliff_fee_numerator: self.cliff_fee_numerator,
+            base_fee_mode: self.base_fee_mode,
+            scheduled_trade_direction: self.scheduled_trade_direction,
+            number_of_period: self.number_of_period,
+            period_frequency: self.period_frequency,
+            reduction_factor: self.reduction_factor,
+            constant_fee_numerator: self.constant_fee_numerator,
+            ..Default::default()
+        };
+        let aligned_bytes = bytemuck::bytes_of(&pod_aligned_struct);
+        // Shall not happen
+        Ok(aligned_bytes
+            .try_into()
+            .map_err(|_| PoolError::UndeterminedError)?)
+    }
+}
+
+#[account(zero_copy)]
+#[derive(Default, Debug, InitSpace)]
+pub struct PodAlignedAsymmetricFeeTimeScheduler {
+    pub cliff_fee_numerator: u64,
+    pub base_fee_mode: u8,
+    pub scheduled_trade_direction: u8,
+    pub number_of_period: u16,
+    pub period_frequency: u32,
+    pub reduction_factor: u32,
+    pub constant_fee_numerator: u32,
+    pub padding: [u8; 8],
+}
+
+static_assertions::const_assert_eq!(
+    BaseFeeInfo::INIT_SPACE,
+    PodAlignedAsymmetricFeeTimeScheduler::INIT_SPACE
+);
+
+static_assertions::const_assert_eq!(
+    BaseFeeInfo::BASE_FEE_MODE_OFFSET,
+    std::mem::offset_of!(PodAlignedAsymmetricFeeTimeScheduler, base_fee_mode)
+);
+
+impl PodAlignedBaseFeeSerde for PodAlignedAsymmetricFeeTimeScheduler {
+    fn to_borsh_bytes(&self) -> Result<[u8; BaseFeeParameters::INIT_SPACE]> {
+        let borsh_struct = BorshAsymmetricFeeTimeScheduler {
+            cliff_fee_numerator: self.cliff_fee_numerator,
+            number_of_period: self.number_of_period,
+            period_frequency: self.period_frequency,
+            reduction_factor: self.reduction_factor,
+            constant_fee_numerator: self.constant_fee_numerator,
+            scheduled_trade_direction: self.scheduled_trade_direction,
+            base_fee_mode: self.base_fee_mode,
+            ..Default::default()
+        };
+        let mut bytes = [0u8; BaseFeeParameters::INIT_SPACE];
+        // Shall not happen
+        borsh::to_writer(&mut bytes[..], &borsh_struct)
+            .map_err(|_| PoolError::UndeterminedError)?;
+        Ok(bytes)
+    }
+}
+
+impl PodAlignedAsymmetricFeeTimeScheduler {
+    fn get_scheduled_trade_direction(&self) -> Result<TradeDirection> {
+        Ok(TradeDirection::try_from(self.scheduled_trade_direction)
+            .map_err(|_| PoolError::InvalidAsymmetricFeeTimeScheduler)?)
+    }
+
+    /// Fee time scheduler applied to swaps in scheduled_trade_direction
+    pub fn to_fee_time_scheduler(&self) -> Result<PodAlignedFeeTimeScheduler> {
+        let base_fee_mode = match BaseFeeMode::try_from(self.base_fee_mode)
+            .map_err(|_| PoolError::TypeCastFailed)?
+        {
+            BaseFeeMode::AsymmetricFeeTimeSchedulerLinear => BaseFeeMode::FeeTimeSchedulerLinear,
+            BaseFeeMode::AsymmetricFeeTimeSchedulerExponential => {
+                BaseFeeMode::FeeTimeSchedulerExponential
+            }
+            _ => return Err(PoolError::UndeterminedError.into()),
+        };
+        Ok(PodAlignedFeeTimeScheduler {
+            cliff_fee_numerator: self.cliff_fee_numerator,
+            base_fee_mode: base_fee_mode.into(),
+            number_of_period: self.number_of_period,
+            period_frequency: self.period_frequency.into(),
+            reduction_factor: self.reduction_factor.into(),
+            ..Default::default()
+        })
+    }
+
+    pub fn get_base_fee_numerator(
+        &self,
+        current_point: u64,
+        activation_point: u64,
+        trade_direction: TradeDirection,
+    ) -> Result<u64> {
+        if trade_direction == self.get_scheduled_trade_direction()? {
+            self.to_fee_time_scheduler()?
+                .get_base_fee_numerator(current_point, activation_point)
+        } else {
+            Ok(self.constant_fee_numerator.into())
+        }
+    }
+}
+
+impl BaseFeeHandler for PodAlignedAsymmetricFeeTimeScheduler {
+    fn validate(
+        &self,
+        collect_fee_mode: CollectFeeMode,
+        activation_type: ActivationType,
+    ) -> Result<()> {
+        self.get_scheduled_trade_direction()?;
+
+        self.to_fee_time_scheduler()?
+            .validate(collect_fee_mode, activation_type)?;
+
+        let constant_fee_numerator: u64 = self.constant_fee_numerator.into();
+        validate_fee_fraction(constant_fee_numerator, FEE_DENOMINATOR)?;
+        require!(
+            constant_fee_numerator >= MIN_FEE_NUMERATOR
+                && constant_fee_numerator <= get_max_fee_numerator(CURRENT_POOL_VERSION)?,
+            PoolError::ExceedMaxFeeBps
+        );
+
+        Ok(())
+    }
+
+    // fee numerator doesn't depend on the swap amount, so included and excluded fee amount share the same numerator
+    fn get_base_fee_numerator_from_included_fee_amount(
+        &self,
+        current_point: u64,
+        activation_point: u64,
+        trade_direction: TradeDirection,
+        _included_fee_amount: u64,
+        _init_sqrt_price: u128,
+        _current_sqrt_price: u128,
+        _rolling_volume: u64,
+    ) -> Result<u64> {
+        self.get_base_fee_numerator(current_point, activation_point, trade_direction)
+    }
+
+    fn get_base_fee_numerator_from_excluded_fee_amount(
+        &self,
+        current_point: u64,
+        activation_point: u64,
+        trade_direction: TradeDirection,
+        _excluded_fee_amount: u64,
+        _init_sqrt_price: u128,
+        _current_sqrt_price: u128,
+        _rolling_volume: u64,
+    ) -> Result<u64> {
+        self.get_base_fee_numerator(current_point, activation_point, trade_direction)
+    }
+
+    fn validate_base_fee_is_static(
+        &self,
+        current_point: u64,
+        activation_point: u64,
+    ) -> Result<bool> {
+        self.to_fee_time_scheduler()?
+            .validate_base_fee_is_static(current_point, activation_point)
+    }
+
+    fn get_min_fee_numerator(&self) -> Result<u64> {
+        let scheduler_min_fee_numerator = self.to_fee_time_scheduler()?.get_min_fee_numerator()?;
+        Ok(scheduler_min_fee_numerator.min(self.constant_fee_numerator.into()))
+    }
+
+    fn get_max_fee_numerator(&self) -> Result<u64> {
+        Ok(self
+            .cliff_fee_numerator
+            .max(self.constant_fee_numerator.into()))
+    }
+}
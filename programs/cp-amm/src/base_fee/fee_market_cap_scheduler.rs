@@ -211,6 +211,7 @@ impl BaseFeeHandler for PodAlignedFeeMarketCapScheduler {
         _excluded_fee_amount: u64,
         init_sqrt_price: u128,
         current_sqrt_price: u128,
+        _rolling_volume: u64,
     ) -> Result<u64> {
         self.get_base_fee_numerator(
             current_point,
@@ -228,6 +229,7 @@ impl BaseFeeHandler for PodAlignedFeeMarketCapScheduler {
         _included_fee_amount: u64,
         init_sqrt_price: u128,
         current_sqrt_price: u128,
+        _rolling_volume: u64,
     ) -> Result<u64> {
         self.get_base_fee_numerator(
             current_point,
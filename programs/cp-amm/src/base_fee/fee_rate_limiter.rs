@@ -430,6 +430,7 @@ impl BaseFeeHandler for PodAlignedFeeRateLimiter {
         included_fee_amount: u64,
         _init_sqrt_price: u128,
         _current_sqrt_price: u128,
+        _rolling_volume: u64,
     ) -> Result<u64> {
         if self.is_rate_limiter_applied(current_point, activation_point, trade_direction)? {
             self.get_fee_numerator_from_included_fee_amount(included_fee_amount)
@@ -446,6 +447,7 @@ impl BaseFeeHandler for PodAlignedFeeRateLimiter {
         excluded_fee_amount: u64,
         _init_sqrt_price: u128,
         _current_sqrt_price: u128,
+        _rolling_volume: u64,
     ) -> Result<u64> {
         if self.is_rate_limiter_applied(current_point, activation_point, trade_direction)? {
             self.get_fee_numerator_from_excluded_fee_amount(excluded_fee_amount)
@@ -169,6 +169,7 @@ impl BaseFeeHandler for PodAlignedFeeTimeScheduler {
         _included_fee_amount: u64,
         _init_sqrt_price: u128,
         _current_sqrt_price: u128,
+        _rolling_volume: u64,
     ) -> Result<u64> {
         self.get_base_fee_numerator(current_point, activation_point)
     }
@@ -181,6 +182,7 @@ impl BaseFeeHandler for PodAlignedFeeTimeScheduler {
         _excluded_fee_amount: u64,
         _init_sqrt_price: u128,
         _current_sqrt_price: u128,
+        _rolling_volume: u64,
     ) -> Result<u64> {
         self.get_base_fee_numerator(current_point, activation_point)
     }
@@ -0,0 +1,206 @@
+use super::BaseFeeHandler;
+use crate::{
+    activation_handler::ActivationType,
+    base_fee::{BaseFeeEnumReader, BorshBaseFeeSerde, PodAlignedBaseFeeSerde},
+    constants::fee::{
+        get_max_fee_numerator, CURRENT_POOL_VERSION, FEE_DENOMINATOR, MIN_FEE_NUMERATOR,
+    },
+    params::{
+        fee_parameters::{validate_fee_fraction, BaseFeeParameters},
+        swap::TradeDirection,
+    },
+    safe_math::SafeMath,
+    state::{BaseFeeInfo, CollectFeeMode},
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u64,
+    PoolError,
+};
+use anchor_lang::prelude::*;
+
+/// fee = cliff_fee_numerator - tier * reduction_factor
+/// tier = min(rolling_volume / tier_volume_step, number_of_tiers)
+/// rolling_volume is the traded volume in token b, decaying linearly to zero in volume_decay_period seconds
+#[derive(
+    Copy, Clone, Debug, AnchorSerialize, AnchorDeserialize, InitSpace, Default, PartialEq, Eq,
+)]
+pub struct BorshFeeVolumeTier {
+    pub cliff_fee_numerator: u64,
+    pub number_of_tiers: u16,
+    pub volume_decay_period: u32,
+    pub reduction_factor: u32,
+    pub tier_volume_step: u64,
+    // Must at offset 26 (without memory alignment padding)
+    pub base_fee_mode: u8,
+}
+
+static_assertions::const_assert_eq!(
+    BaseFeeParameters::INIT_SPACE,
+    BorshFeeVolumeTier::INIT_SPACE
+);
+
+impl BorshBaseFeeSerde for BorshFeeVolumeTier {
+    fn to_pod_aligned_bytes(&self) -> Result<[u8; BaseFeeInfo::INIT_SPACE]> {
+        let pod_aligned_struct = PodAlignedFeeVolumeTier {
+            cliff_fee_numerator: self.cliff_fee_numerator,
+            base_fee_mode: self.base_fee_mode,
+            number_of_tiers: self.number_of_tiers,
+            volume_decay_period: self.volume_decay_period,
+            reduction_factor: self.reduction_factor,
+            tier_volume_step: self.tier_volume_step,
+            ..Default::default()
+        };
+        let aligned_bytes = bytemuck::bytes_of(&pod_aligned_struct);
+        // Shall not happen
+        Ok(aligned_bytes
+            .try_into()
+            .map_err(|_| PoolError::UndeterminedError)?)
+    }
+}
+
+#[account(zero_copy)]
+#[derive(Default, Debug, InitSpace)]
+pub struct PodAlignedFeeVolumeTier {
+    pub cliff_fee_numerator: u64,
+    pub base_fee_mode: u8,
+    pub padding: [u8; 5],
+    pub number_of_tiers: u16,
+    pub volume_decay_period: u32,
+    pub reduction_factor: u32,
+    pub tier_volume_step: u64,
+}
+
+static_assertions::const_assert_eq!(BaseFeeInfo::INIT_SPACE, PodAlignedFeeVolumeTier::INIT_SPACE);
+
+static_assertions::const_assert_eq!(
+    BaseFeeInfo::BASE_FEE_MODE_OFFSET,
+    std::mem::offset_of!(PodAlignedFeeVolumeTier, base_fee_mode)
+);
+
+impl PodAlignedBaseFeeSerde for PodAlignedFeeVolumeTier {
+    fn to_borsh_bytes(&self) -> Result<[u8; BaseFeeParameters::INIT_SPACE]> {
+        let borsh_struct = BorshFeeVolumeTier {
+            cliff_fee_numerator: self.cliff_fee_numerator,
+            number_of_tiers: self.number_of_tiers,
+            volume_decay_period: self.volume_decay_period,
+            reduction_factor: self.reduction_factor,
+            tier_volume_step: self.tier_volume_step,
+            base_fee_mode: self.base_fee_mode,
+        };
+        let mut bytes = [0u8; BaseFeeParameters::INIT_SPACE];
+        // Shall not happen
+        borsh::to_writer(&mut bytes[..], &borsh_struct)
+            .map_err(|_| PoolError::UndeterminedError)?;
+        Ok(bytes)
+    }
+}
+
+impl PodAlignedFeeVolumeTier {
+    fn get_base_fee_numerator_by_tier(&self, tier: u64) -> Result<u64> {
+        let tier = tier.min(self.number_of_tiers.into());
+        let fee_numerator = self
+            .cliff_fee_numerator
+            .safe_sub(u64::from(self.reduction_factor).safe_mul(tier)?)?;
+        Ok(fee_numerator)
+    }
+
+    pub fn get_base_fee_numerator(&self, rolling_volume: u64) -> Result<u64> {
+        let tier = rolling_volume.safe_div(self.tier_volume_step)?;
+        self.get_base_fee_numerator_by_tier(tier)
+    }
+
+    /// Rolling volume after linear decay from last_update_timestamp to current_timestamp
+    pub fn get_decayed_rolling_volume(
+        &self,
+        rolling_volume: u64,
+        last_update_timestamp: u64,
+        current_timestamp: u64,
+    ) -> Result<u64> {
+        let elapsed = current_timestamp.saturating_sub(last_update_timestamp);
+        let volume_decay_period: u64 = self.volume_decay_period.into();
+        if elapsed >= volume_decay_period {
+            return Ok(0);
+        }
+        safe_mul_div_cast_u64(
+            rolling_volume,
+            volume_decay_period.safe_sub(elapsed)?,
+            volume_decay_period,
+            Rounding::Down,
+        )
+    }
+}
+
+impl BaseFeeHandler for PodAlignedFeeVolumeTier {
+    fn validate(
+        &self,
+        _collect_fee_mode: CollectFeeMode,
+        _activation_type: ActivationType,
+    ) -> Result<()> {
+        // doesn't allow zero fee volume tier
+        require!(self.reduction_factor > 0, PoolError::InvalidFeeVolumeTier);
+
+        require!(self.number_of_tiers > 0, PoolError::InvalidFeeVolumeTier);
+
+        require!(self.tier_volume_step > 0, PoolError::InvalidFeeVolumeTier);
+
+        require!(
+            self.volume_decay_period > 0,
+            PoolError::InvalidFeeVolumeTier
+        );
+
+        let min_fee_numerator = self.get_min_fee_numerator()?;
+        let max_fee_numerator = self.get_max_fee_numerator()?;
+        validate_fee_fraction(min_fee_numerator, FEE_DENOMINATOR)?;
+        validate_fee_fraction(max_fee_numerator, FEE_DENOMINATOR)?;
+
+        require!(
+            min_fee_numerator >= MIN_FEE_NUMERATOR
+                && max_fee_numerator <= get_max_fee_numerator(CURRENT_POOL_VERSION)?,
+            PoolError::ExceedMaxFeeBps
+        );
+
+        Ok(())
+    }
+
+    fn get_base_fee_numerator_from_included_fee_amount(
+        &self,
+        _current_point: u64,
+        _activation_point: u64,
+        _trade_direction: TradeDirection,
+        _included_fee_amount: u64,
+        _init_sqrt_price: u128,
+        _current_sqrt_price: u128,
+        rolling_volume: u64,
+    ) -> Result<u64> {
+        self.get_base_fee_numerator(rolling_volume)
+    }
+
+    fn get_base_fee_numerator_from_excluded_fee_amount(
+        &self,
+        _current_point: u64,
+        _activation_point: u64,
+        _trade_direction: TradeDirection,
+        _excluded_fee_amount: u64,
+        _init_sqrt_price: u128,
+        _current_sqrt_price: u128,
+        rolling_volume: u64,
+    ) -> Result<u64> {
+        self.get_base_fee_numerator(rolling_volume)
+    }
+
+    // the fee follows the traded volume, so it is never static
+    fn validate_base_fee_is_static(
+        &self,
+        _current_point: u64,
+        _activation_point: u64,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn get_min_fee_numerator(&self) -> Result<u64> {
+        self.get_base_fee_numerator_by_tier(self.number_of_tiers.into())
+    }
+
+    fn get_max_fee_numerator(&self) -> Result<u64> {
+        Ok(self.cliff_fee_numerator)
+    }
+}
@@ -1,7 +1,9 @@
 pub mod base_fee_serde;
+pub mod fee_asymmetric_time_scheduler;
 pub mod fee_market_cap_scheduler;
 pub mod fee_rate_limiter;
 pub mod fee_time_scheduler;
+pub mod fee_volume_tier;
 pub use base_fee_serde::*;
 
 use anchor_lang::prelude::*;
@@ -16,6 +18,7 @@ pub trait BaseFeeHandler {
         collect_fee_mode: CollectFeeMode,
         activation_type: ActivationType,
     ) -> Result<()>;
+    #[allow(clippy::too_many_arguments)]
     fn get_base_fee_numerator_from_included_fee_amount(
         &self,
         current_point: u64,
@@ -24,7 +27,9 @@ pub trait BaseFeeHandler {
         included_fee_amount: u64,
         init_sqrt_price: u128,
         current_sqrt_price: u128,
+        rolling_volume: u64,
     ) -> Result<u64>;
+    #[allow(clippy::too_many_arguments)]
     fn get_base_fee_numerator_from_excluded_fee_amount(
         &self,
         current_point: u64,
@@ -33,6 +38,7 @@ pub trait BaseFeeHandler {
         excluded_fee_amount: u64,
         init_sqrt_price: u128,
         current_sqrt_price: u128,
+        rolling_volume: u64,
     ) -> Result<u64>;
 
     fn validate_base_fee_is_static(
@@ -33,3 +33,15 @@ pub mod protocol_fee_authority {
     pub const ID: Pubkey = Pubkey::new_from_array(PROTOCOL_FEE_AUTHORITY_AND_BUMP.0);
     pub const BUMP: u8 = PROTOCOL_FEE_AUTHORITY_AND_BUMP.1;
 }
+
+pub mod timelock_config {
+    use super::*;
+
+    const TIMELOCK_CONFIG_AND_BUMP: ([u8; 32], u8) = ed25519::derive_program_address(
+        &[crate::constants::seeds::TIMELOCK_CONFIG_PREFIX],
+        &crate::ID_CONST.to_bytes(),
+    );
+
+    pub const ID: Pubkey = Pubkey::new_from_array(TIMELOCK_CONFIG_AND_BUMP.0);
+    pub const BUMP: u8 = TIMELOCK_CONFIG_AND_BUMP.1;
+}
@@ -42,11 +42,17 @@ pub const NUM_REWARDS: usize = 2;
 pub const REWARD_INDEX_0: usize = 0;
 pub const REWARD_INDEX_1: usize = 1;
 
+// Number of additional rewards supported by the pool reward extension, after NUM_REWARDS
+pub const NUM_EXTENDED_REWARDS: usize = 6;
+
 // Minimum reward duration
 pub const MIN_REWARD_DURATION: u64 = 24 * 60 * 60; // 1 day
 
 pub const MAX_REWARD_DURATION: u64 = 31536000; // 1 year = 365 * 24 * 3600
 
+// Maximum extra reward weight of vested or permanent locked liquidity, 5x in total
+pub const MAX_REWARD_LIQUIDITY_BOOST_BPS: u16 = 40_000;
+
 // need to export as constant in case someone want to calculate split position numerator
 #[constant]
 pub const SPLIT_POSITION_DENOMINATOR: u32 = 1_000_000_000; // 1b
@@ -56,6 +62,21 @@ pub const MAX_RATE_LIMITER_DURATION_IN_SLOTS: u32 = 108000; // 12 hours
 
 pub const MAX_OPERATION: u8 = 12;
 
+pub const MAX_POSITION_DELEGATE_PERMISSION: u8 = 3;
+
+// Maximum delay of timelocked operator actions, 30 days
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+// Pending operator actions can only be executed within this period after the delay has passed, 7 days
+pub const MAX_PENDING_OPERATOR_ACTION_AGE: u64 = 7 * 24 * 60 * 60;
+
+// Max number of receivers of the protocol fee distribution
+pub const MAX_PROTOCOL_FEE_RECEIVERS: usize = 5;
+
+// Max amplification coefficient of stable swap pool
+#[constant]
+pub const MAX_AMPLIFICATION_COEFFICIENT: u64 = 10_000;
+
 static_assertions::const_assert_eq!(
     MAX_RATE_LIMITER_DURATION_IN_SECONDS * 1000 / 400,
     MAX_RATE_LIMITER_DURATION_IN_SLOTS
@@ -126,6 +147,10 @@ pub mod fee {
     #[constant]
     pub const MAX_BASIS_POINT: u16 = 10_000;
 
+    /// Fee of flash loan, charged on the borrowed amount
+    #[constant]
+    pub const FLASH_LOAN_FEE_NUMERATOR: u64 = 500_000; // 0.05%
+
     pub const MIN_FEE_BPS: u64 = 1; // 0.01%
     #[constant]
     pub const MIN_FEE_NUMERATOR: u64 = 100_000;
@@ -152,6 +177,10 @@ pub mod fee {
     static_assertions::const_assert!(PROTOCOL_FEE_PERCENT <= 50);
     static_assertions::const_assert!(HOST_FEE_PERCENT <= 50);
 
+    /// Max creator fee percent of the trading fee after protocol fee
+    #[constant]
+    pub const MAX_CREATOR_FEE_PERCENT: u8 = 50; // 50%
+
     #[constant]
     pub const CURRENT_POOL_VERSION: u8 = 1;
 
@@ -180,6 +209,9 @@ pub mod seeds {
     #[constant]
     pub const CUSTOMIZABLE_POOL_PREFIX: &[u8] = b"cpool";
 
+    #[constant]
+    pub const PERMISSIONED_POOL_PREFIX: &[u8] = b"ppool";
+
     #[constant]
     pub const POOL_PREFIX: &[u8] = b"pool";
 
@@ -197,6 +229,16 @@ pub mod seeds {
     pub const TOKEN_BADGE_PREFIX: &[u8] = b"token_badge";
     pub const REWARD_VAULT_PREFIX: &[u8] = b"reward_vault";
     pub const OPERATOR_PREFIX: &[u8] = b"operator";
+    pub const ORACLE_PREFIX: &[u8] = b"oracle";
+    pub const POSITION_DELEGATE_PREFIX: &[u8] = b"position_delegate";
+    pub const POOL_REWARD_EXTENSION_PREFIX: &[u8] = b"pool_reward_extension";
+    pub const POSITION_REWARD_EXTENSION_PREFIX: &[u8] = b"position_reward_extension";
+    pub const ALLOWLIST_ENTRY_PREFIX: &[u8] = b"allowlist_entry";
+    pub const ALLOWLIST_CONFIG_PREFIX: &[u8] = b"allowlist_config";
+    pub const TIMELOCK_CONFIG_PREFIX: &[u8] = b"timelock_config";
+    pub const PENDING_OPERATOR_ACTION_PREFIX: &[u8] = b"pending_operator_action";
+    pub const OPERATOR_USAGE_LIMIT_PREFIX: &[u8] = b"operator_usage_limit";
+    pub const PROTOCOL_FEE_DISTRIBUTION_PREFIX: &[u8] = b"protocol_fee_distribution";
 }
 
 pub mod protocol_fee_program {
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use super::DynamicFeeHandler;
+use crate::{
+    constants::{fee::MAX_BASIS_POINT, U24_MAX},
+    params::fee_parameters::DynamicFeeParameters,
+    safe_math::SafeMath,
+    state::fee::DynamicFeeStruct,
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u128,
+    PoolError,
+};
+
+/// Exponential moving average of absolute price returns against the reference price
+/// ema = (reduction_factor * |price_return| + (MAX_BASIS_POINT - reduction_factor) * decayed_ema) / MAX_BASIS_POINT
+/// variable_fee_rate = ema_rate * variable_fee_control / MAX_BASIS_POINT
+/// ema and price_return are in basis point unit scaled by MAX_BASIS_POINT (1e-8), stored in volatility_accumulator,
+/// bin_step and bin_step_u128 are not used
+pub struct EmaPriceReturnDynamicFee;
+
+impl EmaPriceReturnDynamicFee {
+    /// |price_a / price_b - 1| in basis point unit scaled by MAX_BASIS_POINT, capped at max_volatility_accumulator
+    pub fn get_price_return(
+        sqrt_price_a: u128,
+        sqrt_price_b: u128,
+        max_volatility_accumulator: u128,
+    ) -> Result<u128> {
+        let (upper_sqrt_price, lower_sqrt_price) = if sqrt_price_a > sqrt_price_b {
+            (sqrt_price_a, sqrt_price_b)
+        } else {
+            (sqrt_price_b, sqrt_price_a)
+        };
+        // price return is capped anyway, bound the price ratio to 4 to prevent overflow
+        let upper_sqrt_price = upper_sqrt_price.min(lower_sqrt_price.safe_mul(2)?);
+
+        // price_return = (upper_sqrt_price^2 - lower_sqrt_price^2) / lower_sqrt_price^2
+        let scale = u128::from(MAX_BASIS_POINT).safe_mul(MAX_BASIS_POINT.into())?;
+        let price_return = safe_mul_div_cast_u128(
+            upper_sqrt_price.safe_sub(lower_sqrt_price)?,
+            scale,
+            lower_sqrt_price,
+            Rounding::Down,
+        )?;
+        let price_return = safe_mul_div_cast_u128(
+            price_return,
+            upper_sqrt_price.safe_add(lower_sqrt_price)?,
+            lower_sqrt_price,
+            Rounding::Down,
+        )?;
+
+        Ok(price_return.min(max_volatility_accumulator))
+    }
+}
+
+impl DynamicFeeHandler for EmaPriceReturnDynamicFee {
+    fn validate(&self, parameters: &DynamicFeeParameters) -> Result<()> {
+        require!(
+            parameters.bin_step == 0 && parameters.bin_step_u128 == 0,
+            PoolError::InvalidInput
+        );
+
+        // filter period < t < decay period
+        require!(
+            parameters.filter_period < parameters.decay_period,
+            PoolError::InvalidInput
+        );
+
+        // reduction factor is the weight of the latest price return, zero would never update the ema
+        require!(
+            parameters.reduction_factor > 0 && parameters.reduction_factor <= MAX_BASIS_POINT,
+            PoolError::InvalidInput
+        );
+
+        // prevent program overflow
+        require!(
+            parameters.variable_fee_control <= U24_MAX,
+            PoolError::InvalidInput
+        );
+        require!(
+            parameters.max_volatility_accumulator <= U24_MAX,
+            PoolError::InvalidInput
+        );
+
+        Ok(())
+    }
+
+    fn update_references(
+        &self,
+        dynamic_fee: &mut DynamicFeeStruct,
+        sqrt_price_current: u128,
+        current_timestamp: u64,
+    ) -> Result<()> {
+        let elapsed = current_timestamp.saturating_sub(dynamic_fee.last_update_timestamp);
+        // swaps within the filter period are measured against the same reference and previous ema
+        if elapsed >= dynamic_fee.filter_period as u64 {
+            dynamic_fee.sqrt_price_reference = sqrt_price_current;
+            // the ema is reset if the pool is idle for the whole decay period
+            if elapsed < dynamic_fee.decay_period as u64 {
+                dynamic_fee.volatility_reference = dynamic_fee.volatility_accumulator;
+            } else {
+                dynamic_fee.volatility_reference = 0;
+            }
+        }
+        Ok(())
+    }
+
+    fn update_volatility_accumulator(
+        &self,
+        dynamic_fee: &mut DynamicFeeStruct,
+        _old_sqrt_price: u128,
+        sqrt_price: u128,
+        current_timestamp: u64,
+    ) -> Result<()> {
+        let price_return = Self::get_price_return(
+            sqrt_price,
+            dynamic_fee.sqrt_price_reference,
+            dynamic_fee.max_volatility_accumulator.into(),
+        )?;
+
+        let reduction_factor: u128 = dynamic_fee.reduction_factor.into();
+        let ema = price_return
+            .safe_mul(reduction_factor)?
+            .safe_add(
+                dynamic_fee
+                    .volatility_reference
+                    .safe_mul(u128::from(MAX_BASIS_POINT).safe_sub(reduction_factor)?)?,
+            )?
+            .safe_div(MAX_BASIS_POINT.into())?;
+
+        dynamic_fee.volatility_accumulator = ema.min(dynamic_fee.max_volatility_accumulator.into());
+
+        if price_return > 0 {
+            dynamic_fee.last_update_timestamp = current_timestamp;
+        }
+        Ok(())
+    }
+
+    fn get_variable_fee(&self, dynamic_fee: &DynamicFeeStruct) -> Result<u128> {
+        // ema is in 1e-8 unit, scale it to 1e9 unit and ceiling the remaining
+        let v_fee = dynamic_fee
+            .volatility_accumulator
+            .safe_mul(dynamic_fee.variable_fee_control.into())?;
+
+        let scaled_v_fee = v_fee.safe_add(999)?.safe_div(1_000)?;
+
+        Ok(scaled_v_fee)
+    }
+}
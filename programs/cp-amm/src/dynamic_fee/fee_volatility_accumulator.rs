@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use super::DynamicFeeHandler;
+use crate::{
+    constants::{fee::MAX_BASIS_POINT, BIN_STEP_BPS_DEFAULT, BIN_STEP_BPS_U128_DEFAULT, U24_MAX},
+    params::fee_parameters::DynamicFeeParameters,
+    safe_math::SafeMath,
+    state::fee::DynamicFeeStruct,
+    PoolError,
+};
+
+/// Volatility accumulator of price bins crossed since the reference price, the same as DLMM
+/// variable_fee = (volatility_accumulator * bin_step)^2 * variable_fee_control
+pub struct VolatilityAccumulatorDynamicFee;
+
+impl DynamicFeeHandler for VolatilityAccumulatorDynamicFee {
+    fn validate(&self, parameters: &DynamicFeeParameters) -> Result<()> {
+        // force all bin_step as 1 bps for first version
+        require!(
+            parameters.bin_step == BIN_STEP_BPS_DEFAULT,
+            PoolError::InvalidInput
+        );
+        require!(
+            parameters.bin_step_u128 == BIN_STEP_BPS_U128_DEFAULT,
+            PoolError::InvalidInput
+        );
+
+        // filter period < t < decay period
+        require!(
+            parameters.filter_period < parameters.decay_period,
+            PoolError::InvalidInput
+        );
+
+        // reduction factor decide the decay rate of variable fee, max reduction_factor is MAX_BASIS_POINT = 100% reduction
+        require!(
+            parameters.reduction_factor <= MAX_BASIS_POINT as u16,
+            PoolError::InvalidInput
+        );
+
+        // prevent program overflow
+        require!(
+            parameters.variable_fee_control <= U24_MAX,
+            PoolError::InvalidInput
+        );
+        require!(
+            parameters.max_volatility_accumulator <= U24_MAX,
+            PoolError::InvalidInput
+        );
+
+        Ok(())
+    }
+
+    fn update_references(
+        &self,
+        dynamic_fee: &mut DynamicFeeStruct,
+        sqrt_price_current: u128,
+        current_timestamp: u64,
+    ) -> Result<()> {
+        // it is fine to use saturating_sub, because never a chance current_timestamp is lesser than last_update_timestamp on-chain
+        // but that can benefit off-chain components for simulation when clock is not synced and pool is high frequency trading
+        // furthermore, the function doesn't update fee in pre-swap, so quoting won't be affected
+        let elapsed = current_timestamp.saturating_sub(dynamic_fee.last_update_timestamp);
+        // Not high frequency trade
+        if elapsed >= dynamic_fee.filter_period as u64 {
+            // Update sqrt of last transaction
+            dynamic_fee.sqrt_price_reference = sqrt_price_current;
+            // filter period < t < decay_period. Decay time window.
+            if elapsed < dynamic_fee.decay_period as u64 {
+                let volatility_reference = dynamic_fee
+                    .volatility_accumulator
+                    .safe_mul(dynamic_fee.reduction_factor.into())?
+                    .safe_div(MAX_BASIS_POINT.into())?;
+
+                dynamic_fee.volatility_reference = volatility_reference;
+            }
+            // Out of decay time window
+            else {
+                dynamic_fee.volatility_reference = 0;
+            }
+        }
+        Ok(())
+    }
+
+    fn update_volatility_accumulator(
+        &self,
+        dynamic_fee: &mut DynamicFeeStruct,
+        old_sqrt_price: u128,
+        sqrt_price: u128,
+        current_timestamp: u64,
+    ) -> Result<()> {
+        let delta_price = DynamicFeeStruct::get_delta_bin_id(
+            dynamic_fee.bin_step_u128,
+            sqrt_price,
+            dynamic_fee.sqrt_price_reference,
+        )?;
+
+        let volatility_accumulator = dynamic_fee
+            .volatility_reference
+            .safe_add(delta_price.safe_mul(MAX_BASIS_POINT.into())?)?;
+
+        dynamic_fee.volatility_accumulator = std::cmp::min(
+            volatility_accumulator,
+            dynamic_fee.max_volatility_accumulator.into(),
+        );
+
+        // update only last_update_timestamp if bin is crossed
+        let delta_price = DynamicFeeStruct::get_delta_bin_id(
+            dynamic_fee.bin_step_u128,
+            old_sqrt_price,
+            sqrt_price,
+        )?;
+        if delta_price > 0 {
+            dynamic_fee.last_update_timestamp = current_timestamp;
+        }
+        Ok(())
+    }
+
+    fn get_variable_fee(&self, dynamic_fee: &DynamicFeeStruct) -> Result<u128> {
+        let square_vfa_bin: u128 = dynamic_fee
+            .volatility_accumulator
+            .safe_mul(dynamic_fee.bin_step.into())?
+            .checked_pow(2)
+            .ok_or_else(|| PoolError::TypeCastFailed)?;
+        // Variable fee control, volatility accumulator, bin step are in basis point unit (10_000)
+        // This is 1e20. Which > 1e9. Scale down it to 1e9 unit and ceiling the remaining.
+        let v_fee = square_vfa_bin.safe_mul(dynamic_fee.variable_fee_control.into())?;
+
+        let scaled_v_fee = v_fee.safe_add(99_999_999_999)?.safe_div(100_000_000_000)?;
+
+        Ok(scaled_v_fee)
+    }
+}
@@ -0,0 +1,46 @@
+pub mod fee_ema_price_return;
+pub mod fee_volatility_accumulator;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    dynamic_fee::{
+        fee_ema_price_return::EmaPriceReturnDynamicFee,
+        fee_volatility_accumulator::VolatilityAccumulatorDynamicFee,
+    },
+    params::fee_parameters::DynamicFeeParameters,
+    state::fee::{DynamicFeeMode, DynamicFeeStruct},
+    PoolError,
+};
+
+pub trait DynamicFeeHandler {
+    fn validate(&self, parameters: &DynamicFeeParameters) -> Result<()>;
+
+    /// Update references before the swap
+    fn update_references(
+        &self,
+        dynamic_fee: &mut DynamicFeeStruct,
+        sqrt_price_current: u128,
+        current_timestamp: u64,
+    ) -> Result<()>;
+
+    /// Update volatility accumulator after the swap moved the price from old_sqrt_price to sqrt_price
+    fn update_volatility_accumulator(
+        &self,
+        dynamic_fee: &mut DynamicFeeStruct,
+        old_sqrt_price: u128,
+        sqrt_price: u128,
+        current_timestamp: u64,
+    ) -> Result<()>;
+
+    fn get_variable_fee(&self, dynamic_fee: &DynamicFeeStruct) -> Result<u128>;
+}
+
+pub fn get_dynamic_fee_handler(dynamic_fee_mode: u8) -> Result<Box<dyn DynamicFeeHandler>> {
+    let dynamic_fee_mode = DynamicFeeMode::try_from(dynamic_fee_mode)
+        .map_err(|_| PoolError::InvalidDynamicFeeParameters)?;
+    match dynamic_fee_mode {
+        DynamicFeeMode::VolatilityAccumulator => Ok(Box::new(VolatilityAccumulatorDynamicFee)),
+        DynamicFeeMode::EmaPriceReturn => Ok(Box::new(EmaPriceReturnDynamicFee)),
+    }
+}
@@ -29,8 +29,9 @@ unsafe fn p_entrypoint(input: *mut u8) -> Option<u64> {
     const UNINIT: core::mem::MaybeUninit<pinocchio::account_info::AccountInfo> =
         core::mem::MaybeUninit::<pinocchio::account_info::AccountInfo>::uninit();
     // Create an array of uninitialized account infos.
-    // In rate limiter we may need an additional account for sysvar program id
-    let mut accounts = [UNINIT; SWAP_IX_ACCOUNTS + 1];
+    // In rate limiter we may need an additional account for sysvar program id, another one for the optional oracle,
+    // and another one for the allowlist entry of permissioned pools
+    let mut accounts = [UNINIT; SWAP_IX_ACCOUNTS + 3];
 
     let (program_id, count, instruction_data) =
         pinocchio::entrypoint::deserialize(input, &mut accounts);
@@ -74,7 +75,7 @@ unsafe fn p_entrypoint(input: *mut u8) -> Option<u64> {
                 swap_parameters.to_swap_parameters2()
             } else {
                 let swap_parameters = unwrap_or_return!(
-                    SwapParameters2::deserialize(
+                    SwapParameters2::deserialize_with_optional_price_limit(
                         &mut &instruction_data[crate::instruction::Swap2::DISCRIMINATOR.len()..]
                     ),
                     Some(ErrorCode::InstructionDidNotDeserialize as u64)
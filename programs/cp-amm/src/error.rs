@@ -215,6 +215,105 @@ pub enum PoolError {
 
     #[msg("Transfer fee excluded amount is zero")]
     TransferFeeExcludedAmountIsZero,
+
+    #[msg("Invalid sqrt price limit")]
+    InvalidSqrtPriceLimit,
+
+    #[msg("Oracle observation is too old")]
+    OracleObservationTooOld,
+
+    #[msg("Invalid oracle account")]
+    InvalidOracleAccount,
+
+    #[msg("Unsupported merge of inner vesting with different schedule")]
+    UnsupportedInnerVestingMerge,
+
+    #[msg("Invalid amplification coefficient")]
+    InvalidAmplificationCoefficient,
+
+    #[msg("Stable swap invariant does not converge")]
+    StableSwapInvariantNotConverged,
+
+    #[msg("Invalid swap route")]
+    InvalidSwapRoute,
+
+    #[msg("Invalid flash loan instruction")]
+    InvalidFlashLoanInstruction,
+
+    #[msg("Missing flash repay instruction")]
+    MissingFlashRepayInstruction,
+
+    #[msg("Invalid fee volume tier")]
+    InvalidFeeVolumeTier,
+
+    #[msg("Invalid asymmetric fee time scheduler")]
+    InvalidAsymmetricFeeTimeScheduler,
+
+    #[msg("Single sided add liquidity is disabled while rate limiter is applied")]
+    SingleSidedAddLiquidityRateLimited,
+
+    #[msg("Single token remove liquidity is disabled while rate limiter is applied")]
+    SingleTokenRemoveLiquidityRateLimited,
+
+    #[msg("Swap of compound position fee is disabled while rate limiter is applied")]
+    CompoundPositionFeeRateLimited,
+
+    #[msg("Invalid position delegate")]
+    InvalidPositionDelegate,
+
+    #[msg("Token account isn't owned by the payout destination of the position delegate")]
+    InvalidPayoutDestination,
+
+    #[msg("Missing reward extension account")]
+    MissingRewardExtension,
+
+    #[msg("Position reward extension has pending rewards")]
+    PositionRewardExtensionNotEmpty,
+
+    #[msg("Invalid reward liquidity boost")]
+    InvalidRewardLiquidityBoost,
+
+    #[msg("Pool is not permissioned")]
+    PoolNotPermissioned,
+
+    #[msg("Operator action is timelocked, it must be proposed first")]
+    OperatorActionTimelocked,
+
+    #[msg("Pending operator action can't be executed before its earliest execution timestamp")]
+    OperatorActionNotExecutable,
+
+    #[msg("Invalid operator action")]
+    InvalidOperatorAction,
+
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+
+    #[msg("Missing operator usage limit")]
+    MissingOperatorUsageLimit,
+
+    #[msg("Operator usage limit exceeded")]
+    OperatorUsageLimitExceeded,
+
+    #[msg("Invalid operator usage limit")]
+    InvalidOperatorUsageLimit,
+
+    #[msg("Invalid protocol fee distribution")]
+    InvalidProtocolFeeDistribution,
+
+    #[msg("Protocol fee is claimed through the protocol fee distribution")]
+    ProtocolFeeDistributionEnabled,
+
+    #[msg("Invalid protocol fee receiver token account")]
+    InvalidProtocolFeeReceiverAccount,
+
+    #[msg("Invalid creator fee percent")]
+    InvalidCreatorFeePercent,
+
+    #[msg("Creator fee is not tracked by the pool")]
+    CreatorFeeNotTracked,
+
+    #[msg("Pending operator action has expired, it can only be cancelled")]
+    OperatorActionExpired,
 }
 
 impl From<ProtozolZapError> for PoolError {
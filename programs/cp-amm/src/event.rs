@@ -4,9 +4,12 @@ use anchor_lang::prelude::*;
 use crate::{
     params::fee_parameters::PoolFeeParameters,
     state::{
-        SplitAmountInfo, SplitAmountInfo2, SplitPositionInfo, SplitPositionInfo2, SwapResult2,
+        OperatorAction, ProtocolFeeReceiverParameters, SplitAmountInfo, SplitAmountInfo2,
+        SplitPositionInfo, SplitPositionInfo2, SwapResult2,
     },
-    SplitPositionParameters2, SplitPositionParameters3, SwapParameters2, UpdatePoolFeesParameters,
+    AddLiquiditySingleSidedParameters, CompoundPositionFeeParameters,
+    RemoveLiquiditySingleTokenParameters, SetPositionDelegateParameters, SplitPositionParameters2,
+    SplitPositionParameters3, SwapParameters2, UpdatePoolFeesParameters,
 };
 
 /// Close config
@@ -40,6 +43,18 @@ pub struct EvtCreateDynamicConfig {
     pub index: u64,
 }
 
+/// Create stable swap config
+#[event]
+pub struct EvtCreateStableSwapConfig {
+    pub pool_fees: PoolFeeParameters,
+    pub vault_config_key: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub activation_type: u8,
+    pub amplification_coefficient: u64,
+    pub index: u64,
+    pub config: Pubkey,
+}
+
 /// Create token badge
 #[event]
 pub struct EvtCreateTokenBadge {
@@ -88,6 +103,12 @@ pub struct EvtCreatePosition {
     pub position_nft_mint: Pubkey,
 }
 
+#[event]
+pub struct EvtInitializeOracle {
+    pub pool: Pubkey,
+    pub oracle: Pubkey,
+}
+
 #[event]
 pub struct EvtClosePosition {
     pub pool: Pubkey,
@@ -151,7 +172,11 @@ pub struct EvtClaimProtocolFee2 {
 #[event]
 pub struct EvtSetPoolStatus {
     pub pool: Pubkey,
+    /// bitmask of paused actions
     pub status: u8,
+    pub previous_status: u8,
+    /// bitmask of actions paused or resumed
+    pub changed_actions: u8,
 }
 
 // Initialize reward
@@ -272,6 +297,18 @@ pub struct EvtSplitPosition3 {
     pub split_position_parameters: SplitPositionParameters3,
 }
 
+#[event]
+pub struct EvtMergePosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub source_position: Pubkey,
+    pub source_position_nft_mint: Pubkey,
+    pub current_sqrt_price: u128,
+    pub amount_merged: SplitAmountInfo2,
+    pub position_info: SplitPositionInfo2,
+}
+
 #[event]
 pub struct EvtLiquidityChange {
     pub pool: Pubkey,
@@ -290,9 +327,201 @@ pub struct EvtLiquidityChange {
     pub change_type: u8,
 }
 
+#[event]
+pub struct EvtAddLiquiditySingleSided {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub params: AddLiquiditySingleSidedParameters,
+    pub swap_result: SwapResult2,
+    pub liquidity_delta: u128,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub included_transfer_fee_amount_in: u64,
+    pub refund_amount_out: u64,
+    pub current_timestamp: u64,
+    pub reserve_a_amount: u64,
+    pub reserve_b_amount: u64,
+}
+
+#[event]
+pub struct EvtRemoveLiquiditySingleToken {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub params: RemoveLiquiditySingleTokenParameters,
+    /// Token a amount withdrawn from the position
+    pub token_a_amount: u64,
+    /// Token b amount withdrawn from the position
+    pub token_b_amount: u64,
+    /// Swap of the withdrawn input token, none when no input token is withdrawn
+    pub swap_result: Option<SwapResult2>,
+    pub included_transfer_fee_amount_out: u64,
+    pub excluded_transfer_fee_amount_out: u64,
+    pub current_timestamp: u64,
+    pub reserve_a_amount: u64,
+    pub reserve_b_amount: u64,
+}
+
+#[event]
+pub struct EvtCompoundPositionFee {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub params: CompoundPositionFeeParameters,
+    /// Swap of the pending fee, none when swap_amount_in is zero
+    pub swap_result: Option<SwapResult2>,
+    pub liquidity_delta: u128,
+    /// Pending fee a deposited into the position
+    pub token_a_amount: u64,
+    /// Pending fee b deposited into the position
+    pub token_b_amount: u64,
+    /// Remaining pending fee a of the position
+    pub fee_a_pending: u64,
+    /// Remaining pending fee b of the position
+    pub fee_b_pending: u64,
+    pub current_timestamp: u64,
+    pub reserve_a_amount: u64,
+    pub reserve_b_amount: u64,
+}
+
 #[event]
 pub struct EvtUpdatePoolFees {
     pub pool: Pubkey,
     pub operator: Pubkey,
     pub params: UpdatePoolFeesParameters,
 }
+
+#[event]
+pub struct EvtFlashBorrow {
+    pub pool: Pubkey,
+    pub borrower: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EvtFlashRepay {
+    pub pool: Pubkey,
+    pub borrower: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub claiming_fee: u64,
+    pub compounding_fee: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub transfer_fee_included_repay_amount: u64,
+}
+
+#[event]
+pub struct EvtSetPositionDelegate {
+    pub position: Pubkey,
+    pub position_delegate: Pubkey,
+    pub owner: Pubkey,
+    pub params: SetPositionDelegateParameters,
+}
+
+#[event]
+pub struct EvtRevokePositionDelegate {
+    pub position: Pubkey,
+    pub position_delegate: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct EvtInitializePoolRewardExtension {
+    pub pool: Pubkey,
+    pub pool_reward_extension: Pubkey,
+}
+
+#[event]
+pub struct EvtInitializePositionRewardExtension {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub position_reward_extension: Pubkey,
+}
+
+#[event]
+pub struct EvtAddAllowlistEntry {
+    pub pool: Pubkey,
+    pub allowlist_entry: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct EvtRemoveAllowlistEntry {
+    pub pool: Pubkey,
+    pub allowlist_entry: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct EvtSetTimelockDelay {
+    /// refer [crate::state::OperatorPermission]
+    pub permission: u8,
+    pub delay: u64,
+    pub previous_delay: u64,
+}
+
+#[event]
+pub struct EvtProposeOperatorAction {
+    pub pool: Pubkey,
+    pub pending_operator_action: Pubkey,
+    pub proposer: Pubkey,
+    pub earliest_execution_timestamp: u64,
+    pub action: OperatorAction,
+}
+
+#[event]
+pub struct EvtExecuteOperatorAction {
+    pub pool: Pubkey,
+    pub pending_operator_action: Pubkey,
+    pub proposer: Pubkey,
+    /// refer [crate::state::OperatorPermission]
+    pub permission: u8,
+}
+
+#[event]
+pub struct EvtCancelOperatorAction {
+    pub pool: Pubkey,
+    pub pending_operator_action: Pubkey,
+    pub proposer: Pubkey,
+    pub operator: Pubkey,
+    /// refer [crate::state::OperatorPermission]
+    pub permission: u8,
+}
+
+#[event]
+pub struct EvtSetProtocolFeeDistribution {
+    pub pool: Pubkey,
+    pub protocol_fee_distribution: Pubkey,
+    pub receivers: Vec<ProtocolFeeReceiverParameters>,
+}
+
+#[event]
+pub struct EvtCloseProtocolFeeDistribution {
+    pub pool: Pubkey,
+    pub protocol_fee_distribution: Pubkey,
+}
+
+#[event]
+pub struct EvtDistributeProtocolFee {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    /// protocol fee distributed, excluding the amounts kept in the pool
+    pub amount: u64,
+    /// amount sent to each receiver, in the receivers order
+    pub receiver_amounts: Vec<u64>,
+    /// amount received by each receiver after the token 2022 transfer fee
+    pub transfer_fee_excluded_amounts: Vec<u64>,
+}
+
+#[event]
+pub struct EvtClaimCreatorFee {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+}
@@ -0,0 +1,40 @@
+use crate::{
+    state::{Pool, ProtocolFeeDistribution},
+    EvtCloseProtocolFeeDistribution,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseProtocolFeeDistributionCtx<'info> {
+    #[account(
+        mut,
+        has_one = pool,
+        close = rent_receiver
+    )]
+    pub protocol_fee_distribution: AccountLoader<'info, ProtocolFeeDistribution>,
+
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub signer: Signer<'info>,
+
+    /// CHECK: Account to receive closed account rental SOL
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+/// Protocol fees of the pool can be claimed by the protocol fee claim instructions again
+pub fn handle_close_protocol_fee_distribution(
+    ctx: Context<CloseProtocolFeeDistributionCtx>,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    pool.protocol_fee_distribution_enabled = 0;
+
+    emit_cpi!(EvtCloseProtocolFeeDistribution {
+        pool: ctx.accounts.pool.key(),
+        protocol_fee_distribution: ctx.accounts.protocol_fee_distribution.key(),
+    });
+
+    Ok(())
+}
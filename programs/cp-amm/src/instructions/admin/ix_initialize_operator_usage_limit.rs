@@ -0,0 +1,37 @@
+use crate::{
+    constants::seeds::OPERATOR_USAGE_LIMIT_PREFIX,
+    state::{Operator, OperatorUsageLimit},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeOperatorUsageLimitCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            OPERATOR_USAGE_LIMIT_PREFIX.as_ref(),
+            operator.key().as_ref(),
+        ],
+        bump,
+        space = 8 + OperatorUsageLimit::INIT_SPACE
+    )]
+    pub operator_usage_limit: AccountLoader<'info, OperatorUsageLimit>,
+
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_operator_usage_limit(
+    ctx: Context<InitializeOperatorUsageLimitCtx>,
+) -> Result<()> {
+    let mut operator_usage_limit = ctx.accounts.operator_usage_limit.load_init()?;
+    operator_usage_limit.initialize(ctx.accounts.operator.key());
+    Ok(())
+}
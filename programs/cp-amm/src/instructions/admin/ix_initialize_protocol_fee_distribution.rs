@@ -0,0 +1,52 @@
+use crate::{
+    constants::seeds::PROTOCOL_FEE_DISTRIBUTION_PREFIX,
+    state::{Pool, ProtocolFeeDistribution, ProtocolFeeReceiverParameters},
+    EvtSetProtocolFeeDistribution,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeProtocolFeeDistributionCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            PROTOCOL_FEE_DISTRIBUTION_PREFIX.as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+        space = 8 + ProtocolFeeDistribution::INIT_SPACE
+    )]
+    pub protocol_fee_distribution: AccountLoader<'info, ProtocolFeeDistribution>,
+
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Protocol fees of the pool can only be claimed by `distribute_protocol_fee` once the distribution is initialized
+pub fn handle_initialize_protocol_fee_distribution(
+    ctx: Context<InitializeProtocolFeeDistributionCtx>,
+    receivers: Vec<ProtocolFeeReceiverParameters>,
+) -> Result<()> {
+    let mut protocol_fee_distribution = ctx.accounts.protocol_fee_distribution.load_init()?;
+    protocol_fee_distribution.initialize(ctx.accounts.pool.key(), &receivers)?;
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    pool.protocol_fee_distribution_enabled = 1;
+
+    emit_cpi!(EvtSetProtocolFeeDistribution {
+        pool: ctx.accounts.pool.key(),
+        protocol_fee_distribution: ctx.accounts.protocol_fee_distribution.key(),
+        receivers,
+    });
+
+    Ok(())
+}
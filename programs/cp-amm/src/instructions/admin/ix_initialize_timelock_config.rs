@@ -0,0 +1,27 @@
+use crate::{constants::seeds::TIMELOCK_CONFIG_PREFIX, state::TimelockConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeTimelockConfigCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TIMELOCK_CONFIG_PREFIX.as_ref()],
+        bump,
+        space = 8 + TimelockConfig::INIT_SPACE
+    )]
+    pub timelock_config: AccountLoader<'info, TimelockConfig>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_timelock_config(ctx: Context<InitializeTimelockConfigCtx>) -> Result<()> {
+    // all delays are zero until set by the admin
+    ctx.accounts.timelock_config.load_init()?;
+    Ok(())
+}
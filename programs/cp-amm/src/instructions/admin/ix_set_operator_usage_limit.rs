@@ -0,0 +1,72 @@
+use crate::{
+    state::{is_usage_limitable_permission, Operator, OperatorPermission, OperatorUsageLimit},
+    PoolError,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct OperatorUsageLimitParameters {
+    /// refer [OperatorPermission]
+    pub permission: u8,
+    /// length of the usage window in seconds
+    pub window_duration: u64,
+    /// max number of calls per window, 0 means no cap
+    pub max_usage: u64,
+    /// max token amount per window, 0 means no cap. Must be 0 for `ClaimProtocolFee` which claims two tokens
+    pub max_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetOperatorUsageLimitCtx<'info> {
+    #[account(mut, has_one = operator)]
+    pub operator_usage_limit: AccountLoader<'info, OperatorUsageLimit>,
+
+    #[account(mut)]
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Set the usage caps of the permission, the permission is no longer usage limited when both caps are 0
+pub fn handle_set_operator_usage_limit(
+    ctx: Context<SetOperatorUsageLimitCtx>,
+    params: OperatorUsageLimitParameters,
+) -> Result<()> {
+    let OperatorUsageLimitParameters {
+        permission,
+        window_duration,
+        max_usage,
+        max_amount,
+    } = params;
+
+    let permission =
+        OperatorPermission::try_from(permission).map_err(|_| PoolError::InvalidPermission)?;
+    require!(
+        is_usage_limitable_permission(permission),
+        PoolError::InvalidPermission
+    );
+
+    // token a and token b amounts of claim_protocol_fee can't be capped by a single amount
+    require!(
+        permission != OperatorPermission::ClaimProtocolFee || max_amount == 0,
+        PoolError::InvalidOperatorUsageLimit
+    );
+
+    let is_usage_limited = max_usage > 0 || max_amount > 0;
+    require!(
+        !is_usage_limited || window_duration > 0,
+        PoolError::InvalidOperatorUsageLimit
+    );
+
+    let mut operator_usage_limit = ctx.accounts.operator_usage_limit.load_mut()?;
+    operator_usage_limit.get_limit_mut(permission).initialize(
+        window_duration,
+        max_usage,
+        max_amount,
+    );
+
+    let mut operator = ctx.accounts.operator.load_mut()?;
+    operator.set_usage_limited(permission, is_usage_limited)?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::{
+    const_pda,
+    constants::MAX_TIMELOCK_DELAY,
+    state::{is_timelocked_permission, OperatorPermission, TimelockConfig},
+    EvtSetTimelockDelay, PoolError,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTimelockDelayCtx<'info> {
+    #[account(mut, address = const_pda::timelock_config::ID)]
+    pub timelock_config: AccountLoader<'info, TimelockConfig>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_set_timelock_delay(
+    ctx: Context<SetTimelockDelayCtx>,
+    permission: u8,
+    delay: u64,
+) -> Result<()> {
+    let permission =
+        OperatorPermission::try_from(permission).map_err(|_| PoolError::InvalidPermission)?;
+    require!(
+        is_timelocked_permission(permission),
+        PoolError::InvalidPermission
+    );
+    require!(delay <= MAX_TIMELOCK_DELAY, PoolError::InvalidTimelockDelay);
+
+    let mut timelock_config = ctx.accounts.timelock_config.load_mut()?;
+    let previous_delay = timelock_config.get_delay(permission);
+    timelock_config.set_delay(permission, delay);
+
+    emit_cpi!(EvtSetTimelockDelay {
+        permission: permission.into(),
+        delay,
+        previous_delay,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{constants::MAX_OPERATION, state::Operator, PoolError};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct UpdateOperatorParameters {
+    pub permission: u128,
+    /// permissions expire at this timestamp, 0 means no expiry
+    pub expiry_timestamp: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOperatorAccountCtx<'info> {
+    #[account(mut)]
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_update_operator(
+    ctx: Context<UpdateOperatorAccountCtx>,
+    params: UpdateOperatorParameters,
+) -> Result<()> {
+    let UpdateOperatorParameters {
+        permission,
+        expiry_timestamp,
+    } = params;
+
+    require!(
+        permission > 0 && permission < 1 << MAX_OPERATION,
+        PoolError::InvalidPermission
+    );
+
+    let mut operator = ctx.accounts.operator.load_mut()?;
+    operator.update(permission, expiry_timestamp);
+    Ok(())
+}
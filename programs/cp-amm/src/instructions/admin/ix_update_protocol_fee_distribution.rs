@@ -0,0 +1,30 @@
+use crate::{
+    state::{ProtocolFeeDistribution, ProtocolFeeReceiverParameters},
+    EvtSetProtocolFeeDistribution,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateProtocolFeeDistributionCtx<'info> {
+    #[account(mut)]
+    pub protocol_fee_distribution: AccountLoader<'info, ProtocolFeeDistribution>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_update_protocol_fee_distribution(
+    ctx: Context<UpdateProtocolFeeDistributionCtx>,
+    receivers: Vec<ProtocolFeeReceiverParameters>,
+) -> Result<()> {
+    let mut protocol_fee_distribution = ctx.accounts.protocol_fee_distribution.load_mut()?;
+    protocol_fee_distribution.set_receivers(&receivers)?;
+
+    emit_cpi!(EvtSetProtocolFeeDistribution {
+        pool: protocol_fee_distribution.pool,
+        protocol_fee_distribution: ctx.accounts.protocol_fee_distribution.key(),
+        receivers,
+    });
+
+    Ok(())
+}
@@ -2,5 +2,21 @@ pub mod ix_close_operator_account;
 pub mod ix_create_operator_account;
 pub use ix_close_operator_account::*;
 pub use ix_create_operator_account::*;
+pub mod ix_initialize_timelock_config;
+pub mod ix_set_timelock_delay;
+pub use ix_initialize_timelock_config::*;
+pub use ix_set_timelock_delay::*;
+pub mod ix_update_operator_account;
+pub use ix_update_operator_account::*;
+pub mod ix_initialize_operator_usage_limit;
+pub use ix_initialize_operator_usage_limit::*;
+pub mod ix_set_operator_usage_limit;
+pub use ix_set_operator_usage_limit::*;
+pub mod ix_initialize_protocol_fee_distribution;
+pub use ix_initialize_protocol_fee_distribution::*;
+pub mod ix_update_protocol_fee_distribution;
+pub use ix_update_protocol_fee_distribution::*;
+pub mod ix_close_protocol_fee_distribution;
+pub use ix_close_protocol_fee_distribution::*;
 pub mod auth;
 pub use auth::*;
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::ALLOWLIST_ENTRY_PREFIX,
+    state::{AllowlistConfig, AllowlistEntry, Pool},
+    EvtAddAllowlistEntry, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddAllowlistEntryCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(has_one = pool, has_one = allowlist_authority)]
+    pub allowlist_config: AccountLoader<'info, AllowlistConfig>,
+
+    #[account(
+        init,
+        seeds = [
+            ALLOWLIST_ENTRY_PREFIX.as_ref(),
+            pool.key().as_ref(),
+            wallet.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + AllowlistEntry::INIT_SPACE
+    )]
+    pub allowlist_entry: AccountLoader<'info, AllowlistEntry>,
+
+    /// CHECK: wallet to allowlist
+    pub wallet: UncheckedAccount<'info>,
+
+    pub allowlist_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_add_allowlist_entry(ctx: Context<AddAllowlistEntryCtx>) -> Result<()> {
+    require!(
+        ctx.accounts.pool.load()?.is_permissioned(),
+        PoolError::PoolNotPermissioned
+    );
+
+    let mut allowlist_entry = ctx.accounts.allowlist_entry.load_init()?;
+    allowlist_entry.initialize(ctx.accounts.pool.key(), ctx.accounts.wallet.key());
+
+    emit_cpi!(EvtAddAllowlistEntry {
+        pool: ctx.accounts.pool.key(),
+        allowlist_entry: ctx.accounts.allowlist_entry.key(),
+        wallet: ctx.accounts.wallet.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AllowlistConfig, AllowlistEntry, Pool},
+    EvtRemoveAllowlistEntry,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveAllowlistEntryCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(has_one = pool, has_one = allowlist_authority)]
+    pub allowlist_config: AccountLoader<'info, AllowlistConfig>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        close = rent_receiver
+    )]
+    pub allowlist_entry: AccountLoader<'info, AllowlistEntry>,
+
+    pub allowlist_authority: Signer<'info>,
+
+    /// CHECK: account receives the rent
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handle_remove_allowlist_entry(ctx: Context<RemoveAllowlistEntryCtx>) -> Result<()> {
+    let allowlist_entry = ctx.accounts.allowlist_entry.load()?;
+
+    emit_cpi!(EvtRemoveAllowlistEntry {
+        pool: ctx.accounts.pool.key(),
+        allowlist_entry: ctx.accounts.allowlist_entry.key(),
+        wallet: allowlist_entry.wallet,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+pub mod ix_add_allowlist_entry;
+pub use ix_add_allowlist_entry::*;
+
+pub mod ix_remove_allowlist_entry;
+pub use ix_remove_allowlist_entry::*;
+
+use anchor_lang::prelude::*;
+
+use crate::state::{AllowlistEntry, Pool};
+
+fn is_allowlist_entry_of(data: &[u8], pool_key: &[u8; 32], wallet: &[u8; 32]) -> bool {
+    let space = AllowlistEntry::DISCRIMINATOR.len() + std::mem::size_of::<AllowlistEntry>();
+    if !data.starts_with(AllowlistEntry::DISCRIMINATOR) || data.len() < space {
+        return false;
+    }
+    let allowlist_entry: AllowlistEntry =
+        bytemuck::pod_read_unaligned(&data[AllowlistEntry::DISCRIMINATOR.len()..space]);
+    allowlist_entry.pool.as_array() == pool_key && allowlist_entry.wallet.as_array() == wallet
+}
+
+/// Whether `wallet` is allowlisted by the permissioned pool, the allowlist entry can be passed at any position
+/// of the remaining accounts. Only looked up for permissioned pools
+pub fn is_wallet_allowlisted(
+    remaining_accounts: &[AccountInfo],
+    pool_key: Pubkey,
+    pool: &Pool,
+    wallet: Pubkey,
+) -> Result<bool> {
+    if !pool.is_permissioned() {
+        return Ok(false);
+    }
+    for account in remaining_accounts {
+        if account.owner != &crate::ID {
+            continue;
+        }
+        if is_allowlist_entry_of(
+            &account.try_borrow_data()?,
+            pool_key.as_array(),
+            wallet.as_array(),
+        ) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// A pinocchio equivalent of the above is_wallet_allowlisted
+pub fn p_is_wallet_allowlisted(
+    remaining_accounts: &[pinocchio::account_info::AccountInfo],
+    pool_key: &pinocchio::pubkey::Pubkey,
+    pool: &Pool,
+    wallet: &pinocchio::pubkey::Pubkey,
+) -> Result<bool> {
+    if !pool.is_permissioned() {
+        return Ok(false);
+    }
+    for account in remaining_accounts {
+        if account.owner() != crate::ID.as_array() {
+            continue;
+        }
+        let data = account
+            .try_borrow_data()
+            .map_err(|err| ProgramError::from(u64::from(err)))?;
+        if is_allowlist_entry_of(&data, pool_key, wallet) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::SysvarId;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pinocchio::sysvars::instructions::Instructions;
+
+use crate::{
+    const_pda, get_pool_access_validator_with_allowlist,
+    instruction::{FlashBorrow as FlashBorrowInstruction, FlashRepay as FlashRepayInstruction},
+    is_wallet_allowlisted,
+    p_helper::p_get_number_of_accounts_in_instruction,
+    safe_math::{SafeCast, SafeMath},
+    state::Pool,
+    token::transfer_from_pool,
+    EvtFlashBorrow, PoolError,
+};
+
+/// Index of the pool account in [FlashLoanCtx]
+pub const FLASH_LOAN_POOL_ACCOUNT_INDEX: usize = 1;
+/// Index of the token vault account in [FlashLoanCtx]
+pub const FLASH_LOAN_TOKEN_VAULT_ACCOUNT_INDEX: usize = 2;
+
+/// Accounts for both flash_borrow and flash_repay, the pair must use the same pool and token vault
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashLoanCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    /// The vault token account of the borrowed token
+    #[account(mut, token::token_program = token_program, token::mint = token_mint)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account to receive the borrowed token, and to repay from
+    #[account(mut, token::token_program = token_program, token::mint = token_mint)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of the borrowed token
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub borrower: Signer<'info>,
+
+    /// Token program of the borrowed token
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Sysvar Instructions account
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+impl<'info> FlashLoanCtx<'info> {
+    /// Return whether the borrowed token is token a of the pool
+    pub fn is_borrowing_token_a(&self, pool: &Pool) -> Result<bool> {
+        let token_vault = self.token_vault.key();
+        if token_vault == pool.token_a_vault {
+            Ok(true)
+        } else {
+            require!(
+                token_vault == pool.token_b_vault,
+                PoolError::InvalidFlashLoanInstruction
+            );
+            Ok(false)
+        }
+    }
+}
+
+/// Flash loan instructions must be top level instructions, so the instructions sysvar reflects them
+pub fn validate_flash_loan_stack_height() -> Result<()> {
+    require!(
+        get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT,
+        PoolError::InvalidFlashLoanInstruction
+    );
+    Ok(())
+}
+
+/// Find the flash_repay instruction of the current flash_borrow instruction.
+/// Rules:
+/// 1. The first flash_repay after the current instruction must point to the current instruction, with the same pool and token vault
+/// 2. No other flash_borrow before the flash_repay
+/// 3. No other instruction of the program touches the pool before the flash_repay
+fn validate_flash_repay_instruction(
+    pool: &Pubkey,
+    token_vault: &Pubkey,
+    instruction_sysvar: &[u8],
+) -> Result<()> {
+    let instruction_sysvar_instructions =
+        unsafe { Instructions::new_unchecked(instruction_sysvar) };
+    let current_index = instruction_sysvar_instructions.load_current_index();
+    let number_of_instructions: u16 = instruction_sysvar_instructions
+        .num_instructions()
+        .safe_cast()?;
+
+    for i in current_index.safe_add(1)?..number_of_instructions {
+        let instruction = instruction_sysvar_instructions
+            .load_instruction_at(i.into())
+            .map_err(|err| ProgramError::from(u64::from(err)))?;
+
+        if instruction.get_program_id() != crate::ID.as_array() {
+            continue;
+        }
+
+        let instruction_data = instruction.get_instruction_data();
+        let instruction_discriminator = instruction_data
+            .get(..8)
+            .ok_or_else(|| PoolError::InvalidFlashLoanInstruction)?;
+
+        require!(
+            !instruction_discriminator.eq(FlashBorrowInstruction::DISCRIMINATOR),
+            PoolError::InvalidFlashLoanInstruction
+        );
+
+        if instruction_discriminator.eq(FlashRepayInstruction::DISCRIMINATOR) {
+            let borrow_instruction_index = instruction_data
+                .get(8..10)
+                .and_then(|data| data.try_into().ok())
+                .map(u16::from_le_bytes)
+                .ok_or_else(|| PoolError::InvalidFlashLoanInstruction)?;
+            require!(
+                borrow_instruction_index == current_index,
+                PoolError::InvalidFlashLoanInstruction
+            );
+
+            let repay_pool = instruction
+                .get_account_meta_at(FLASH_LOAN_POOL_ACCOUNT_INDEX)
+                .map_err(|err| ProgramError::from(u64::from(err)))?;
+            let repay_token_vault = instruction
+                .get_account_meta_at(FLASH_LOAN_TOKEN_VAULT_ACCOUNT_INDEX)
+                .map_err(|err| ProgramError::from(u64::from(err)))?;
+            require!(
+                &repay_pool.key == pool.as_array()
+                    && &repay_token_vault.key == token_vault.as_array(),
+                PoolError::InvalidFlashLoanInstruction
+            );
+
+            return Ok(());
+        }
+
+        // the pool reserves are lent out until the repay
+        let num_accounts = p_get_number_of_accounts_in_instruction(&instruction);
+        for j in 0..num_accounts {
+            let account_metadata = instruction
+                .get_account_meta_at(j.into())
+                .map_err(|err| ProgramError::from(u64::from(err)))?;
+            require!(
+                &account_metadata.key != pool.as_array(),
+                PoolError::InvalidFlashLoanInstruction
+            );
+        }
+    }
+
+    Err(PoolError::MissingFlashRepayInstruction.into())
+}
+
+pub fn handle_flash_borrow(ctx: Context<FlashLoanCtx>, amount: u64) -> Result<()> {
+    require!(amount > 0, PoolError::AmountIsZero);
+
+    {
+        let pool = ctx.accounts.pool.load()?;
+        let is_allowlisted = is_wallet_allowlisted(
+            ctx.remaining_accounts,
+            ctx.accounts.pool.key(),
+            &pool,
+            ctx.accounts.borrower.key(),
+        )?;
+        let access_validator = get_pool_access_validator_with_allowlist(&pool, is_allowlisted)?;
+        require!(access_validator.can_flash_loan(), PoolError::PoolDisabled);
+        ctx.accounts.is_borrowing_token_a(&pool)?;
+    }
+
+    validate_flash_loan_stack_height()?;
+    validate_flash_repay_instruction(
+        &ctx.accounts.pool.key(),
+        &ctx.accounts.token_vault.key(),
+        &ctx.accounts.sysvar_instructions.try_borrow_data()?,
+    )?;
+
+    transfer_from_pool(
+        ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_vault,
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    emit_cpi!(EvtFlashBorrow {
+        pool: ctx.accounts.pool.key(),
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
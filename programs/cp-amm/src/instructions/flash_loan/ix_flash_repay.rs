@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use pinocchio::sysvars::instructions::Instructions;
+
+use crate::{
+    constants::fee::{FEE_DENOMINATOR, FLASH_LOAN_FEE_NUMERATOR},
+    instruction::FlashBorrow as FlashBorrowInstruction,
+    safe_math::SafeMath,
+    state::fee::SplitFees,
+    token::{calculate_transfer_fee_included_amount, transfer_from_user},
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u64,
+    validate_flash_loan_stack_height, EvtFlashRepay, FlashLoanCtx, PoolError,
+    FLASH_LOAN_POOL_ACCOUNT_INDEX, FLASH_LOAN_TOKEN_VAULT_ACCOUNT_INDEX,
+};
+
+/// Get the borrowed amount of the flash_borrow instruction, it must be before the current instruction with the same pool and token vault
+fn get_flash_borrow_amount(
+    pool: &Pubkey,
+    token_vault: &Pubkey,
+    borrow_instruction_index: u16,
+    instruction_sysvar: &[u8],
+) -> Result<u64> {
+    let instruction_sysvar_instructions =
+        unsafe { Instructions::new_unchecked(instruction_sysvar) };
+    let current_index = instruction_sysvar_instructions.load_current_index();
+    require!(
+        borrow_instruction_index < current_index,
+        PoolError::InvalidFlashLoanInstruction
+    );
+
+    let instruction = instruction_sysvar_instructions
+        .load_instruction_at(borrow_instruction_index.into())
+        .map_err(|err| ProgramError::from(u64::from(err)))?;
+    require!(
+        instruction.get_program_id() == crate::ID.as_array(),
+        PoolError::InvalidFlashLoanInstruction
+    );
+
+    let instruction_data = instruction.get_instruction_data();
+    require!(
+        instruction_data
+            .get(..8)
+            .is_some_and(|discriminator| discriminator.eq(FlashBorrowInstruction::DISCRIMINATOR)),
+        PoolError::InvalidFlashLoanInstruction
+    );
+
+    let borrow_pool = instruction
+        .get_account_meta_at(FLASH_LOAN_POOL_ACCOUNT_INDEX)
+        .map_err(|err| ProgramError::from(u64::from(err)))?;
+    let borrow_token_vault = instruction
+        .get_account_meta_at(FLASH_LOAN_TOKEN_VAULT_ACCOUNT_INDEX)
+        .map_err(|err| ProgramError::from(u64::from(err)))?;
+    require!(
+        &borrow_pool.key == pool.as_array() && &borrow_token_vault.key == token_vault.as_array(),
+        PoolError::InvalidFlashLoanInstruction
+    );
+
+    let amount = instruction_data
+        .get(8..16)
+        .and_then(|data| data.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| PoolError::InvalidFlashLoanInstruction)?;
+
+    Ok(amount)
+}
+
+pub fn handle_flash_repay(ctx: Context<FlashLoanCtx>, borrow_instruction_index: u16) -> Result<()> {
+    validate_flash_loan_stack_height()?;
+    let amount = get_flash_borrow_amount(
+        &ctx.accounts.pool.key(),
+        &ctx.accounts.token_vault.key(),
+        borrow_instruction_index,
+        &ctx.accounts.sysvar_instructions.try_borrow_data()?,
+    )?;
+
+    let fee = safe_mul_div_cast_u64(
+        amount,
+        FLASH_LOAN_FEE_NUMERATOR,
+        FEE_DENOMINATOR,
+        Rounding::Up,
+    )?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u64;
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let is_token_a = ctx.accounts.is_borrowing_token_a(&pool)?;
+    let SplitFees {
+        claiming_fee,
+        compounding_fee,
+        protocol_fee,
+        creator_fee,
+        ..
+    } = pool.apply_flash_loan_fee(fee, is_token_a, current_timestamp)?;
+    drop(pool);
+
+    // the vault must receive the borrowed amount and fee after Token-2022 transfer fee
+    let repay_amount = amount.safe_add(fee)?;
+    let transfer_fee_included_repay_amount = calculate_transfer_fee_included_amount(
+        &ctx.accounts
+            .token_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        repay_amount,
+    )?
+    .amount;
+
+    transfer_from_user(
+        &ctx.accounts.borrower,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_vault,
+        &ctx.accounts.token_program,
+        transfer_fee_included_repay_amount,
+    )?;
+
+    emit_cpi!(EvtFlashRepay {
+        pool: ctx.accounts.pool.key(),
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        amount,
+        fee,
+        claiming_fee,
+        compounding_fee,
+        protocol_fee,
+        creator_fee,
+        transfer_fee_included_repay_amount,
+    });
+
+    Ok(())
+}
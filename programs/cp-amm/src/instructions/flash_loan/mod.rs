@@ -0,0 +1,5 @@
+pub mod ix_flash_borrow;
+pub use ix_flash_borrow::*;
+
+pub mod ix_flash_repay;
+pub use ix_flash_repay::*;
@@ -56,7 +56,9 @@ pub fn validate_initial_sqrt_price(
     sqrt_min_price: u128,
     sqrt_max_price: u128,
 ) -> Result<()> {
-    if collect_fee_mode == CollectFeeMode::Compounding {
+    if collect_fee_mode == CollectFeeMode::Compounding
+        || collect_fee_mode == CollectFeeMode::StableSwap
+    {
         // we still have a boundary for initial sqrt price
         require!(
             sqrt_price >= MIN_SQRT_PRICE && sqrt_price <= MAX_SQRT_PRICE,
@@ -79,6 +81,12 @@ impl InitializeCustomizablePoolParameters {
         let collect_fee_mode = CollectFeeMode::try_from(self.collect_fee_mode)
             .map_err(|_| PoolError::InvalidCollectFeeMode)?;
 
+        // amplification coefficient is only configurable in stable swap config
+        require!(
+            collect_fee_mode != CollectFeeMode::StableSwap,
+            PoolError::InvalidCollectFeeMode
+        );
+
         if collect_fee_mode != CollectFeeMode::Compounding {
             // we only care for price range if collect fee mode is not Compounding
             require!(
@@ -248,16 +256,75 @@ pub struct InitializeCustomizablePoolCtx<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Accounts shared by `initialize_customizable_pool` and `initialize_permissioned_pool`, which only differ in the
+/// pool address
+pub struct InitializeCustomizablePoolAccounts<'a, 'info> {
+    pub creator: &'a UncheckedAccount<'info>,
+    pub position_nft_mint: &'a InterfaceAccount<'info, Mint>,
+    pub position_nft_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub payer: &'a Signer<'info>,
+    pub pool_authority: &'a UncheckedAccount<'info>,
+    pub pool: &'a AccountLoader<'info, Pool>,
+    pub position: &'a AccountLoader<'info, Position>,
+    pub token_a_mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_b_mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_a_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_b_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub payer_token_a: &'a InterfaceAccount<'info, TokenAccount>,
+    pub payer_token_b: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_a_program: &'a Interface<'info, TokenInterface>,
+    pub token_b_program: &'a Interface<'info, TokenInterface>,
+    pub token_2022_program: &'a Program<'info, Token2022>,
+    pub system_program: &'a Program<'info, System>,
+    pub remaining_accounts: &'info [AccountInfo<'info>],
+}
+
 pub fn handle_initialize_customizable_pool<'info>(
     ctx: Context<'info, InitializeCustomizablePoolCtx<'info>>,
     params: InitializeCustomizablePoolParameters,
 ) -> Result<()> {
+    let accounts = InitializeCustomizablePoolAccounts {
+        creator: &ctx.accounts.creator,
+        position_nft_mint: &ctx.accounts.position_nft_mint,
+        position_nft_account: &ctx.accounts.position_nft_account,
+        payer: &ctx.accounts.payer,
+        pool_authority: &ctx.accounts.pool_authority,
+        pool: &ctx.accounts.pool,
+        position: &ctx.accounts.position,
+        token_a_mint: &ctx.accounts.token_a_mint,
+        token_b_mint: &ctx.accounts.token_b_mint,
+        token_a_vault: &ctx.accounts.token_a_vault,
+        token_b_vault: &ctx.accounts.token_b_vault,
+        payer_token_a: &ctx.accounts.payer_token_a,
+        payer_token_b: &ctx.accounts.payer_token_b,
+        token_a_program: &ctx.accounts.token_a_program,
+        token_b_program: &ctx.accounts.token_b_program,
+        token_2022_program: &ctx.accounts.token_2022_program,
+        system_program: &ctx.accounts.system_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    let (evt_create_position, evt_initialize_pool) =
+        process_initialize_customizable_pool(&accounts, params, PoolType::Customizable)?;
+
+    emit_cpi!(evt_create_position);
+    emit_cpi!(evt_initialize_pool);
+
+    Ok(())
+}
+
+/// Initialize the pool and its first position, returns the events to emit
+pub fn process_initialize_customizable_pool<'info>(
+    accounts: &InitializeCustomizablePoolAccounts<'_, 'info>,
+    params: InitializeCustomizablePoolParameters,
+    pool_type: PoolType,
+) -> Result<(EvtCreatePosition, EvtInitializePool)> {
     params.validate()?;
-    if !is_supported_mint(&ctx.accounts.token_a_mint)? {
+    if !is_supported_mint(accounts.token_a_mint)? {
         require!(
             is_token_badge_initialized(
-                ctx.accounts.token_a_mint.key(),
-                ctx.remaining_accounts
+                accounts.token_a_mint.key(),
+                accounts
+                    .remaining_accounts
                     .get(0)
                     .ok_or(PoolError::InvalidTokenBadge)?,
             )?,
@@ -265,11 +332,12 @@ pub fn handle_initialize_customizable_pool<'info>(
         )
     }
 
-    if !is_supported_mint(&ctx.accounts.token_b_mint)? {
+    if !is_supported_mint(accounts.token_b_mint)? {
         require!(
             is_token_badge_initialized(
-                ctx.accounts.token_b_mint.key(),
-                ctx.remaining_accounts
+                accounts.token_b_mint.key(),
+                accounts
+                    .remaining_accounts
                     .get(1)
                     .ok_or(PoolError::InvalidTokenBadge)?,
             )?,
@@ -303,6 +371,7 @@ pub fn handle_initialize_customizable_pool<'info>(
         sqrt_max_price,
         sqrt_price,
         liquidity,
+        0, // stable swap pool can only be created from static config
     )?;
 
     require!(
@@ -310,26 +379,23 @@ pub fn handle_initialize_customizable_pool<'info>(
         PoolError::AmountIsZero
     );
 
-    let mut pool = ctx.accounts.pool.load_init()?;
+    let mut pool = accounts.pool.load_init()?;
 
-    let token_a_flag: u8 = get_token_program_flags(&ctx.accounts.token_a_mint).into();
-    let token_b_flag: u8 = get_token_program_flags(&ctx.accounts.token_b_mint).into();
+    let token_a_flag: u8 = get_token_program_flags(accounts.token_a_mint).into();
+    let token_b_flag: u8 = get_token_program_flags(accounts.token_b_mint).into();
     let activation_point =
         activation_point.unwrap_or(ActivationHandler::get_current_point(activation_type)?);
-    let alpha_vault = get_whitelisted_alpha_vault(
-        ctx.accounts.payer.key(),
-        ctx.accounts.pool.key(),
-        has_alpha_vault,
-    );
-    let pool_type: u8 = PoolType::Customizable.into();
+    let alpha_vault =
+        get_whitelisted_alpha_vault(accounts.payer.key(), accounts.pool.key(), has_alpha_vault);
+    let pool_type: u8 = pool_type.into();
 
     pool.initialize(
-        ctx.accounts.creator.key(),
+        accounts.creator.key(),
         pool_fees.to_pool_fees_struct(sqrt_price)?,
-        ctx.accounts.token_a_mint.key(),
-        ctx.accounts.token_b_mint.key(),
-        ctx.accounts.token_a_vault.key(),
-        ctx.accounts.token_b_vault.key(),
+        accounts.token_a_mint.key(),
+        accounts.token_b_mint.key(),
+        accounts.token_a_vault.key(),
+        accounts.token_b_vault.key(),
         alpha_vault,
         sqrt_min_price,
         sqrt_max_price,
@@ -343,50 +409,45 @@ pub fn handle_initialize_customizable_pool<'info>(
         pool_type,
         token_a_amount,
         token_b_amount,
+        0,
     );
 
-    let mut position = ctx.accounts.position.load_init()?;
+    let mut position = accounts.position.load_init()?;
     position.initialize(
         &mut pool,
-        ctx.accounts.pool.key(),
-        ctx.accounts.position_nft_mint.key(),
+        accounts.pool.key(),
+        accounts.position_nft_mint.key(),
         initial_liquidity,
     );
 
     // create position nft
     drop(position);
     create_position_nft(
-        ctx.accounts.payer.to_account_info(),
-        ctx.accounts.position_nft_mint.to_account_info(),
-        ctx.accounts.pool_authority.to_account_info(),
-        ctx.accounts.system_program.to_account_info(),
-        ctx.accounts.token_2022_program.to_account_info(),
-        ctx.accounts.position_nft_account.to_account_info(),
+        accounts.payer.to_account_info(),
+        accounts.position_nft_mint.to_account_info(),
+        accounts.pool_authority.to_account_info(),
+        accounts.system_program.to_account_info(),
+        accounts.token_2022_program.to_account_info(),
+        accounts.position_nft_account.to_account_info(),
     )?;
 
-    emit_cpi!(EvtCreatePosition {
-        pool: ctx.accounts.pool.key(),
-        owner: ctx.accounts.creator.key(),
-        position: ctx.accounts.position.key(),
-        position_nft_mint: ctx.accounts.position_nft_mint.key(),
-    });
+    let evt_create_position = EvtCreatePosition {
+        pool: accounts.pool.key(),
+        owner: accounts.creator.key(),
+        position: accounts.position.key(),
+        position_nft_mint: accounts.position_nft_mint.key(),
+    };
 
     // transfer token
 
     let mut total_amount_a = calculate_transfer_fee_included_amount(
-        &ctx.accounts
-            .token_a_mint
-            .to_account_info()
-            .try_borrow_data()?,
+        &accounts.token_a_mint.to_account_info().try_borrow_data()?,
         token_a_amount,
     )?
     .amount;
 
     let mut total_amount_b = calculate_transfer_fee_included_amount(
-        &ctx.accounts
-            .token_b_mint
-            .to_account_info()
-            .try_borrow_data()?,
+        &accounts.token_b_mint.to_account_info().try_borrow_data()?,
         token_b_amount,
     )?
     .amount;
@@ -396,29 +457,29 @@ pub fn handle_initialize_customizable_pool<'info>(
     total_amount_b = total_amount_b.max(1);
 
     transfer_from_user(
-        &ctx.accounts.payer,
-        &ctx.accounts.token_a_mint,
-        &ctx.accounts.payer_token_a,
-        &ctx.accounts.token_a_vault,
-        &ctx.accounts.token_a_program,
+        accounts.payer,
+        accounts.token_a_mint,
+        accounts.payer_token_a,
+        accounts.token_a_vault,
+        accounts.token_a_program,
         total_amount_a,
     )?;
     transfer_from_user(
-        &ctx.accounts.payer,
-        &ctx.accounts.token_b_mint,
-        &ctx.accounts.payer_token_b,
-        &ctx.accounts.token_b_vault,
-        &ctx.accounts.token_b_program,
+        accounts.payer,
+        accounts.token_b_mint,
+        accounts.payer_token_b,
+        accounts.token_b_vault,
+        accounts.token_b_program,
         total_amount_b,
     )?;
 
-    emit_cpi!(EvtInitializePool {
-        pool: ctx.accounts.pool.key(),
-        token_a_mint: ctx.accounts.token_a_mint.key(),
-        token_b_mint: ctx.accounts.token_b_mint.key(),
+    let evt_initialize_pool = EvtInitializePool {
+        pool: accounts.pool.key(),
+        token_a_mint: accounts.token_a_mint.key(),
+        token_b_mint: accounts.token_b_mint.key(),
         pool_fees,
-        creator: ctx.accounts.creator.key(),
-        payer: ctx.accounts.payer.key(),
+        creator: accounts.creator.key(),
+        payer: accounts.payer.key(),
         activation_point,
         activation_type,
         token_a_flag,
@@ -434,9 +495,9 @@ pub fn handle_initialize_customizable_pool<'info>(
         total_amount_a,
         total_amount_b,
         pool_type,
-    });
+    };
 
-    Ok(())
+    Ok((evt_create_position, evt_initialize_pool))
 }
 
 pub fn get_whitelisted_alpha_vault(payer: Pubkey, pool: Pubkey, has_alpha_vault: bool) -> Pubkey {
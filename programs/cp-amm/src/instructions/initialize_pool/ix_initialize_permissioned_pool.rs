@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    const_pda,
+    constants::seeds::{
+        ALLOWLIST_CONFIG_PREFIX, PERMISSIONED_POOL_PREFIX, POSITION_NFT_ACCOUNT_PREFIX,
+        POSITION_PREFIX, TOKEN_VAULT_PREFIX,
+    },
+    state::{AllowlistConfig, Pool, PoolType, Position},
+    InitializeCustomizablePoolAccounts, InitializeCustomizablePoolParameters,
+};
+
+use super::{max_key, min_key, process_initialize_customizable_pool};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePermissionedPoolCtx<'info> {
+    /// CHECK: Pool creator
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: adds and removes allowlist entries of the pool
+    pub allowlist_authority: UncheckedAccount<'info>,
+
+    /// position_nft_mint
+    #[account(
+        init,
+        signer,
+        payer = payer,
+        mint::token_program = token_2022_program,
+        mint::decimals = 0,
+        mint::authority = pool_authority,
+        mint::freeze_authority = pool, // use pool, so we can filter all position_nft_mint given pool address
+        extensions::metadata_pointer::authority = pool_authority,
+        extensions::metadata_pointer::metadata_address = position_nft_mint,
+        extensions::close_authority::authority = pool_authority,
+    )]
+    pub position_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// position nft account
+    #[account(
+        init,
+        seeds = [POSITION_NFT_ACCOUNT_PREFIX.as_ref(), position_nft_mint.key().as_ref()],
+        token::mint = position_nft_mint,
+        token::authority = creator,
+        token::token_program = token_2022_program,
+        payer = payer,
+        bump,
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Address paying to create the pool. Can be anyone
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Initialize an account to store the pool state
+    #[account(
+        init,
+        seeds = [
+            PERMISSIONED_POOL_PREFIX.as_ref(),
+            &max_key(&token_a_mint.key(), &token_b_mint.key()),
+            &min_key(&token_a_mint.key(), &token_b_mint.key()),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + Pool::INIT_SPACE
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [
+            ALLOWLIST_CONFIG_PREFIX.as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + AllowlistConfig::INIT_SPACE
+    )]
+    pub allowlist_config: AccountLoader<'info, AllowlistConfig>,
+
+    #[account(
+        init,
+        seeds = [
+            POSITION_PREFIX.as_ref(),
+            position_nft_mint.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + Position::INIT_SPACE
+    )]
+    pub position: AccountLoader<'info, Position>,
+
+    /// Token a mint
+    #[account(
+        constraint = token_a_mint.key() != token_b_mint.key(),
+        mint::token_program = token_a_program,
+    )]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token b mint
+    #[account(
+        mint::token_program = token_b_program,
+    )]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token a vault for the pool
+    #[account(
+        init,
+        seeds = [
+            TOKEN_VAULT_PREFIX.as_ref(),
+            token_a_mint.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_a_program,
+        payer = payer,
+        bump,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token b vault for the pool
+    #[account(
+        init,
+        seeds = [
+            TOKEN_VAULT_PREFIX.as_ref(),
+            token_b_mint.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_b_program,
+        payer = payer,
+        bump,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// payer token a account
+    #[account(mut)]
+    pub payer_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// creator token b account
+    #[account(mut)]
+    pub payer_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program to create mint account and mint tokens
+    pub token_a_program: Interface<'info, TokenInterface>,
+    /// Program to create mint account and mint tokens
+    pub token_b_program: Interface<'info, TokenInterface>,
+
+    /// Program to create NFT mint/token account and transfer for token22 account
+    pub token_2022_program: Program<'info, Token2022>,
+
+    // Sysvar for program account
+    pub system_program: Program<'info, System>,
+}
+
+/// Same as `initialize_customizable_pool` with a separate pool address, only wallets allowlisted by the
+/// allowlist authority can swap, add liquidity and create positions
+pub fn handle_initialize_permissioned_pool<'info>(
+    ctx: Context<'info, InitializePermissionedPoolCtx<'info>>,
+    params: InitializeCustomizablePoolParameters,
+) -> Result<()> {
+    let accounts = InitializeCustomizablePoolAccounts {
+        creator: &ctx.accounts.creator,
+        position_nft_mint: &ctx.accounts.position_nft_mint,
+        position_nft_account: &ctx.accounts.position_nft_account,
+        payer: &ctx.accounts.payer,
+        pool_authority: &ctx.accounts.pool_authority,
+        pool: &ctx.accounts.pool,
+        position: &ctx.accounts.position,
+        token_a_mint: &ctx.accounts.token_a_mint,
+        token_b_mint: &ctx.accounts.token_b_mint,
+        token_a_vault: &ctx.accounts.token_a_vault,
+        token_b_vault: &ctx.accounts.token_b_vault,
+        payer_token_a: &ctx.accounts.payer_token_a,
+        payer_token_b: &ctx.accounts.payer_token_b,
+        token_a_program: &ctx.accounts.token_a_program,
+        token_b_program: &ctx.accounts.token_b_program,
+        token_2022_program: &ctx.accounts.token_2022_program,
+        system_program: &ctx.accounts.system_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    let (evt_create_position, evt_initialize_pool) =
+        process_initialize_customizable_pool(&accounts, params, PoolType::Permissioned)?;
+
+    let mut allowlist_config = ctx.accounts.allowlist_config.load_init()?;
+    allowlist_config.initialize(
+        ctx.accounts.pool.key(),
+        ctx.accounts.allowlist_authority.key(),
+    );
+
+    emit_cpi!(evt_create_position);
+    emit_cpi!(evt_initialize_pool);
+
+    Ok(())
+}
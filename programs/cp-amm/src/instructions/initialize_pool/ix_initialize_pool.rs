@@ -264,6 +264,7 @@ pub fn handle_initialize_pool<'info>(
         config.sqrt_max_price,
         sqrt_price,
         liquidity,
+        config.amplification_coefficient,
     )?;
 
     require!(
@@ -297,6 +298,7 @@ pub fn handle_initialize_pool<'info>(
         pool_type,
         token_a_amount,
         token_b_amount,
+        config.amplification_coefficient,
     );
 
     // init position
@@ -227,6 +227,7 @@ pub fn handle_initialize_pool_with_dynamic_config<'info>(
         sqrt_max_price,
         sqrt_price,
         liquidity,
+        0, // stable swap pool can only be created from static config
     )?;
 
     require!(
@@ -267,6 +268,7 @@ pub fn handle_initialize_pool_with_dynamic_config<'info>(
         pool_type,
         token_a_amount,
         token_b_amount,
+        0,
     );
 
     let mut position = ctx.accounts.position.load_init()?;
@@ -4,3 +4,5 @@ pub mod ix_initialize_customizable_pool;
 pub use ix_initialize_customizable_pool::*;
 pub mod ix_initialize_pool_with_dynamic_config;
 pub use ix_initialize_pool_with_dynamic_config::*;
+pub mod ix_initialize_permissioned_pool;
+pub use ix_initialize_permissioned_pool::*;
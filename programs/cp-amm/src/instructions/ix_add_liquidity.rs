@@ -2,11 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    get_pool_access_validator,
+    get_pool_access_validator_with_allowlist, is_wallet_allowlisted,
     state::{Pool, Position},
     token::{calculate_transfer_fee_included_amount, transfer_from_user},
     u128x128_math::Rounding,
-    EvtLiquidityChange, PoolError,
+    update_extended_rewards, write_oracle_observation_if_passed, EvtLiquidityChange, PoolError,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
@@ -71,8 +71,8 @@ pub struct AddLiquidityCtx<'info> {
     pub token_b_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_add_liquidity(
-    ctx: Context<AddLiquidityCtx>,
+pub fn handle_add_liquidity<'info>(
+    ctx: Context<'info, AddLiquidityCtx<'info>>,
     params: AddLiquidityParameters,
 ) -> Result<()> {
     let AddLiquidityParameters {
@@ -84,7 +84,13 @@ pub fn handle_add_liquidity(
 
     {
         let pool = ctx.accounts.pool.load()?;
-        let access_validator = get_pool_access_validator(&pool)?;
+        let is_allowlisted = is_wallet_allowlisted(
+            ctx.remaining_accounts,
+            ctx.accounts.pool.key(),
+            &pool,
+            ctx.accounts.owner.key(),
+        )?;
+        let access_validator = get_pool_access_validator_with_allowlist(&pool, is_allowlisted)?;
         require!(
             access_validator.can_add_liquidity(),
             PoolError::PoolDisabled
@@ -100,6 +106,13 @@ pub fn handle_add_liquidity(
     // update current pool reward & postion reward before any logic
     let current_time = Clock::get()?.unix_timestamp as u64;
     position.update_rewards(&mut pool, current_time)?;
+    update_extended_rewards(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[(ctx.accounts.position.key(), &position)],
+        current_time,
+    )?;
 
     let liquidity_handler = pool.get_liquidity_handler()?;
     let (token_a_amount, token_b_amount) =
@@ -110,6 +123,8 @@ pub fn handle_add_liquidity(
         PoolError::AmountIsZero
     );
 
+    pool.update_oracle_cumulatives(current_time)?;
+
     pool.apply_add_liquidity(
         &mut position,
         liquidity_delta,
@@ -117,6 +132,9 @@ pub fn handle_add_liquidity(
         token_b_amount,
     )?;
 
+    // oracle account is optional
+    write_oracle_observation_if_passed(ctx.remaining_accounts, ctx.accounts.pool.key(), &pool)?;
+
     let total_amount_a = calculate_transfer_fee_included_amount(
         &ctx.accounts
             .token_a_mint
@@ -0,0 +1,306 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    const_pda, get_pool_access_validator_with_allowlist, is_wallet_allowlisted,
+    params::swap::TradeDirection,
+    safe_math::{SafeCast, SafeMath},
+    state::{fee::FeeMode, CollectFeeMode, Pool, Position},
+    token::{
+        calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
+        transfer_from_pool, transfer_from_user,
+    },
+    u128x128_math::Rounding,
+    update_extended_rewards, write_oracle_observation_if_passed, EvtAddLiquiditySingleSided,
+    PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
+pub struct AddLiquiditySingleSidedParameters {
+    /// Amount of the deposit token swapped into the other token, excluding Token-2022 transfer fee
+    pub swap_amount_in: u64,
+    /// Maximum amount of the deposit token transferred from the user, including Token-2022 transfer fee
+    pub max_amount_in: u64,
+    /// Minimum liquidity delta added to the position
+    pub minimum_liquidity_delta: u128,
+    /// Deposit token, AtoB deposits token a, BtoA deposits token b. Refer [TradeDirection]
+    pub trade_direction: u8,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddLiquiditySingleSidedCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut, has_one = token_a_vault, has_one = token_b_vault, has_one = token_a_mint, has_one = token_b_mint)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+      mut,
+      has_one = pool,
+    )]
+    pub position: AccountLoader<'info, Position>,
+
+    /// The user token a account
+    #[account(mut)]
+    pub token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token b account
+    #[account(mut)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for input token
+    #[account(mut, token::token_program = token_a_program, token::mint = token_a_mint)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    #[account(mut, token::token_program = token_b_program, token::mint = token_b_mint)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token a
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token b
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token account for nft
+    #[account(
+            constraint = position_nft_account.mint == position.load()?.nft_mint,
+            constraint = position_nft_account.amount == 1,
+            token::authority = owner
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// owner of position
+    pub owner: Signer<'info>,
+
+    /// Token a program
+    pub token_a_program: Interface<'info, TokenInterface>,
+
+    /// Token b program
+    pub token_b_program: Interface<'info, TokenInterface>,
+}
+
+/// Swap `swap_amount_in` of the deposit token through the pool, then deposit the rest of the deposit token
+/// together with the swap output. Swap output which can't be deposited is refunded to the user
+pub fn handle_add_liquidity_single_sided<'info>(
+    ctx: Context<'info, AddLiquiditySingleSidedCtx<'info>>,
+    params: AddLiquiditySingleSidedParameters,
+) -> Result<()> {
+    let AddLiquiditySingleSidedParameters {
+        swap_amount_in,
+        max_amount_in,
+        minimum_liquidity_delta,
+        trade_direction,
+    } = params;
+    let trade_direction =
+        TradeDirection::try_from(trade_direction).map_err(|_| PoolError::InvalidInput)?;
+    require!(swap_amount_in > 0, PoolError::AmountIsZero);
+
+    {
+        let pool = ctx.accounts.pool.load()?;
+        let is_allowlisted = is_wallet_allowlisted(
+            ctx.remaining_accounts,
+            ctx.accounts.pool.key(),
+            &pool,
+            ctx.accounts.owner.key(),
+        )?;
+        let access_validator = get_pool_access_validator_with_allowlist(&pool, is_allowlisted)?;
+        require!(
+            access_validator.can_add_liquidity(),
+            PoolError::PoolDisabled
+        );
+        require!(
+            access_validator.can_swap(&ctx.accounts.owner.key()),
+            PoolError::PoolDisabled
+        );
+    }
+
+    let (
+        token_in_mint,
+        token_out_mint,
+        input_token_account,
+        output_token_account,
+        input_vault_account,
+        output_vault_account,
+        input_program,
+        output_program,
+    ) = match trade_direction {
+        TradeDirection::AtoB => (
+            &ctx.accounts.token_a_mint,
+            &ctx.accounts.token_b_mint,
+            &ctx.accounts.token_a_account,
+            &ctx.accounts.token_b_account,
+            &ctx.accounts.token_a_vault,
+            &ctx.accounts.token_b_vault,
+            &ctx.accounts.token_a_program,
+            &ctx.accounts.token_b_program,
+        ),
+        TradeDirection::BtoA => (
+            &ctx.accounts.token_b_mint,
+            &ctx.accounts.token_a_mint,
+            &ctx.accounts.token_b_account,
+            &ctx.accounts.token_a_account,
+            &ctx.accounts.token_b_vault,
+            &ctx.accounts.token_a_vault,
+            &ctx.accounts.token_b_program,
+            &ctx.accounts.token_a_program,
+        ),
+    };
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    pool.update_layout_version_if_needed()?;
+
+    let mut position = ctx.accounts.position.load_mut()?;
+
+    // update current pool reward & postion reward before any logic
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time)?;
+    update_extended_rewards(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[(ctx.accounts.position.key(), &position)],
+        current_time,
+    )?;
+
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+
+    // single swap instruction can't be validated here, so the internal swap is disabled while rate limiter is applied
+    if let Ok(rate_limiter) = pool.pool_fees.base_fee.to_fee_rate_limiter() {
+        require!(
+            !rate_limiter.is_rate_limiter_applied(
+                current_point,
+                pool.activation_point,
+                trade_direction,
+            )?,
+            PoolError::SingleSidedAddLiquidityRateLimited
+        );
+    }
+
+    // update for dynamic fee reference
+    pool.update_pre_swap(current_time)?;
+
+    let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast()?;
+    let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+    let mut swap_result = pool.get_swap_result_from_exact_input(
+        swap_amount_in,
+        &fee_mode,
+        trade_direction,
+        current_point,
+    )?;
+
+    // also accumulates the oracle with the price before the internal swap, the deposit doesn't move the price
+    pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, current_time)?;
+
+    // re-update next_sqrt_price for compounding pool
+    swap_result.next_sqrt_price = pool.sqrt_price;
+
+    // swap output never leaves the vault, so it's not charged transfer fee
+    let excluded_transfer_fee_max_amount_in = calculate_transfer_fee_excluded_amount(
+        &token_in_mint.to_account_info().try_borrow_data()?,
+        max_amount_in,
+    )?
+    .amount;
+    let available_amount_in = excluded_transfer_fee_max_amount_in
+        .checked_sub(swap_amount_in)
+        .ok_or(PoolError::ExceededSlippage)?;
+    let available_amount_out = swap_result.output_amount;
+
+    let (available_token_a_amount, available_token_b_amount) = match trade_direction {
+        TradeDirection::AtoB => (available_amount_in, available_amount_out),
+        TradeDirection::BtoA => (available_amount_out, available_amount_in),
+    };
+
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let liquidity_delta = liquidity_handler
+        .get_max_liquidity_delta_from_amounts(available_token_a_amount, available_token_b_amount)?;
+    require!(liquidity_delta > 0, PoolError::AmountIsZero);
+    require!(
+        liquidity_delta >= minimum_liquidity_delta,
+        PoolError::ExceededSlippage
+    );
+
+    let (token_a_amount, token_b_amount) =
+        liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)?;
+    // Shall not happen
+    require!(
+        token_a_amount <= available_token_a_amount && token_b_amount <= available_token_b_amount,
+        PoolError::MathOverflow
+    );
+
+    pool.apply_add_liquidity(
+        &mut position,
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+    )?;
+
+    // oracle account is optional
+    write_oracle_observation_if_passed(ctx.remaining_accounts, ctx.accounts.pool.key(), &pool)?;
+
+    let (deposit_amount_in, deposit_amount_out) = match trade_direction {
+        TradeDirection::AtoB => (token_a_amount, token_b_amount),
+        TradeDirection::BtoA => (token_b_amount, token_a_amount),
+    };
+    let refund_amount_out = available_amount_out.safe_sub(deposit_amount_out)?;
+
+    let included_transfer_fee_amount_in = calculate_transfer_fee_included_amount(
+        &token_in_mint.to_account_info().try_borrow_data()?,
+        swap_amount_in.safe_add(deposit_amount_in)?,
+    )?
+    .amount;
+    require!(
+        included_transfer_fee_amount_in <= max_amount_in,
+        PoolError::ExceededSlippage
+    );
+
+    let (reserve_a_amount, reserve_b_amount) = (pool.token_a_amount, pool.token_b_amount);
+    drop(pool);
+    drop(position);
+
+    transfer_from_user(
+        &ctx.accounts.owner,
+        token_in_mint,
+        input_token_account,
+        input_vault_account,
+        input_program,
+        included_transfer_fee_amount_in,
+    )?;
+
+    if refund_amount_out > 0 {
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            token_out_mint,
+            output_vault_account,
+            &output_token_account.to_account_info(),
+            output_program,
+            refund_amount_out,
+        )?;
+    }
+
+    emit_cpi!(EvtAddLiquiditySingleSided {
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        owner: ctx.accounts.owner.key(),
+        params,
+        swap_result,
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        included_transfer_fee_amount_in,
+        refund_amount_out,
+        current_timestamp: current_time,
+        reserve_a_amount,
+        reserve_b_amount,
+    });
+
+    Ok(())
+}
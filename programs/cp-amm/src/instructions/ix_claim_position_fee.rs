@@ -3,9 +3,9 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     const_pda,
-    state::{Pool, Position},
+    state::{Pool, Position, PositionDelegatePermission},
     token::transfer_from_pool,
-    EvtClaimPositionFee,
+    validate_position_authority, EvtClaimPositionFee, PoolError,
 };
 
 #[event_cpi]
@@ -52,15 +52,14 @@ pub struct ClaimPositionFeeCtx<'info> {
     /// The mint of token b
     pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// The token account for nft
+    /// The token account for nft, its authority is validated against the owner or the position delegate
     #[account(
             constraint = position_nft_account.mint == position.load()?.nft_mint,
             constraint = position_nft_account.amount == 1,
-            token::authority = owner
     )]
     pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// owner of position
+    /// owner of position, or its delegate with the position delegate account as the first remaining account
     pub owner: Signer<'info>,
 
     /// Token a program
@@ -70,7 +69,24 @@ pub struct ClaimPositionFeeCtx<'info> {
     pub token_b_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_claim_position_fee(ctx: Context<ClaimPositionFeeCtx>) -> Result<()> {
+pub fn handle_claim_position_fee<'info>(
+    ctx: Context<'info, ClaimPositionFeeCtx<'info>>,
+) -> Result<()> {
+    let (payout_destination, _) = validate_position_authority(
+        ctx.accounts.position.key(),
+        &ctx.accounts.position_nft_account,
+        ctx.accounts.owner.key(),
+        ctx.remaining_accounts,
+        PositionDelegatePermission::ClaimPositionFee,
+    )?;
+    if let Some(payout_destination) = payout_destination {
+        require!(
+            ctx.accounts.token_a_account.owner == payout_destination
+                && ctx.accounts.token_b_account.owner == payout_destination,
+            PoolError::InvalidPayoutDestination
+        );
+    }
+
     let mut position = ctx.accounts.position.load_mut()?;
 
     let pool = ctx.accounts.pool.load()?;
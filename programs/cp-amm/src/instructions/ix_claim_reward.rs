@@ -3,11 +3,15 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     const_pda,
-    constants::NUM_REWARDS,
     error::PoolError,
     event::EvtClaimReward,
-    state::{pool::Pool, position::Position},
+    find_pool_reward_extension_for_reward, find_position_reward_extension,
+    state::{
+        get_extended_reward_index, get_reward_info_mut, pool::Pool, position::Position,
+        PositionDelegatePermission, RewardInfo,
+    },
     token::transfer_from_pool,
+    validate_position_authority,
 };
 
 #[event_cpi]
@@ -36,26 +40,21 @@ pub struct ClaimRewardCtx<'info> {
     #[account(mut)]
     pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// The token account for nft
+    /// The token account for nft, its authority is validated against the owner or the position delegate
     #[account(
             constraint = position_nft_account.mint == position.load()?.nft_mint,
             constraint = position_nft_account.amount == 1,
-            token::authority = owner
     )]
     pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// owner of position
+    /// owner of position, or its delegate with the position delegate account as the first remaining account
     pub owner: Signer<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl<'info> ClaimRewardCtx<'info> {
-    fn validate(&self, reward_index: usize) -> Result<()> {
-        let pool = self.pool.load()?;
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
-
-        let reward_info = &pool.reward_infos[reward_index];
+    fn validate(&self, reward_info: &RewardInfo) -> Result<()> {
         require!(reward_info.initialized(), PoolError::RewardUninitialized);
         require!(
             reward_info.vault.eq(&self.reward_vault.key()),
@@ -66,15 +65,47 @@ impl<'info> ClaimRewardCtx<'info> {
     }
 }
 
-pub fn handle_claim_reward(
-    ctx: Context<ClaimRewardCtx>,
+pub fn handle_claim_reward<'info>(
+    ctx: Context<'info, ClaimRewardCtx<'info>>,
     reward_index: u8,
     skip_reward: u8,
 ) -> Result<()> {
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
-    ctx.accounts.validate(index)?;
+
+    // extended rewards are stored in the pool and position reward extensions
+    let pool_reward_extension_loader = find_pool_reward_extension_for_reward(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        index,
+    )?;
+    let mut pool_reward_extension = pool_reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+    {
+        let mut pool = ctx.accounts.pool.load_mut()?;
+        ctx.accounts.validate(get_reward_info_mut(
+            &mut pool,
+            pool_reward_extension.as_deref_mut(),
+            index,
+        )?)?;
+    }
+
+    let (payout_destination, _) = validate_position_authority(
+        ctx.accounts.position.key(),
+        &ctx.accounts.position_nft_account,
+        ctx.accounts.owner.key(),
+        ctx.remaining_accounts,
+        PositionDelegatePermission::ClaimReward,
+    )?;
+    if let Some(payout_destination) = payout_destination {
+        require!(
+            ctx.accounts.user_token_account.owner == payout_destination,
+            PoolError::InvalidPayoutDestination
+        );
+    }
 
     let mut position = ctx.accounts.position.load_mut()?;
 
@@ -85,7 +116,25 @@ pub fn handle_claim_reward(
     position.update_rewards(&mut pool, current_time)?;
 
     // get all pending reward
-    let total_reward = position.claim_reward(index)?;
+    let total_reward = match get_extended_reward_index(index)? {
+        Some(extended_reward_index) => {
+            let pool_reward_extension = pool_reward_extension
+                .as_deref_mut()
+                .ok_or(PoolError::MissingRewardExtension)?;
+            pool_reward_extension.update_rewards(pool.liquidity, current_time)?;
+
+            let position_reward_extension_loader = find_position_reward_extension(
+                ctx.remaining_accounts,
+                ctx.accounts.position.key(),
+            )?
+            .ok_or(PoolError::MissingRewardExtension)?;
+            let mut position_reward_extension = position_reward_extension_loader.load_mut()?;
+            position_reward_extension
+                .update_position_reward(position.get_total_liquidity()?, pool_reward_extension)?;
+            position_reward_extension.claim_reward(extended_reward_index)?
+        }
+        None => position.claim_reward(index)?,
+    };
 
     // transfer rewards to user
     if total_reward > 0 {
@@ -5,7 +5,7 @@ use anchor_spl::{
 };
 
 use crate::{
-    const_pda,
+    const_pda, find_position_reward_extension,
     state::{Pool, Position},
     EvtClosePosition, PoolError,
 };
@@ -51,11 +51,28 @@ pub struct ClosePositionCtx<'info> {
     pub token_program: Program<'info, Token2022>,
 }
 
-pub fn handle_close_position(ctx: Context<ClosePositionCtx>) -> Result<()> {
+pub fn handle_close_position<'info>(ctx: Context<'info, ClosePositionCtx<'info>>) -> Result<()> {
     let position = ctx.accounts.position.load()?;
     require!(position.is_empty()?, PoolError::PositionIsNotEmpty);
 
     let mut pool = ctx.accounts.pool.load_mut()?;
+
+    // position reward extension is only required once an extended reward is initialized, so pending extended rewards
+    // can't be left behind
+    let position_reward_extension =
+        find_position_reward_extension(ctx.remaining_accounts, ctx.accounts.position.key())?;
+    require!(
+        position_reward_extension.is_some() || !pool.is_extended_reward_initialized(),
+        PoolError::MissingRewardExtension
+    );
+    if let Some(position_reward_extension) = position_reward_extension {
+        require!(
+            position_reward_extension.load()?.is_empty(),
+            PoolError::PositionRewardExtensionNotEmpty
+        );
+        position_reward_extension.close(ctx.accounts.rent_receiver.to_account_info())?;
+    }
+
     pool.metrics.reduce_position();
 
     // burn
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    activation_handler::ActivationHandler,
+    get_pool_access_validator_with_allowlist, is_wallet_allowlisted,
+    params::swap::TradeDirection,
+    safe_math::SafeCast,
+    state::{fee::FeeMode, CollectFeeMode, Pool, Position, PositionDelegatePermission},
+    u128x128_math::Rounding,
+    update_extended_rewards, validate_position_authority, write_oracle_observation_if_passed,
+    EvtCompoundPositionFee, PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
+pub struct CompoundPositionFeeParameters {
+    /// Amount of pending fee swapped through the pool before depositing, zero to skip the swap
+    pub swap_amount_in: u64,
+    /// Pending fee token swapped, AtoB swaps token a fee into token b, BtoA swaps token b fee into token a. Refer [TradeDirection]
+    pub trade_direction: u8,
+    /// Minimum liquidity delta added to the position
+    pub minimum_liquidity_delta: u128,
+}
+
+/// Pending fees never leave the vaults, so no token account is required
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CompoundPositionFeeCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+      mut,
+      has_one = pool,
+    )]
+    pub position: AccountLoader<'info, Position>,
+
+    /// The token account for nft, its authority is validated against the owner or the position delegate
+    #[account(
+            constraint = position_nft_account.mint == position.load()?.nft_mint,
+            constraint = position_nft_account.amount == 1,
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// owner of position, or its delegate with the position delegate account as the first remaining account
+    pub owner: Signer<'info>,
+}
+
+/// Deposit pending fees of the position back into the position as unlocked liquidity.
+/// The swap rebalances the pending fees to the pool ratio, pending fees which can't be deposited are kept pending
+pub fn handle_compound_position_fee<'info>(
+    ctx: Context<'info, CompoundPositionFeeCtx<'info>>,
+    params: CompoundPositionFeeParameters,
+) -> Result<()> {
+    let CompoundPositionFeeParameters {
+        swap_amount_in,
+        trade_direction,
+        minimum_liquidity_delta,
+    } = params;
+    let trade_direction =
+        TradeDirection::try_from(trade_direction).map_err(|_| PoolError::InvalidInput)?;
+
+    // compounded fees stay in the position, so the payout destination of the delegate doesn't apply
+    let (_, remaining_accounts) = validate_position_authority(
+        ctx.accounts.position.key(),
+        &ctx.accounts.position_nft_account,
+        ctx.accounts.owner.key(),
+        ctx.remaining_accounts,
+        PositionDelegatePermission::CompoundPositionFee,
+    )?;
+
+    {
+        let pool = ctx.accounts.pool.load()?;
+        let is_allowlisted = is_wallet_allowlisted(
+            ctx.remaining_accounts,
+            ctx.accounts.pool.key(),
+            &pool,
+            ctx.accounts.position_nft_account.owner,
+        )?;
+        let access_validator = get_pool_access_validator_with_allowlist(&pool, is_allowlisted)?;
+        require!(
+            access_validator.can_add_liquidity(),
+            PoolError::PoolDisabled
+        );
+        if swap_amount_in > 0 {
+            require!(
+                access_validator.can_swap(&ctx.accounts.owner.key()),
+                PoolError::PoolDisabled
+            );
+        }
+    }
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    pool.update_layout_version_if_needed()?;
+
+    let mut position = ctx.accounts.position.load_mut()?;
+
+    // update current pool reward & postion reward before any logic
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time)?;
+    update_extended_rewards(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[(ctx.accounts.position.key(), &position)],
+        current_time,
+    )?;
+
+    position.update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())?;
+
+    let swap_result = if swap_amount_in > 0 {
+        let fee_pending = match trade_direction {
+            TradeDirection::AtoB => position.fee_a_pending,
+            TradeDirection::BtoA => position.fee_b_pending,
+        };
+        require!(swap_amount_in <= fee_pending, PoolError::InvalidInput);
+
+        let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+
+        // single swap instruction can't be validated here, so the internal swap is disabled while rate limiter is applied
+        if let Ok(rate_limiter) = pool.pool_fees.base_fee.to_fee_rate_limiter() {
+            require!(
+                !rate_limiter.is_rate_limiter_applied(
+                    current_point,
+                    pool.activation_point,
+                    trade_direction,
+                )?,
+                PoolError::CompoundPositionFeeRateLimited
+            );
+        }
+
+        // update for dynamic fee reference
+        pool.update_pre_swap(current_time)?;
+
+        let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast()?;
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+        let mut swap_result = pool.get_swap_result_from_exact_input(
+            swap_amount_in,
+            &fee_mode,
+            trade_direction,
+            current_point,
+        )?;
+
+        pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, current_time)?;
+
+        // re-update next_sqrt_price for compounding pool
+        swap_result.next_sqrt_price = pool.sqrt_price;
+
+        // swap input is taken from pending fee and swap output is kept as pending fee of the position
+        match trade_direction {
+            TradeDirection::AtoB => {
+                position.remove_fee_pending(swap_amount_in, 0)?;
+                position.add_fee_pending(0, swap_result.output_amount)?;
+            }
+            TradeDirection::BtoA => {
+                position.remove_fee_pending(0, swap_amount_in)?;
+                position.add_fee_pending(swap_result.output_amount, 0)?;
+            }
+        }
+
+        Some(swap_result)
+    } else {
+        None
+    };
+
+    // position share of the swap fee is compounded as well
+    position.update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())?;
+
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let liquidity_delta = liquidity_handler
+        .get_max_liquidity_delta_from_amounts(position.fee_a_pending, position.fee_b_pending)?;
+    require!(liquidity_delta > 0, PoolError::AmountIsZero);
+    require!(
+        liquidity_delta >= minimum_liquidity_delta,
+        PoolError::ExceededSlippage
+    );
+
+    let (token_a_amount, token_b_amount) =
+        liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)?;
+
+    pool.update_oracle_cumulatives(current_time)?;
+
+    pool.apply_add_liquidity(
+        &mut position,
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+    )?;
+
+    position.compound_pending_fee(token_a_amount, token_b_amount)?;
+
+    // oracle account is optional
+    write_oracle_observation_if_passed(remaining_accounts, ctx.accounts.pool.key(), &pool)?;
+
+    emit_cpi!(EvtCompoundPositionFee {
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        owner: ctx.accounts.owner.key(),
+        params,
+        swap_result,
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        fee_a_pending: position.fee_a_pending,
+        fee_b_pending: position.fee_b_pending,
+        current_timestamp: current_time,
+        reserve_a_amount: pool.token_a_amount,
+        reserve_b_amount: pool.token_b_amount,
+    });
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use anchor_spl::{
 use crate::{
     const_pda,
     constants::seeds::{POSITION_NFT_ACCOUNT_PREFIX, POSITION_PREFIX},
-    get_pool_access_validator,
+    get_pool_access_validator_with_allowlist, is_wallet_allowlisted,
     state::{Pool, Position},
     token::update_account_lamports_to_minimum_balance,
     EvtCreatePosition, PoolError,
@@ -78,7 +78,13 @@ pub struct CreatePositionCtx<'info> {
 pub fn handle_create_position(ctx: Context<CreatePositionCtx>) -> Result<()> {
     {
         let pool = ctx.accounts.pool.load()?;
-        let access_validator = get_pool_access_validator(&pool)?;
+        let is_allowlisted = is_wallet_allowlisted(
+            ctx.remaining_accounts,
+            ctx.accounts.pool.key(),
+            &pool,
+            ctx.accounts.owner.key(),
+        )?;
+        let access_validator = get_pool_access_validator_with_allowlist(&pool, is_allowlisted)?;
         require!(
             access_validator.can_create_position(),
             PoolError::PoolDisabled
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    safe_math::SafeMath,
+    state::{Pool, ProtocolFeeDistribution},
+    token::{calculate_transfer_fee_excluded_amount, transfer_from_pool},
+    EvtDistributeProtocolFee, PoolError,
+};
+
+/// Accounts for distributing protocol fees, the receiver token accounts are passed as remaining accounts in the
+/// order of the receivers
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DistributeProtocolFeeCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(has_one = pool)]
+    pub protocol_fee_distribution: AccountLoader<'info, ProtocolFeeDistribution>,
+
+    #[account(mut)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+fn get_distribute_direction_and_validate_accounts(
+    pool: &Pool,
+    token_vault: &InterfaceAccount<TokenAccount>,
+    token_mint: &InterfaceAccount<Mint>,
+    token_program: &Interface<TokenInterface>,
+) -> Result<bool> {
+    let is_distributing_token_a = token_mint.key() == pool.token_a_mint;
+    let token_vault_key = if is_distributing_token_a {
+        pool.token_a_vault
+    } else {
+        require!(
+            token_mint.key() == pool.token_b_mint,
+            PoolError::InvalidClaimProtocolFeeAccounts
+        );
+        pool.token_b_vault
+    };
+    require!(
+        token_vault.key() == token_vault_key,
+        PoolError::InvalidClaimProtocolFeeAccounts
+    );
+
+    let token_mint_ai = token_mint.to_account_info();
+    require!(
+        *token_mint_ai.owner == token_program.key(),
+        PoolError::InvalidClaimProtocolFeeAccounts
+    );
+
+    Ok(is_distributing_token_a)
+}
+
+/// Permissionless, split up to `max_amount` of the pool protocol fee of one token between the receivers. Receiver
+/// amounts which are zero after the transfer fee are kept as protocol fee
+pub fn handle_distribute_protocol_fee<'info>(
+    ctx: Context<'info, DistributeProtocolFeeCtx<'info>>,
+    max_amount: u64,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let is_distributing_token_a = get_distribute_direction_and_validate_accounts(
+        &pool,
+        &ctx.accounts.token_vault,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+    )?;
+
+    let amount = if is_distributing_token_a {
+        let (amount_a, _) = pool.claim_protocol_fee(max_amount, 0)?;
+        amount_a
+    } else {
+        let (_, amount_b) = pool.claim_protocol_fee(0, max_amount)?;
+        amount_b
+    };
+    require!(amount > 0, PoolError::AmountIsZero);
+
+    let protocol_fee_distribution = ctx.accounts.protocol_fee_distribution.load()?;
+    let receivers = protocol_fee_distribution.get_receivers();
+    require!(
+        ctx.remaining_accounts.len() >= receivers.len(),
+        PoolError::InvalidProtocolFeeReceiverAccount
+    );
+
+    let mut receiver_amounts = protocol_fee_distribution.get_receiver_amounts(amount)?;
+    let mut transfer_fee_excluded_amounts = Vec::with_capacity(receivers.len());
+    let mut undistributed_amount = 0u64;
+    for ((receiver, receiver_token_account), receiver_amount) in receivers
+        .iter()
+        .zip(ctx.remaining_accounts)
+        .zip(receiver_amounts.iter_mut())
+    {
+        let receiver_token: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(receiver_token_account)?;
+        require!(
+            receiver_token.owner == receiver.wallet
+                && receiver_token.mint == ctx.accounts.token_mint.key(),
+            PoolError::InvalidProtocolFeeReceiverAccount
+        );
+
+        let transfer_fee_excluded_amount = if *receiver_amount > 0 {
+            calculate_transfer_fee_excluded_amount(
+                &ctx.accounts
+                    .token_mint
+                    .to_account_info()
+                    .try_borrow_data()?,
+                *receiver_amount,
+            )?
+            .amount
+        } else {
+            0
+        };
+
+        if transfer_fee_excluded_amount == 0 {
+            undistributed_amount = undistributed_amount.safe_add(*receiver_amount)?;
+            *receiver_amount = 0;
+        } else {
+            transfer_from_pool(
+                ctx.accounts.pool_authority.to_account_info(),
+                &ctx.accounts.token_mint,
+                &ctx.accounts.token_vault,
+                receiver_token_account,
+                &ctx.accounts.token_program,
+                *receiver_amount,
+            )?;
+        }
+        transfer_fee_excluded_amounts.push(transfer_fee_excluded_amount);
+    }
+
+    // keep the dust as protocol fee
+    if is_distributing_token_a {
+        pool.protocol_a_fee = pool.protocol_a_fee.safe_add(undistributed_amount)?;
+    } else {
+        pool.protocol_b_fee = pool.protocol_b_fee.safe_add(undistributed_amount)?;
+    }
+
+    emit_cpi!(EvtDistributeProtocolFee {
+        pool: ctx.accounts.pool.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        amount: amount.safe_sub(undistributed_amount)?,
+        receiver_amounts,
+        transfer_fee_excluded_amounts,
+    });
+
+    Ok(())
+}
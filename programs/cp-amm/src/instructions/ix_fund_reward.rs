@@ -2,10 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    constants::{NUM_REWARDS, REWARD_RATE_SCALE},
+    constants::REWARD_RATE_SCALE,
     event::EvtFundReward,
+    find_pool_reward_extension_for_reward,
     math::safe_math::SafeMath,
-    state::Pool,
+    state::{get_reward_info_mut, Pool, RewardInfo},
     token::{calculate_transfer_fee_excluded_amount, transfer_from_user},
     utils_math::safe_mul_shr_cast,
     PoolError,
@@ -31,12 +32,7 @@ pub struct FundRewardCtx<'info> {
 }
 
 impl<'info> FundRewardCtx<'info> {
-    fn validate(&self, reward_index: usize) -> Result<()> {
-        let pool = self.pool.load()?;
-
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
-
-        let reward_info = &pool.reward_infos[reward_index];
+    fn validate(&self, reward_info: &RewardInfo) -> Result<()> {
         require!(reward_info.initialized(), PoolError::RewardUninitialized);
         require!(
             reward_info.vault.eq(&self.reward_vault.key()),
@@ -51,8 +47,8 @@ impl<'info> FundRewardCtx<'info> {
     }
 }
 
-pub fn handle_fund_reward(
-    ctx: Context<FundRewardCtx>,
+pub fn handle_fund_reward<'info>(
+    ctx: Context<'info, FundRewardCtx<'info>>,
     reward_index: u8,
     amount: u64,
     carry_forward: bool,
@@ -60,7 +56,24 @@ pub fn handle_fund_reward(
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
-    ctx.accounts.validate(index)?;
+
+    // extended rewards are stored in the pool reward extension
+    let pool_reward_extension_loader = find_pool_reward_extension_for_reward(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        index,
+    )?;
+    let mut pool_reward_extension = pool_reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    ctx.accounts.validate(get_reward_info_mut(
+        &mut pool,
+        pool_reward_extension.as_deref_mut(),
+        index,
+    )?)?;
 
     // actual amount need to transfer
     let transfer_fee_excluded_amount_in = calculate_transfer_fee_excluded_amount(
@@ -74,13 +87,15 @@ pub fn handle_fund_reward(
 
     require!(transfer_fee_excluded_amount_in > 0, PoolError::AmountIsZero);
 
-    let mut pool = ctx.accounts.pool.load_mut()?;
     let current_time = Clock::get()?.unix_timestamp;
     // 1. update pool rewards
     pool.update_rewards(current_time as u64)?;
+    if let Some(pool_reward_extension) = pool_reward_extension.as_deref_mut() {
+        pool_reward_extension.update_rewards(pool.liquidity, current_time as u64)?;
+    }
 
     // 2. set new farming rate
-    let reward_info = &mut pool.reward_infos[index];
+    let reward_info = get_reward_info_mut(&mut pool, pool_reward_extension.as_deref_mut(), index)?;
     let pre_reward_rate = reward_info.reward_rate;
 
     let total_amount = if carry_forward {
@@ -12,6 +12,7 @@ use anchor_spl::token_interface::TokenAccount;
 #[derive(Accounts)]
 #[instruction(params: VestingParameters)]
 pub struct LockInnerPositionCtx<'info> {
+    #[account(mut)]
     pub pool: AccountLoader<'info, Pool>,
 
     #[account(mut, has_one = pool)]
@@ -34,10 +35,15 @@ pub fn handle_lock_inner_position(
     params: VestingParameters,
 ) -> Result<()> {
     let mut position = ctx.accounts.position.load_mut()?;
-    let pool = ctx.accounts.pool.load()?;
-    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
-    // refresh inner vesting firstly to retrieve the latest state of unlocked liquidity
-    position.refresh_inner_vesting(current_point)?;
+    {
+        let mut pool = ctx.accounts.pool.load_mut()?;
+        let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+        // update rewards before vested liquidity changes, it may be boosted
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        position.update_rewards(&mut pool, current_time)?;
+        // refresh inner vesting firstly to retrieve the latest state of unlocked liquidity
+        pool.refresh_position_inner_vesting(&mut position, current_point)?;
+    }
 
     require!(
         position.inner_vesting.is_empty(),
@@ -50,6 +56,10 @@ pub fn handle_lock_inner_position(
     } = process_initialize_inner_vesting(&params, &ctx.accounts.pool, &mut position.inner_vesting)?;
 
     position.lock(total_lock_liquidity)?;
+    ctx.accounts
+        .pool
+        .load_mut()?
+        .update_vested_liquidity(0, total_lock_liquidity)?;
 
     emit_cpi!(EvtLockPosition {
         position: ctx.accounts.position.key(),
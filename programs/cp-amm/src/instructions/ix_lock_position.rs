@@ -73,6 +73,7 @@ impl VestingParameters {
 #[derive(Accounts)]
 #[instruction(params: VestingParameters)]
 pub struct LockPositionCtx<'info> {
+    #[account(mut)]
     pub pool: AccountLoader<'info, Pool>,
 
     #[account(mut, has_one = pool)]
@@ -115,12 +116,16 @@ pub fn handle_lock_position(
     } = process_initialize_inner_vesting(&params, &ctx.accounts.pool, &mut vesting.inner_vesting)?;
 
     let mut position = ctx.accounts.position.load_mut()?;
-    let pool = ctx.accounts.pool.load()?;
+    let mut pool = ctx.accounts.pool.load_mut()?;
     let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    // update rewards before vested liquidity changes, it may be boosted
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time)?;
     // refresh inner vesting firstly to retrieve the latest state of unlocked liquidity
-    position.refresh_inner_vesting(current_point)?;
+    pool.refresh_position_inner_vesting(&mut position, current_point)?;
     // lock position
     position.lock(total_lock_liquidity)?;
+    pool.update_vested_liquidity(0, total_lock_liquidity)?;
 
     emit_cpi!(EvtLockPosition {
         position: ctx.accounts.position.key(),
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    const_pda, find_position_reward_extension, get_pool_access_validator,
+    safe_math::SafeMath,
+    state::{Pool, Position},
+    update_extended_rewards, EvtMergePosition, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MergePositionCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    /// The position to merge into
+    #[account(
+        mut,
+        has_one = pool,
+        constraint = position.key() != source_position.key() @ PoolError::SamePosition,
+    )]
+    pub position: AccountLoader<'info, Position>,
+
+    /// The token account for position nft
+    #[account(
+        constraint = position_nft_account.mint == position.load()?.nft_mint,
+        constraint = position_nft_account.amount == 1,
+        token::authority = owner
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The position to be merged and closed
+    #[account(
+        mut,
+        has_one = pool,
+        close = rent_receiver
+    )]
+    pub source_position: AccountLoader<'info, Position>,
+
+    /// source_position_nft_mint
+    #[account(mut, address = source_position.load()?.nft_mint)]
+    pub source_position_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token account for source position nft
+    #[account(
+        mut,
+        constraint = source_position_nft_account.mint == source_position.load()?.nft_mint,
+        constraint = source_position_nft_account.amount == 1,
+        token::authority = owner
+    )]
+    pub source_position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// CHECK: rent receiver
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Owner of both positions
+    pub owner: Signer<'info>,
+
+    /// Program to burn and close the source position NFT
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handle_merge_position<'info>(ctx: Context<'info, MergePositionCtx<'info>>) -> Result<()> {
+    {
+        let pool = ctx.accounts.pool.load()?;
+        let access_validator = get_pool_access_validator(&pool)?;
+        require!(
+            access_validator.can_merge_position(),
+            PoolError::PoolDisabled
+        );
+    }
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let mut position = ctx.accounts.position.load_mut()?;
+    let mut source_position = ctx.accounts.source_position.load_mut()?;
+
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    // update current pool reward
+    pool.update_rewards(current_time)?;
+    // update both positions reward
+    position.update_position_reward(&pool)?;
+    source_position.update_position_reward(&pool)?;
+    update_extended_rewards(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[
+            (ctx.accounts.position.key(), &position),
+            (ctx.accounts.source_position.key(), &source_position),
+        ],
+        current_time,
+    )?;
+
+    let vested_liquidity = position
+        .vested_liquidity
+        .safe_add(source_position.vested_liquidity)?;
+    position.refresh_inner_vesting(current_point)?;
+    source_position.refresh_inner_vesting(current_point)?;
+
+    let amount_merged = pool.apply_merge_position(&mut position, &mut source_position)?;
+    pool.update_vested_liquidity(vested_liquidity, position.vested_liquidity)?;
+    require!(source_position.is_empty()?, PoolError::PositionIsNotEmpty);
+
+    // move extended reward pendings and close the source position reward extension
+    if let Some(source_position_reward_extension) =
+        find_position_reward_extension(ctx.remaining_accounts, ctx.accounts.source_position.key())?
+    {
+        let position_reward_extension =
+            find_position_reward_extension(ctx.remaining_accounts, ctx.accounts.position.key())?
+                .ok_or(PoolError::MissingRewardExtension)?;
+        position_reward_extension
+            .load_mut()?
+            .merge(&mut *source_position_reward_extension.load_mut()?)?;
+        source_position_reward_extension.close(ctx.accounts.rent_receiver.to_account_info())?;
+    }
+
+    pool.metrics.reduce_position();
+
+    // burn
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.key(),
+            token_2022::Burn {
+                mint: ctx.accounts.source_position_nft_mint.to_account_info(),
+                from: ctx.accounts.source_position_nft_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    // close source_position_nft_account
+    token_2022::close_account(CpiContext::new(
+        ctx.accounts.token_program.key(),
+        token_2022::CloseAccount {
+            account: ctx.accounts.source_position_nft_account.to_account_info(),
+            destination: ctx.accounts.rent_receiver.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        },
+    ))?;
+
+    // close source_position_nft_mint
+    let signer_seeds = pool_authority_seeds!();
+    token_2022::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.key(),
+        token_2022::CloseAccount {
+            account: ctx.accounts.source_position_nft_mint.to_account_info(),
+            destination: ctx.accounts.rent_receiver.to_account_info(),
+            authority: ctx.accounts.pool_authority.to_account_info(),
+        },
+        &[&signer_seeds[..]],
+    ))?;
+
+    emit_cpi!(EvtMergePosition {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.owner.key(),
+        position: ctx.accounts.position.key(),
+        source_position: ctx.accounts.source_position.key(),
+        source_position_nft_mint: ctx.accounts.source_position_nft_mint.key(),
+        current_sqrt_price: pool.sqrt_price,
+        amount_merged,
+        position_info: position.to_split_info(),
+    });
+
+    Ok(())
+}
@@ -46,8 +46,11 @@ pub fn handle_permanent_lock_position(
     let mut position = ctx.accounts.position.load_mut()?;
 
     let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    // update rewards before locked liquidity changes, it may be boosted
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time)?;
     // refresh inner vesting firstly to retrieve the latest state of unlocked liquidity
-    position.refresh_inner_vesting(current_point)?;
+    pool.refresh_position_inner_vesting(&mut position, current_point)?;
 
     // permanent lock liquidity
     position.permanent_lock_liquidity(permanent_lock_liquidity)?;
@@ -11,6 +11,7 @@ use crate::{
 
 #[derive(Accounts)]
 pub struct RefreshVestingCtx<'info> {
+    #[account(mut)]
     pub pool: AccountLoader<'info, Pool>,
 
     #[account(
@@ -49,12 +50,17 @@ impl<'info> VestingRemainingAccount<'info> {
 }
 
 pub fn handle_refresh_vesting<'info>(ctx: Context<'info, RefreshVestingCtx<'info>>) -> Result<()> {
-    let pool = ctx.accounts.pool.load()?;
+    let mut pool = ctx.accounts.pool.load_mut()?;
 
     let (current_point, _) =
         ActivationHandler::get_current_point_and_buffer_duration(pool.activation_type)?;
 
     let mut position = ctx.accounts.position.load_mut()?;
+    // update rewards before vested liquidity is released, it may be boosted
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time)?;
+
+    let vested_liquidity = position.vested_liquidity;
     position.refresh_inner_vesting(current_point)?;
 
     let mut remaining_accounts = &ctx.remaining_accounts[..];
@@ -83,6 +89,8 @@ pub fn handle_refresh_vesting<'info>(ctx: Context<'info, RefreshVestingCtx<'info
         }
     }
 
+    pool.update_vested_liquidity(vested_liquidity, position.vested_liquidity)?;
+
     Ok(())
 }
 
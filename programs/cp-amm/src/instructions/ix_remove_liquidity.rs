@@ -9,7 +9,7 @@ use crate::{
     state::{Pool, Position},
     token::{calculate_transfer_fee_excluded_amount, transfer_from_pool},
     u128x128_math::Rounding,
-    EvtLiquidityChange, PoolError,
+    update_extended_rewards, write_oracle_observation_if_passed, EvtLiquidityChange, PoolError,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
@@ -78,8 +78,8 @@ pub struct RemoveLiquidityCtx<'info> {
     pub token_b_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_remove_liquidity(
-    ctx: Context<RemoveLiquidityCtx>,
+pub fn handle_remove_liquidity<'info>(
+    ctx: Context<'info, RemoveLiquidityCtx<'info>>,
     liquidity_delta: Option<u128>,
     token_a_amount_threshold: u64,
     token_b_amount_threshold: u64,
@@ -98,7 +98,19 @@ pub fn handle_remove_liquidity(
     let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
 
     let mut position = ctx.accounts.position.load_mut()?;
-    position.refresh_inner_vesting(current_point)?;
+
+    // update current pool reward & position reward before any logic
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time)?;
+    update_extended_rewards(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[(ctx.accounts.position.key(), &position)],
+        current_time,
+    )?;
+
+    pool.refresh_position_inner_vesting(&mut position, current_point)?;
 
     let liquidity_delta = liquidity_delta.unwrap_or(position.unlocked_liquidity);
     require!(
@@ -106,10 +118,6 @@ pub fn handle_remove_liquidity(
         PoolError::InsufficientLiquidity
     );
 
-    // update current pool reward & position reward before any logic
-    let current_time = Clock::get()?.unix_timestamp as u64;
-    position.update_rewards(&mut pool, current_time)?;
-
     let liquidity_handler = pool.get_liquidity_handler()?;
     let (token_a_amount, token_b_amount) =
         liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Down)?;
@@ -146,6 +154,8 @@ pub fn handle_remove_liquidity(
         PoolError::ExceededSlippage
     );
 
+    pool.update_oracle_cumulatives(current_time)?;
+
     pool.apply_remove_liquidity(
         &mut position,
         liquidity_delta,
@@ -153,6 +163,9 @@ pub fn handle_remove_liquidity(
         token_b_amount,
     )?;
 
+    // oracle account is optional
+    write_oracle_observation_if_passed(ctx.remaining_accounts, ctx.accounts.pool.key(), &pool)?;
+
     // send to user
     transfer_from_pool(
         ctx.accounts.pool_authority.to_account_info(),
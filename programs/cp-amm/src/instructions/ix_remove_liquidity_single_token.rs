@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    const_pda, get_pool_access_validator_with_allowlist, is_wallet_allowlisted,
+    params::swap::TradeDirection,
+    safe_math::{SafeCast, SafeMath},
+    state::{fee::FeeMode, CollectFeeMode, Pool, Position},
+    token::{calculate_transfer_fee_excluded_amount, transfer_from_pool},
+    u128x128_math::Rounding,
+    update_extended_rewards, write_oracle_observation_if_passed, EvtRemoveLiquiditySingleToken,
+    PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
+pub struct RemoveLiquiditySingleTokenParameters {
+    /// delta liquidity
+    pub liquidity_delta: u128,
+    /// Minimum amount of the output token received by the user, excluding Token-2022 transfer fee
+    pub minimum_amount_out: u64,
+    /// Withdrawn token swapped back through the pool, AtoB swaps token a and the user receives token b,
+    /// BtoA swaps token b and the user receives token a. Refer [TradeDirection]
+    pub trade_direction: u8,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveLiquiditySingleTokenCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut, has_one = token_a_vault, has_one = token_b_vault, has_one = token_a_mint, has_one = token_b_mint)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+      mut,
+      has_one = pool,
+    )]
+    pub position: AccountLoader<'info, Position>,
+
+    /// The user token account for output token
+    #[account(mut)]
+    pub output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token a
+    #[account(mut, token::token_program = token_a_program, token::mint = token_a_mint)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token b
+    #[account(mut, token::token_program = token_b_program, token::mint = token_b_mint)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token a
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token b
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token account for nft
+    #[account(
+            constraint = position_nft_account.mint == position.load()?.nft_mint,
+            constraint = position_nft_account.amount == 1,
+            token::authority = owner
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// owner of position
+    pub owner: Signer<'info>,
+
+    /// Token a program
+    pub token_a_program: Interface<'info, TokenInterface>,
+
+    /// Token b program
+    pub token_b_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraw `liquidity_delta` from the position, then swap the withdrawn input token back through the pool,
+/// so the user only receives the output token
+pub fn handle_remove_liquidity_single_token<'info>(
+    ctx: Context<'info, RemoveLiquiditySingleTokenCtx<'info>>,
+    params: RemoveLiquiditySingleTokenParameters,
+) -> Result<()> {
+    let RemoveLiquiditySingleTokenParameters {
+        liquidity_delta,
+        minimum_amount_out,
+        trade_direction,
+    } = params;
+    let trade_direction =
+        TradeDirection::try_from(trade_direction).map_err(|_| PoolError::InvalidInput)?;
+
+    {
+        let pool = ctx.accounts.pool.load()?;
+        let is_allowlisted = is_wallet_allowlisted(
+            ctx.remaining_accounts,
+            ctx.accounts.pool.key(),
+            &pool,
+            ctx.accounts.owner.key(),
+        )?;
+        let access_validator = get_pool_access_validator_with_allowlist(&pool, is_allowlisted)?;
+        require!(
+            access_validator.can_remove_liquidity(),
+            PoolError::PoolDisabled
+        );
+        require!(
+            access_validator.can_swap(&ctx.accounts.owner.key()),
+            PoolError::PoolDisabled
+        );
+    }
+
+    let (token_out_mint, output_vault_account, output_program) = match trade_direction {
+        TradeDirection::AtoB => (
+            &ctx.accounts.token_b_mint,
+            &ctx.accounts.token_b_vault,
+            &ctx.accounts.token_b_program,
+        ),
+        TradeDirection::BtoA => (
+            &ctx.accounts.token_a_mint,
+            &ctx.accounts.token_a_vault,
+            &ctx.accounts.token_a_program,
+        ),
+    };
+    require!(
+        ctx.accounts.output_token_account.mint == token_out_mint.key(),
+        ErrorCode::ConstraintTokenMint
+    );
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    pool.update_layout_version_if_needed()?;
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+
+    let mut position = ctx.accounts.position.load_mut()?;
+
+    // update current pool reward & position reward before any logic
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time)?;
+    update_extended_rewards(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[(ctx.accounts.position.key(), &position)],
+        current_time,
+    )?;
+
+    pool.refresh_position_inner_vesting(&mut position, current_point)?;
+
+    require!(
+        liquidity_delta <= position.unlocked_liquidity && liquidity_delta > 0,
+        PoolError::InsufficientLiquidity
+    );
+
+    // single swap instruction can't be validated here, so the internal swap is disabled while rate limiter is applied
+    if let Ok(rate_limiter) = pool.pool_fees.base_fee.to_fee_rate_limiter() {
+        require!(
+            !rate_limiter.is_rate_limiter_applied(
+                current_point,
+                pool.activation_point,
+                trade_direction,
+            )?,
+            PoolError::SingleTokenRemoveLiquidityRateLimited
+        );
+    }
+
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let (token_a_amount, token_b_amount) =
+        liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Down)?;
+
+    require!(
+        token_a_amount > 0 || token_b_amount > 0,
+        PoolError::AmountIsZero
+    );
+
+    pool.update_oracle_cumulatives(current_time)?;
+
+    pool.apply_remove_liquidity(
+        &mut position,
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+    )?;
+
+    let (swap_amount_in, withdrawn_amount_out) = match trade_direction {
+        TradeDirection::AtoB => (token_a_amount, token_b_amount),
+        TradeDirection::BtoA => (token_b_amount, token_a_amount),
+    };
+
+    // withdrawn input token never leaves the vault, so it's not charged transfer fee
+    let swap_result = if swap_amount_in > 0 {
+        // update for dynamic fee reference
+        pool.update_pre_swap(current_time)?;
+
+        let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast()?;
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+        let mut swap_result = pool.get_swap_result_from_exact_input(
+            swap_amount_in,
+            &fee_mode,
+            trade_direction,
+            current_point,
+        )?;
+
+        pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, current_time)?;
+
+        // re-update next_sqrt_price for compounding pool
+        swap_result.next_sqrt_price = pool.sqrt_price;
+        Some(swap_result)
+    } else {
+        None
+    };
+
+    // oracle account is optional
+    write_oracle_observation_if_passed(ctx.remaining_accounts, ctx.accounts.pool.key(), &pool)?;
+
+    let swap_amount_out = swap_result.map_or(0, |swap_result| swap_result.output_amount);
+    let included_transfer_fee_amount_out = withdrawn_amount_out.safe_add(swap_amount_out)?;
+    let excluded_transfer_fee_amount_out = calculate_transfer_fee_excluded_amount(
+        &token_out_mint.to_account_info().try_borrow_data()?,
+        included_transfer_fee_amount_out,
+    )?
+    .amount;
+    require!(
+        excluded_transfer_fee_amount_out >= minimum_amount_out,
+        PoolError::ExceededSlippage
+    );
+
+    let (reserve_a_amount, reserve_b_amount) = (pool.token_a_amount, pool.token_b_amount);
+    drop(pool);
+    drop(position);
+
+    // send to user
+    transfer_from_pool(
+        ctx.accounts.pool_authority.to_account_info(),
+        token_out_mint,
+        output_vault_account,
+        &ctx.accounts.output_token_account.to_account_info(),
+        output_program,
+        included_transfer_fee_amount_out,
+    )?;
+
+    emit_cpi!(EvtRemoveLiquiditySingleToken {
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        owner: ctx.accounts.owner.key(),
+        params,
+        token_a_amount,
+        token_b_amount,
+        swap_result,
+        included_transfer_fee_amount_out,
+        excluded_transfer_fee_amount_out,
+        current_timestamp: current_time,
+        reserve_a_amount,
+        reserve_b_amount,
+    });
+
+    Ok(())
+}
@@ -3,9 +3,10 @@ use anchor_lang::prelude::*;
 use crate::{
     activation_handler::ActivationHandler,
     constants::{REWARD_INDEX_0, REWARD_INDEX_1, SPLIT_POSITION_DENOMINATOR},
-    get_pool_access_validator,
+    find_position_reward_extension, get_pool_access_validator,
+    safe_math::SafeMath,
     state::{Position, SplitAmountInfo2, SplitPositionInfo},
-    EvtSplitPosition3, PoolError, SplitPositionCtx,
+    update_extended_rewards, EvtSplitPosition3, PoolError, SplitPositionCtx,
 };
 
 #[allow(deprecated)]
@@ -121,8 +122,8 @@ fn check_position_split_validity(
     Ok(())
 }
 
-pub fn handle_split_position2(
-    ctx: Context<SplitPositionCtx>,
+pub fn handle_split_position2<'info>(
+    ctx: Context<'info, SplitPositionCtx<'info>>,
     params: SplitPositionParameters3,
 ) -> Result<()> {
     {
@@ -155,6 +156,26 @@ pub fn handle_split_position2(
 
     let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
 
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    // update current pool reward
+    pool.update_rewards(current_time)?;
+    // update first and second position reward
+    first_position.update_position_reward(&pool)?;
+    second_position.update_position_reward(&pool)?;
+    update_extended_rewards(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[
+            (ctx.accounts.first_position.key(), &first_position),
+            (ctx.accounts.second_position.key(), &second_position),
+        ],
+        current_time,
+    )?;
+
+    let vested_liquidity = first_position
+        .vested_liquidity
+        .safe_add(second_position.vested_liquidity)?;
     first_position.refresh_inner_vesting(current_point)?;
     second_position.refresh_inner_vesting(current_point)?;
 
@@ -165,13 +186,7 @@ pub fn handle_split_position2(
         check_position_split_validity(&first_position, &second_position)?;
     }
 
-    let current_time = Clock::get()?.unix_timestamp as u64;
-    // update current pool reward
-    pool.update_rewards(current_time)?;
-    // update first and second position reward
-    first_position.update_position_reward(&pool)?;
-    second_position.update_position_reward(&pool)?;
-
+    let first_liquidity_before_split = first_position.get_total_liquidity()?;
     let split_amount_info: SplitAmountInfo2 = pool.apply_split_position(
         &mut first_position,
         &mut second_position,
@@ -185,6 +200,32 @@ pub fn handle_split_position2(
         current_point,
     )?;
 
+    // extended reward pendings are split in proportion of the liquidity moved to the second position
+    if pool.is_extended_reward_initialized() {
+        let first_position_reward_extension = find_position_reward_extension(
+            ctx.remaining_accounts,
+            ctx.accounts.first_position.key(),
+        )?
+        .ok_or(PoolError::MissingRewardExtension)?;
+        let second_position_reward_extension = find_position_reward_extension(
+            ctx.remaining_accounts,
+            ctx.accounts.second_position.key(),
+        )?
+        .ok_or(PoolError::MissingRewardExtension)?;
+        first_position_reward_extension.load_mut()?.split(
+            &mut *second_position_reward_extension.load_mut()?,
+            first_liquidity_before_split.safe_sub(first_position.get_total_liquidity()?)?,
+            first_liquidity_before_split,
+        )?;
+    }
+
+    pool.update_vested_liquidity(
+        vested_liquidity,
+        first_position
+            .vested_liquidity
+            .safe_add(second_position.vested_liquidity)?,
+    )?;
+
     #[allow(deprecated)]
     {
         emit_cpi!(EvtSplitPosition2 {
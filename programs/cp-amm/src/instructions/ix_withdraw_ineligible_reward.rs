@@ -2,8 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    const_pda, constants::NUM_REWARDS, error::PoolError, event::EvtWithdrawIneligibleReward,
-    state::pool::Pool, token::transfer_from_pool,
+    const_pda,
+    error::PoolError,
+    event::EvtWithdrawIneligibleReward,
+    find_pool_reward_extension_for_reward,
+    state::{get_reward_info_mut, pool::Pool, RewardInfo},
+    token::transfer_from_pool,
 };
 
 #[event_cpi]
@@ -30,12 +34,7 @@ pub struct WithdrawIneligibleRewardCtx<'info> {
 }
 
 impl<'info> WithdrawIneligibleRewardCtx<'info> {
-    fn validate(&self, reward_index: usize) -> Result<()> {
-        let pool = self.pool.load()?;
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
-
-        let reward_info = &pool.reward_infos[reward_index];
-
+    fn validate(&self, reward_info: &RewardInfo) -> Result<()> {
         require!(reward_info.initialized(), PoolError::RewardUninitialized);
 
         require!(
@@ -58,23 +57,43 @@ impl<'info> WithdrawIneligibleRewardCtx<'info> {
     }
 }
 
-pub fn handle_withdraw_ineligible_reward(
-    ctx: Context<WithdrawIneligibleRewardCtx>,
+pub fn handle_withdraw_ineligible_reward<'info>(
+    ctx: Context<'info, WithdrawIneligibleRewardCtx<'info>>,
     reward_index: u8,
 ) -> Result<()> {
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
-    ctx.accounts.validate(index)?;
+
+    // extended rewards are stored in the pool reward extension
+    let pool_reward_extension_loader = find_pool_reward_extension_for_reward(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        index,
+    )?;
+    let mut pool_reward_extension = pool_reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
 
     let mut pool = ctx.accounts.pool.load_mut()?;
+    ctx.accounts.validate(get_reward_info_mut(
+        &mut pool,
+        pool_reward_extension.as_deref_mut(),
+        index,
+    )?)?;
 
     let current_time = Clock::get()?.unix_timestamp as u64;
 
     // update pool reward
     pool.update_rewards(current_time)?;
+    if let Some(pool_reward_extension) = pool_reward_extension.as_deref_mut() {
+        pool_reward_extension.update_rewards(pool.liquidity, current_time)?;
+    }
 
-    let ineligible_reward = pool.claim_ineligible_reward(index)?;
+    let ineligible_reward =
+        get_reward_info_mut(&mut pool, pool_reward_extension.as_deref_mut(), index)?
+            .claim_ineligible_reward()?;
 
     // transfer rewards to funder
     if ineligible_reward > 0 {
@@ -6,12 +6,20 @@ pub mod swap;
 pub use swap::*;
 pub mod ix_add_liquidity;
 pub use ix_add_liquidity::*;
+pub mod ix_add_liquidity_single_sided;
+pub use ix_add_liquidity_single_sided::*;
 pub mod ix_create_position;
 pub use ix_create_position::*;
 pub mod ix_remove_liquidity;
 pub use ix_remove_liquidity::*;
+pub mod ix_remove_liquidity_single_token;
+pub use ix_remove_liquidity_single_token::*;
 pub mod ix_claim_position_fee;
 pub use ix_claim_position_fee::*;
+pub mod ix_claim_creator_fee;
+pub use ix_claim_creator_fee::*;
+pub mod ix_compound_position_fee;
+pub use ix_compound_position_fee::*;
 pub mod initialize_pool;
 pub use initialize_pool::*;
 pub mod ix_lock_position;
@@ -34,5 +42,29 @@ pub use ix_split_position::*;
 pub mod ix_split_position2;
 pub use ix_split_position2::*;
 
+pub mod ix_merge_position;
+pub use ix_merge_position::*;
+
 pub mod ix_lock_inner_position;
 pub use ix_lock_inner_position::*;
+
+pub mod oracle;
+pub use oracle::*;
+
+pub mod flash_loan;
+pub use flash_loan::*;
+
+pub mod position_delegate;
+pub use position_delegate::*;
+
+pub mod reward_extension;
+pub use reward_extension::*;
+
+pub mod allowlist;
+pub use allowlist::*;
+
+pub mod timelock;
+pub use timelock::*;
+
+pub mod ix_distribute_protocol_fee;
+pub use ix_distribute_protocol_fee::*;
@@ -1,9 +1,10 @@
 use crate::{
     const_pda,
     constants::treasury,
-    state::{Operator, Pool},
+    consume_operator_usage,
+    state::{Operator, OperatorPermission, Pool},
     token::{transfer_from_pool, validate_ata_token},
-    EvtClaimProtocolFee,
+    EvtClaimProtocolFee, PoolError,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -55,15 +56,29 @@ pub struct ClaimProtocolFeesCtx<'info> {
 }
 
 /// withdraw protocol fees and validate destination is treasury ATA
-pub fn handle_claim_protocol_fee(
-    ctx: Context<ClaimProtocolFeesCtx>,
+pub fn handle_claim_protocol_fee<'info>(
+    ctx: Context<'info, ClaimProtocolFeesCtx<'info>>,
     max_amount_a: u64,
     max_amount_b: u64,
 ) -> Result<()> {
     let mut pool = ctx.accounts.pool.load_mut()?;
+    require!(
+        !pool.is_protocol_fee_distribution_enabled(),
+        PoolError::ProtocolFeeDistributionEnabled
+    );
 
     let (token_a_amount, token_b_amount) = pool.claim_protocol_fee(max_amount_a, max_amount_b)?;
 
+    // token a and token b amounts can't be added up, only the number of calls is capped
+    consume_operator_usage(
+        ctx.remaining_accounts,
+        ctx.accounts.operator.key(),
+        &ctx.accounts.operator.load()?,
+        OperatorPermission::ClaimProtocolFee,
+        Clock::get()?.unix_timestamp as u64,
+        0,
+    )?;
+
     if token_a_amount > 0 {
         validate_ata_token(
             &ctx.accounts.token_a_account.to_account_info(),
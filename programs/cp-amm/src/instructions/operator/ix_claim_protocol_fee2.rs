@@ -77,6 +77,10 @@ pub fn handle_claim_protocol_fee2(
     max_amount: u64,
 ) -> Result<()> {
     let mut pool = ctx.accounts.pool.load_mut()?;
+    require!(
+        !pool.is_protocol_fee_distribution_enabled(),
+        PoolError::ProtocolFeeDistributionEnabled
+    );
 
     let is_claiming_token_a = get_claim_direction_and_validate_accounts(
         &pool,
@@ -1,36 +1,61 @@
 use anchor_lang::prelude::*;
 
-use crate::{event, PoolError};
+use crate::{
+    event, require_no_timelock,
+    state::{Config, OperatorPermission},
+    PoolError,
+};
 
 use super::CreateConfigCtx;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
 pub struct DynamicConfigParameters {
     pub pool_creator_authority: Pubkey,
 }
 
+impl DynamicConfigParameters {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.pool_creator_authority.ne(&Pubkey::default()),
+            PoolError::InvalidPoolCreatorAuthority
+        );
+        Ok(())
+    }
+}
+
 pub fn handle_create_dynamic_config(
     ctx: Context<CreateConfigCtx>,
     index: u64,
     config_parameters: DynamicConfigParameters,
 ) -> Result<()> {
+    require_no_timelock(ctx.remaining_accounts, OperatorPermission::CreateConfigKey)?;
+
+    let evt_create_dynamic_config =
+        process_create_dynamic_config(&ctx.accounts.config, index, config_parameters)?;
+
+    emit_cpi!(evt_create_dynamic_config);
+
+    Ok(())
+}
+
+/// Shared by `create_dynamic_config` and the timelocked config creation of `execute_config_operator_action`
+pub fn process_create_dynamic_config(
+    config: &AccountLoader<Config>,
+    index: u64,
+    config_parameters: DynamicConfigParameters,
+) -> Result<event::EvtCreateDynamicConfig> {
+    config_parameters.validate()?;
+
     let DynamicConfigParameters {
         pool_creator_authority,
     } = config_parameters;
 
-    require!(
-        pool_creator_authority.ne(&Pubkey::default()),
-        PoolError::InvalidPoolCreatorAuthority
-    );
+    let mut config_state = config.load_init()?;
+    config_state.init_dynamic_config(index, pool_creator_authority);
 
-    let mut config = ctx.accounts.config.load_init()?;
-    config.init_dynamic_config(index, pool_creator_authority);
-
-    emit_cpi!(event::EvtCreateDynamicConfig {
-        config: ctx.accounts.config.key(),
+    Ok(event::EvtCreateDynamicConfig {
+        config: config.key(),
         pool_creator_authority,
         index,
-    });
-
-    Ok(())
+    })
 }
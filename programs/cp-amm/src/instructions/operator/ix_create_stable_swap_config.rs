@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    event,
+    params::fee_parameters::PoolFeeParameters,
+    require_no_timelock,
+    state::{CollectFeeMode, Config, OperatorPermission},
+    validate_amplification_coefficient, CreateConfigCtx, StaticConfigParameters,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct StableSwapConfigParameters {
+    pub pool_fees: PoolFeeParameters,
+    pub vault_config_key: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub activation_type: u8,
+    /// amplification coefficient of the stable swap curve
+    pub amplification_coefficient: u64,
+}
+
+impl StableSwapConfigParameters {
+    /// Stable swap config is a static config with collect fee mode StableSwap and full price range
+    fn to_static_config_parameters(&self) -> StaticConfigParameters {
+        StaticConfigParameters {
+            pool_fees: self.pool_fees.clone(),
+            sqrt_min_price: 0,
+            sqrt_max_price: u128::MAX,
+            vault_config_key: self.vault_config_key,
+            pool_creator_authority: self.pool_creator_authority,
+            activation_type: self.activation_type,
+            collect_fee_mode: CollectFeeMode::StableSwap.into(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        validate_amplification_coefficient(self.amplification_coefficient)?;
+        self.to_static_config_parameters().validate_common()
+    }
+}
+
+pub fn handle_create_stable_swap_config(
+    ctx: Context<CreateConfigCtx>,
+    index: u64,
+    config_parameters: StableSwapConfigParameters,
+) -> Result<()> {
+    require_no_timelock(ctx.remaining_accounts, OperatorPermission::CreateConfigKey)?;
+
+    let evt_create_stable_swap_config =
+        process_create_stable_swap_config(&ctx.accounts.config, index, config_parameters)?;
+
+    emit_cpi!(evt_create_stable_swap_config);
+
+    Ok(())
+}
+
+/// Shared by `create_stable_swap_config` and the timelocked config creation of `execute_config_operator_action`
+pub fn process_create_stable_swap_config(
+    config: &AccountLoader<Config>,
+    index: u64,
+    config_parameters: StableSwapConfigParameters,
+) -> Result<event::EvtCreateStableSwapConfig> {
+    config_parameters.validate()?;
+
+    let StaticConfigParameters {
+        pool_fees,
+        sqrt_min_price,
+        sqrt_max_price,
+        vault_config_key,
+        pool_creator_authority,
+        activation_type,
+        collect_fee_mode,
+    } = config_parameters.to_static_config_parameters();
+    let amplification_coefficient = config_parameters.amplification_coefficient;
+
+    let mut config_state = config.load_init()?;
+    config_state.init_static_config(
+        index,
+        &pool_fees,
+        vault_config_key,
+        pool_creator_authority,
+        activation_type,
+        sqrt_min_price,
+        sqrt_max_price,
+        collect_fee_mode,
+        amplification_coefficient,
+    )?;
+
+    Ok(event::EvtCreateStableSwapConfig {
+        pool_fees,
+        config: config.key(),
+        vault_config_key,
+        pool_creator_authority,
+        activation_type,
+        amplification_coefficient,
+        index,
+    })
+}
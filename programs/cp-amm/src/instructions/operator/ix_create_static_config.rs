@@ -5,11 +5,12 @@ use crate::{
     constants::{seeds::CONFIG_PREFIX, MAX_SQRT_PRICE, MIN_SQRT_PRICE},
     event,
     params::{activation::ActivationParams, fee_parameters::PoolFeeParameters},
-    state::{CollectFeeMode, Config, Operator},
+    require_no_timelock,
+    state::{CollectFeeMode, Config, Operator, OperatorPermission},
     PoolError,
 };
 
-#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
 pub struct StaticConfigParameters {
     pub pool_fees: PoolFeeParameters,
     pub sqrt_min_price: u128,
@@ -48,56 +49,94 @@ pub fn handle_create_static_config(
     index: u64,
     config_parameters: StaticConfigParameters,
 ) -> Result<()> {
-    let StaticConfigParameters {
-        pool_fees,
-        vault_config_key,
-        pool_creator_authority,
-        activation_type,
-        sqrt_min_price,
-        sqrt_max_price,
-        collect_fee_mode,
-    } = config_parameters;
+    require_no_timelock(ctx.remaining_accounts, OperatorPermission::CreateConfigKey)?;
 
-    let pool_collect_fee_mode =
-        CollectFeeMode::try_from(collect_fee_mode).map_err(|_| PoolError::InvalidCollectFeeMode)?;
+    let evt_create_config =
+        process_create_static_config(&ctx.accounts.config, index, config_parameters)?;
 
-    if pool_collect_fee_mode == CollectFeeMode::Compounding {
-        require!(
-            sqrt_min_price == 0 && sqrt_max_price == u128::MAX,
-            PoolError::InvalidPriceRange
-        );
-    } else {
-        require!(
-            sqrt_min_price >= MIN_SQRT_PRICE && sqrt_max_price <= MAX_SQRT_PRICE,
-            PoolError::InvalidPriceRange
-        );
+    emit_cpi!(evt_create_config);
 
+    Ok(())
+}
+
+impl StaticConfigParameters {
+    pub fn validate(&self) -> Result<()> {
+        // stable swap configs need an amplification coefficient, they are created by `create_stable_swap_config`
         require!(
-            sqrt_min_price < sqrt_max_price,
-            PoolError::InvalidPriceRange
+            self.collect_fee_mode != u8::from(CollectFeeMode::StableSwap),
+            PoolError::InvalidCollectFeeMode
         );
+
+        self.validate_common()
     }
 
-    let has_alpha_vault = vault_config_key.ne(&Pubkey::default());
+    /// Validation shared with `StableSwapConfigParameters`
+    pub(crate) fn validate_common(&self) -> Result<()> {
+        let pool_collect_fee_mode = CollectFeeMode::try_from(self.collect_fee_mode)
+            .map_err(|_| PoolError::InvalidCollectFeeMode)?;
+
+        if pool_collect_fee_mode == CollectFeeMode::Compounding
+            || pool_collect_fee_mode == CollectFeeMode::StableSwap
+        {
+            require!(
+                self.sqrt_min_price == 0 && self.sqrt_max_price == u128::MAX,
+                PoolError::InvalidPriceRange
+            );
+        } else {
+            require!(
+                self.sqrt_min_price >= MIN_SQRT_PRICE && self.sqrt_max_price <= MAX_SQRT_PRICE,
+                PoolError::InvalidPriceRange
+            );
+
+            require!(
+                self.sqrt_min_price < self.sqrt_max_price,
+                PoolError::InvalidPriceRange
+            );
+        }
+
+        let has_alpha_vault = self.vault_config_key.ne(&Pubkey::default());
+
+        let activation_point = Some(ActivationHandler::get_max_activation_point(
+            self.activation_type,
+        )?);
+
+        let activation_params = ActivationParams {
+            activation_point,
+            activation_type: self.activation_type,
+            has_alpha_vault,
+        };
+        activation_params.validate()?;
+
+        let pool_activation_type = ActivationType::try_from(self.activation_type)
+            .map_err(|_| PoolError::InvalidActivationType)?;
+
+        self.pool_fees
+            .validate(pool_collect_fee_mode, pool_activation_type)?;
+
+        Ok(())
+    }
+}
 
-    let activation_point = Some(ActivationHandler::get_max_activation_point(
-        activation_type,
-    )?);
+/// Shared by `create_config` and the timelocked config creation of `execute_config_operator_action`
+pub fn process_create_static_config(
+    config: &AccountLoader<Config>,
+    index: u64,
+    config_parameters: StaticConfigParameters,
+) -> Result<event::EvtCreateConfig> {
+    config_parameters.validate()?;
 
-    let activation_params = ActivationParams {
-        activation_point,
+    let StaticConfigParameters {
+        pool_fees,
+        vault_config_key,
+        pool_creator_authority,
         activation_type,
-        has_alpha_vault,
-    };
-    activation_params.validate()?;
-
-    let pool_activation_type =
-        ActivationType::try_from(activation_type).map_err(|_| PoolError::InvalidActivationType)?;
-
-    pool_fees.validate(pool_collect_fee_mode, pool_activation_type)?;
+        sqrt_min_price,
+        sqrt_max_price,
+        collect_fee_mode,
+    } = config_parameters;
 
-    let mut config = ctx.accounts.config.load_init()?;
-    config.init_static_config(
+    let mut config_state = config.load_init()?;
+    config_state.init_static_config(
         index,
         &pool_fees,
         vault_config_key,
@@ -106,11 +145,12 @@ pub fn handle_create_static_config(
         sqrt_min_price,
         sqrt_max_price,
         collect_fee_mode,
+        0,
     )?;
 
-    emit_cpi!(event::EvtCreateConfig {
+    Ok(event::EvtCreateConfig {
         pool_fees,
-        config: ctx.accounts.config.key(),
+        config: config.key(),
         vault_config_key,
         pool_creator_authority,
         activation_type,
@@ -118,7 +158,5 @@ pub fn handle_create_static_config(
         sqrt_min_price,
         sqrt_max_price,
         index,
-    });
-
-    Ok(())
+    })
 }
@@ -59,7 +59,7 @@ pub fn handle_fix_pool_fee_params(
     let max_fee_numerator_0 = base_fee_handler_0.get_max_fee_numerator()?;
     let base_fee_mode_0 = pool.pool_fees.base_fee.base_fee_info.get_base_fee_mode()?;
 
-    pool.pool_fees.base_fee = params.to_base_fee_struct()?;
+    pool.update_base_fee(params.to_base_fee_struct()?);
 
     // Reload
     let base_fee_handler_1 = pool
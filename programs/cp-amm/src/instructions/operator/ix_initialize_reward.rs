@@ -4,14 +4,26 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::{
     const_pda,
     constants::{
-        seeds::REWARD_VAULT_PREFIX, MAX_REWARD_DURATION, MIN_REWARD_DURATION, NUM_REWARDS,
+        seeds::REWARD_VAULT_PREFIX, MAX_REWARD_DURATION, MAX_REWARD_LIQUIDITY_BOOST_BPS,
+        MIN_REWARD_DURATION,
     },
     error::PoolError,
     event::EvtInitializeReward,
-    state::{Operator, OperatorPermission, Pool},
+    find_pool_reward_extension_for_reward,
+    state::{get_extended_reward_index, get_reward_info_mut, Operator, OperatorPermission, Pool},
     token::{get_token_program_flags, is_supported_mint, is_token_badge_initialized},
 };
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct InitializeRewardParameters {
+    pub reward_duration: u64,
+    pub funder: Pubkey,
+    /// Extra reward weight of vested liquidity in bps, only supported by pools tracking vested liquidity
+    pub vested_liquidity_boost_bps: u16,
+    /// Extra reward weight of permanent locked liquidity in bps
+    pub permanent_lock_liquidity_boost_bps: u16,
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(reward_index: u8)]
@@ -46,18 +58,36 @@ pub struct InitializeRewardCtx<'info> {
 }
 
 impl<'info> InitializeRewardCtx<'info> {
-    fn validate(&self, reward_index: usize, reward_duration: u64) -> Result<()> {
-        let pool = self.pool.load()?;
-
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+    fn validate(&self, reward_index: usize, params: &InitializeRewardParameters) -> Result<()> {
+        let extended_reward_index = get_extended_reward_index(reward_index)?;
 
         require!(
-            reward_duration >= MIN_REWARD_DURATION && reward_duration <= MAX_REWARD_DURATION,
+            params.reward_duration >= MIN_REWARD_DURATION
+                && params.reward_duration <= MAX_REWARD_DURATION,
             PoolError::InvalidRewardDuration
         );
 
-        let reward_info = &pool.reward_infos[reward_index];
-        require!(!reward_info.initialized(), PoolError::RewardInitialized);
+        require!(
+            params.vested_liquidity_boost_bps <= MAX_REWARD_LIQUIDITY_BOOST_BPS
+                && params.permanent_lock_liquidity_boost_bps <= MAX_REWARD_LIQUIDITY_BOOST_BPS,
+            PoolError::InvalidRewardLiquidityBoost
+        );
+
+        if params.vested_liquidity_boost_bps > 0 || params.permanent_lock_liquidity_boost_bps > 0 {
+            // rewards of the pool reward extension are distributed by total liquidity only
+            require!(
+                extended_reward_index.is_none(),
+                PoolError::InvalidRewardLiquidityBoost
+            );
+        }
+
+        if params.vested_liquidity_boost_bps > 0 {
+            let pool = self.pool.load()?;
+            require!(
+                pool.is_vested_liquidity_tracked()?,
+                PoolError::InvalidRewardLiquidityBoost
+            );
+        }
 
         Ok(())
     }
@@ -66,8 +96,7 @@ impl<'info> InitializeRewardCtx<'info> {
 pub fn handle_initialize_reward<'info>(
     ctx: Context<'info, InitializeRewardCtx<'info>>,
     reward_index: u8,
-    reward_duration: u64,
-    funder: Pubkey,
+    params: InitializeRewardParameters,
 ) -> Result<()> {
     if !is_supported_mint(&ctx.accounts.reward_mint)? {
         require!(
@@ -84,7 +113,14 @@ pub fn handle_initialize_reward<'info>(
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
 
-    ctx.accounts.validate(index, reward_duration)?;
+    ctx.accounts.validate(index, &params)?;
+
+    let InitializeRewardParameters {
+        reward_duration,
+        funder,
+        vested_liquidity_boost_bps,
+        permanent_lock_liquidity_boost_bps,
+    } = params;
 
     let mut pool = ctx.accounts.pool.load_mut()?;
 
@@ -96,14 +132,27 @@ pub fn handle_initialize_reward<'info>(
         let operator_loader: AccountLoader<'info, Operator> =
             AccountLoader::try_from(operator_account)?;
         let operator = operator_loader.load()?;
+        let current_time = Clock::get()?.unix_timestamp as u64;
         require!(
             operator.whitelisted_address.eq(&ctx.accounts.signer.key())
-                && operator.is_permission_allow(OperatorPermission::InitializeReward),
+                && operator.is_permission_allow(OperatorPermission::InitializeReward, current_time),
             PoolError::InvalidAuthority
         )
     }
 
-    let reward_info = &mut pool.reward_infos[index];
+    // extended rewards are stored in the pool reward extension
+    let pool_reward_extension_loader = find_pool_reward_extension_for_reward(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        index,
+    )?;
+    let mut pool_reward_extension = pool_reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+
+    let reward_info = get_reward_info_mut(&mut pool, pool_reward_extension.as_deref_mut(), index)?;
+    require!(!reward_info.initialized(), PoolError::RewardInitialized);
 
     reward_info.init_reward(
         ctx.accounts.reward_mint.key(),
@@ -111,8 +160,14 @@ pub fn handle_initialize_reward<'info>(
         funder,
         reward_duration,
         get_token_program_flags(&ctx.accounts.reward_mint).into(),
+        vested_liquidity_boost_bps,
+        permanent_lock_liquidity_boost_bps,
     );
 
+    if pool_reward_extension.is_some() {
+        pool.set_extended_reward_initialized();
+    }
+
     emit_cpi!(EvtInitializeReward {
         pool: ctx.accounts.pool.key(),
         reward_mint: ctx.accounts.reward_mint.key(),
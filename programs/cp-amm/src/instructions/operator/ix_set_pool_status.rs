@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    event,
-    state::{Operator, Pool, PoolStatus},
+    event, require_no_timelock,
+    state::{Operator, OperatorPermission, Pool},
     PoolError,
 };
 
@@ -18,21 +18,28 @@ pub struct SetPoolStatusCtx<'info> {
     pub signer: Signer<'info>,
 }
 
+/// Set the bitmask of paused actions, returns the previous one
+pub fn process_set_pool_status(pool: &mut Pool, status: u8) -> Result<u8> {
+    let previous_status = pool.pool_status;
+
+    require!(status != previous_status, PoolError::InvalidPoolStatus);
+    pool.pool_status = status;
+
+    Ok(previous_status)
+}
+
+/// `status` is the bitmask of paused actions, refer [crate::state::PoolPauseAction]
 pub fn handle_set_pool_status(ctx: Context<SetPoolStatusCtx>, status: u8) -> Result<()> {
-    let mut pool = ctx.accounts.pool.load_mut()?;
-    let new_pool_status = PoolStatus::try_from(status).map_err(|_| PoolError::TypeCastFailed)?;
-    let current_pool_status =
-        PoolStatus::try_from(pool.pool_status).map_err(|_| PoolError::TypeCastFailed)?;
+    require_no_timelock(ctx.remaining_accounts, OperatorPermission::SetPoolStatus)?;
 
-    require!(
-        new_pool_status != current_pool_status,
-        PoolError::InvalidPoolStatus
-    );
-    pool.pool_status = new_pool_status.into();
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let previous_status = process_set_pool_status(&mut pool, status)?;
 
     emit_cpi!(event::EvtSetPoolStatus {
         pool: ctx.accounts.pool.key(),
         status,
+        previous_status,
+        changed_actions: status ^ previous_status,
     });
 
     Ok(())
@@ -3,7 +3,8 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::fee::MAX_BASIS_POINT,
     params::fee_parameters::DynamicFeeParameters,
-    state::{Operator, Pool},
+    require_no_timelock,
+    state::{Operator, OperatorPermission, Pool},
     EvtUpdatePoolFees, PoolError,
 };
 
@@ -20,7 +21,7 @@ pub struct UpdatePoolFeesParameters {
     pub dynamic_fee: Option<DynamicFeeParameters>,
     /// Compounding fee update mode:
     /// - None: skip compounding fee update
-    /// - Some: update compounding_fee_bps; pool must use CollectFeeMode::Compounding
+    /// - Some: update compounding_fee_bps; pool must use CollectFeeMode::Compounding or CollectFeeMode::StableSwap
     pub compounding_fee_bps: Option<u16>,
 }
 
@@ -73,7 +74,7 @@ impl UpdatePoolFeesParameters {
         }
     }
 
-    fn validate(&self) -> Result<()> {
+    pub fn validate(&self) -> Result<()> {
         // We don't need to validate `cliff_fee_numerator` in case we update it.
         // Because after update pool fee we will validate pool fee with new updated parameters
         require!(
@@ -116,6 +117,7 @@ pub fn handle_update_pool_fees(
     ctx: Context<UpdatePoolFeesCtx>,
     params: UpdatePoolFeesParameters,
 ) -> Result<()> {
+    require_no_timelock(ctx.remaining_accounts, OperatorPermission::UpdatePoolFees)?;
     params.validate()?;
 
     let mut pool = ctx.accounts.pool.load_mut()?;
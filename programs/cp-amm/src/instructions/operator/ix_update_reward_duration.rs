@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{MAX_REWARD_DURATION, MIN_REWARD_DURATION, NUM_REWARDS},
-    state::{Operator, OperatorPermission, Pool},
+    constants::{MAX_REWARD_DURATION, MIN_REWARD_DURATION},
+    find_pool_reward_extension_for_reward,
+    state::{get_reward_info_mut, Operator, OperatorPermission, Pool, RewardInfo},
     EvtUpdateRewardDuration, PoolError,
 };
 
@@ -16,17 +17,13 @@ pub struct UpdateRewardDurationCtx<'info> {
 }
 
 impl<'info> UpdateRewardDurationCtx<'info> {
-    fn validate(&self, reward_index: usize, new_reward_duration: u64) -> Result<()> {
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
-
+    fn validate(&self, reward_info: &RewardInfo, new_reward_duration: u64) -> Result<()> {
         require!(
             new_reward_duration >= MIN_REWARD_DURATION
                 && new_reward_duration <= MAX_REWARD_DURATION,
             PoolError::InvalidRewardDuration
         );
 
-        let pool = self.pool.load()?;
-        let reward_info = &pool.reward_infos[reward_index];
         require!(reward_info.initialized(), PoolError::RewardInitialized);
 
         require!(
@@ -54,9 +51,22 @@ pub fn handle_update_reward_duration<'info>(
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
 
-    ctx.accounts.validate(index, new_reward_duration)?;
+    // extended rewards are stored in the pool reward extension
+    let pool_reward_extension_loader = find_pool_reward_extension_for_reward(
+        ctx.remaining_accounts,
+        ctx.accounts.pool.key(),
+        index,
+    )?;
+    let mut pool_reward_extension = pool_reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
 
     let mut pool = ctx.accounts.pool.load_mut()?;
+    ctx.accounts.validate(
+        get_reward_info_mut(&mut pool, pool_reward_extension.as_deref_mut(), index)?,
+        new_reward_duration,
+    )?;
 
     if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
         let operator_account = ctx
@@ -66,14 +76,16 @@ pub fn handle_update_reward_duration<'info>(
         let operator_loader: AccountLoader<'info, Operator> =
             AccountLoader::try_from(operator_account)?;
         let operator = operator_loader.load()?;
+        let current_time = Clock::get()?.unix_timestamp as u64;
         require!(
             operator.whitelisted_address.eq(&ctx.accounts.signer.key())
-                && operator.is_permission_allow(OperatorPermission::UpdateRewardDuration),
+                && operator
+                    .is_permission_allow(OperatorPermission::UpdateRewardDuration, current_time),
             PoolError::InvalidAuthority
         )
     }
 
-    let reward_info = &mut pool.reward_infos[index];
+    let reward_info = get_reward_info_mut(&mut pool, pool_reward_extension.as_deref_mut(), index)?;
 
     let old_reward_duration = reward_info.reward_duration;
     reward_info.reward_duration = new_reward_duration;
@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::NUM_REWARDS,
-    state::{Operator, OperatorPermission, Pool},
+    find_pool_reward_extension_for_reward, require_no_timelock,
+    state::{get_reward_info_mut, Operator, OperatorPermission, Pool, RewardInfo},
     EvtUpdateRewardFunder, PoolError,
 };
 
@@ -15,19 +15,38 @@ pub struct UpdateRewardFunderCtx<'info> {
     pub signer: Signer<'info>,
 }
 
-impl<'info> UpdateRewardFunderCtx<'info> {
-    fn validate(&self, reward_index: usize, new_funder: Pubkey) -> Result<()> {
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+fn validate_update_reward_funder(reward_info: &RewardInfo, new_funder: Pubkey) -> Result<()> {
+    require!(reward_info.initialized(), PoolError::RewardUninitialized);
 
-        let pool = self.pool.load()?;
-        let reward_info = &pool.reward_infos[reward_index];
+    require!(reward_info.funder != new_funder, PoolError::IdenticalFunder);
 
-        require!(reward_info.initialized(), PoolError::RewardUninitialized);
+    Ok(())
+}
 
-        require!(reward_info.funder != new_funder, PoolError::IdenticalFunder);
+/// Set the funder of the reward, returns the previous one. Extended rewards need the pool reward extension
+/// in the remaining accounts
+pub fn process_update_reward_funder<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    pool_loader: &AccountLoader<'info, Pool>,
+    index: usize,
+    new_funder: Pubkey,
+) -> Result<Pubkey> {
+    // extended rewards are stored in the pool reward extension
+    let pool_reward_extension_loader =
+        find_pool_reward_extension_for_reward(remaining_accounts, pool_loader.key(), index)?;
+    let mut pool_reward_extension = pool_reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
 
-        Ok(())
-    }
+    let mut pool = pool_loader.load_mut()?;
+    let reward_info = get_reward_info_mut(&mut pool, pool_reward_extension.as_deref_mut(), index)?;
+    validate_update_reward_funder(reward_info, new_funder)?;
+
+    let old_funder = reward_info.funder;
+    reward_info.funder = new_funder;
+
+    Ok(old_funder)
 }
 
 pub fn handle_update_reward_funder<'info>(
@@ -38,11 +57,13 @@ pub fn handle_update_reward_funder<'info>(
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
-    ctx.accounts.validate(index, new_funder)?;
-
-    let mut pool = ctx.accounts.pool.load_mut()?;
 
-    if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
+    let is_pool_creator = ctx
+        .accounts
+        .pool
+        .load()?
+        .check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key());
+    if !is_pool_creator {
         let operator_account = ctx
             .remaining_accounts
             .get(0)
@@ -50,17 +71,26 @@ pub fn handle_update_reward_funder<'info>(
         let operator_loader: AccountLoader<'info, Operator> =
             AccountLoader::try_from(operator_account)?;
         let operator = operator_loader.load()?;
+        let current_time = Clock::get()?.unix_timestamp as u64;
         require!(
             operator.whitelisted_address.eq(&ctx.accounts.signer.key())
-                && operator.is_permission_allow(OperatorPermission::UpdateRewardFunder),
+                && operator
+                    .is_permission_allow(OperatorPermission::UpdateRewardFunder, current_time),
             PoolError::InvalidAuthority
-        )
+        );
+        // pool creator isn't bound by the operator timelock
+        require_no_timelock(
+            ctx.remaining_accounts,
+            OperatorPermission::UpdateRewardFunder,
+        )?;
     }
 
-    let reward_info = &mut pool.reward_infos[index];
-
-    let old_funder = reward_info.funder;
-    reward_info.funder = new_funder;
+    let old_funder = process_update_reward_funder(
+        ctx.remaining_accounts,
+        &ctx.accounts.pool,
+        index,
+        new_funder,
+    )?;
 
     emit_cpi!(EvtUpdateRewardFunder {
         pool: ctx.accounts.pool.key(),
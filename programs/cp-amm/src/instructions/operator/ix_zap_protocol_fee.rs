@@ -3,7 +3,8 @@ use crate::PoolError;
 use crate::{
     const_pda,
     constants::treasury as TREASURY,
-    state::{Operator, Pool},
+    consume_operator_usage,
+    state::{Operator, OperatorPermission, Pool},
     token::transfer_from_pool,
 };
 use anchor_lang::prelude::*;
@@ -85,8 +86,15 @@ fn validate_accounts_and_return_withdraw_direction<'info>(
 // Rules:
 // 1. If the token mint is SOL or USDC, then must withdraw to treasury using `claim_protocol_fee` endpoint. No zap out allowed.
 // 2. If the token mint is not SOL or USDC, operator require to zap out to SOL or USDC or either one of the token of the pool
-pub fn handle_zap_protocol_fee(ctx: Context<ZapProtocolFee>, max_amount: u64) -> Result<()> {
+pub fn handle_zap_protocol_fee<'info>(
+    ctx: Context<'info, ZapProtocolFee<'info>>,
+    max_amount: u64,
+) -> Result<()> {
     let mut pool = ctx.accounts.pool.load_mut()?;
+    require!(
+        !pool.is_protocol_fee_distribution_enabled(),
+        PoolError::ProtocolFeeDistributionEnabled
+    );
     let is_withdrawing_a = validate_accounts_and_return_withdraw_direction(
         &pool,
         &ctx.accounts.token_vault,
@@ -120,6 +128,15 @@ pub fn handle_zap_protocol_fee(ctx: Context<ZapProtocolFee>, max_amount: u64) ->
 
     require!(amount > 0, PoolError::AmountIsZero);
 
+    consume_operator_usage(
+        ctx.remaining_accounts,
+        ctx.accounts.operator.key(),
+        &ctx.accounts.operator.load()?,
+        OperatorPermission::ZapProtocolFee,
+        Clock::get()?.unix_timestamp as u64,
+        amount,
+    )?;
+
     drop(pool);
 
     let receiver_token_ai = ctx.accounts.receiver_token.to_account_info();
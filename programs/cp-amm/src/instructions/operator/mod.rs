@@ -2,6 +2,8 @@ pub mod ix_create_static_config;
 pub use ix_create_static_config::*;
 pub mod ix_create_dynamic_config;
 pub use ix_create_dynamic_config::*;
+pub mod ix_create_stable_swap_config;
+pub use ix_create_stable_swap_config::*;
 pub mod ix_close_config;
 pub use ix_close_config::*;
 pub mod ix_create_token_badge;
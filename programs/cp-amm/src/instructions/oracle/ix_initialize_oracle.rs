@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::ORACLE_PREFIX,
+    state::{Oracle, Pool},
+    EvtInitializeOracle,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeOracleCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [
+            ORACLE_PREFIX.as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + Oracle::INIT_SPACE
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    /// Address paying to create the oracle. Can be anyone
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_oracle(ctx: Context<InitializeOracleCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u64;
+    pool.update_oracle_cumulatives(current_timestamp)?;
+
+    let mut oracle = ctx.accounts.oracle.load_init()?;
+    oracle.initialize(ctx.accounts.pool.key(), &pool);
+
+    emit_cpi!(EvtInitializeOracle {
+        pool: ctx.accounts.pool.key(),
+        oracle: ctx.accounts.oracle.key(),
+    });
+
+    Ok(())
+}
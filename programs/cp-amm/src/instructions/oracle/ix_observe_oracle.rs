@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Observation, Oracle, Pool},
+    PoolError,
+};
+
+/// Max number of timestamps can be observed in 1 instruction
+pub const MAX_OBSERVE_SECONDS_AGOS: usize = 16;
+
+#[derive(Accounts)]
+pub struct ObserveOracleCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(has_one = pool)]
+    pub oracle: AccountLoader<'info, Oracle>,
+}
+
+/// Returns the oracle cumulatives at each `current_timestamp - seconds_ago`
+pub fn handle_observe_oracle(
+    ctx: Context<ObserveOracleCtx>,
+    seconds_agos: Vec<u64>,
+) -> Result<Vec<Observation>> {
+    require!(
+        !seconds_agos.is_empty() && seconds_agos.len() <= MAX_OBSERVE_SECONDS_AGOS,
+        PoolError::InvalidInput
+    );
+
+    let pool = ctx.accounts.pool.load()?;
+    let oracle = ctx.accounts.oracle.load()?;
+    let current_timestamp = Clock::get()?.unix_timestamp as u64;
+
+    seconds_agos
+        .into_iter()
+        .map(|seconds_ago| oracle.observe(&pool, current_timestamp, seconds_ago))
+        .collect()
+}
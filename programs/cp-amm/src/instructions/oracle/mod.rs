@@ -0,0 +1,89 @@
+pub mod ix_initialize_oracle;
+pub use ix_initialize_oracle::*;
+
+pub mod ix_observe_oracle;
+pub use ix_observe_oracle::*;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    p_helper::p_load_mut_checked,
+    state::{Oracle, Pool},
+    PoolError,
+};
+
+/// Write an observation to the pool oracle, the oracle account is optional for swap and liquidity changes
+pub fn write_oracle_observation<'info>(
+    oracle_account: &'info AccountInfo<'info>,
+    pool_key: Pubkey,
+    pool: &Pool,
+) -> Result<()> {
+    let oracle_loader: AccountLoader<'info, Oracle> = AccountLoader::try_from(oracle_account)?;
+    let mut oracle = oracle_loader.load_mut()?;
+    require!(oracle.pool == pool_key, PoolError::InvalidOracleAccount);
+    oracle.write_observation(pool)
+}
+
+/// Oracle accounts are identified by owner and discriminator, so they can be passed at any position
+pub fn is_oracle_account(account: &AccountInfo) -> bool {
+    account.owner == &crate::ID
+        && account
+            .try_borrow_data()
+            .is_ok_and(|data| data.starts_with(Oracle::DISCRIMINATOR))
+}
+
+/// Write an observation to the pool oracle if it's passed at any position of the remaining accounts
+pub fn write_oracle_observation_if_passed<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    pool_key: Pubkey,
+    pool: &Pool,
+) -> Result<()> {
+    let oracle_account = remaining_accounts
+        .iter()
+        .find(|account| is_oracle_account(account));
+    if let Some(oracle_account) = oracle_account {
+        write_oracle_observation(oracle_account, pool_key, pool)?;
+    }
+    Ok(())
+}
+
+/// Write an observation to the pool oracle if it's one of `oracle_accounts`, which may include oracles of other pools
+pub fn write_pool_oracle_observation_if_passed<'info>(
+    oracle_accounts: &'info [AccountInfo<'info>],
+    pool_key: Pubkey,
+    pool: &Pool,
+) -> Result<()> {
+    for oracle_account in oracle_accounts {
+        let oracle_loader: AccountLoader<'info, Oracle> = AccountLoader::try_from(oracle_account)?;
+        if oracle_loader.load()?.pool == pool_key {
+            return write_oracle_observation(oracle_account, pool_key, pool);
+        }
+    }
+    Ok(())
+}
+
+/// A pinocchio equivalent of the above write_oracle_observation_if_passed
+pub fn p_write_oracle_observation(
+    remaining_accounts: &[pinocchio::account_info::AccountInfo],
+    pool_key: &pinocchio::pubkey::Pubkey,
+    pool: &Pool,
+) -> Result<()> {
+    for account in remaining_accounts {
+        if account.owner() != crate::ID.as_array() {
+            continue;
+        }
+        let is_oracle = account
+            .try_borrow_data()
+            .is_ok_and(|data| data.starts_with(Oracle::DISCRIMINATOR));
+        if !is_oracle {
+            continue;
+        }
+        let mut oracle: pinocchio::account_info::RefMut<'_, Oracle> = p_load_mut_checked(account)?;
+        require!(
+            oracle.pool.as_array() == pool_key,
+            PoolError::InvalidOracleAccount
+        );
+        oracle.write_observation(pool)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::PositionDelegate, EvtRevokePositionDelegate};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokePositionDelegateCtx<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner
+    )]
+    pub position_delegate: AccountLoader<'info, PositionDelegate>,
+
+    /// owner of position which set the delegate, receives the rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_revoke_position_delegate(ctx: Context<RevokePositionDelegateCtx>) -> Result<()> {
+    let position_delegate = ctx.accounts.position_delegate.load()?;
+
+    emit_cpi!(EvtRevokePositionDelegate {
+        position: position_delegate.position,
+        position_delegate: ctx.accounts.position_delegate.key(),
+        owner: ctx.accounts.owner.key(),
+        delegate: position_delegate.delegate,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    constants::{seeds::POSITION_DELEGATE_PREFIX, MAX_POSITION_DELEGATE_PERMISSION},
+    state::{Position, PositionDelegate},
+    EvtSetPositionDelegate, PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
+pub struct SetPositionDelegateParameters {
+    /// Address allowed to act on behalf of the position owner
+    pub delegate: Pubkey,
+    /// Wallet which must own the token accounts receiving fees and rewards claimed by the delegate
+    pub payout_destination: Pubkey,
+    /// Bitmask of PositionDelegatePermission
+    pub permission: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPositionDelegateCtx<'info> {
+    pub position: AccountLoader<'info, Position>,
+
+    /// The token account for nft
+    #[account(
+            constraint = position_nft_account.mint == position.load()?.nft_mint,
+            constraint = position_nft_account.amount == 1,
+            token::authority = owner
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        seeds = [
+            POSITION_DELEGATE_PREFIX.as_ref(),
+            position.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + PositionDelegate::INIT_SPACE
+    )]
+    pub position_delegate: AccountLoader<'info, PositionDelegate>,
+
+    /// owner of position
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_position_delegate(
+    ctx: Context<SetPositionDelegateCtx>,
+    params: SetPositionDelegateParameters,
+) -> Result<()> {
+    let SetPositionDelegateParameters {
+        delegate,
+        payout_destination,
+        permission,
+    } = params;
+    require!(
+        permission > 0 && permission < 1 << MAX_POSITION_DELEGATE_PERMISSION,
+        PoolError::InvalidPermission
+    );
+    require!(
+        delegate != Pubkey::default() && payout_destination != Pubkey::default(),
+        PoolError::InvalidInput
+    );
+
+    let mut position_delegate = ctx.accounts.position_delegate.load_init()?;
+    position_delegate.initialize(
+        ctx.accounts.position.key(),
+        ctx.accounts.owner.key(),
+        delegate,
+        payout_destination,
+        permission,
+    );
+
+    emit_cpi!(EvtSetPositionDelegate {
+        position: ctx.accounts.position.key(),
+        position_delegate: ctx.accounts.position_delegate.key(),
+        owner: ctx.accounts.owner.key(),
+        params,
+    });
+
+    Ok(())
+}
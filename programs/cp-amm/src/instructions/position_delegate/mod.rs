@@ -0,0 +1,52 @@
+pub mod ix_set_position_delegate;
+pub use ix_set_position_delegate::*;
+
+pub mod ix_revoke_position_delegate;
+pub use ix_revoke_position_delegate::*;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    state::{PositionDelegate, PositionDelegatePermission},
+    PoolError,
+};
+
+/// Validate the signer is the position owner, or its delegate with `permission`. The position delegate account is the
+/// first remaining account when the signer is a delegate.
+/// Returns the payout destination required by the delegate and the rest of the remaining accounts
+pub fn validate_position_authority<'info>(
+    position: Pubkey,
+    position_nft_account: &InterfaceAccount<'info, TokenAccount>,
+    signer: Pubkey,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    permission: PositionDelegatePermission,
+) -> Result<(Option<Pubkey>, &'info [AccountInfo<'info>])> {
+    if position_nft_account.owner == signer {
+        return Ok((None, remaining_accounts));
+    }
+
+    let (position_delegate_account, remaining_accounts) = remaining_accounts
+        .split_first()
+        .ok_or(PoolError::InvalidPositionDelegate)?;
+    let position_delegate_loader: AccountLoader<'info, PositionDelegate> =
+        AccountLoader::try_from(position_delegate_account)?;
+    let position_delegate = position_delegate_loader.load()?;
+
+    // delegate set by a previous owner of the position nft is no longer valid
+    require!(
+        position_delegate.position == position
+            && position_delegate.owner == position_nft_account.owner
+            && position_delegate.delegate == signer,
+        PoolError::InvalidPositionDelegate
+    );
+    require!(
+        position_delegate.is_permission_allow(permission),
+        PoolError::InvalidPermission
+    );
+
+    Ok((
+        Some(position_delegate.payout_destination),
+        remaining_accounts,
+    ))
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::POOL_REWARD_EXTENSION_PREFIX,
+    state::{Pool, PoolRewardExtension},
+    EvtInitializePoolRewardExtension,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePoolRewardExtensionCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [
+            POOL_REWARD_EXTENSION_PREFIX.as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + PoolRewardExtension::INIT_SPACE
+    )]
+    pub pool_reward_extension: AccountLoader<'info, PoolRewardExtension>,
+
+    /// Address paying to create the pool reward extension. Can be anyone
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_pool_reward_extension(
+    ctx: Context<InitializePoolRewardExtensionCtx>,
+) -> Result<()> {
+    let mut pool_reward_extension = ctx.accounts.pool_reward_extension.load_init()?;
+    pool_reward_extension.initialize(ctx.accounts.pool.key());
+
+    emit_cpi!(EvtInitializePoolRewardExtension {
+        pool: ctx.accounts.pool.key(),
+        pool_reward_extension: ctx.accounts.pool_reward_extension.key(),
+    });
+
+    Ok(())
+}
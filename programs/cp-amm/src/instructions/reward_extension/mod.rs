@@ -0,0 +1,96 @@
+pub mod ix_initialize_pool_reward_extension;
+pub use ix_initialize_pool_reward_extension::*;
+
+pub mod ix_initialize_position_reward_extension;
+pub use ix_initialize_position_reward_extension::*;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        get_extended_reward_index, Pool, PoolRewardExtension, Position, PositionRewardExtension,
+    },
+    PoolError,
+};
+
+/// Find the reward extension of the pool in the remaining accounts, reward extensions can be passed at any position
+pub fn find_pool_reward_extension<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    pool_key: Pubkey,
+) -> Result<Option<AccountLoader<'info, PoolRewardExtension>>> {
+    for account in remaining_accounts {
+        if account.owner != &crate::ID {
+            continue;
+        }
+        let Ok(loader) = AccountLoader::<PoolRewardExtension>::try_from(account) else {
+            continue;
+        };
+        if loader.load()?.pool == pool_key {
+            return Ok(Some(loader));
+        }
+    }
+    Ok(None)
+}
+
+/// Find the reward extension of the position in the remaining accounts, reward extensions can be passed at any position
+pub fn find_position_reward_extension<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    position_key: Pubkey,
+) -> Result<Option<AccountLoader<'info, PositionRewardExtension>>> {
+    for account in remaining_accounts {
+        if account.owner != &crate::ID {
+            continue;
+        }
+        let Ok(loader) = AccountLoader::<PositionRewardExtension>::try_from(account) else {
+            continue;
+        };
+        if loader.load()?.position == position_key {
+            return Ok(Some(loader));
+        }
+    }
+    Ok(None)
+}
+
+/// Find the pool reward extension holding `reward_index`, none for rewards stored in the pool
+pub fn find_pool_reward_extension_for_reward<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    pool_key: Pubkey,
+    reward_index: usize,
+) -> Result<Option<AccountLoader<'info, PoolRewardExtension>>> {
+    if get_extended_reward_index(reward_index)?.is_none() {
+        return Ok(None);
+    }
+    let pool_reward_extension = find_pool_reward_extension(remaining_accounts, pool_key)?
+        .ok_or(PoolError::MissingRewardExtension)?;
+    Ok(Some(pool_reward_extension))
+}
+
+/// Update extended rewards of the pool and the positions before the liquidity of the positions changes.
+/// The reward extensions are only required once an extended reward is initialized
+pub fn update_extended_rewards<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    pool_key: Pubkey,
+    pool: &Pool,
+    positions: &[(Pubkey, &Position)],
+    current_time: u64,
+) -> Result<()> {
+    if !pool.is_extended_reward_initialized() {
+        return Ok(());
+    }
+
+    let pool_reward_extension_loader = find_pool_reward_extension(remaining_accounts, pool_key)?
+        .ok_or(PoolError::MissingRewardExtension)?;
+    let mut pool_reward_extension = pool_reward_extension_loader.load_mut()?;
+    pool_reward_extension.update_rewards(pool.liquidity, current_time)?;
+
+    for (position_key, position) in positions {
+        let position_reward_extension_loader =
+            find_position_reward_extension(remaining_accounts, *position_key)?
+                .ok_or(PoolError::MissingRewardExtension)?;
+        let mut position_reward_extension = position_reward_extension_loader.load_mut()?;
+        position_reward_extension
+            .update_position_reward(position.get_total_liquidity()?, &pool_reward_extension)?;
+    }
+
+    Ok(())
+}
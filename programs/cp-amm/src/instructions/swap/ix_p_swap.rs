@@ -5,10 +5,13 @@ use crate::p_helper::{
     p_transfer_from_pool, p_transfer_from_user,
 };
 use crate::state::CollectFeeMode;
-use crate::{instruction::Swap as SwapInstruction, instruction::Swap2 as Swap2Instruction};
 use crate::{
-    process_swap_exact_in, process_swap_exact_out, process_swap_partial_fill, EvtSwap2,
-    ProcessSwapParams, ProcessSwapResult, SwapCtx,
+    instruction::Swap as SwapInstruction, instruction::Swap2 as Swap2Instruction,
+    instruction::SwapRoute as SwapRouteInstruction,
+};
+use crate::{
+    p_write_oracle_observation, process_swap_exact_in, process_swap_exact_out,
+    process_swap_partial_fill, EvtSwap2, ProcessSwapParams, ProcessSwapResult, SwapCtx,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::{get_stack_height, Instruction};
@@ -19,7 +22,7 @@ use solana_instruction::syscalls::get_processed_sibling_instruction;
 use crate::safe_math::{SafeCast, SafeMath};
 use crate::{
     activation_handler::ActivationHandler,
-    get_pool_access_validator,
+    get_pool_access_validator_with_allowlist, p_is_wallet_allowlisted,
     params::swap::TradeDirection,
     state::{fee::FeeMode, Pool},
     PoolError, SwapMode, SwapParameters2,
@@ -78,7 +81,9 @@ pub fn p_handle_swap(
     let mut pool: pinocchio::account_info::RefMut<'_, Pool> = p_load_mut_unchecked(pool)?;
 
     {
-        let access_validator = get_pool_access_validator(&pool)?;
+        let is_allowlisted =
+            p_is_wallet_allowlisted(remaining_accounts, pool_key, &pool, payer.key())?;
+        let access_validator = get_pool_access_validator_with_allowlist(&pool, is_allowlisted)?;
         require!(
             access_validator.can_swap(&Pubkey::new_from_array(*payer.key())),
             PoolError::PoolDisabled
@@ -91,10 +96,18 @@ pub fn p_handle_swap(
         amount_0,
         amount_1,
         swap_mode,
+        sqrt_price_limit,
     } = params;
 
     let swap_mode = SwapMode::try_from(swap_mode).map_err(|_| PoolError::InvalidInput)?;
 
+    let sqrt_price_limit = if swap_mode == SwapMode::PartialFillWithPriceLimit {
+        Some(sqrt_price_limit)
+    } else {
+        require!(sqrt_price_limit == 0, PoolError::InvalidSqrtPriceLimit);
+        None
+    };
+
     let trade_direction = get_trade_direction(&input_token_account, token_a_mint)?;
     let (
         token_in_mint,
@@ -160,6 +173,7 @@ pub fn p_handle_swap(
         fee_mode: &fee_mode,
         trade_direction,
         current_point,
+        sqrt_price_limit,
     };
 
     let ProcessSwapResult {
@@ -169,12 +183,16 @@ pub fn p_handle_swap(
         included_transfer_fee_amount_out,
     } = match swap_mode {
         SwapMode::ExactIn => process_swap_exact_in(process_swap_params),
-        SwapMode::PartialFill => process_swap_partial_fill(process_swap_params),
+        SwapMode::PartialFill | SwapMode::PartialFillWithPriceLimit => {
+            process_swap_partial_fill(process_swap_params)
+        }
         SwapMode::ExactOut => process_swap_exact_out(process_swap_params),
     }?;
 
     pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, current_timestamp)?;
 
+    p_write_oracle_observation(remaining_accounts, pool_key, &pool)?;
+
     // re-update next_sqrt_price for compounding pool
     swap_result.next_sqrt_price = pool.sqrt_price;
 
@@ -286,6 +304,15 @@ pub fn validate_single_swap_instruction<'c, 'info>(
     let instruction_sysvar = instruction_sysvar_account_info
         .try_borrow_data()
         .map_err(|err| ProgramError::from(u64::from(err)))?;
+
+    validate_single_swap_instruction_with_sysvar(pool, &instruction_sysvar)
+}
+
+/// `instruction_sysvar` is the data of the instructions sysvar account, the caller must validate the account address
+pub fn validate_single_swap_instruction_with_sysvar(
+    pool: &Pubkey,
+    instruction_sysvar: &[u8],
+) -> Result<()> {
     let instruction_sysvar_instructions =
         unsafe { Instructions::new_unchecked(instruction_sysvar) };
     let current_index = instruction_sysvar_instructions.load_current_index();
@@ -355,6 +382,13 @@ fn is_instruction_include_pool_swap(instruction: &Instruction, pool: &Pubkey) ->
     {
         return instruction.accounts[1].pubkey.eq(pool);
     }
+    // pools of swap route are in remaining accounts
+    if instruction_discriminator.eq(SwapRouteInstruction::DISCRIMINATOR) {
+        return instruction
+            .accounts
+            .iter()
+            .any(|account_meta| account_meta.pubkey.eq(pool));
+    }
     false
 }
 
@@ -372,5 +406,17 @@ fn is_p_instruction_include_pool_swap(
             .map_err(|err| ProgramError::from(u64::from(err)))?;
         return Ok(&account_metadata.key == pool.as_array());
     }
+    // pools of swap route are in remaining accounts
+    if instruction_discriminator.eq(SwapRouteInstruction::DISCRIMINATOR) {
+        let num_accounts = p_get_number_of_accounts_in_instruction(instruction);
+        for i in 0..num_accounts {
+            let account_metadata = instruction
+                .get_account_meta_at(i.into())
+                .map_err(|err| ProgramError::from(u64::from(err)))?;
+            if &account_metadata.key == pool.as_array() {
+                return Ok(true);
+            }
+        }
+    }
     Ok(false)
 }
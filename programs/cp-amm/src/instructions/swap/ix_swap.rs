@@ -20,6 +20,7 @@ pub enum SwapMode {
     ExactIn,
     PartialFill,
     ExactOut,
+    PartialFillWithPriceLimit,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -34,6 +35,7 @@ impl SwapParameters {
             amount_0: self.amount_in,
             amount_1: self.minimum_amount_out,
             swap_mode: SwapMode::ExactIn.into(),
+            sqrt_price_limit: 0,
         }
     }
 }
@@ -46,6 +48,29 @@ pub struct SwapParameters2 {
     pub amount_1: u64,
     /// Swap mode, refer [SwapMode]
     pub swap_mode: u8,
+    /// Only used by [SwapMode::PartialFillWithPriceLimit], the sqrt price that the swap stops at. Must be zero for other modes
+    pub sqrt_price_limit: u128,
+}
+
+impl SwapParameters2 {
+    /// Clients built before `sqrt_price_limit` was added don't send it, so it's defaulted to zero when absent
+    pub fn deserialize_with_optional_price_limit(data: &mut &[u8]) -> std::io::Result<Self> {
+        let amount_0 = u64::deserialize(data)?;
+        let amount_1 = u64::deserialize(data)?;
+        let swap_mode = u8::deserialize(data)?;
+        let sqrt_price_limit = if data.is_empty() {
+            0
+        } else {
+            u128::deserialize(data)?
+        };
+
+        Ok(SwapParameters2 {
+            amount_0,
+            amount_1,
+            swap_mode,
+            sqrt_price_limit,
+        })
+    }
 }
 
 #[event_cpi]
@@ -0,0 +1,320 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::SysvarId;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    const_pda, get_pool_access_validator, is_oracle_account,
+    params::swap::TradeDirection,
+    safe_math::SafeCast,
+    state::{fee::FeeMode, CollectFeeMode, Pool},
+    token::{calculate_transfer_fee_excluded_amount, transfer_from_pool, transfer_from_user},
+    validate_single_swap_instruction_with_sysvar, write_pool_oracle_observation_if_passed,
+    EvtSwap2, PoolError, SwapMode, SwapParameters2,
+};
+
+/// Number of remaining accounts of each hop, refer [SwapRouteHop]
+pub const SWAP_ROUTE_HOP_ACCOUNTS: usize = 8;
+
+pub const MAX_SWAP_ROUTE_HOPS: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct SwapRouteParameters {
+    /// Input amount of the first hop, including Token-2022 transfer fee
+    pub amount_in: u64,
+    /// Minimum output amount of the last hop received by the user, excluding Token-2022 transfer fee
+    pub minimum_amount_out: u64,
+}
+
+/// Hops are passed in remaining accounts, each hop takes [SWAP_ROUTE_HOP_ACCOUNTS] accounts:
+/// pool, output_token_account, token_a_vault, token_b_vault, token_a_mint, token_b_mint, token_a_program, token_b_program.
+/// Input token account of a hop is the output token account of the previous hop.
+/// Oracles of the hop pools are optional and passed after the hops
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SwapRouteCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// The user token account for input token of the first hop
+    #[account(mut)]
+    pub input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user performing the swap
+    pub payer: Signer<'info>,
+
+    /// CHECK: Sysvar Instructions account, to validate single swap instruction of pools with rate limiter
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+struct SwapRouteHop<'info> {
+    pool: AccountLoader<'info, Pool>,
+    output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+    token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+    token_a_program: Interface<'info, TokenInterface>,
+    token_b_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SwapRouteHop<'info> {
+    /// Validate hop accounts the same as [crate::SwapCtx]
+    fn try_from_accounts(accounts: &'info [AccountInfo<'info>]) -> Result<Self> {
+        let [pool, output_token_account, token_a_vault, token_b_vault, token_a_mint, token_b_mint, token_a_program, token_b_program] =
+            accounts
+        else {
+            return Err(PoolError::InvalidSwapRoute.into());
+        };
+
+        for account in [pool, output_token_account, token_a_vault, token_b_vault] {
+            require!(account.is_writable, ErrorCode::ConstraintMut);
+        }
+
+        let hop = SwapRouteHop {
+            pool: AccountLoader::try_from(pool)?,
+            output_token_account: Box::new(InterfaceAccount::try_from(output_token_account)?),
+            token_a_vault: Box::new(InterfaceAccount::try_from(token_a_vault)?),
+            token_b_vault: Box::new(InterfaceAccount::try_from(token_b_vault)?),
+            token_a_mint: Box::new(InterfaceAccount::try_from(token_a_mint)?),
+            token_b_mint: Box::new(InterfaceAccount::try_from(token_b_mint)?),
+            token_a_program: Interface::try_from(token_a_program)?,
+            token_b_program: Interface::try_from(token_b_program)?,
+        };
+
+        {
+            let pool = hop.pool.load()?;
+            require!(
+                pool.token_a_vault == hop.token_a_vault.key()
+                    && pool.token_b_vault == hop.token_b_vault.key(),
+                ErrorCode::ConstraintHasOne
+            );
+        }
+
+        require!(
+            hop.token_a_vault.mint == hop.token_a_mint.key()
+                && hop.token_b_vault.mint == hop.token_b_mint.key(),
+            ErrorCode::ConstraintTokenMint
+        );
+        require!(
+            *token_a_vault.owner == hop.token_a_program.key()
+                && *token_b_vault.owner == hop.token_b_program.key(),
+            ErrorCode::ConstraintTokenTokenProgram
+        );
+
+        Ok(hop)
+    }
+}
+
+pub fn handle_swap_route<'info>(
+    ctx: Context<'info, SwapRouteCtx<'info>>,
+    params: SwapRouteParameters,
+) -> Result<()> {
+    let SwapRouteParameters {
+        amount_in,
+        minimum_amount_out,
+    } = params;
+    require!(amount_in > 0, PoolError::AmountIsZero);
+
+    // the last hop account is a token program, so trailing oracles don't overlap with hops
+    let number_of_oracles = ctx
+        .remaining_accounts
+        .iter()
+        .rev()
+        .take_while(|account| is_oracle_account(account))
+        .count();
+    let (remaining_accounts, oracle_accounts) = ctx
+        .remaining_accounts
+        .split_at(ctx.remaining_accounts.len() - number_of_oracles);
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % SWAP_ROUTE_HOP_ACCOUNTS == 0,
+        PoolError::InvalidSwapRoute
+    );
+    let number_of_hops = remaining_accounts.len() / SWAP_ROUTE_HOP_ACCOUNTS;
+    require!(
+        number_of_oracles <= number_of_hops,
+        PoolError::InvalidSwapRoute
+    );
+    require!(
+        number_of_hops <= MAX_SWAP_ROUTE_HOPS,
+        PoolError::InvalidSwapRoute
+    );
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u64;
+    let mut pool_keys: Vec<Pubkey> = Vec::with_capacity(number_of_hops);
+    let mut input_token_account = ctx.accounts.input_token_account.clone();
+    let mut included_transfer_fee_amount_in = amount_in;
+
+    for (hop_index, hop_accounts) in remaining_accounts
+        .chunks(SWAP_ROUTE_HOP_ACCOUNTS)
+        .enumerate()
+    {
+        let hop = SwapRouteHop::try_from_accounts(hop_accounts)?;
+        let pool_key = hop.pool.key();
+
+        // a pool can only be swapped once, otherwise it could be used to bypass the rate limiter
+        require!(!pool_keys.contains(&pool_key), PoolError::InvalidSwapRoute);
+        pool_keys.push(pool_key);
+
+        let trade_direction = if input_token_account.mint == hop.token_a_mint.key() {
+            TradeDirection::AtoB
+        } else {
+            require!(
+                input_token_account.mint == hop.token_b_mint.key(),
+                PoolError::InvalidSwapRoute
+            );
+            TradeDirection::BtoA
+        };
+        let (
+            token_in_mint,
+            token_out_mint,
+            input_vault_account,
+            output_vault_account,
+            input_program,
+            output_program,
+        ) = match trade_direction {
+            TradeDirection::AtoB => (
+                &hop.token_a_mint,
+                &hop.token_b_mint,
+                &hop.token_a_vault,
+                &hop.token_b_vault,
+                &hop.token_a_program,
+                &hop.token_b_program,
+            ),
+            TradeDirection::BtoA => (
+                &hop.token_b_mint,
+                &hop.token_a_mint,
+                &hop.token_b_vault,
+                &hop.token_a_vault,
+                &hop.token_b_program,
+                &hop.token_a_program,
+            ),
+        };
+        require!(
+            hop.output_token_account.mint == token_out_mint.key(),
+            PoolError::InvalidSwapRoute
+        );
+
+        let is_last_hop = hop_index + 1 == number_of_hops;
+        let hop_minimum_amount_out = if is_last_hop { minimum_amount_out } else { 0 };
+
+        let mut pool = hop.pool.load_mut()?;
+        {
+            // allowlist entries aren't passed, so permissioned pools can't be routed
+            let access_validator = get_pool_access_validator(&pool)?;
+            require!(
+                access_validator.can_swap(&ctx.accounts.payer.key()),
+                PoolError::PoolDisabled
+            );
+        }
+
+        pool.update_layout_version_if_needed()?;
+
+        let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+
+        if let Ok(rate_limiter) = pool.pool_fees.base_fee.to_fee_rate_limiter() {
+            if rate_limiter.is_rate_limiter_applied(
+                current_point,
+                pool.activation_point,
+                trade_direction,
+            )? {
+                validate_single_swap_instruction_with_sysvar(
+                    &pool_key,
+                    &ctx.accounts.sysvar_instructions.try_borrow_data()?,
+                )?;
+            }
+        }
+
+        // update for dynamic fee reference
+        pool.update_pre_swap(current_timestamp)?;
+
+        let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast()?;
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+        let excluded_transfer_fee_amount_in = calculate_transfer_fee_excluded_amount(
+            &token_in_mint.to_account_info().try_borrow_data()?,
+            included_transfer_fee_amount_in,
+        )?
+        .amount;
+        require!(excluded_transfer_fee_amount_in > 0, PoolError::AmountIsZero);
+
+        let mut swap_result = pool.get_swap_result_from_exact_input(
+            excluded_transfer_fee_amount_in,
+            &fee_mode,
+            trade_direction,
+            current_point,
+        )?;
+
+        let excluded_transfer_fee_amount_out = calculate_transfer_fee_excluded_amount(
+            &token_out_mint.to_account_info().try_borrow_data()?,
+            swap_result.output_amount,
+        )?
+        .amount;
+
+        pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, current_timestamp)?;
+
+        // re-update next_sqrt_price for compounding pool
+        swap_result.next_sqrt_price = pool.sqrt_price;
+
+        write_pool_oracle_observation_if_passed(oracle_accounts, pool_key, &pool)?;
+
+        let (reserve_a_amount, reserve_b_amount) = (pool.token_a_amount, pool.token_b_amount);
+        drop(pool);
+
+        // send to reserve
+        transfer_from_user(
+            &ctx.accounts.payer,
+            token_in_mint,
+            &input_token_account,
+            input_vault_account,
+            input_program,
+            included_transfer_fee_amount_in,
+        )?;
+        // send to user
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            token_out_mint,
+            output_vault_account,
+            &hop.output_token_account.to_account_info(),
+            output_program,
+            swap_result.output_amount,
+        )?;
+
+        emit_cpi!(EvtSwap2 {
+            pool: pool_key,
+            trade_direction: trade_direction.into(),
+            collect_fee_mode: collect_fee_mode.into(),
+            has_referral: false,
+            params: SwapParameters2 {
+                amount_0: included_transfer_fee_amount_in,
+                amount_1: hop_minimum_amount_out,
+                swap_mode: SwapMode::ExactIn.into(),
+                sqrt_price_limit: 0,
+            },
+            swap_result,
+            current_timestamp,
+            included_transfer_fee_amount_in,
+            included_transfer_fee_amount_out: swap_result.output_amount,
+            excluded_transfer_fee_amount_out,
+            reserve_a_amount,
+            reserve_b_amount,
+        });
+
+        // output of this hop is the input of the next hop
+        input_token_account = hop.output_token_account;
+        included_transfer_fee_amount_in = excluded_transfer_fee_amount_out;
+    }
+
+    require!(
+        included_transfer_fee_amount_in >= minimum_amount_out,
+        PoolError::ExceededSlippage
+    );
+
+    Ok(())
+}
@@ -4,6 +4,9 @@ pub use ix_swap::*;
 pub mod ix_p_swap;
 pub use ix_p_swap::*;
 
+pub mod ix_swap_route;
+pub use ix_swap_route::*;
+
 pub mod swap_exact_in;
 pub use swap_exact_in::*;
 
@@ -27,6 +30,8 @@ pub struct ProcessSwapParams<'a> {
     pub current_point: u64,
     pub amount_0: u64,
     pub amount_1: u64,
+    /// Set only for [SwapMode::PartialFillWithPriceLimit]
+    pub sqrt_price_limit: Option<u128>,
 }
 
 pub struct ProcessSwapResult {
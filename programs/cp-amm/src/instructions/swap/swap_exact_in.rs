@@ -15,6 +15,7 @@ pub fn process_swap_exact_in<'a>(params: ProcessSwapParams<'a>) -> Result<Proces
         fee_mode,
         trade_direction,
         current_point,
+        sqrt_price_limit: _,
     } = params;
 
     let excluded_transfer_fee_amount_in = calculate_transfer_fee_excluded_amount(
@@ -16,6 +16,7 @@ pub fn process_swap_exact_out<'a>(params: ProcessSwapParams<'a>) -> Result<Proce
         current_point,
         amount_0: amount_out,
         amount_1: maximum_amount_in,
+        sqrt_price_limit: _,
     } = params;
 
     let included_transfer_fee_amount_out = calculate_transfer_fee_included_amount(
@@ -15,6 +15,7 @@ pub fn process_swap_partial_fill<'a>(params: ProcessSwapParams<'a>) -> Result<Pr
         fee_mode,
         trade_direction,
         current_point,
+        sqrt_price_limit,
     } = params;
 
     let excluded_transfer_fee_amount_in = calculate_transfer_fee_excluded_amount(
@@ -33,6 +34,7 @@ pub fn process_swap_partial_fill<'a>(params: ProcessSwapParams<'a>) -> Result<Pr
         fee_mode,
         trade_direction,
         current_point,
+        sqrt_price_limit,
     )?;
 
     // require in amount is non-zero
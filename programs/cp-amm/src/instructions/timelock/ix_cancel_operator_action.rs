@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    is_valid_operator_role,
+    state::{Operator, OperatorPermission, PendingOperatorAction},
+    EvtCancelOperatorAction, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelOperatorActionCtx<'info> {
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub pending_operator_action: AccountLoader<'info, PendingOperatorAction>,
+
+    /// CHECK: proposer of the pending action, receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_cancel_operator_action(ctx: Context<CancelOperatorActionCtx>) -> Result<()> {
+    let pending_operator_action = ctx.accounts.pending_operator_action.load()?;
+
+    // any operator with the permission of the pending action can cancel it
+    let permission = OperatorPermission::try_from(pending_operator_action.permission)
+        .map_err(|_| PoolError::InvalidOperatorAction)?;
+    is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, permission)?;
+
+    emit_cpi!(EvtCancelOperatorAction {
+        pool: pending_operator_action.pool,
+        pending_operator_action: ctx.accounts.pending_operator_action.key(),
+        proposer: pending_operator_action.proposer,
+        operator: ctx.accounts.signer.key(),
+        permission: pending_operator_action.permission,
+    });
+
+    Ok(())
+}
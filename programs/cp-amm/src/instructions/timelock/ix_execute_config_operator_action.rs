@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::CONFIG_PREFIX,
+    process_create_dynamic_config, process_create_stable_swap_config, process_create_static_config,
+    state::{Config, Operator, OperatorAction, PendingOperatorAction},
+    validate_pending_operator_action, EvtExecuteOperatorAction, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ExecuteConfigOperatorActionCtx<'info> {
+    #[account(
+        mut,
+        has_one = proposer,
+        constraint = pending_operator_action.load()?.pool == config.key() @ PoolError::InvalidOperatorAction,
+        close = proposer
+    )]
+    pub pending_operator_action: AccountLoader<'info, PendingOperatorAction>,
+
+    #[account(
+        init,
+        seeds = [CONFIG_PREFIX.as_ref(), index.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + Config::INIT_SPACE
+    )]
+    pub config: AccountLoader<'info, Config>,
+
+    /// CHECK: proposer of the pending action, receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// operator account of the proposer
+    pub operator: AccountLoader<'info, Operator>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless, same as `execute_operator_action` for the config actions, which create the config account
+pub fn handle_execute_config_operator_action(
+    ctx: Context<ExecuteConfigOperatorActionCtx>,
+    index: u64,
+) -> Result<()> {
+    let (action, permission) = {
+        let pending_operator_action = ctx.accounts.pending_operator_action.load()?;
+        (
+            validate_pending_operator_action(&pending_operator_action, &ctx.accounts.operator)?,
+            pending_operator_action.permission,
+        )
+    };
+    require!(
+        action.get_config_index() == Some(index),
+        PoolError::InvalidOperatorAction
+    );
+
+    match action {
+        OperatorAction::CreateConfig {
+            config_parameters, ..
+        } => {
+            let evt_create_config =
+                process_create_static_config(&ctx.accounts.config, index, config_parameters)?;
+            emit_cpi!(evt_create_config);
+        }
+        OperatorAction::CreateDynamicConfig {
+            config_parameters, ..
+        } => {
+            let evt_create_dynamic_config =
+                process_create_dynamic_config(&ctx.accounts.config, index, config_parameters)?;
+            emit_cpi!(evt_create_dynamic_config);
+        }
+        OperatorAction::CreateStableSwapConfig {
+            config_parameters, ..
+        } => {
+            let evt_create_stable_swap_config =
+                process_create_stable_swap_config(&ctx.accounts.config, index, config_parameters)?;
+            emit_cpi!(evt_create_stable_swap_config);
+        }
+        _ => return err!(PoolError::InvalidOperatorAction),
+    }
+
+    emit_cpi!(EvtExecuteOperatorAction {
+        pool: ctx.accounts.config.key(),
+        pending_operator_action: ctx.accounts.pending_operator_action.key(),
+        proposer: ctx.accounts.proposer.key(),
+        permission,
+    });
+
+    Ok(())
+}
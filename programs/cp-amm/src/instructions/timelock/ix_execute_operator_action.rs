@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    event, is_valid_operator_role, process_set_pool_status, process_update_reward_funder,
+    state::{Operator, OperatorAction, OperatorPermission, PendingOperatorAction, Pool},
+    EvtExecuteOperatorAction, EvtUpdatePoolFees, EvtUpdateRewardFunder, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteOperatorActionCtx<'info> {
+    #[account(
+        mut,
+        has_one = pool,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub pending_operator_action: AccountLoader<'info, PendingOperatorAction>,
+
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    /// CHECK: proposer of the pending action, receives the rent
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// operator account of the proposer
+    pub operator: AccountLoader<'info, Operator>,
+}
+
+/// The proposer must still hold the permission of the action when it is executed, expired actions and actions of
+/// a closed or expired operator can only be cancelled
+pub fn validate_pending_operator_action(
+    pending_operator_action: &PendingOperatorAction,
+    operator: &AccountLoader<Operator>,
+) -> Result<OperatorAction> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        pending_operator_action.is_executable(current_time),
+        PoolError::OperatorActionNotExecutable
+    );
+    require!(
+        !pending_operator_action.is_expired(current_time),
+        PoolError::OperatorActionExpired
+    );
+
+    let permission = OperatorPermission::try_from(pending_operator_action.permission)
+        .map_err(|_| PoolError::InvalidOperatorAction)?;
+    is_valid_operator_role(operator, &pending_operator_action.proposer, permission)?;
+
+    pending_operator_action.get_action()
+}
+
+/// Permissionless, the pending action can be executed by anyone once the delay has passed
+pub fn handle_execute_operator_action<'info>(
+    ctx: Context<'info, ExecuteOperatorActionCtx<'info>>,
+) -> Result<()> {
+    let (action, permission) = {
+        let pending_operator_action = ctx.accounts.pending_operator_action.load()?;
+        (
+            validate_pending_operator_action(&pending_operator_action, &ctx.accounts.operator)?,
+            pending_operator_action.permission,
+        )
+    };
+
+    match action {
+        OperatorAction::SetPoolStatus { status } => {
+            let mut pool = ctx.accounts.pool.load_mut()?;
+            let previous_status = process_set_pool_status(&mut pool, status)?;
+
+            emit_cpi!(event::EvtSetPoolStatus {
+                pool: ctx.accounts.pool.key(),
+                status,
+                previous_status,
+                changed_actions: status ^ previous_status,
+            });
+        }
+        OperatorAction::UpdateRewardFunder {
+            reward_index,
+            new_funder,
+        } => {
+            let index: usize = reward_index
+                .try_into()
+                .map_err(|_| PoolError::TypeCastFailed)?;
+            let old_funder = process_update_reward_funder(
+                ctx.remaining_accounts,
+                &ctx.accounts.pool,
+                index,
+                new_funder,
+            )?;
+
+            emit_cpi!(EvtUpdateRewardFunder {
+                pool: ctx.accounts.pool.key(),
+                reward_index,
+                old_funder,
+                new_funder,
+            });
+        }
+        OperatorAction::UpdatePoolFees { params } => {
+            params.validate()?;
+            let mut pool = ctx.accounts.pool.load_mut()?;
+            pool.validate_and_update_pool_fees(&params)?;
+
+            emit_cpi!(EvtUpdatePoolFees {
+                pool: ctx.accounts.pool.key(),
+                operator: ctx.accounts.proposer.key(),
+                params,
+            });
+        }
+        OperatorAction::CreateConfig { .. }
+        | OperatorAction::CreateDynamicConfig { .. }
+        | OperatorAction::CreateStableSwapConfig { .. } => {
+            return err!(PoolError::InvalidOperatorAction);
+        }
+    }
+
+    emit_cpi!(EvtExecuteOperatorAction {
+        pool: ctx.accounts.pool.key(),
+        pending_operator_action: ctx.accounts.pending_operator_action.key(),
+        proposer: ctx.accounts.proposer.key(),
+        permission,
+    });
+
+    Ok(())
+}
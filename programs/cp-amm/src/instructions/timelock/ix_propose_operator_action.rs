@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    const_pda,
+    constants::seeds::{CONFIG_PREFIX, PENDING_OPERATOR_ACTION_PREFIX},
+    safe_math::SafeMath,
+    state::{Operator, OperatorAction, PendingOperatorAction, Pool, TimelockConfig},
+    EvtProposeOperatorAction, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(action: OperatorAction)]
+pub struct ProposeOperatorActionCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            PENDING_OPERATOR_ACTION_PREFIX.as_ref(),
+            pool.key().as_ref(),
+            &[u8::from(action.get_permission())],
+        ],
+        bump,
+        space = 8 + PendingOperatorAction::INIT_SPACE
+    )]
+    pub pending_operator_action: AccountLoader<'info, PendingOperatorAction>,
+
+    /// CHECK: pool of the action, or the config created by config actions, validated in the handler
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(address = const_pda::timelock_config::ID)]
+    pub timelock_config: AccountLoader<'info, TimelockConfig>,
+
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_propose_operator_action(
+    ctx: Context<ProposeOperatorActionCtx>,
+    action: OperatorAction,
+) -> Result<()> {
+    action.validate()?;
+
+    match action.get_config_index() {
+        Some(index) => {
+            let config = Pubkey::find_program_address(
+                &[CONFIG_PREFIX.as_ref(), index.to_le_bytes().as_ref()],
+                &crate::ID,
+            )
+            .0;
+            require!(
+                ctx.accounts.pool.key() == config,
+                PoolError::InvalidOperatorAction
+            );
+        }
+        None => {
+            let is_pool = ctx.accounts.pool.owner == &crate::ID
+                && ctx
+                    .accounts
+                    .pool
+                    .try_borrow_data()
+                    .is_ok_and(|data| data.starts_with(Pool::DISCRIMINATOR));
+            require!(is_pool, PoolError::InvalidOperatorAction);
+        }
+    }
+
+    let delay = ctx
+        .accounts
+        .timelock_config
+        .load()?
+        .get_delay(action.get_permission());
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let earliest_execution_timestamp = current_time.safe_add(delay)?;
+
+    let mut pending_operator_action = ctx.accounts.pending_operator_action.load_init()?;
+    pending_operator_action.initialize(
+        ctx.accounts.pool.key(),
+        ctx.accounts.signer.key(),
+        earliest_execution_timestamp,
+        &action,
+    )?;
+
+    emit_cpi!(EvtProposeOperatorAction {
+        pool: ctx.accounts.pool.key(),
+        pending_operator_action: ctx.accounts.pending_operator_action.key(),
+        proposer: ctx.accounts.signer.key(),
+        earliest_execution_timestamp,
+        action,
+    });
+
+    Ok(())
+}
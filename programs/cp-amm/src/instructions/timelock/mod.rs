@@ -0,0 +1,56 @@
+pub mod ix_propose_operator_action;
+pub use ix_propose_operator_action::*;
+
+pub mod ix_cancel_operator_action;
+pub use ix_cancel_operator_action::*;
+
+pub mod ix_execute_operator_action;
+pub use ix_execute_operator_action::*;
+
+pub mod ix_execute_config_operator_action;
+pub use ix_execute_config_operator_action::*;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    const_pda,
+    state::{OperatorPermission, TimelockConfig},
+    PoolError,
+};
+
+/// Delay of the operator permission, zero when the timelock config isn't initialized
+pub fn get_timelock_delay(
+    timelock_config: &AccountInfo,
+    permission: OperatorPermission,
+) -> Result<u64> {
+    if timelock_config.owner != &crate::ID {
+        return Ok(0);
+    }
+    let data = timelock_config.try_borrow_data()?;
+    let space = TimelockConfig::DISCRIMINATOR.len() + std::mem::size_of::<TimelockConfig>();
+    if !data.starts_with(TimelockConfig::DISCRIMINATOR) || data.len() < space {
+        return Ok(0);
+    }
+    let timelock_config: TimelockConfig =
+        bytemuck::pod_read_unaligned(&data[TimelockConfig::DISCRIMINATOR.len()..space]);
+    Ok(timelock_config.get_delay(permission))
+}
+
+/// Operator actions with a delay must go through `propose_operator_action`. The timelock config is an optional
+/// remaining account, so clients built before the timelock keep working and the action has no delay without it
+pub fn require_no_timelock(
+    remaining_accounts: &[AccountInfo],
+    permission: OperatorPermission,
+) -> Result<()> {
+    let Some(timelock_config) = remaining_accounts
+        .iter()
+        .find(|account| account.key == &const_pda::timelock_config::ID)
+    else {
+        return Ok(());
+    };
+    require!(
+        get_timelock_delay(timelock_config, permission)? == 0,
+        PoolError::OperatorActionTimelocked
+    );
+    Ok(())
+}
@@ -18,6 +18,7 @@ pub use event::*;
 pub mod utils;
 pub use utils::*;
 pub mod base_fee;
+pub mod dynamic_fee;
 pub mod math;
 pub use math::*;
 pub mod liquidity_handler;
@@ -30,7 +31,7 @@ pub use pool_action_access::*;
 pub mod access_control;
 pub use access_control::*;
 use params::fee_parameters::BaseFeeParameters;
-use state::OperatorPermission;
+use state::{OperatorAction, OperatorPermission, ProtocolFeeReceiverParameters};
 
 #[cfg(not(feature = "no-custom-entrypoint"))]
 mod entrypoint;
@@ -51,6 +52,12 @@ pub struct ForIdlTypeGenerationDoNotCallThis<'info> {
         AccountLoader<'info, base_fee::fee_rate_limiter::PodAlignedFeeRateLimiter>,
     pod_aligned_fee_market_cap_scheduler:
         AccountLoader<'info, base_fee::fee_market_cap_scheduler::PodAlignedFeeMarketCapScheduler>,
+    pod_aligned_fee_volume_tier:
+        AccountLoader<'info, base_fee::fee_volume_tier::PodAlignedFeeVolumeTier>,
+    pod_aligned_asymmetric_fee_time_scheduler: AccountLoader<
+        'info,
+        base_fee::fee_asymmetric_time_scheduler::PodAlignedAsymmetricFeeTimeScheduler,
+    >,
 }
 
 #[cfg(feature = "idl-build")]
@@ -60,6 +67,9 @@ pub struct DummyParams {
     borsh_fee_rate_limiter_params: base_fee::fee_rate_limiter::BorshFeeRateLimiter,
     borsh_fee_market_cap_scheduler_params:
         base_fee::fee_market_cap_scheduler::BorshFeeMarketCapScheduler,
+    borsh_fee_volume_tier_params: base_fee::fee_volume_tier::BorshFeeVolumeTier,
+    borsh_asymmetric_fee_time_scheduler_params:
+        base_fee::fee_asymmetric_time_scheduler::BorshAsymmetricFeeTimeScheduler,
 }
 
 #[program]
@@ -81,6 +91,66 @@ pub mod cp_amm {
         Ok(())
     }
 
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn initialize_timelock_config(ctx: Context<InitializeTimelockConfigCtx>) -> Result<()> {
+        instructions::handle_initialize_timelock_config(ctx)
+    }
+
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn set_timelock_delay(
+        ctx: Context<SetTimelockDelayCtx>,
+        permission: u8,
+        delay: u64,
+    ) -> Result<()> {
+        instructions::handle_set_timelock_delay(ctx, permission, delay)
+    }
+
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn update_operator_account(
+        ctx: Context<UpdateOperatorAccountCtx>,
+        params: UpdateOperatorParameters,
+    ) -> Result<()> {
+        instructions::handle_update_operator(ctx, params)
+    }
+
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn initialize_operator_usage_limit(
+        ctx: Context<InitializeOperatorUsageLimitCtx>,
+    ) -> Result<()> {
+        instructions::handle_initialize_operator_usage_limit(ctx)
+    }
+
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn set_operator_usage_limit(
+        ctx: Context<SetOperatorUsageLimitCtx>,
+        params: OperatorUsageLimitParameters,
+    ) -> Result<()> {
+        instructions::handle_set_operator_usage_limit(ctx, params)
+    }
+
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn initialize_protocol_fee_distribution(
+        ctx: Context<InitializeProtocolFeeDistributionCtx>,
+        receivers: Vec<ProtocolFeeReceiverParameters>,
+    ) -> Result<()> {
+        instructions::handle_initialize_protocol_fee_distribution(ctx, receivers)
+    }
+
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn update_protocol_fee_distribution(
+        ctx: Context<UpdateProtocolFeeDistributionCtx>,
+        receivers: Vec<ProtocolFeeReceiverParameters>,
+    ) -> Result<()> {
+        instructions::handle_update_protocol_fee_distribution(ctx, receivers)
+    }
+
+    #[access_control(is_admin(ctx.accounts.signer.key))]
+    pub fn close_protocol_fee_distribution(
+        ctx: Context<CloseProtocolFeeDistributionCtx>,
+    ) -> Result<()> {
+        instructions::handle_close_protocol_fee_distribution(ctx)
+    }
+
     /// OPERATOR FUNCTIONS /////
     // create static config
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CreateConfigKey))]
@@ -102,6 +172,16 @@ pub mod cp_amm {
         instructions::handle_create_dynamic_config(ctx, index, config_parameters)
     }
 
+    // create static config with the stable swap curve
+    #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CreateConfigKey))]
+    pub fn create_stable_swap_config(
+        ctx: Context<CreateConfigCtx>,
+        index: u64,
+        config_parameters: StableSwapConfigParameters,
+    ) -> Result<()> {
+        instructions::handle_create_stable_swap_config(ctx, index, config_parameters)
+    }
+
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CreateTokenBadge))]
     pub fn create_token_badge(ctx: Context<CreateTokenBadgeCtx>) -> Result<()> {
         instructions::handle_create_token_badge(ctx)
@@ -134,11 +214,27 @@ pub mod cp_amm {
         reward_duration: u64,
         funder: Pubkey,
     ) -> Result<()> {
-        instructions::handle_initialize_reward(ctx, reward_index, reward_duration, funder)
+        instructions::handle_initialize_reward(
+            ctx,
+            reward_index,
+            InitializeRewardParameters {
+                reward_duration,
+                funder,
+                ..Default::default()
+            },
+        )
     }
 
-    pub fn fund_reward(
-        ctx: Context<FundRewardCtx>,
+    pub fn initialize_reward2<'info>(
+        ctx: Context<'info, InitializeRewardCtx<'info>>,
+        reward_index: u8,
+        params: InitializeRewardParameters,
+    ) -> Result<()> {
+        instructions::handle_initialize_reward(ctx, reward_index, params)
+    }
+
+    pub fn fund_reward<'info>(
+        ctx: Context<'info, FundRewardCtx<'info>>,
         reward_index: u8,
         amount: u64,
         carry_forward: bool,
@@ -146,8 +242,8 @@ pub mod cp_amm {
         instructions::handle_fund_reward(ctx, reward_index, amount, carry_forward)
     }
 
-    pub fn withdraw_ineligible_reward(
-        ctx: Context<WithdrawIneligibleRewardCtx>,
+    pub fn withdraw_ineligible_reward<'info>(
+        ctx: Context<'info, WithdrawIneligibleRewardCtx<'info>>,
         reward_index: u8,
     ) -> Result<()> {
         instructions::handle_withdraw_ineligible_reward(ctx, reward_index)
@@ -179,8 +275,8 @@ pub mod cp_amm {
         note = "Use claim_protocol_fee2 through protocol_fee program instead"
     )]
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::ClaimProtocolFee))]
-    pub fn claim_protocol_fee(
-        ctx: Context<ClaimProtocolFeesCtx>,
+    pub fn claim_protocol_fee<'info>(
+        ctx: Context<'info, ClaimProtocolFeesCtx<'info>>,
         max_amount_a: u64,
         max_amount_b: u64,
     ) -> Result<()> {
@@ -192,7 +288,10 @@ pub mod cp_amm {
         note = "Use claim_protocol_fee2 through protocol_fee program instead"
     )]
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::ZapProtocolFee))]
-    pub fn zap_protocol_fee(ctx: Context<ZapProtocolFee>, max_amount: u64) -> Result<()> {
+    pub fn zap_protocol_fee<'info>(
+        ctx: Context<'info, ZapProtocolFee<'info>>,
+        max_amount: u64,
+    ) -> Result<()> {
         instructions::handle_zap_protocol_fee(ctx, max_amount)
     }
 
@@ -200,6 +299,13 @@ pub mod cp_amm {
         instructions::handle_claim_protocol_fee2(ctx, max_amount)
     }
 
+    pub fn distribute_protocol_fee<'info>(
+        ctx: Context<'info, DistributeProtocolFeeCtx<'info>>,
+        max_amount: u64,
+    ) -> Result<()> {
+        instructions::handle_distribute_protocol_fee(ctx, max_amount)
+    }
+
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CloseTokenBadge))]
     pub fn close_token_badge(ctx: Context<CloseTokenBadgeCtx>) -> Result<()> {
         instructions::handle_close_token_badge(ctx)
@@ -213,6 +319,31 @@ pub mod cp_amm {
         instructions::handle_update_pool_fees(ctx, params)
     }
 
+    #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, action.get_permission()))]
+    pub fn propose_operator_action(
+        ctx: Context<ProposeOperatorActionCtx>,
+        action: OperatorAction,
+    ) -> Result<()> {
+        instructions::handle_propose_operator_action(ctx, action)
+    }
+
+    pub fn cancel_operator_action(ctx: Context<CancelOperatorActionCtx>) -> Result<()> {
+        instructions::handle_cancel_operator_action(ctx)
+    }
+
+    pub fn execute_operator_action<'info>(
+        ctx: Context<'info, ExecuteOperatorActionCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_execute_operator_action(ctx)
+    }
+
+    pub fn execute_config_operator_action(
+        ctx: Context<ExecuteConfigOperatorActionCtx>,
+        index: u64,
+    ) -> Result<()> {
+        instructions::handle_execute_config_operator_action(ctx, index)
+    }
+
     /// USER FUNCTIONS ////
 
     pub fn initialize_pool<'info>(
@@ -236,19 +367,41 @@ pub mod cp_amm {
         instructions::handle_initialize_customizable_pool(ctx, params)
     }
 
+    pub fn initialize_permissioned_pool<'info>(
+        ctx: Context<'info, InitializePermissionedPoolCtx<'info>>,
+        params: InitializeCustomizablePoolParameters,
+    ) -> Result<()> {
+        instructions::handle_initialize_permissioned_pool(ctx, params)
+    }
+
+    pub fn add_allowlist_entry(ctx: Context<AddAllowlistEntryCtx>) -> Result<()> {
+        instructions::handle_add_allowlist_entry(ctx)
+    }
+
+    pub fn remove_allowlist_entry(ctx: Context<RemoveAllowlistEntryCtx>) -> Result<()> {
+        instructions::handle_remove_allowlist_entry(ctx)
+    }
+
     pub fn create_position(ctx: Context<CreatePositionCtx>) -> Result<()> {
         instructions::handle_create_position(ctx)
     }
 
-    pub fn add_liquidity(
-        ctx: Context<AddLiquidityCtx>,
+    pub fn add_liquidity<'info>(
+        ctx: Context<'info, AddLiquidityCtx<'info>>,
         params: AddLiquidityParameters,
     ) -> Result<()> {
         instructions::handle_add_liquidity(ctx, params)
     }
 
-    pub fn remove_liquidity(
-        ctx: Context<RemoveLiquidityCtx>,
+    pub fn add_liquidity_single_sided<'info>(
+        ctx: Context<'info, AddLiquiditySingleSidedCtx<'info>>,
+        params: AddLiquiditySingleSidedParameters,
+    ) -> Result<()> {
+        instructions::handle_add_liquidity_single_sided(ctx, params)
+    }
+
+    pub fn remove_liquidity<'info>(
+        ctx: Context<'info, RemoveLiquidityCtx<'info>>,
         params: RemoveLiquidityParameters,
     ) -> Result<()> {
         instructions::handle_remove_liquidity(
@@ -259,8 +412,8 @@ pub mod cp_amm {
         )
     }
 
-    pub fn remove_all_liquidity(
-        ctx: Context<RemoveLiquidityCtx>,
+    pub fn remove_all_liquidity<'info>(
+        ctx: Context<'info, RemoveLiquidityCtx<'info>>,
         token_a_amount_threshold: u64,
         token_b_amount_threshold: u64,
     ) -> Result<()> {
@@ -272,7 +425,14 @@ pub mod cp_amm {
         )
     }
 
-    pub fn close_position(ctx: Context<ClosePositionCtx>) -> Result<()> {
+    pub fn remove_liquidity_single_token<'info>(
+        ctx: Context<'info, RemoveLiquiditySingleTokenCtx<'info>>,
+        params: RemoveLiquiditySingleTokenParameters,
+    ) -> Result<()> {
+        instructions::handle_remove_liquidity_single_token(ctx, params)
+    }
+
+    pub fn close_position<'info>(ctx: Context<'info, ClosePositionCtx<'info>>) -> Result<()> {
         instructions::handle_close_position(ctx)
     }
 
@@ -284,10 +444,53 @@ pub mod cp_amm {
         Ok(())
     }
 
-    pub fn claim_position_fee(ctx: Context<ClaimPositionFeeCtx>) -> Result<()> {
+    pub fn swap_route<'info>(
+        ctx: Context<'info, SwapRouteCtx<'info>>,
+        params: SwapRouteParameters,
+    ) -> Result<()> {
+        instructions::handle_swap_route(ctx, params)
+    }
+
+    pub fn flash_borrow(ctx: Context<FlashLoanCtx>, amount: u64) -> Result<()> {
+        instructions::handle_flash_borrow(ctx, amount)
+    }
+
+    pub fn flash_repay(ctx: Context<FlashLoanCtx>, borrow_instruction_index: u16) -> Result<()> {
+        instructions::handle_flash_repay(ctx, borrow_instruction_index)
+    }
+
+    pub fn claim_position_fee<'info>(
+        ctx: Context<'info, ClaimPositionFeeCtx<'info>>,
+    ) -> Result<()> {
         instructions::handle_claim_position_fee(ctx)
     }
 
+    pub fn claim_creator_fee(
+        ctx: Context<ClaimCreatorFeeCtx>,
+        max_amount_a: u64,
+        max_amount_b: u64,
+    ) -> Result<()> {
+        instructions::handle_claim_creator_fee(ctx, max_amount_a, max_amount_b)
+    }
+
+    pub fn compound_position_fee<'info>(
+        ctx: Context<'info, CompoundPositionFeeCtx<'info>>,
+        params: CompoundPositionFeeParameters,
+    ) -> Result<()> {
+        instructions::handle_compound_position_fee(ctx, params)
+    }
+
+    pub fn set_position_delegate(
+        ctx: Context<SetPositionDelegateCtx>,
+        params: SetPositionDelegateParameters,
+    ) -> Result<()> {
+        instructions::handle_set_position_delegate(ctx, params)
+    }
+
+    pub fn revoke_position_delegate(ctx: Context<RevokePositionDelegateCtx>) -> Result<()> {
+        instructions::handle_revoke_position_delegate(ctx)
+    }
+
     pub fn lock_position(ctx: Context<LockPositionCtx>, params: VestingParameters) -> Result<()> {
         instructions::handle_lock_position(ctx, params)
     }
@@ -310,22 +513,37 @@ pub mod cp_amm {
         instructions::handle_permanent_lock_position(ctx, permanent_lock_liquidity)
     }
 
-    pub fn claim_reward(
-        ctx: Context<ClaimRewardCtx>,
+    pub fn claim_reward<'info>(
+        ctx: Context<'info, ClaimRewardCtx<'info>>,
         reward_index: u8,
         skip_reward: u8,
     ) -> Result<()> {
         instructions::handle_claim_reward(ctx, reward_index, skip_reward)
     }
 
-    pub fn split_position(
-        ctx: Context<SplitPositionCtx>,
+    pub fn initialize_pool_reward_extension(
+        ctx: Context<InitializePoolRewardExtensionCtx>,
+    ) -> Result<()> {
+        instructions::handle_initialize_pool_reward_extension(ctx)
+    }
+
+    pub fn initialize_position_reward_extension(
+        ctx: Context<InitializePositionRewardExtensionCtx>,
+    ) -> Result<()> {
+        instructions::handle_initialize_position_reward_extension(ctx)
+    }
+
+    pub fn split_position<'info>(
+        ctx: Context<'info, SplitPositionCtx<'info>>,
         params: SplitPositionParameters,
     ) -> Result<()> {
         instructions::handle_split_position2(ctx, params.get_split_position_parameters()?)
     }
 
-    pub fn split_position2(ctx: Context<SplitPositionCtx>, numerator: u32) -> Result<()> {
+    pub fn split_position2<'info>(
+        ctx: Context<'info, SplitPositionCtx<'info>>,
+        numerator: u32,
+    ) -> Result<()> {
         instructions::handle_split_position2(
             ctx,
             SplitPositionParameters3 {
@@ -340,6 +558,21 @@ pub mod cp_amm {
         )
     }
 
+    pub fn merge_position<'info>(ctx: Context<'info, MergePositionCtx<'info>>) -> Result<()> {
+        instructions::handle_merge_position(ctx)
+    }
+
+    pub fn initialize_oracle(ctx: Context<InitializeOracleCtx>) -> Result<()> {
+        instructions::handle_initialize_oracle(ctx)
+    }
+
+    pub fn observe_oracle(
+        ctx: Context<ObserveOracleCtx>,
+        seconds_agos: Vec<u64>,
+    ) -> Result<Vec<state::Observation>> {
+        instructions::handle_observe_oracle(ctx, seconds_agos)
+    }
+
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::FixPool))]
     pub fn fix_pool_layout_version(ctx: Context<FixPoolLayoutVersionCtx>) -> Result<()> {
         instructions::handle_fix_pool_layout_version(ctx)
@@ -101,17 +101,60 @@ impl LiquidityHandler for CompoundingLiquidity {
     fn calculate_a_to_b_from_partial_amount_in(
         &self,
         amount_in: u64,
+        sqrt_price_limit: Option<u128>,
     ) -> Result<SwapAmountFromInput> {
-        // it is constant-product, so no price range
-        self.calculate_a_to_b_from_amount_in(amount_in)
+        // it is constant-product, so there is no price range, only the caller price limit
+        let Some(sqrt_price_limit) = sqrt_price_limit else {
+            return self.calculate_a_to_b_from_amount_in(amount_in);
+        };
+        // b' / a' = sqrt_price_limit ^ 2 and a' * b' = a * b
+        // => a' = sqrt(a * b) / sqrt_price_limit
+        let token_a_amount = get_sqrt_reserves_product(self.token_a_amount, self.token_b_amount)?
+            .safe_div(U256::from(sqrt_price_limit))?;
+        let max_amount_in = token_a_amount.saturating_sub(U256::from(self.token_a_amount));
+
+        let consumed_in_amount = get_consumed_in_amount(amount_in, max_amount_in)?;
+        let SwapAmountFromInput {
+            output_amount,
+            next_sqrt_price,
+            ..
+        } = self.calculate_a_to_b_from_amount_in(consumed_in_amount)?;
+
+        Ok(SwapAmountFromInput {
+            amount_left: amount_in.safe_sub(consumed_in_amount)?,
+            output_amount,
+            next_sqrt_price,
+        })
     }
 
     fn calculate_b_to_a_from_partial_amount_in(
         &self,
         amount_in: u64,
+        sqrt_price_limit: Option<u128>,
     ) -> Result<SwapAmountFromInput> {
-        // it is constant-product, so no price range
-        self.calculate_b_to_a_from_amount_in(amount_in)
+        // it is constant-product, so there is no price range, only the caller price limit
+        let Some(sqrt_price_limit) = sqrt_price_limit else {
+            return self.calculate_b_to_a_from_amount_in(amount_in);
+        };
+        // b' / a' = sqrt_price_limit ^ 2 and a' * b' = a * b
+        // => b' = sqrt(a * b) * sqrt_price_limit
+        let token_b_amount = get_sqrt_reserves_product(self.token_a_amount, self.token_b_amount)?
+            .safe_mul(U256::from(sqrt_price_limit))?
+            .safe_shr(128)?;
+        let max_amount_in = token_b_amount.saturating_sub(U256::from(self.token_b_amount));
+
+        let consumed_in_amount = get_consumed_in_amount(amount_in, max_amount_in)?;
+        let SwapAmountFromInput {
+            output_amount,
+            next_sqrt_price,
+            ..
+        } = self.calculate_b_to_a_from_amount_in(consumed_in_amount)?;
+
+        Ok(SwapAmountFromInput {
+            amount_left: amount_in.safe_sub(consumed_in_amount)?,
+            output_amount,
+            next_sqrt_price,
+        })
     }
 
     fn calculate_a_to_b_from_amount_out(&self, amount_out: u64) -> Result<SwapAmountFromOutput> {
@@ -148,6 +191,20 @@ impl LiquidityHandler for CompoundingLiquidity {
         Ok((self.token_a_amount, self.token_b_amount))
     }
 
+    fn get_max_liquidity_delta_from_amounts(
+        &self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<u128> {
+        get_max_liquidity_delta_from_reserves(
+            token_a_amount,
+            token_b_amount,
+            self.token_a_amount,
+            self.token_b_amount,
+            self.liquidity,
+        )
+    }
+
     // xyk, the price is determined by the ratio of reserves and it always rounded down.
     fn get_next_sqrt_price(&self, _next_sqrt_price: u128) -> Result<u128> {
         get_sqrt_price_from_amounts(self.token_a_amount, self.token_b_amount)
@@ -168,12 +225,61 @@ fn get_sqrt_price_from_amounts(token_a_amount: u64, token_b_amount: u64) -> Resu
         .map_err(|_| PoolError::TypeCastFailed)?)
 }
 
-fn get_initial_token_a(sqrt_price: u128, liquidity: u128) -> Result<u64> {
+/// sqrt(a * b) in Q64.64, rounded down so the swap never crosses the price limit
+fn get_sqrt_reserves_product(token_a_amount: u64, token_b_amount: u64) -> Result<U256> {
+    let product = U256::from(token_a_amount)
+        .safe_mul(U256::from(token_b_amount))?
+        .safe_shl(128)?;
+    Ok(sqrt_u256(product).ok_or_else(|| PoolError::MathOverflow)?)
+}
+
+fn get_consumed_in_amount(amount_in: u64, max_amount_in: U256) -> Result<u64> {
+    if U256::from(amount_in) >= max_amount_in {
+        Ok(max_amount_in
+            .try_into()
+            .map_err(|_| PoolError::TypeCastFailed)?)
+    } else {
+        Ok(amount_in)
+    }
+}
+
+/// Liquidity is a share of the reserves, token_amount = liquidity_delta * reserve / liquidity (round up)
+/// => liquidity_delta = token_amount * liquidity / reserve (round down), saturated at u128::MAX
+pub(crate) fn get_max_liquidity_delta_from_reserves(
+    token_a_amount: u64,
+    token_b_amount: u64,
+    reserve_a_amount: u64,
+    reserve_b_amount: u64,
+    liquidity: u128,
+) -> Result<u128> {
+    let liquidity_delta_from_a =
+        get_liquidity_delta_from_reserve(token_a_amount, reserve_a_amount, liquidity)?;
+    let liquidity_delta_from_b =
+        get_liquidity_delta_from_reserve(token_b_amount, reserve_b_amount, liquidity)?;
+    Ok(liquidity_delta_from_a.min(liquidity_delta_from_b))
+}
+
+/// Liquidity delta of `token_amount` as a share of the reserve, rounded down and saturated at u128::MAX
+pub fn get_liquidity_delta_from_reserve(
+    token_amount: u64,
+    reserve_amount: u64,
+    liquidity: u128,
+) -> Result<u128> {
+    let liquidity_delta = U256::from(token_amount)
+        .safe_mul(U256::from(liquidity))?
+        .safe_div(U256::from(reserve_amount))?
+        .min(U256::from(u128::MAX));
+    Ok(liquidity_delta
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?)
+}
+
+pub(crate) fn get_initial_token_a(sqrt_price: u128, liquidity: u128) -> Result<u64> {
     let amount = liquidity.div_ceil(sqrt_price);
     Ok(amount.safe_cast()?)
 }
 
-fn get_initial_token_b(sqrt_price: u128, liquidity: u128) -> Result<u64> {
+pub(crate) fn get_initial_token_b(sqrt_price: u128, liquidity: u128) -> Result<u64> {
     let liquidity = U256::from(liquidity);
     let sqrt_price = U256::from(sqrt_price);
     let numerator = liquidity.safe_mul(sqrt_price)?;
@@ -7,7 +7,7 @@ use crate::{
     InitialPoolInformation, LiquidityHandler, PoolError,
 };
 use anchor_lang::prelude::*;
-use ruint::aliases::U256;
+use ruint::aliases::{U256, U512};
 pub struct ConcentratedLiquidity {
     pub sqrt_max_price: u128,
     pub sqrt_min_price: u128,
@@ -113,9 +113,11 @@ impl LiquidityHandler for ConcentratedLiquidity {
     fn calculate_a_to_b_from_partial_amount_in(
         &self,
         amount_in: u64,
+        sqrt_price_limit: Option<u128>,
     ) -> Result<SwapAmountFromInput> {
+        let sqrt_price_limit = sqrt_price_limit.unwrap_or(self.sqrt_min_price);
         let max_amount_in = get_delta_amount_a_unsigned_unchecked(
-            self.sqrt_min_price,
+            sqrt_price_limit,
             self.sqrt_price,
             self.liquidity,
             Rounding::Up,
@@ -126,7 +128,7 @@ impl LiquidityHandler for ConcentratedLiquidity {
                 max_amount_in
                     .try_into()
                     .map_err(|_| PoolError::TypeCastFailed)?,
-                self.sqrt_min_price,
+                sqrt_price_limit,
             )
         } else {
             let next_sqrt_price =
@@ -153,10 +155,12 @@ impl LiquidityHandler for ConcentratedLiquidity {
     fn calculate_b_to_a_from_partial_amount_in(
         &self,
         amount_in: u64,
+        sqrt_price_limit: Option<u128>,
     ) -> Result<SwapAmountFromInput> {
+        let sqrt_price_limit = sqrt_price_limit.unwrap_or(self.sqrt_max_price);
         let max_amount_in = get_delta_amount_b_unsigned_unchecked(
             self.sqrt_price,
-            self.sqrt_max_price,
+            sqrt_price_limit,
             self.liquidity,
             Rounding::Up,
         )?;
@@ -166,7 +170,7 @@ impl LiquidityHandler for ConcentratedLiquidity {
                 max_amount_in
                     .try_into()
                     .map_err(|_| PoolError::TypeCastFailed)?,
-                self.sqrt_max_price,
+                sqrt_price_limit,
             )
         } else {
             let next_sqrt_price =
@@ -250,6 +254,40 @@ impl LiquidityHandler for ConcentratedLiquidity {
         Ok((reserve_a_amount, reserve_b_amount))
     }
 
+    fn get_max_liquidity_delta_from_amounts(
+        &self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<u128> {
+        // only token b is required at the upper edge of the price range, and only token a at the lower edge
+        if self.sqrt_price >= self.sqrt_max_price {
+            return get_liquidity_delta_from_amount_b(
+                self.sqrt_min_price,
+                self.sqrt_price,
+                token_b_amount,
+            );
+        }
+        if self.sqrt_price <= self.sqrt_min_price {
+            return get_liquidity_delta_from_amount_a(
+                self.sqrt_price,
+                self.sqrt_max_price,
+                token_a_amount,
+            );
+        }
+
+        let liquidity_delta_from_a = get_liquidity_delta_from_amount_a(
+            self.sqrt_price,
+            self.sqrt_max_price,
+            token_a_amount,
+        )?;
+        let liquidity_delta_from_b = get_liquidity_delta_from_amount_b(
+            self.sqrt_min_price,
+            self.sqrt_price,
+            token_b_amount,
+        )?;
+        Ok(liquidity_delta_from_a.min(liquidity_delta_from_b))
+    }
+
     // It does nothing because next_sqrt_price is computed by swap-path + rounding direction.
     fn get_next_sqrt_price(&self, next_sqrt_price: u128) -> Result<u128> {
         Ok(next_sqrt_price)
@@ -319,6 +357,44 @@ pub fn get_delta_amount_a_unsigned_unchecked(
     Ok(result)
 }
 
+/// Gets the max liquidity that can be minted from amount_a for given price range, saturated at u128::MAX
+///
+/// # Formula
+///
+/// * `L = Δa * √P_upper * √P_lower / (√P_upper - √P_lower)` (round down)
+pub fn get_liquidity_delta_from_amount_a(
+    lower_sqrt_price: u128,
+    upper_sqrt_price: u128,
+    token_a_amount: u64,
+) -> Result<u128> {
+    let numerator = U512::from(token_a_amount)
+        .safe_mul(U512::from(lower_sqrt_price))?
+        .safe_mul(U512::from(upper_sqrt_price))?;
+    let denominator = U512::from(upper_sqrt_price.safe_sub(lower_sqrt_price)?);
+    let liquidity_delta = numerator.safe_div(denominator)?.min(U512::from(u128::MAX));
+    Ok(liquidity_delta
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?)
+}
+
+/// Gets the max liquidity that can be minted from amount_b for given price range, saturated at u128::MAX
+///
+/// # Formula
+///
+/// * `L = Δb / (√P_upper - √P_lower)` (round down)
+pub fn get_liquidity_delta_from_amount_b(
+    lower_sqrt_price: u128,
+    upper_sqrt_price: u128,
+    token_b_amount: u64,
+) -> Result<u128> {
+    let numerator = U256::from(token_b_amount).safe_shl(128)?;
+    let denominator = U256::from(upper_sqrt_price.safe_sub(lower_sqrt_price)?);
+    let liquidity_delta = numerator.safe_div(denominator)?.min(U256::from(u128::MAX));
+    Ok(liquidity_delta
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?)
+}
+
 /// Gets the delta amount_b for given liquidity and price range
 /// Δb = L * (√P_upper - √P_lower)
 pub fn get_delta_amount_b_unsigned(
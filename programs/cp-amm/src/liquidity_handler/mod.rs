@@ -4,6 +4,9 @@ pub use compounding_liquidity::*;
 pub mod concentrated_liquidity;
 pub use concentrated_liquidity::*;
 
+pub mod stable_swap_liquidity;
+pub use stable_swap_liquidity::*;
+
 use anchor_lang::prelude::*;
 
 #[cfg(test)]
@@ -24,14 +27,17 @@ pub trait LiquidityHandler {
 
     fn calculate_b_to_a_from_amount_in(&self, amount_in: u64) -> Result<SwapAmountFromInput>;
 
+    /// Swap until `amount_in` is consumed or the price reaches `sqrt_price_limit` (the price range when none), the rest is returned as `amount_left`
     fn calculate_a_to_b_from_partial_amount_in(
         &self,
         amount_in: u64,
+        sqrt_price_limit: Option<u128>,
     ) -> Result<SwapAmountFromInput>;
 
     fn calculate_b_to_a_from_partial_amount_in(
         &self,
         amount_in: u64,
+        sqrt_price_limit: Option<u128>,
     ) -> Result<SwapAmountFromInput>;
 
     fn calculate_a_to_b_from_amount_out(&self, amount_out: u64) -> Result<SwapAmountFromOutput>;
@@ -40,6 +46,13 @@ pub trait LiquidityHandler {
 
     fn get_reserves_amount(&self) -> Result<(u64, u64)>;
 
+    /// Maximum liquidity delta can be minted by depositing at most `token_a_amount` and `token_b_amount`
+    fn get_max_liquidity_delta_from_amounts(
+        &self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<u128>;
+
     // Note: Due to different way of concentrated liquidity and compounding liquidity calculating price, compounding and concentrated pools can update dynamic-fee volatility differently for equivalent swap price moves.
     // Additionally the market cap based base fee will also behave differently:
     // Concentrated Amount_In B to A -> Rounding Down
@@ -68,15 +81,24 @@ pub fn get_initial_pool_information(
     sqrt_max_price: u128,
     sqrt_price: u128,
     liquidity: u128,
+    amplification_coefficient: u64,
 ) -> Result<InitialPoolInformation> {
-    if collect_fee_mode == CollectFeeMode::Compounding {
-        CompoundingLiquidity::get_initial_pool_information(sqrt_price, liquidity)
-    } else {
-        ConcentratedLiquidity::get_initial_pool_information(
-            sqrt_min_price,
-            sqrt_max_price,
+    match collect_fee_mode {
+        CollectFeeMode::Compounding => {
+            CompoundingLiquidity::get_initial_pool_information(sqrt_price, liquidity)
+        }
+        CollectFeeMode::StableSwap => StableSwapLiquidity::get_initial_pool_information(
             sqrt_price,
             liquidity,
-        )
+            amplification_coefficient,
+        ),
+        CollectFeeMode::BothToken | CollectFeeMode::OnlyB => {
+            ConcentratedLiquidity::get_initial_pool_information(
+                sqrt_min_price,
+                sqrt_max_price,
+                sqrt_price,
+                liquidity,
+            )
+        }
     }
 }
@@ -0,0 +1,433 @@
+#[cfg(test)]
+use crate::params::swap::TradeDirection;
+use crate::{
+    constants::MAX_AMPLIFICATION_COEFFICIENT,
+    liquidity_handler::compounding_liquidity::{
+        get_initial_token_a, get_initial_token_b, get_max_liquidity_delta_from_reserves,
+    },
+    safe_math::{SafeCast, SafeMath},
+    state::{SwapAmountFromInput, SwapAmountFromOutput},
+    u128x128_math::Rounding,
+    utils_math::{safe_mul_div_cast_u128, sqrt_u256},
+    InitialPoolInformation, LiquidityHandler, PoolError, DEAD_LIQUIDITY,
+};
+use anchor_lang::prelude::*;
+use ruint::aliases::{U256, U512};
+
+/// Max newton iterations to compute the invariant and the reserve
+const MAX_ITERATIONS: usize = 255;
+
+/// StableSwap invariant for 2 tokens: 4A(x + y) + D = 4AD + D^3 / (4xy)
+pub struct StableSwapLiquidity {
+    pub token_a_amount: u64, // current token a reserve
+    pub token_b_amount: u64, // current token b reserve
+    pub liquidity: u128,     // current liquidity
+    pub amplification_coefficient: u64,
+}
+
+impl StableSwapLiquidity {
+    /// Reserves are derived the same way as compounding pool, the pool price is the marginal price of the curve at these reserves
+    pub fn get_initial_pool_information(
+        sqrt_price: u128,
+        liquidity: u128,
+        amplification_coefficient: u64,
+    ) -> Result<InitialPoolInformation> {
+        validate_amplification_coefficient(amplification_coefficient)?;
+        require!(
+            liquidity > DEAD_LIQUIDITY,
+            PoolError::InvalidMinimumLiquidity
+        );
+        let token_a_amount = get_initial_token_a(sqrt_price, liquidity)?;
+        let token_b_amount = get_initial_token_b(sqrt_price, liquidity)?;
+        Ok(InitialPoolInformation {
+            token_a_amount,
+            token_b_amount,
+            sqrt_price: get_stable_swap_sqrt_price(
+                token_a_amount,
+                token_b_amount,
+                amplification_coefficient,
+            )?,
+            initial_liquidity: liquidity.safe_sub(DEAD_LIQUIDITY)?, // we lock DEAD_LIQUIDITY in pool
+            sqrt_min_price: 0,
+            sqrt_max_price: u128::MAX,
+        })
+    }
+
+    /// Invariant rounded up, so swap output is rounded down and swap input is rounded up
+    fn get_invariant(&self) -> Result<U256> {
+        Ok(get_stable_swap_invariant(
+            self.token_a_amount,
+            self.token_b_amount,
+            self.amplification_coefficient,
+        )?
+        .safe_add(U256::from(1))?)
+    }
+
+    /// Output amount when the input reserve becomes `new_input_reserve`, rounded down
+    fn get_output_amount(
+        &self,
+        output_reserve: u64,
+        new_input_reserve: U256,
+        invariant: U256,
+    ) -> Result<u64> {
+        let new_output_reserve =
+            get_reserve(new_input_reserve, invariant, self.amplification_coefficient)?
+                .safe_add(U256::from(1))?;
+        let output_amount = U256::from(output_reserve).saturating_sub(new_output_reserve);
+        Ok(output_amount
+            .try_into()
+            .map_err(|_| PoolError::TypeCastFailed)?)
+    }
+
+    /// Input amount to take `amount_out` from `output_reserve`, rounded up
+    fn get_input_amount(
+        &self,
+        input_reserve: u64,
+        output_reserve: u64,
+        amount_out: u64,
+    ) -> Result<u64> {
+        let new_output_reserve = output_reserve.safe_sub(amount_out)?;
+        require!(new_output_reserve > 0, PoolError::MathOverflow);
+        let new_input_reserve = get_reserve(
+            U256::from(new_output_reserve),
+            self.get_invariant()?,
+            self.amplification_coefficient,
+        )?
+        .safe_add(U256::from(1))?;
+        let input_amount = new_input_reserve.saturating_sub(U256::from(input_reserve));
+        Ok(input_amount
+            .try_into()
+            .map_err(|_| PoolError::TypeCastFailed)?)
+    }
+
+    /// Max consumed input amount, so the price after swap doesn't cross `sqrt_price_limit`
+    fn get_max_amount_in_with_price_limit(
+        &self,
+        amount_in: u64,
+        sqrt_price_limit: u128,
+        a_to_b: bool,
+    ) -> Result<u64> {
+        // the invariant doesn't depend on the input amount, so it is computed once for the whole search
+        let invariant = self.get_invariant()?;
+        let is_valid_amount_in = |amount_in: u64| -> Result<bool> {
+            let (token_a_amount, token_b_amount) = if a_to_b {
+                let Some(token_a_amount) = self.token_a_amount.checked_add(amount_in) else {
+                    return Ok(false);
+                };
+                let output_amount = self.get_output_amount(
+                    self.token_b_amount,
+                    U256::from(token_a_amount),
+                    invariant,
+                )?;
+                (token_a_amount, self.token_b_amount.safe_sub(output_amount)?)
+            } else {
+                let Some(token_b_amount) = self.token_b_amount.checked_add(amount_in) else {
+                    return Ok(false);
+                };
+                let output_amount = self.get_output_amount(
+                    self.token_a_amount,
+                    U256::from(token_b_amount),
+                    invariant,
+                )?;
+                (self.token_a_amount.safe_sub(output_amount)?, token_b_amount)
+            };
+            let sqrt_price = get_stable_swap_sqrt_price(
+                token_a_amount,
+                token_b_amount,
+                self.amplification_coefficient,
+            )?;
+            Ok(if a_to_b {
+                sqrt_price >= sqrt_price_limit
+            } else {
+                sqrt_price <= sqrt_price_limit
+            })
+        };
+
+        if is_valid_amount_in(amount_in)? {
+            return Ok(amount_in);
+        }
+
+        // there is no closed form for the curve price, binary search the largest valid amount
+        let mut low = 0;
+        let mut high = amount_in;
+        while high.safe_sub(low)? > 1 {
+            let mid = low.safe_add(high.safe_sub(low)?.safe_div(2)?)?;
+            if is_valid_amount_in(mid)? {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+}
+
+impl LiquidityHandler for StableSwapLiquidity {
+    fn get_amounts_for_modify_liquidity(
+        &self,
+        liquidity_delta: u128,
+        round: Rounding,
+    ) -> Result<(u64, u64)> {
+        // liquidity is a share of the reserves, same as compounding pool
+        let token_a_amount = safe_mul_div_cast_u128(
+            liquidity_delta,
+            self.token_a_amount.into(),
+            self.liquidity,
+            round,
+        )?;
+        let token_b_amount = safe_mul_div_cast_u128(
+            liquidity_delta,
+            self.token_b_amount.into(),
+            self.liquidity,
+            round,
+        )?;
+
+        Ok((token_a_amount.safe_cast()?, token_b_amount.safe_cast()?))
+    }
+
+    fn calculate_a_to_b_from_amount_in(&self, amount_in: u64) -> Result<SwapAmountFromInput> {
+        let new_token_a_amount = U256::from(self.token_a_amount).safe_add(U256::from(amount_in))?;
+        let output_amount = self.get_output_amount(
+            self.token_b_amount,
+            new_token_a_amount,
+            self.get_invariant()?,
+        )?;
+
+        Ok(SwapAmountFromInput {
+            amount_left: 0,
+            output_amount,
+            next_sqrt_price: 0,
+        })
+    }
+
+    fn calculate_b_to_a_from_amount_in(&self, amount_in: u64) -> Result<SwapAmountFromInput> {
+        let new_token_b_amount = U256::from(self.token_b_amount).safe_add(U256::from(amount_in))?;
+        let output_amount = self.get_output_amount(
+            self.token_a_amount,
+            new_token_b_amount,
+            self.get_invariant()?,
+        )?;
+
+        Ok(SwapAmountFromInput {
+            amount_left: 0,
+            output_amount,
+            next_sqrt_price: 0,
+        })
+    }
+
+    fn calculate_a_to_b_from_partial_amount_in(
+        &self,
+        amount_in: u64,
+        sqrt_price_limit: Option<u128>,
+    ) -> Result<SwapAmountFromInput> {
+        // there is no price range, only the caller price limit
+        let Some(sqrt_price_limit) = sqrt_price_limit else {
+            return self.calculate_a_to_b_from_amount_in(amount_in);
+        };
+        let consumed_in_amount =
+            self.get_max_amount_in_with_price_limit(amount_in, sqrt_price_limit, true)?;
+        let SwapAmountFromInput {
+            output_amount,
+            next_sqrt_price,
+            ..
+        } = self.calculate_a_to_b_from_amount_in(consumed_in_amount)?;
+
+        Ok(SwapAmountFromInput {
+            amount_left: amount_in.safe_sub(consumed_in_amount)?,
+            output_amount,
+            next_sqrt_price,
+        })
+    }
+
+    fn calculate_b_to_a_from_partial_amount_in(
+        &self,
+        amount_in: u64,
+        sqrt_price_limit: Option<u128>,
+    ) -> Result<SwapAmountFromInput> {
+        // there is no price range, only the caller price limit
+        let Some(sqrt_price_limit) = sqrt_price_limit else {
+            return self.calculate_b_to_a_from_amount_in(amount_in);
+        };
+        let consumed_in_amount =
+            self.get_max_amount_in_with_price_limit(amount_in, sqrt_price_limit, false)?;
+        let SwapAmountFromInput {
+            output_amount,
+            next_sqrt_price,
+            ..
+        } = self.calculate_b_to_a_from_amount_in(consumed_in_amount)?;
+
+        Ok(SwapAmountFromInput {
+            amount_left: amount_in.safe_sub(consumed_in_amount)?,
+            output_amount,
+            next_sqrt_price,
+        })
+    }
+
+    fn calculate_a_to_b_from_amount_out(&self, amount_out: u64) -> Result<SwapAmountFromOutput> {
+        let input_amount =
+            self.get_input_amount(self.token_a_amount, self.token_b_amount, amount_out)?;
+        Ok(SwapAmountFromOutput {
+            input_amount,
+            next_sqrt_price: 0,
+        })
+    }
+
+    fn calculate_b_to_a_from_amount_out(&self, amount_out: u64) -> Result<SwapAmountFromOutput> {
+        let input_amount =
+            self.get_input_amount(self.token_b_amount, self.token_a_amount, amount_out)?;
+        Ok(SwapAmountFromOutput {
+            input_amount,
+            next_sqrt_price: 0,
+        })
+    }
+
+    fn get_reserves_amount(&self) -> Result<(u64, u64)> {
+        Ok((self.token_a_amount, self.token_b_amount))
+    }
+
+    fn get_max_liquidity_delta_from_amounts(
+        &self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<u128> {
+        // liquidity is a share of the reserves, same as compounding pool
+        get_max_liquidity_delta_from_reserves(
+            token_a_amount,
+            token_b_amount,
+            self.token_a_amount,
+            self.token_b_amount,
+            self.liquidity,
+        )
+    }
+
+    // marginal price of the curve at the current reserves, rounded down
+    fn get_next_sqrt_price(&self, _next_sqrt_price: u128) -> Result<u128> {
+        get_stable_swap_sqrt_price(
+            self.token_a_amount,
+            self.token_b_amount,
+            self.amplification_coefficient,
+        )
+    }
+
+    #[cfg(test)]
+    fn get_max_amount_in(&self, _trade_direction: TradeDirection) -> Result<u64> {
+        Ok(std::u64::MAX)
+    }
+}
+
+pub fn validate_amplification_coefficient(amplification_coefficient: u64) -> Result<()> {
+    require!(
+        amplification_coefficient > 0 && amplification_coefficient <= MAX_AMPLIFICATION_COEFFICIENT,
+        PoolError::InvalidAmplificationCoefficient
+    );
+    Ok(())
+}
+
+/// A * n^n, n = 2
+fn get_ann(amplification_coefficient: u64) -> Result<U256> {
+    Ok(U256::from(amplification_coefficient).safe_mul(U256::from(4))?)
+}
+
+/// Solve D from 4A(x + y) + D = 4AD + D^3 / (4xy) with newton method, rounded down
+pub fn get_stable_swap_invariant(
+    token_a_amount: u64,
+    token_b_amount: u64,
+    amplification_coefficient: u64,
+) -> Result<U256> {
+    require!(
+        token_a_amount > 0 && token_b_amount > 0,
+        PoolError::AmountIsZero
+    );
+    let ann = get_ann(amplification_coefficient)?;
+    let x = U256::from(token_a_amount);
+    let y = U256::from(token_b_amount);
+    let sum = x.safe_add(y)?;
+
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        // d_p = D^3 / (4xy), single division to avoid the iteration oscillating on rounding
+        let d_p = d
+            .safe_mul(d)?
+            .safe_mul(d)?
+            .safe_div(x.safe_mul(y)?.safe_mul(U256::from(4))?)?;
+        let d_prev = d;
+        // D = (Ann * S + 2 * d_p) * D / ((Ann - 1) * D + 3 * d_p)
+        let numerator = ann
+            .safe_mul(sum)?
+            .safe_add(d_p.safe_mul(U256::from(2))?)?
+            .safe_mul(d)?;
+        let denominator = ann
+            .safe_sub(U256::from(1))?
+            .safe_mul(d)?
+            .safe_add(d_p.safe_mul(U256::from(3))?)?;
+        d = numerator.safe_div(denominator)?;
+
+        if d.abs_diff(d_prev) <= U256::from(1) {
+            return Ok(d);
+        }
+    }
+
+    Err(PoolError::StableSwapInvariantNotConverged.into())
+}
+
+/// Solve the other reserve y from the invariant D when one reserve is x, with newton method
+fn get_reserve(x: U256, d: U256, amplification_coefficient: u64) -> Result<U256> {
+    require!(x > U256::ZERO, PoolError::AmountIsZero);
+    let ann = get_ann(amplification_coefficient)?;
+
+    // y^2 + (x + D / Ann - D) * y = D^3 / (4 * Ann * x)
+    let c = d
+        .safe_mul(d)?
+        .safe_mul(d)?
+        .safe_div(ann.safe_mul(x)?.safe_mul(U256::from(4))?)?;
+    let b = x.safe_add(d.safe_div(ann)?)?;
+
+    let mut y = d;
+    for _ in 0..MAX_ITERATIONS {
+        let y_prev = y;
+        // y = (y^2 + c) / (2y + b - D)
+        let numerator = y.safe_mul(y)?.safe_add(c)?;
+        let denominator = y.safe_mul(U256::from(2))?.safe_add(b)?.safe_sub(d)?;
+        y = numerator.safe_div(denominator)?;
+
+        if y.abs_diff(y_prev) <= U256::from(1) {
+            return Ok(y);
+        }
+    }
+
+    Err(PoolError::StableSwapInvariantNotConverged.into())
+}
+
+/// sqrt of the marginal price (token b per token a) in Q64.64
+/// price = (4 * Ann * x^2 * y^2 + D^3 * y) / (4 * Ann * x^2 * y^2 + D^3 * x)
+pub fn get_stable_swap_sqrt_price(
+    token_a_amount: u64,
+    token_b_amount: u64,
+    amplification_coefficient: u64,
+) -> Result<u128> {
+    let d = U512::from(get_stable_swap_invariant(
+        token_a_amount,
+        token_b_amount,
+        amplification_coefficient,
+    )?);
+    let ann = U512::from(get_ann(amplification_coefficient)?);
+    let x = U512::from(token_a_amount);
+    let y = U512::from(token_b_amount);
+
+    let d_cube = d.safe_mul(d)?.safe_mul(d)?;
+    let amplified_term = ann
+        .safe_mul(U512::from(4))?
+        .safe_mul(x)?
+        .safe_mul(x)?
+        .safe_mul(y)?
+        .safe_mul(y)?;
+    let numerator = amplified_term.safe_add(d_cube.safe_mul(y)?)?;
+    let denominator = amplified_term.safe_add(d_cube.safe_mul(x)?)?;
+
+    let price = numerator.safe_shl(128)?.safe_div(denominator)?;
+    require!(price <= U512::from(U256::MAX), PoolError::MathOverflow);
+    let sqrt_price = sqrt_u256(U256::from(price)).ok_or_else(|| PoolError::MathOverflow)?;
+    Ok(sqrt_price
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?)
+}
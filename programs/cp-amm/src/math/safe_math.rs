@@ -143,6 +143,9 @@ macro_rules! try_into_impl {
 
 try_into_impl!(u128, u64);
 try_into_impl!(i64, u64);
+try_into_impl!(u64, i64);
+try_into_impl!(u64, usize);
+try_into_impl!(i128, i64);
 try_into_impl!(usize, u16);
 try_into_impl!(U512, u64);
 try_into_impl!(u8, TokenProgramFlags);
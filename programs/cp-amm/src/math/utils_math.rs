@@ -98,3 +98,34 @@ pub fn sqrt_u256(radicand: U256) -> Option<U256> {
     }
     Some(result)
 }
+
+/// Number of fractional bits of [get_log2_sqrt_price]
+pub const LOG2_FRACTIONAL_BITS: u32 = 32;
+
+/// log2(sqrt_price / 2^64) in Q32.32, rounded down
+pub fn get_log2_sqrt_price(sqrt_price: u128) -> Result<i64> {
+    if sqrt_price == 0 {
+        return Err(PoolError::MathOverflow.into());
+    }
+    let msb = 127 - sqrt_price.leading_zeros();
+    let integer_part = i64::from(msb) - 64;
+
+    // normalize to [1, 2) in Q1.63
+    let mut x = if msb >= 63 {
+        sqrt_price >> (msb - 63)
+    } else {
+        sqrt_price << (63 - msb)
+    };
+
+    // square x for each fractional bit, if x >= 2 the bit is set
+    let mut fractional_part = 0i64;
+    for bit in (0..LOG2_FRACTIONAL_BITS).rev() {
+        x = x.safe_mul(x)? >> 63;
+        if x >= 1u128 << 64 {
+            x >>= 1;
+            fractional_part |= 1 << bit;
+        }
+    }
+
+    Ok((integer_part << LOG2_FRACTIONAL_BITS) + fractional_part)
+}
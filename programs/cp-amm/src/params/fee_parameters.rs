@@ -1,11 +1,13 @@
 //! Fees module includes information about fee charges
 use crate::activation_handler::ActivationType;
 use crate::base_fee::{base_fee_parameters_to_base_fee_info, BaseFeeHandlerBuilder};
-use crate::constants::fee::{HOST_FEE_PERCENT, MAX_BASIS_POINT, PROTOCOL_FEE_PERCENT};
-use crate::constants::{BIN_STEP_BPS_DEFAULT, BIN_STEP_BPS_U128_DEFAULT, U24_MAX};
+use crate::constants::fee::{
+    HOST_FEE_PERCENT, MAX_BASIS_POINT, MAX_CREATOR_FEE_PERCENT, PROTOCOL_FEE_PERCENT,
+};
+use crate::dynamic_fee::get_dynamic_fee_handler;
 use crate::error::PoolError;
 use crate::safe_math::SafeMath;
-use crate::state::fee::{BaseFeeStruct, DynamicFeeStruct, PoolFeesStruct};
+use crate::state::fee::{BaseFeeStruct, DynamicFeeMode, DynamicFeeStruct, PoolFeesStruct};
 use crate::state::{BaseFeeInfo, CollectFeeMode, DynamicFeeConfig, PoolFeesConfig};
 use anchor_lang::prelude::*;
 
@@ -14,10 +16,10 @@ use anchor_lang::prelude::*;
 pub struct PoolFeeParameters {
     /// Base fee
     pub base_fee: BaseFeeParameters,
-    /// compounding fee bps, only have value if CollectFeeMode::Compounding
+    /// compounding fee bps, only have value if CollectFeeMode::Compounding or CollectFeeMode::StableSwap
     pub compounding_fee_bps: u16,
-    /// padding for future use
-    pub padding: u8,
+    /// creator fee percent of the trading fee after protocol fee, previously padding
+    pub creator_fee_percent: u8,
     /// dynamic fee
     pub dynamic_fee: Option<DynamicFeeParameters>,
 }
@@ -56,12 +58,13 @@ impl PoolFeeParameters {
             base_fee,
             compounding_fee_bps,
             dynamic_fee,
-            padding: _,
+            creator_fee_percent,
         } = self;
         if let Some(dynamic_fee) = dynamic_fee {
             Ok(PoolFeesConfig {
                 base_fee: base_fee.to_base_fee_config()?,
                 protocol_fee_percent: PROTOCOL_FEE_PERCENT,
+                creator_fee_percent,
                 referral_fee_percent: HOST_FEE_PERCENT,
                 compounding_fee_bps,
                 dynamic_fee: dynamic_fee.to_dynamic_fee_config(),
@@ -71,6 +74,7 @@ impl PoolFeeParameters {
             Ok(PoolFeesConfig {
                 base_fee: base_fee.to_base_fee_config()?,
                 protocol_fee_percent: PROTOCOL_FEE_PERCENT,
+                creator_fee_percent,
                 referral_fee_percent: HOST_FEE_PERCENT,
                 compounding_fee_bps,
                 ..Default::default()
@@ -82,12 +86,13 @@ impl PoolFeeParameters {
             base_fee,
             compounding_fee_bps,
             dynamic_fee,
-            padding: _,
+            creator_fee_percent,
         } = self;
         if let Some(dynamic_fee) = dynamic_fee {
             Ok(PoolFeesStruct {
                 base_fee: base_fee.to_base_fee_struct()?,
                 protocol_fee_percent: PROTOCOL_FEE_PERCENT,
+                creator_fee_percent,
                 referral_fee_percent: HOST_FEE_PERCENT,
                 compounding_fee_bps,
                 dynamic_fee: dynamic_fee.to_dynamic_fee_struct(),
@@ -98,6 +103,7 @@ impl PoolFeeParameters {
             Ok(PoolFeesStruct {
                 base_fee: base_fee.to_base_fee_struct()?,
                 protocol_fee_percent: PROTOCOL_FEE_PERCENT,
+                creator_fee_percent,
                 referral_fee_percent: HOST_FEE_PERCENT,
                 compounding_fee_bps,
                 init_sqrt_price,
@@ -122,6 +128,7 @@ impl DynamicFeeParameters {
     fn to_dynamic_fee_config(&self) -> DynamicFeeConfig {
         DynamicFeeConfig {
             initialized: 1,
+            dynamic_fee_mode: self.get_dynamic_fee_mode().into(),
             bin_step: self.bin_step,
             filter_period: self.filter_period,
             decay_period: self.decay_period,
@@ -135,6 +142,7 @@ impl DynamicFeeParameters {
     pub fn to_dynamic_fee_struct(&self) -> DynamicFeeStruct {
         DynamicFeeStruct {
             initialized: 1,
+            dynamic_fee_mode: self.get_dynamic_fee_mode().into(),
             bin_step: self.bin_step,
             bin_step_u128: self.bin_step_u128,
             filter_period: self.filter_period,
@@ -145,40 +153,19 @@ impl DynamicFeeParameters {
             ..Default::default()
         }
     }
-    pub fn validate(&self) -> Result<()> {
-        // force all bin_step as 1 bps for first version
-        require!(
-            self.bin_step == BIN_STEP_BPS_DEFAULT,
-            PoolError::InvalidInput
-        );
-        require!(
-            self.bin_step_u128 == BIN_STEP_BPS_U128_DEFAULT,
-            PoolError::InvalidInput
-        );
-
-        // filter period < t < decay period
-        require!(
-            self.filter_period < self.decay_period,
-            PoolError::InvalidInput
-        );
-
-        // reduction factor decide the decay rate of variable fee, max reduction_factor is MAX_BASIS_POINT = 100% reduction
-        require!(
-            self.reduction_factor <= MAX_BASIS_POINT as u16,
-            PoolError::InvalidInput
-        );
-
-        // prevent program overflow
-        require!(
-            self.variable_fee_control <= U24_MAX,
-            PoolError::InvalidInput
-        );
-        require!(
-            self.max_volatility_accumulator <= U24_MAX,
-            PoolError::InvalidInput
-        );
+    /// The dynamic fee model is inferred from the bin step, so the parameters keep their layout:
+    /// zero bin steps select [DynamicFeeMode::EmaPriceReturn], anything else [DynamicFeeMode::VolatilityAccumulator]
+    pub fn get_dynamic_fee_mode(&self) -> DynamicFeeMode {
+        if self.bin_step == 0 && self.bin_step_u128 == 0 {
+            DynamicFeeMode::EmaPriceReturn
+        } else {
+            DynamicFeeMode::VolatilityAccumulator
+        }
+    }
 
-        Ok(())
+    pub fn validate(&self) -> Result<()> {
+        let dynamic_fee_handler = get_dynamic_fee_handler(self.get_dynamic_fee_mode().into())?;
+        dynamic_fee_handler.validate(self)
     }
 }
 
@@ -235,6 +222,12 @@ impl PoolFeeParameters {
         // validate compounding fee
         validate_compounding_fee(collect_fee_mode, self.compounding_fee_bps)?;
 
+        // validate creator fee
+        require!(
+            self.creator_fee_percent <= MAX_CREATOR_FEE_PERCENT,
+            PoolError::InvalidCreatorFeePercent
+        );
+
         // validate base fee
         self.base_fee.validate(collect_fee_mode, activation_type)?;
         // validate dynamic fee
@@ -255,6 +248,12 @@ fn validate_compounding_fee(
             compounding_fee_bps > 0 && compounding_fee_bps <= MAX_BASIS_POINT,
             PoolError::InvalidCompoundingFeeBps
         );
+    } else if collect_fee_mode == CollectFeeMode::StableSwap {
+        // stable swap pool can either compound fees or leave all of them for claiming
+        require!(
+            compounding_fee_bps <= MAX_BASIS_POINT,
+            PoolError::InvalidCompoundingFeeBps
+        );
     } else {
         require!(
             compounding_fee_bps == 0,
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::Pubkey;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
-use crate::{state::Pool, PermissionlessActionAccess};
+use crate::{state::Pool, PermissionedActionAccess, PermissionlessActionAccess};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
@@ -19,9 +19,25 @@ pub trait PoolActionAccess {
     fn can_create_position(&self) -> bool;
     fn can_lock_position(&self) -> bool;
     fn can_split_position(&self) -> bool;
+    fn can_merge_position(&self) -> bool;
+    fn can_flash_loan(&self) -> bool;
 }
 
+/// Access validator of the pool, no wallet is allowlisted for permissioned pools
 pub fn get_pool_access_validator<'a>(pool: &'a Pool) -> Result<Box<dyn PoolActionAccess + 'a>> {
-    let access_validator = PermissionlessActionAccess::new(pool)?;
-    Ok(Box::new(access_validator))
+    get_pool_access_validator_with_allowlist(pool, false)
+}
+
+/// Access validator of the pool for a wallet, `is_allowlisted` is only used by permissioned pools
+pub fn get_pool_access_validator_with_allowlist<'a>(
+    pool: &'a Pool,
+    is_allowlisted: bool,
+) -> Result<Box<dyn PoolActionAccess + 'a>> {
+    if pool.is_permissioned() {
+        let access_validator = PermissionedActionAccess::new(pool, is_allowlisted)?;
+        Ok(Box::new(access_validator))
+    } else {
+        let access_validator = PermissionlessActionAccess::new(pool)?;
+        Ok(Box::new(access_validator))
+    }
 }
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Pool, PermissionlessActionAccess, PoolActionAccess};
+
+/// Swap, add liquidity, create position and flash loan of permissioned pools are only allowed for allowlisted wallets
+pub struct PermissionedActionAccess {
+    permissionless_action_access: PermissionlessActionAccess,
+    is_allowlisted: bool,
+}
+
+impl PermissionedActionAccess {
+    pub fn new(pool: &Pool, is_allowlisted: bool) -> Result<Self> {
+        Ok(Self {
+            permissionless_action_access: PermissionlessActionAccess::new(pool)?,
+            is_allowlisted,
+        })
+    }
+}
+
+impl PoolActionAccess for PermissionedActionAccess {
+    fn can_add_liquidity(&self) -> bool {
+        self.is_allowlisted && self.permissionless_action_access.can_add_liquidity()
+    }
+
+    fn can_remove_liquidity(&self) -> bool {
+        self.permissionless_action_access.can_remove_liquidity()
+    }
+
+    fn can_swap(&self, sender: &Pubkey) -> bool {
+        self.is_allowlisted && self.permissionless_action_access.can_swap(sender)
+    }
+
+    fn can_create_position(&self) -> bool {
+        self.is_allowlisted && self.permissionless_action_access.can_create_position()
+    }
+    fn can_lock_position(&self) -> bool {
+        self.permissionless_action_access.can_lock_position()
+    }
+    fn can_split_position(&self) -> bool {
+        self.permissionless_action_access.can_split_position()
+    }
+    fn can_merge_position(&self) -> bool {
+        self.permissionless_action_access.can_merge_position()
+    }
+    fn can_flash_loan(&self) -> bool {
+        self.is_allowlisted && self.permissionless_action_access.can_flash_loan()
+    }
+}
@@ -3,12 +3,12 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::activation::{SLOT_BUFFER, TIME_BUFFER},
     safe_math::SafeMath,
-    state::{Pool, PoolStatus},
+    state::{is_pool_action_paused, Pool, PoolPauseAction},
     PoolError, {ActivationType, PoolActionAccess},
 };
 
 pub struct PermissionlessActionAccess {
-    is_enabled: bool,
+    pool_status: u8,
     activation_point: u64,
     pre_activation_point: u64,
     current_point: u64,
@@ -29,7 +29,7 @@ impl PermissionlessActionAccess {
             0
         };
         Ok(Self {
-            is_enabled: pool.pool_status == Into::<u8>::into(PoolStatus::Enable),
+            pool_status: pool.pool_status,
             current_point,
             activation_point: pool.activation_point,
             whitelisted_vault: pool.whitelisted_vault,
@@ -38,17 +38,24 @@ impl PermissionlessActionAccess {
     }
 }
 
+impl PermissionlessActionAccess {
+    fn is_paused(&self, action: PoolPauseAction) -> bool {
+        is_pool_action_paused(self.pool_status, action)
+    }
+}
+
 impl PoolActionAccess for PermissionlessActionAccess {
     fn can_add_liquidity(&self) -> bool {
-        self.is_enabled
+        !self.is_paused(PoolPauseAction::AddLiquidity)
     }
 
     fn can_remove_liquidity(&self) -> bool {
-        self.current_point >= self.activation_point
+        !self.is_paused(PoolPauseAction::RemoveLiquidity)
+            && self.current_point >= self.activation_point
     }
 
     fn can_swap(&self, sender: &Pubkey) -> bool {
-        if self.is_enabled {
+        if !self.is_paused(PoolPauseAction::Swap) {
             if sender.eq(&self.whitelisted_vault) {
                 self.current_point >= self.pre_activation_point
             } else {
@@ -60,12 +67,18 @@ impl PoolActionAccess for PermissionlessActionAccess {
     }
 
     fn can_create_position(&self) -> bool {
-        self.is_enabled
+        !self.is_paused(PoolPauseAction::CreatePosition)
     }
     fn can_lock_position(&self) -> bool {
-        self.is_enabled
+        !self.is_paused(PoolPauseAction::LockPosition)
     }
     fn can_split_position(&self) -> bool {
-        self.is_enabled
+        !self.is_paused(PoolPauseAction::SplitMergePosition)
+    }
+    fn can_merge_position(&self) -> bool {
+        !self.is_paused(PoolPauseAction::SplitMergePosition)
+    }
+    fn can_flash_loan(&self) -> bool {
+        !self.is_paused(PoolPauseAction::FlashLoan) && self.current_point >= self.activation_point
     }
 }
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+/// Wallet allowlisted by the allowlist authority of a permissioned pool
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct AllowlistEntry {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub padding: [u64; 4], // padding for future use
+}
+
+const_assert_eq!(AllowlistEntry::INIT_SPACE, 96);
+
+impl AllowlistEntry {
+    pub fn initialize(&mut self, pool: Pubkey, wallet: Pubkey) {
+        self.pool = pool;
+        self.wallet = wallet;
+    }
+}
+
+/// Allowlist authority of a permissioned pool, set when the pool is initialized.
+/// Kept outside of the pool account which has no room left for a pubkey
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct AllowlistConfig {
+    pub pool: Pubkey,
+    /// adds and removes allowlist entries of the pool
+    pub allowlist_authority: Pubkey,
+    pub padding: [u64; 4], // padding for future use
+}
+
+const_assert_eq!(AllowlistConfig::INIT_SPACE, 96);
+
+impl AllowlistConfig {
+    pub fn initialize(&mut self, pool: Pubkey, allowlist_authority: Pubkey) {
+        self.pool = pool;
+        self.allowlist_authority = allowlist_authority;
+    }
+}
@@ -39,7 +39,8 @@ pub struct PoolFeesConfig {
     pub base_fee: BaseFeeInfo,
     pub dynamic_fee: DynamicFeeConfig,
     pub protocol_fee_percent: u8,
-    pub padding_0: u8,
+    /// Creator fee percent of the trading fee after protocol fee
+    pub creator_fee_percent: u8,
     pub referral_fee_percent: u8,
     pub padding_1: [u8; 3],
     /// Compounding fee bps, only non-zero if collect_fee_mode is compounding
@@ -76,6 +77,7 @@ impl PoolFeesConfig {
         let &PoolFeesConfig {
             base_fee,
             compounding_fee_bps,
+            creator_fee_percent,
             dynamic_fee:
                 DynamicFeeConfig {
                     initialized,
@@ -94,6 +96,7 @@ impl PoolFeesConfig {
             Ok(PoolFeeParameters {
                 base_fee: base_fee.to_base_fee_parameters()?,
                 compounding_fee_bps,
+                creator_fee_percent,
                 dynamic_fee: Some(DynamicFeeParameters {
                     bin_step,
                     bin_step_u128,
@@ -109,6 +112,7 @@ impl PoolFeesConfig {
             Ok(PoolFeeParameters {
                 base_fee: base_fee.to_base_fee_parameters()?,
                 compounding_fee_bps,
+                creator_fee_percent,
                 ..Default::default()
             })
         }
@@ -118,6 +122,7 @@ impl PoolFeesConfig {
         let &PoolFeesConfig {
             base_fee,
             protocol_fee_percent,
+            creator_fee_percent,
             referral_fee_percent,
             compounding_fee_bps,
             dynamic_fee,
@@ -127,6 +132,7 @@ impl PoolFeesConfig {
         PoolFeesStruct {
             base_fee: base_fee.to_base_fee_struct(),
             protocol_fee_percent,
+            creator_fee_percent,
             referral_fee_percent,
             compounding_fee_bps,
             dynamic_fee: dynamic_fee.to_dynamic_fee_struct(),
@@ -139,7 +145,8 @@ impl PoolFeesConfig {
 #[derive(Debug, InitSpace, Default)]
 pub struct DynamicFeeConfig {
     pub initialized: u8, // 0, ignore for dynamic fee
-    pub padding: [u8; 7],
+    pub dynamic_fee_mode: u8,
+    pub padding: [u8; 6],
     pub max_volatility_accumulator: u32,
     pub variable_fee_control: u32,
     pub bin_step: u16,
@@ -159,6 +166,7 @@ impl DynamicFeeConfig {
         } else {
             DynamicFeeStruct {
                 initialized: 1,
+                dynamic_fee_mode: self.dynamic_fee_mode,
                 bin_step: self.bin_step,
                 bin_step_u128: self.bin_step_u128,
                 filter_period: self.filter_period,
@@ -195,9 +203,11 @@ pub struct Config {
     pub sqrt_min_price: u128,
     /// sqrt max price
     pub sqrt_max_price: u128,
+    /// amplification coefficient, only non-zero if collect_fee_mode is StableSwap
+    pub amplification_coefficient: u64,
     /// Fee curve point
     /// Padding for further use
-    pub _padding_1: [u64; 10],
+    pub _padding_1: [u64; 9],
 }
 
 const_assert_eq!(Config::INIT_SPACE, 320);
@@ -261,6 +271,7 @@ impl Config {
         sqrt_min_price: u128,
         sqrt_max_price: u128,
         collect_fee_mode: u8,
+        amplification_coefficient: u64,
     ) -> Result<()> {
         self.index = index;
         self.pool_fees = pool_fees.to_pool_fees_config()?;
@@ -270,6 +281,7 @@ impl Config {
         self.sqrt_min_price = sqrt_min_price;
         self.sqrt_max_price = sqrt_max_price;
         self.collect_fee_mode = collect_fee_mode;
+        self.amplification_coefficient = amplification_coefficient;
         self.config_type = ConfigType::Static.into();
         Ok(())
     }
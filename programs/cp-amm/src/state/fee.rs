@@ -4,12 +4,14 @@ use static_assertions::const_assert_eq;
 
 use crate::{
     base_fee::{
-        fee_rate_limiter::PodAlignedFeeRateLimiter, BaseFeeEnumReader, BaseFeeHandlerBuilder,
+        fee_rate_limiter::PodAlignedFeeRateLimiter, fee_volume_tier::PodAlignedFeeVolumeTier,
+        BaseFeeEnumReader, BaseFeeHandlerBuilder,
     },
     constants::{
         fee::{FEE_DENOMINATOR, MAX_BASIS_POINT},
         ONE_Q64,
     },
+    dynamic_fee::{get_dynamic_fee_handler, DynamicFeeHandler},
     params::swap::TradeDirection,
     safe_math::SafeMath,
     state::BaseFeeInfo,
@@ -27,6 +29,7 @@ pub struct FeeOnAmountResult {
     pub compounding_fee: u64,
     pub protocol_fee: u64,
     pub referral_fee: u64,
+    pub creator_fee: u64,
 }
 
 /// collect fee mode
@@ -57,6 +60,16 @@ pub enum BaseFeeMode {
     FeeMarketCapSchedulerLinear,
     // fee = cliff_fee_numerator * (1-reduction_factor/10_000)^passed_period
     FeeMarketCapSchedulerExponential,
+    // fee = cliff_fee_numerator - tier * reduction_factor
+    // tier = rolling_volume / tier_volume_step
+    // rolling_volume decays linearly to zero in volume_decay_period seconds
+    FeeVolumeTier,
+    // fee of scheduled_trade_direction = cliff_fee_numerator - passed_period * reduction_factor
+    // fee of the opposite direction = constant_fee_numerator
+    AsymmetricFeeTimeSchedulerLinear,
+    // fee of scheduled_trade_direction = cliff_fee_numerator * (1-reduction_factor/10_000)^passed_period
+    // fee of the opposite direction = constant_fee_numerator
+    AsymmetricFeeTimeSchedulerExponential,
 }
 
 #[zero_copy]
@@ -64,6 +77,7 @@ pub enum BaseFeeMode {
 /// trading_fee = amount * trade_fee_numerator / denominator
 /// protocol_fee = trading_fee * protocol_fee_percentage / 100
 /// referral_fee = protocol_fee * referral_percentage / 100
+/// creator_fee = (trading_fee - protocol_fee) * creator_fee_percentage / 100
 #[derive(Debug, InitSpace, Default)]
 pub struct PoolFeesStruct {
     /// Trade fees are extra token amounts that are held inside the token
@@ -76,13 +90,13 @@ pub struct PoolFeesStruct {
     /// the protocol of the program.
     /// Protocol trade fee numerator
     pub protocol_fee_percent: u8,
-    /// padding for future use
-    pub padding_0: u8,
+    /// creator fee percent of the trading fee after protocol fee, claimable by the pool creator
+    pub creator_fee_percent: u8,
     /// referral fee
     pub referral_fee_percent: u8,
     /// padding
     pub padding_1: [u8; 3],
-    /// compounding fee bps, only non-zero in CollectFeeMode::Compounding
+    /// compounding fee bps, only non-zero in CollectFeeMode::Compounding or CollectFeeMode::StableSwap
     pub compounding_fee_bps: u16,
 
     /// dynamic fee
@@ -114,6 +128,20 @@ impl BaseFeeStruct {
 
         Ok(fee_rate_limiter)
     }
+
+    pub fn to_fee_volume_tier(&self) -> Result<PodAlignedFeeVolumeTier> {
+        let base_fee_mode = self.base_fee_info.get_base_fee_mode()?;
+        require!(
+            base_fee_mode == BaseFeeMode::FeeVolumeTier,
+            PoolError::InvalidBaseFeeMode
+        );
+
+        let fee_volume_tier =
+            *bytemuck::try_from_bytes::<PodAlignedFeeVolumeTier>(&self.base_fee_info.data)
+                .map_err(|_| PoolError::UndeterminedError)?;
+
+        Ok(fee_volume_tier)
+    }
 }
 
 const_assert_eq!(BaseFeeStruct::INIT_SPACE, 40);
@@ -138,6 +166,7 @@ impl PoolFeesStruct {
     }
 
     // in numerator
+    #[allow(clippy::too_many_arguments)]
     pub fn get_total_trading_fee_from_included_fee_amount(
         &self,
         current_point: u64,
@@ -146,6 +175,7 @@ impl PoolFeesStruct {
         trade_direction: TradeDirection,
         max_fee_numerator: u64,
         sqrt_price: u128,
+        rolling_volume: u64,
     ) -> Result<u64> {
         let base_fee_handler = self.base_fee.base_fee_info.get_base_fee_handler()?;
 
@@ -156,11 +186,13 @@ impl PoolFeesStruct {
             included_fee_amount,
             self.init_sqrt_price,
             sqrt_price,
+            rolling_volume,
         )?;
 
         self.get_total_fee_numerator(base_fee_numerator, max_fee_numerator)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn get_total_trading_fee_from_excluded_fee_amount(
         &self,
         current_point: u64,
@@ -169,6 +201,7 @@ impl PoolFeesStruct {
         trade_direction: TradeDirection,
         max_fee_numerator: u64,
         sqrt_price: u128,
+        rolling_volume: u64,
     ) -> Result<u64> {
         let base_fee_handler = self.base_fee.base_fee_info.get_base_fee_handler()?;
 
@@ -179,6 +212,7 @@ impl PoolFeesStruct {
             excluded_fee_amount,
             self.init_sqrt_price,
             sqrt_price,
+            rolling_volume,
         )?;
 
         self.get_total_fee_numerator(base_fee_numerator, max_fee_numerator)
@@ -189,6 +223,7 @@ impl PoolFeesStruct {
         amount: u64,
         trade_fee_numerator: u64,
         has_referral: bool,
+        has_creator_fee: bool,
     ) -> Result<FeeOnAmountResult> {
         let (amount, trading_fee) =
             PoolFeesStruct::get_excluded_fee_amount(trade_fee_numerator, amount)?;
@@ -198,7 +233,8 @@ impl PoolFeesStruct {
             compounding_fee,
             protocol_fee,
             referral_fee,
-        } = self.split_fees(trading_fee, has_referral)?;
+            creator_fee,
+        } = self.split_fees(trading_fee, has_referral, has_creator_fee)?;
 
         Ok(FeeOnAmountResult {
             amount,
@@ -206,6 +242,7 @@ impl PoolFeesStruct {
             compounding_fee,
             protocol_fee,
             referral_fee,
+            creator_fee,
         })
     }
 
@@ -237,7 +274,13 @@ impl PoolFeesStruct {
         Ok((included_fee_amount, fee_amount))
     }
 
-    pub fn split_fees(&self, fee_amount: u64, has_referral: bool) -> Result<SplitFees> {
+    /// `has_creator_fee` is false for pools created before layout version 2, where `creator_fee_percent` is the legacy padding
+    pub fn split_fees(
+        &self,
+        fee_amount: u64,
+        has_referral: bool,
+        has_creator_fee: bool,
+    ) -> Result<SplitFees> {
         let protocol_fee = safe_mul_div_cast_u64(
             fee_amount,
             self.protocol_fee_percent.into(),
@@ -245,8 +288,19 @@ impl PoolFeesStruct {
             Rounding::Down,
         )?;
 
+        let creator_fee = if has_creator_fee {
+            safe_mul_div_cast_u64(
+                fee_amount.safe_sub(protocol_fee)?,
+                self.creator_fee_percent.into(),
+                100,
+                Rounding::Down,
+            )?
+        } else {
+            0
+        };
+
         // update trading fee
-        let trading_fee: u64 = fee_amount.safe_sub(protocol_fee)?;
+        let trading_fee: u64 = fee_amount.safe_sub(protocol_fee)?.safe_sub(creator_fee)?;
 
         let (compounding_fee, claiming_fee) = if self.compounding_fee_bps > 0 {
             let compounding_fee: u64 = safe_mul_div_cast_u64(
@@ -279,15 +333,40 @@ impl PoolFeesStruct {
             compounding_fee,
             protocol_fee,
             referral_fee,
+            creator_fee,
         })
     }
 }
 
+/// dynamic fee mode
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+    Default,
+)]
+pub enum DynamicFeeMode {
+    // variable_fee = (volatility_accumulator * bin_step)^2 * variable_fee_control
+    // volatility_accumulator = volatility_reference + delta_bin_id * MAX_BASIS_POINT
+    #[default]
+    VolatilityAccumulator,
+    // variable_fee_rate = ema_rate * variable_fee_control / MAX_BASIS_POINT
+    // ema = reduction_factor * |price_return| + (1 - reduction_factor) * previous ema
+    EmaPriceReturn,
+}
+
 #[zero_copy]
 #[derive(Debug, InitSpace, Default)]
 pub struct DynamicFeeStruct {
     pub initialized: u8, // 0, ignore for dynamic fee
-    pub padding: [u8; 7],
+    pub dynamic_fee_mode: u8,
+    pub padding: [u8; 6],
     pub max_volatility_accumulator: u32,
     pub variable_fee_control: u32,
     pub bin_step: u16,
@@ -324,19 +403,24 @@ impl DynamicFeeStruct {
 
         Ok(delta_bin_id.safe_mul(2)?)
     }
-    pub fn update_volatility_accumulator(&mut self, sqrt_price: u128) -> Result<()> {
-        let delta_price =
-            Self::get_delta_bin_id(self.bin_step_u128, sqrt_price, self.sqrt_price_reference)?;
 
-        let volatility_accumulator = self
-            .volatility_reference
-            .safe_add(delta_price.safe_mul(MAX_BASIS_POINT.into())?)?;
+    pub fn get_dynamic_fee_handler(&self) -> Result<Box<dyn DynamicFeeHandler>> {
+        get_dynamic_fee_handler(self.dynamic_fee_mode)
+    }
 
-        self.volatility_accumulator = std::cmp::min(
-            volatility_accumulator,
-            self.max_volatility_accumulator.into(),
-        );
-        Ok(())
+    pub fn update_volatility_accumulator(
+        &mut self,
+        old_sqrt_price: u128,
+        sqrt_price: u128,
+        current_timestamp: u64,
+    ) -> Result<()> {
+        let dynamic_fee_handler = self.get_dynamic_fee_handler()?;
+        dynamic_fee_handler.update_volatility_accumulator(
+            self,
+            old_sqrt_price,
+            sqrt_price,
+            current_timestamp,
+        )
     }
 
     pub fn update_references(
@@ -344,29 +428,8 @@ impl DynamicFeeStruct {
         sqrt_price_current: u128,
         current_timestamp: u64,
     ) -> Result<()> {
-        // it is fine to use saturating_sub, because never a chance current_timestamp is lesser than last_update_timestamp on-chain
-        // but that can benefit off-chain components for simulation when clock is not synced and pool is high frequency trading
-        // furthermore, the function doesn't update fee in pre-swap, so quoting won't be affected
-        let elapsed = current_timestamp.saturating_sub(self.last_update_timestamp);
-        // Not high frequency trade
-        if elapsed >= self.filter_period as u64 {
-            // Update sqrt of last transaction
-            self.sqrt_price_reference = sqrt_price_current;
-            // filter period < t < decay_period. Decay time window.
-            if elapsed < self.decay_period as u64 {
-                let volatility_reference = self
-                    .volatility_accumulator
-                    .safe_mul(self.reduction_factor.into())?
-                    .safe_div(MAX_BASIS_POINT.into())?;
-
-                self.volatility_reference = volatility_reference;
-            }
-            // Out of decay time window
-            else {
-                self.volatility_reference = 0;
-            }
-        }
-        Ok(())
+        let dynamic_fee_handler = self.get_dynamic_fee_handler()?;
+        dynamic_fee_handler.update_references(self, sqrt_price_current, current_timestamp)
     }
 
     pub fn is_dynamic_fee_enable(&self) -> bool {
@@ -375,18 +438,7 @@ impl DynamicFeeStruct {
 
     pub fn get_variable_fee(&self) -> Result<u128> {
         if self.is_dynamic_fee_enable() {
-            let square_vfa_bin: u128 = self
-                .volatility_accumulator
-                .safe_mul(self.bin_step.into())?
-                .checked_pow(2)
-                .ok_or_else(|| PoolError::TypeCastFailed)?;
-            // Variable fee control, volatility accumulator, bin step are in basis point unit (10_000)
-            // This is 1e20. Which > 1e9. Scale down it to 1e9 unit and ceiling the remaining.
-            let v_fee = square_vfa_bin.safe_mul(self.variable_fee_control.into())?;
-
-            let scaled_v_fee = v_fee.safe_add(99_999_999_999)?.safe_div(100_000_000_000)?;
-
-            Ok(scaled_v_fee)
+            self.get_dynamic_fee_handler()?.get_variable_fee(self)
         } else {
             Ok(0)
         }
@@ -418,6 +470,10 @@ impl FeeMode {
             // when collecting fees on compounding
             (CollectFeeMode::Compounding, TradeDirection::AtoB) => (false, false),
             (CollectFeeMode::Compounding, TradeDirection::BtoA) => (true, false),
+
+            // stable swap collects fees the same way as compounding
+            (CollectFeeMode::StableSwap, TradeDirection::AtoB) => (false, false),
+            (CollectFeeMode::StableSwap, TradeDirection::BtoA) => (true, false),
         };
 
         FeeMode {
@@ -433,4 +489,5 @@ pub struct SplitFees {
     pub compounding_fee: u64,
     pub protocol_fee: u64,
     pub referral_fee: u64,
+    pub creator_fee: u64,
 }
@@ -4,6 +4,12 @@ pub mod pool;
 pub use pool::*;
 pub mod position;
 pub use position::*;
+pub mod position_delegate;
+pub use position_delegate::*;
+pub mod allowlist_entry;
+pub use allowlist_entry::*;
+pub mod reward_extension;
+pub use reward_extension::*;
 pub mod fee;
 pub mod token_badge;
 pub use token_badge::*;
@@ -11,3 +17,9 @@ pub mod vesting;
 pub use vesting::*;
 pub mod operator;
 pub use operator::*;
+pub mod timelock;
+pub use timelock::*;
+pub mod protocol_fee_distribution;
+pub use protocol_fee_distribution::*;
+pub mod oracle;
+pub use oracle::*;
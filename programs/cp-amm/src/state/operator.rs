@@ -4,6 +4,8 @@ use anchor_lang::prelude::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::const_assert_eq;
 
+use crate::{safe_math::SafeMath, PoolError};
+
 #[repr(u8)]
 #[derive(
     Clone,
@@ -34,8 +36,11 @@ pub enum OperatorPermission {
 #[derive(InitSpace, Debug, Default)]
 pub struct Operator {
     pub whitelisted_address: Pubkey,
-    pub permission: u128,  // max 128 actions?
-    pub padding: [u64; 2], // padding for future use
+    pub permission: u128, // max 128 actions?
+    /// permissions expire at this timestamp, 0 means no expiry
+    pub expiry_timestamp: u64,
+    /// bitmask of permissions capped by the [OperatorUsageLimit] of the operator
+    pub usage_limited_permission: u64,
 }
 
 const_assert_eq!(Operator::INIT_SPACE, 64);
@@ -46,10 +51,130 @@ impl Operator {
         self.permission = permission;
     }
 
-    pub fn is_permission_allow(&self, permission: OperatorPermission) -> bool {
+    pub fn update(&mut self, permission: u128, expiry_timestamp: u64) {
+        self.permission = permission;
+        self.expiry_timestamp = expiry_timestamp;
+    }
+
+    pub fn is_expired(&self, current_timestamp: u64) -> bool {
+        self.expiry_timestamp != 0 && current_timestamp >= self.expiry_timestamp
+    }
+
+    pub fn is_permission_allow(
+        &self,
+        permission: OperatorPermission,
+        current_timestamp: u64,
+    ) -> bool {
         let result: u128 = self
             .permission
             .bitand(1u128 << Into::<u8>::into(permission));
+        result != 0 && !self.is_expired(current_timestamp)
+    }
+
+    pub fn is_usage_limited(&self, permission: OperatorPermission) -> bool {
+        let result: u64 = self
+            .usage_limited_permission
+            .bitand(1u64 << Into::<u8>::into(permission));
         result != 0
     }
+
+    /// Only permissions whose handlers consume the [OperatorUsageLimit] can be usage limited, other permissions
+    /// would never be capped
+    pub fn set_usage_limited(
+        &mut self,
+        permission: OperatorPermission,
+        is_usage_limited: bool,
+    ) -> Result<()> {
+        let mask = 1u64 << Into::<u8>::into(permission);
+        if is_usage_limited {
+            require!(
+                is_usage_limitable_permission(permission),
+                PoolError::InvalidPermission
+            );
+            self.usage_limited_permission |= mask;
+        } else {
+            self.usage_limited_permission &= !mask;
+        }
+        Ok(())
+    }
+}
+
+/// Permissions which can be capped by the [OperatorUsageLimit]
+pub fn is_usage_limitable_permission(permission: OperatorPermission) -> bool {
+    matches!(
+        permission,
+        OperatorPermission::ClaimProtocolFee | OperatorPermission::ZapProtocolFee
+    )
+}
+
+#[zero_copy]
+#[derive(InitSpace, Debug, Default)]
+pub struct PermissionUsageLimit {
+    /// length of the usage window in seconds
+    pub window_duration: u64,
+    /// max number of calls per window, 0 means no cap
+    pub max_usage: u64,
+    /// max token amount per window, 0 means no cap
+    pub max_amount: u64,
+    pub window_start_timestamp: u64,
+    /// number of calls in the current window
+    pub usage: u64,
+    /// token amount in the current window
+    pub amount: u64,
+}
+
+impl PermissionUsageLimit {
+    pub fn initialize(&mut self, window_duration: u64, max_usage: u64, max_amount: u64) {
+        *self = PermissionUsageLimit {
+            window_duration,
+            max_usage,
+            max_amount,
+            ..Default::default()
+        };
+    }
+
+    /// Record a call with `amount` in the current window, fails when it goes over the caps
+    pub fn consume(&mut self, current_timestamp: u64, amount: u64) -> Result<()> {
+        if current_timestamp >= self.window_start_timestamp.safe_add(self.window_duration)? {
+            self.window_start_timestamp = current_timestamp;
+            self.usage = 0;
+            self.amount = 0;
+        }
+
+        self.usage = self.usage.safe_add(1)?;
+        self.amount = self.amount.safe_add(amount)?;
+
+        require!(
+            self.max_usage == 0 || self.usage <= self.max_usage,
+            PoolError::OperatorUsageLimitExceeded
+        );
+        require!(
+            self.max_amount == 0 || self.amount <= self.max_amount,
+            PoolError::OperatorUsageLimitExceeded
+        );
+
+        Ok(())
+    }
+}
+
+/// Usage caps of an operator, indexed by [OperatorPermission]. Must be passed as a writable remaining account of
+/// the instructions of usage limited permissions
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct OperatorUsageLimit {
+    pub operator: Pubkey,
+    pub limits: [PermissionUsageLimit; 16],
+    pub padding: [u64; 4], // padding for future use
+}
+
+const_assert_eq!(OperatorUsageLimit::INIT_SPACE, 832);
+
+impl OperatorUsageLimit {
+    pub fn initialize(&mut self, operator: Pubkey) {
+        self.operator = operator;
+    }
+
+    pub fn get_limit_mut(&mut self, permission: OperatorPermission) -> &mut PermissionUsageLimit {
+        &mut self.limits[usize::from(Into::<u8>::into(permission))]
+    }
 }
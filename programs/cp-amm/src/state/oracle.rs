@@ -0,0 +1,220 @@
+use crate::{
+    safe_math::{SafeCast, SafeMath},
+    state::Pool,
+    utils_math::get_log2_sqrt_price,
+    PoolError,
+};
+use anchor_lang::prelude::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    *,
+};
+use ruint::aliases::U256;
+use static_assertions::const_assert_eq;
+
+pub const ORACLE_OBSERVATION_SIZE: usize = 256;
+
+/// Cumulatives are wrapping, so only the difference between 2 observations is meaningful
+#[zero_copy]
+#[derive(Debug, InitSpace, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Observation {
+    /// unix timestamp of the observation
+    pub timestamp: u64,
+    /// sum of log2(sqrt_price) in Q32.32 per second
+    pub log_sqrt_price_cumulative: i64,
+    /// sum of (seconds << 128) / liquidity
+    pub seconds_per_liquidity_cumulative: u128,
+}
+
+const_assert_eq!(Observation::INIT_SPACE, 32);
+
+impl Observation {
+    /// Accumulate from the observation timestamp to `timestamp` with sqrt_price and liquidity unchanged
+    pub fn transform(&self, timestamp: u64, sqrt_price: u128, liquidity: u128) -> Result<Self> {
+        let delta = timestamp.safe_sub(self.timestamp)?;
+
+        let log_sqrt_price = get_log2_sqrt_price(sqrt_price)?;
+        let log_sqrt_price_cumulative = self
+            .log_sqrt_price_cumulative
+            .wrapping_add(log_sqrt_price.wrapping_mul(delta.safe_cast()?));
+
+        let seconds_per_liquidity_cumulative = if liquidity > 0 {
+            let seconds_per_liquidity = U256::from(delta)
+                .safe_shl(128)?
+                .safe_div(U256::from(liquidity))?;
+            // only keep the lower 128 bits, the cumulative is wrapping
+            let seconds_per_liquidity: u128 = (seconds_per_liquidity & U256::from(u128::MAX))
+                .try_into()
+                .map_err(|_| PoolError::TypeCastFailed)?;
+            self.seconds_per_liquidity_cumulative
+                .wrapping_add(seconds_per_liquidity)
+        } else {
+            self.seconds_per_liquidity_cumulative
+        };
+
+        Ok(Observation {
+            timestamp,
+            log_sqrt_price_cumulative,
+            seconds_per_liquidity_cumulative,
+        })
+    }
+
+    /// Linear interpolation between `self` and `next` observation
+    fn interpolate(&self, next: &Observation, timestamp: u64) -> Result<Self> {
+        let total_delta = next.timestamp.safe_sub(self.timestamp)?;
+        if total_delta == 0 {
+            return Ok(*self);
+        }
+        let delta = timestamp.safe_sub(self.timestamp)?;
+
+        let log_sqrt_price_delta = i128::from(
+            next.log_sqrt_price_cumulative
+                .wrapping_sub(self.log_sqrt_price_cumulative),
+        )
+        .safe_mul(delta.into())?
+        .safe_div(total_delta.into())?;
+
+        let seconds_per_liquidity_delta = U256::from(
+            next.seconds_per_liquidity_cumulative
+                .wrapping_sub(self.seconds_per_liquidity_cumulative),
+        )
+        .safe_mul(U256::from(delta))?
+        .safe_div(U256::from(total_delta))?;
+        let seconds_per_liquidity_delta: u128 = seconds_per_liquidity_delta
+            .try_into()
+            .map_err(|_| PoolError::TypeCastFailed)?;
+
+        Ok(Observation {
+            timestamp,
+            log_sqrt_price_cumulative: self
+                .log_sqrt_price_cumulative
+                .wrapping_add(log_sqrt_price_delta.safe_cast()?),
+            seconds_per_liquidity_cumulative: self
+                .seconds_per_liquidity_cumulative
+                .wrapping_add(seconds_per_liquidity_delta),
+        })
+    }
+}
+
+/// Ring buffer of the pool oracle cumulatives. The cumulatives are tracked in pool, so observations can be written at any time
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+pub struct Oracle {
+    /// pool
+    pub pool: Pubkey,
+    /// index of the latest observation
+    pub observation_index: u64,
+    /// number of written observations, capped at [ORACLE_OBSERVATION_SIZE]
+    pub observation_count: u64,
+    /// padding for future use
+    pub padding: [u64; 4],
+    /// observations
+    pub observations: [Observation; ORACLE_OBSERVATION_SIZE],
+}
+
+const_assert_eq!(Oracle::INIT_SPACE, 8272);
+
+impl Oracle {
+    pub fn initialize(&mut self, pool_key: Pubkey, pool: &Pool) {
+        self.pool = pool_key;
+        self.observation_index = 0;
+        self.observation_count = 1;
+        self.observations[0] = pool.get_oracle_observation();
+    }
+
+    pub fn get_latest_observation(&self) -> Result<&Observation> {
+        let index: usize = self.observation_index.safe_cast()?;
+        Ok(&self.observations[index])
+    }
+
+    pub fn get_oldest_observation(&self) -> Result<&Observation> {
+        Ok(&self.observations[self.get_oldest_index()?])
+    }
+
+    fn get_oldest_index(&self) -> Result<usize> {
+        if self.observation_count < ORACLE_OBSERVATION_SIZE as u64 {
+            Ok(0)
+        } else {
+            let index: usize = self.observation_index.safe_cast()?;
+            Ok(index.safe_add(1)?.safe_rem(ORACLE_OBSERVATION_SIZE)?)
+        }
+    }
+
+    /// Pool oracle cumulatives must be updated before writing observation
+    pub fn write_observation(&mut self, pool: &Pool) -> Result<()> {
+        let observation = pool.get_oracle_observation();
+        // at most 1 observation per second
+        if observation.timestamp <= self.get_latest_observation()?.timestamp {
+            return Ok(());
+        }
+
+        let index: usize = self.observation_index.safe_cast()?;
+        let next_index = index.safe_add(1)?.safe_rem(ORACLE_OBSERVATION_SIZE)?;
+        self.observations[next_index] = observation;
+        self.observation_index = next_index as u64;
+        if self.observation_count < ORACLE_OBSERVATION_SIZE as u64 {
+            self.observation_count = self.observation_count.safe_add(1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the cumulatives at `current_timestamp - seconds_ago`. Between 2 observations, the cumulatives are interpolated
+    pub fn observe(
+        &self,
+        pool: &Pool,
+        current_timestamp: u64,
+        seconds_ago: u64,
+    ) -> Result<Observation> {
+        let target = current_timestamp.safe_sub(seconds_ago)?;
+
+        // pool cumulatives are always newer than the observations
+        let pool_observation = pool.get_oracle_observation();
+        if target >= pool_observation.timestamp {
+            return pool_observation.transform(target, pool.sqrt_price, pool.liquidity);
+        }
+
+        let latest = self.get_latest_observation()?;
+        if target >= latest.timestamp {
+            return latest.interpolate(&pool_observation, target);
+        }
+
+        let oldest_index = self.get_oldest_index()?;
+        require!(
+            target >= self.observations[oldest_index].timestamp,
+            PoolError::OracleObservationTooOld
+        );
+
+        // binary search for the latest observation before target, the latest one is already after target
+        let count: usize = self.observation_count.safe_cast()?;
+        let mut low = 0;
+        let mut high = count.safe_sub(1)?;
+        while high.safe_sub(low)? > 1 {
+            let mid = low.safe_add(high)?.safe_div(2)?;
+            if self
+                .get_observation_from_oldest(oldest_index, mid)?
+                .timestamp
+                <= target
+            {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        let before = self.get_observation_from_oldest(oldest_index, low)?;
+        let after = self.get_observation_from_oldest(oldest_index, high)?;
+        before.interpolate(after, target)
+    }
+
+    /// Observations in the ring buffer are ordered by timestamp from the oldest one
+    fn get_observation_from_oldest(
+        &self,
+        oldest_index: usize,
+        offset: usize,
+    ) -> Result<&Observation> {
+        let index = oldest_index
+            .safe_add(offset)?
+            .safe_rem(ORACLE_OBSERVATION_SIZE)?;
+        Ok(&self.observations[index])
+    }
+}
@@ -8,7 +8,7 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 use crate::activation_handler::{ActivationHandler, ActivationType};
 use crate::base_fee::{BaseFeeHandlerBuilder, UpdateCliffFeeNumerator};
 use crate::constants::fee::{
-    get_max_fee_numerator, CURRENT_POOL_VERSION, MAX_FEE_NUMERATOR_POST_UPDATE,
+    get_max_fee_numerator, CURRENT_POOL_VERSION, MAX_BASIS_POINT, MAX_FEE_NUMERATOR_POST_UPDATE,
 };
 use crate::safe_math::SafeCast;
 use crate::state::fee::{FeeOnAmountResult, SplitFees};
@@ -17,16 +17,16 @@ use crate::{
     params::swap::TradeDirection,
     safe_math::SafeMath,
     state::{
-        fee::{DynamicFeeStruct, PoolFeesStruct},
-        Position, SplitFeeAmount,
+        fee::{BaseFeeStruct, DynamicFeeStruct, PoolFeesStruct},
+        Observation, Position, SplitFeeAmount,
     },
     u128x128_math::{shl_div_256, Rounding},
-    utils_math::{safe_mul_shr_cast, safe_shl_div_cast},
+    utils_math::{safe_mul_div_cast_u128, safe_mul_shr_cast, safe_shl_div_cast},
     PoolError,
 };
 use crate::{
     BaseFeeUpdateMode, CompoundingFeeUpdateMode, CompoundingLiquidity, ConcentratedLiquidity,
-    DynamicFeeUpdateMode, LiquidityHandler, UpdatePoolFeesParameters,
+    DynamicFeeUpdateMode, LiquidityHandler, StableSwapLiquidity, UpdatePoolFeesParameters,
 };
 
 use super::fee::FeeMode;
@@ -51,9 +51,11 @@ pub enum CollectFeeMode {
     /// In the compounding, a percentage fees will be accumulated in liquidity, while remainings are used for clamining, fees are always be in token B
     /// Pool with compounding won't have price range, instead of using constant-product formula: x * y = constant
     Compounding,
+    /// Same fee collection as compounding, but reserves follow the stable swap invariant with the pool amplification coefficient, refer [StableSwapLiquidity]
+    StableSwap,
 }
 
-/// pool status
+/// Pool actions which can be paused, `pool_status` is the bitmask of paused actions
 #[repr(u8)]
 #[derive(
     Clone,
@@ -65,9 +67,22 @@ pub enum CollectFeeMode {
     AnchorDeserialize,
     AnchorSerialize,
 )]
-pub enum PoolStatus {
-    Enable,
-    Disable,
+pub enum PoolPauseAction {
+    Disable,      // 0, previous binary pool status, pauses every action except remove liquidity
+    Swap,         // 1
+    AddLiquidity, // 2, including compound position fee
+    RemoveLiquidity, // 3
+    CreatePosition, // 4
+    LockPosition, // 5
+    SplitMergePosition, // 6
+    FlashLoan,    // 7
+}
+
+/// Whether `action` is paused by the `pool_status` bitmask
+pub fn is_pool_action_paused(pool_status: u8, action: PoolPauseAction) -> bool {
+    let is_paused = |action: PoolPauseAction| pool_status & (1u8 << Into::<u8>::into(action)) != 0;
+    is_paused(action)
+        || (action != PoolPauseAction::RemoveLiquidity && is_paused(PoolPauseAction::Disable))
 }
 
 #[repr(u8)]
@@ -84,6 +99,7 @@ pub enum PoolStatus {
 pub enum PoolType {
     Permissionless,
     Customizable,
+    Permissioned, // 2, only wallets allowlisted by the pool creator can swap, add liquidity and create positions
 }
 
 #[repr(u8)]
@@ -100,6 +116,7 @@ pub enum PoolType {
 pub enum LayoutVersion {
     V0, // 0
     V1, // 1
+    V2, // 2, vested liquidity and creator fee are tracked since creation
 }
 
 #[account(zero_copy)]
@@ -117,18 +134,27 @@ pub struct Pool {
     pub token_b_vault: Pubkey,
     /// Whitelisted vault to be able to buy pool before activation_point
     pub whitelisted_vault: Pubkey,
-    /// padding, previously partner pubkey, be careful when using this field
-    pub padding_0: [u8; 32],
+    /// total vested liquidity of positions, only tracked by pools created with layout version 2.
+    /// Previously half of partner pubkey, be careful when using this field
+    pub vested_liquidity: u128,
+    /// creator a fee, only tracked by pools created with layout version 2.
+    /// Previously half of partner pubkey, be careful when using this field
+    pub creator_a_fee: u64,
+    /// creator b fee, only tracked by pools created with layout version 2
+    pub creator_b_fee: u64,
     /// liquidity share
     pub liquidity: u128,
-    /// padding, previous reserve amount, be careful to use that field
-    pub padding_1: u128,
+    /// rolling traded volume in token b, only tracked by BaseFeeMode::FeeVolumeTier.
+    /// Previously reserve amount with padding_1, volume tier pools are always created after it is cleared
+    pub rolling_volume: u64,
+    /// last timestamp when rolling_volume is decayed
+    pub rolling_volume_last_update_timestamp: u64,
     /// protocol a fee
     pub protocol_a_fee: u64,
     /// protocol b fee
     pub protocol_b_fee: u64,
-    // padding for future use
-    pub padding_2: u128,
+    /// oracle cumulative of seconds per liquidity, refer [Observation]
+    pub seconds_per_liquidity_cumulative: u128,
     /// min price
     pub sqrt_min_price: u128,
     /// max price
@@ -139,7 +165,7 @@ pub struct Pool {
     pub activation_point: u64,
     /// Activation type, 0 means by slot, 1 means by timestamp
     pub activation_type: u8,
-    /// pool status, 0: enable, 1 disable
+    /// bitmask of paused actions, refer [PoolPauseAction]. 0: enable, 1: disable as the previous binary pool status
     pub pool_status: u8,
     /// token a flag
     pub token_a_flag: u8,
@@ -151,8 +177,8 @@ pub struct Pool {
     pub pool_type: u8,
     /// pool fee version, 0: max_fee is still capped at 50%, 1: max_fee is capped at 99%
     pub fee_version: u8,
-    /// padding
-    pub padding_3: u8,
+    /// 1 once a reward of the pool reward extension is initialized, liquidity changes must update the reward extensions after that
+    pub extended_reward_initialized: u8,
     /// cumulative
     pub fee_a_per_liquidity: [u8; 32], // U256
     /// cumulative
@@ -169,10 +195,16 @@ pub struct Pool {
     pub token_b_amount: u64,
     /// layout version: version 0: haven't track token_a_amount and token_b_amount, version 1: track token_a_amount and token_b_amount
     pub layout_version: u8,
+    /// 1 once the protocol fee distribution of the pool is initialized, protocol fees can only be claimed by `distribute_protocol_fee` after that
+    pub protocol_fee_distribution_enabled: u8,
     /// Padding for further use
-    pub padding_4: [u8; 7],
-    /// Padding for further use
-    pub padding_5: [u64; 3],
+    pub padding_4: [u8; 6],
+    /// last timestamp that oracle cumulatives are updated, 0 means not started yet
+    pub oracle_last_update_timestamp: u64,
+    /// oracle cumulative of log2(sqrt_price), refer [Observation]
+    pub log_sqrt_price_cumulative: i64,
+    /// amplification coefficient of the stable swap curve, only non-zero if collect_fee_mode is StableSwap
+    pub amplification_coefficient: u64,
     /// Farming reward information
     pub reward_infos: [RewardInfo; NUM_REWARDS],
 }
@@ -227,8 +259,12 @@ pub struct RewardInfo {
     pub initialized: u8,
     /// reward token flag
     pub reward_token_flag: u8,
+    /// extra reward weight of vested liquidity in bps, immutable after the reward is initialized
+    pub vested_liquidity_boost_bps: u16,
+    /// extra reward weight of permanent locked liquidity in bps, immutable after the reward is initialized
+    pub permanent_lock_liquidity_boost_bps: u16,
     /// padding
-    pub _padding_0: [u8; 6],
+    pub _padding_0: [u8; 2],
     /// Padding to ensure `reward_rate: u128` is 16-byte aligned
     pub _padding_1: [u8; 8], // 8 bytes
     /// Reward token mint.
@@ -272,6 +308,8 @@ impl RewardInfo {
         funder: Pubkey,
         reward_duration: u64,
         reward_token_flag: u8,
+        vested_liquidity_boost_bps: u16,
+        permanent_lock_liquidity_boost_bps: u16,
     ) {
         self.initialized = 1;
         self.mint = mint;
@@ -279,6 +317,33 @@ impl RewardInfo {
         self.funder = funder;
         self.reward_duration = reward_duration;
         self.reward_token_flag = reward_token_flag;
+        self.vested_liquidity_boost_bps = vested_liquidity_boost_bps;
+        self.permanent_lock_liquidity_boost_bps = permanent_lock_liquidity_boost_bps;
+    }
+
+    /// Liquidity earning this reward, vested and permanent locked liquidity earn the extra weight on top
+    pub fn get_reward_liquidity(
+        &self,
+        liquidity: u128,
+        vested_liquidity: u128,
+        permanent_locked_liquidity: u128,
+    ) -> Result<u128> {
+        let vested_liquidity_boost = safe_mul_div_cast_u128(
+            vested_liquidity,
+            self.vested_liquidity_boost_bps.into(),
+            MAX_BASIS_POINT.into(),
+            Rounding::Down,
+        )?;
+        let permanent_locked_liquidity_boost = safe_mul_div_cast_u128(
+            permanent_locked_liquidity,
+            self.permanent_lock_liquidity_boost_bps.into(),
+            MAX_BASIS_POINT.into(),
+            Rounding::Down,
+        )?;
+
+        Ok(liquidity
+            .safe_add(vested_liquidity_boost)?
+            .safe_add(permanent_locked_liquidity_boost)?)
     }
 
     pub fn update_rewards(&mut self, liquidity_supply: u128, current_time: u64) -> Result<()> {
@@ -347,6 +412,19 @@ impl RewardInfo {
         U256::from_le_bytes(self.reward_per_token_stored)
     }
 
+    pub fn claim_ineligible_reward(&mut self) -> Result<u64> {
+        // calculate ineligible reward
+        let ineligible_reward: u64 = safe_mul_shr_cast(
+            self.cumulative_seconds_with_empty_liquidity_reward.into(),
+            self.reward_rate,
+            REWARD_RATE_SCALE,
+        )?;
+
+        self.cumulative_seconds_with_empty_liquidity_reward = 0;
+
+        Ok(ineligible_reward)
+    }
+
     /// Farming rate after funding
     pub fn update_rate_after_funding(
         &mut self,
@@ -403,6 +481,7 @@ impl Pool {
         pool_type: u8,
         token_a_amount: u64,
         token_b_amount: u64,
+        amplification_coefficient: u64,
     ) {
         self.creator = creator;
         self.pool_fees = pool_fees;
@@ -424,7 +503,8 @@ impl Pool {
         self.fee_version = CURRENT_POOL_VERSION; // still use v0 now, after notify integrators will pump to v1 to allow higher fee numerator constraint
         self.token_a_amount = token_a_amount;
         self.token_b_amount = token_b_amount;
-        self.layout_version = LayoutVersion::V1.into();
+        self.layout_version = LayoutVersion::V2.into();
+        self.amplification_coefficient = amplification_coefficient;
     }
 
     pub fn pool_reward_initialized(&self) -> bool {
@@ -442,6 +522,7 @@ impl Pool {
         let mut actual_compounding_fee = 0;
         let mut actual_claiming_fee = 0;
         let mut actual_referral_fee = 0;
+        let mut actual_creator_fee = 0;
 
         let liquidity_handler = self.get_liquidity_handler()?;
 
@@ -459,6 +540,7 @@ impl Pool {
                     trade_direction,
                     max_fee_numerator,
                     self.sqrt_price,
+                    self.rolling_volume,
                 )?;
 
             let (included_fee_amount_out, fee_amount) =
@@ -469,14 +551,18 @@ impl Pool {
                 claiming_fee,
                 protocol_fee,
                 referral_fee,
-            } = self
-                .pool_fees
-                .split_fees(fee_amount, fee_mode.has_referral)?;
+                creator_fee,
+            } = self.pool_fees.split_fees(
+                fee_amount,
+                fee_mode.has_referral,
+                self.is_creator_fee_tracked()?,
+            )?;
 
             actual_protocol_fee = protocol_fee;
             actual_claiming_fee = claiming_fee;
             actual_compounding_fee = compounding_fee;
             actual_referral_fee = referral_fee;
+            actual_creator_fee = creator_fee;
 
             included_fee_amount_out
         };
@@ -503,6 +589,7 @@ impl Pool {
                     trade_direction,
                     max_fee_numerator,
                     self.sqrt_price,
+                    self.rolling_volume,
                 )?;
 
             let (included_fee_input_amount, fee_amount) =
@@ -513,14 +600,18 @@ impl Pool {
                 compounding_fee,
                 protocol_fee,
                 referral_fee,
-            } = self
-                .pool_fees
-                .split_fees(fee_amount, fee_mode.has_referral)?;
+                creator_fee,
+            } = self.pool_fees.split_fees(
+                fee_amount,
+                fee_mode.has_referral,
+                self.is_creator_fee_tracked()?,
+            )?;
 
             actual_protocol_fee = protocol_fee;
             actual_claiming_fee = claiming_fee;
             actual_compounding_fee = compounding_fee;
             actual_referral_fee = referral_fee;
+            actual_creator_fee = creator_fee;
 
             included_fee_input_amount
         } else {
@@ -537,20 +628,28 @@ impl Pool {
             compounding_fee: actual_compounding_fee,
             protocol_fee: actual_protocol_fee,
             referral_fee: actual_referral_fee,
+            creator_fee: actual_creator_fee,
         })
     }
 
+    /// When `sqrt_price_limit` is none, the swap is bounded by the pool price range
     pub fn get_swap_result_from_partial_input(
         &self,
         amount_in: u64,
         fee_mode: &FeeMode,
         trade_direction: TradeDirection,
         current_point: u64,
+        sqrt_price_limit: Option<u128>,
     ) -> Result<SwapResult2> {
         let mut actual_protocol_fee = 0;
         let mut actual_claiming_fee = 0;
         let mut actual_compounding_fee = 0;
         let mut actual_referral_fee = 0;
+        let mut actual_creator_fee = 0;
+
+        if let Some(sqrt_price_limit) = sqrt_price_limit {
+            self.validate_sqrt_price_limit(sqrt_price_limit, trade_direction)?;
+        }
 
         let liquidity_handler = self.get_liquidity_handler()?;
 
@@ -565,6 +664,7 @@ impl Pool {
                 trade_direction,
                 max_fee_numerator,
                 self.sqrt_price,
+                self.rolling_volume,
             )?;
 
         let mut actual_amount_in = if fee_mode.fees_on_input {
@@ -574,16 +674,19 @@ impl Pool {
                 compounding_fee,
                 protocol_fee,
                 referral_fee,
+                creator_fee,
             } = self.pool_fees.get_fee_on_amount(
                 amount_in,
                 trade_fee_numerator,
                 fee_mode.has_referral,
+                self.is_creator_fee_tracked()?,
             )?;
 
             actual_protocol_fee = protocol_fee;
             actual_claiming_fee = claiming_fee;
             actual_compounding_fee = compounding_fee;
             actual_referral_fee = referral_fee;
+            actual_creator_fee = creator_fee;
 
             amount
         } else {
@@ -591,49 +694,33 @@ impl Pool {
         };
 
         let SwapAmountFromInput {
-            amount_left,
-            output_amount,
-            next_sqrt_price,
+            mut amount_left,
+            mut output_amount,
+            mut next_sqrt_price,
         } = match trade_direction {
-            TradeDirection::AtoB => {
-                liquidity_handler.calculate_a_to_b_from_partial_amount_in(actual_amount_in)
-            }
-            TradeDirection::BtoA => {
-                liquidity_handler.calculate_b_to_a_from_partial_amount_in(actual_amount_in)
-            }
+            TradeDirection::AtoB => liquidity_handler
+                .calculate_a_to_b_from_partial_amount_in(actual_amount_in, sqrt_price_limit),
+            TradeDirection::BtoA => liquidity_handler
+                .calculate_b_to_a_from_partial_amount_in(actual_amount_in, sqrt_price_limit),
         }?;
 
-        let included_fee_input_amount = if amount_left > 0 {
+        let mut included_fee_input_amount = if amount_left > 0 {
             actual_amount_in = actual_amount_in.safe_sub(amount_left)?;
 
             if fee_mode.fees_on_input {
-                let trade_fee_numerator = self
-                    .pool_fees
-                    .get_total_trading_fee_from_excluded_fee_amount(
-                        current_point,
-                        self.activation_point,
-                        actual_amount_in,
-                        trade_direction,
-                        max_fee_numerator,
-                        self.sqrt_price,
-                    )?;
-
-                let (included_fee_amount_in, fee_amount) =
-                    PoolFeesStruct::get_included_fee_amount(trade_fee_numerator, actual_amount_in)?;
-
-                let SplitFees {
-                    claiming_fee,
-                    compounding_fee,
-                    protocol_fee,
-                    referral_fee,
-                } = self
-                    .pool_fees
-                    .split_fees(fee_amount, fee_mode.has_referral)?;
+                let (included_fee_amount_in, split_fees) = self.get_included_fee_input_amount(
+                    actual_amount_in,
+                    fee_mode,
+                    trade_direction,
+                    current_point,
+                    max_fee_numerator,
+                )?;
 
-                actual_protocol_fee = protocol_fee;
-                actual_claiming_fee = claiming_fee;
-                actual_compounding_fee = compounding_fee;
-                actual_referral_fee = referral_fee;
+                actual_protocol_fee = split_fees.protocol_fee;
+                actual_claiming_fee = split_fees.claiming_fee;
+                actual_compounding_fee = split_fees.compounding_fee;
+                actual_referral_fee = split_fees.referral_fee;
+                actual_creator_fee = split_fees.creator_fee;
 
                 included_fee_amount_in
             } else {
@@ -643,6 +730,49 @@ impl Pool {
             amount_in
         };
 
+        // compounding fees are added to the token b reserve after the swap, so for b to a the
+        // consumed input must also leave room for them under the price limit
+        if trade_direction == TradeDirection::BtoA
+            && fee_mode.fees_on_input
+            && sqrt_price_limit.is_some()
+            && actual_compounding_fee > 0
+        {
+            let SwapAmountFromInput {
+                amount_left: exceeded_amount,
+                ..
+            } = liquidity_handler.calculate_b_to_a_from_partial_amount_in(
+                actual_amount_in.safe_add(actual_compounding_fee)?,
+                sqrt_price_limit,
+            )?;
+
+            if exceeded_amount > 0 {
+                // the compounding fee only shrinks with the input, so the post-fee reserve stays within the limit
+                actual_amount_in = actual_amount_in.safe_sub(exceeded_amount)?;
+
+                let (included_fee_amount_in, split_fees) = self.get_included_fee_input_amount(
+                    actual_amount_in,
+                    fee_mode,
+                    trade_direction,
+                    current_point,
+                    max_fee_numerator,
+                )?;
+
+                actual_protocol_fee = split_fees.protocol_fee;
+                actual_claiming_fee = split_fees.claiming_fee;
+                actual_compounding_fee = split_fees.compounding_fee;
+                actual_referral_fee = split_fees.referral_fee;
+                actual_creator_fee = split_fees.creator_fee;
+
+                SwapAmountFromInput {
+                    output_amount,
+                    next_sqrt_price,
+                    ..
+                } = liquidity_handler.calculate_b_to_a_from_amount_in(actual_amount_in)?;
+                included_fee_input_amount = included_fee_amount_in;
+                amount_left = amount_in.safe_sub(included_fee_input_amount)?;
+            }
+        }
+
         let actual_amount_out = if fee_mode.fees_on_input {
             output_amount
         } else {
@@ -652,16 +782,19 @@ impl Pool {
                 compounding_fee,
                 protocol_fee,
                 referral_fee,
+                creator_fee,
             } = self.pool_fees.get_fee_on_amount(
                 output_amount,
                 trade_fee_numerator,
                 fee_mode.has_referral,
+                self.is_creator_fee_tracked()?,
             )?;
 
             actual_protocol_fee = protocol_fee;
             actual_claiming_fee = claiming_fee;
             actual_compounding_fee = compounding_fee;
             actual_referral_fee = referral_fee;
+            actual_creator_fee = creator_fee;
 
             amount
         };
@@ -676,9 +809,62 @@ impl Pool {
             compounding_fee: actual_compounding_fee,
             protocol_fee: actual_protocol_fee,
             referral_fee: actual_referral_fee,
+            creator_fee: actual_creator_fee,
         })
     }
 
+    /// Fee inclusive input amount and its fee split for an excluded fee input amount
+    fn get_included_fee_input_amount(
+        &self,
+        excluded_fee_amount_in: u64,
+        fee_mode: &FeeMode,
+        trade_direction: TradeDirection,
+        current_point: u64,
+        max_fee_numerator: u64,
+    ) -> Result<(u64, SplitFees)> {
+        let trade_fee_numerator = self
+            .pool_fees
+            .get_total_trading_fee_from_excluded_fee_amount(
+                current_point,
+                self.activation_point,
+                excluded_fee_amount_in,
+                trade_direction,
+                max_fee_numerator,
+                self.sqrt_price,
+                self.rolling_volume,
+            )?;
+
+        let (included_fee_amount_in, fee_amount) =
+            PoolFeesStruct::get_included_fee_amount(trade_fee_numerator, excluded_fee_amount_in)?;
+
+        let split_fees = self.pool_fees.split_fees(
+            fee_amount,
+            fee_mode.has_referral,
+            self.is_creator_fee_tracked()?,
+        )?;
+
+        Ok((included_fee_amount_in, split_fees))
+    }
+
+    fn validate_sqrt_price_limit(
+        &self,
+        sqrt_price_limit: u128,
+        trade_direction: TradeDirection,
+    ) -> Result<()> {
+        // zero is not a "no limit" sentinel, callers pass none instead
+        let is_valid = sqrt_price_limit > 0
+            && match trade_direction {
+                TradeDirection::AtoB => {
+                    sqrt_price_limit >= self.sqrt_min_price && sqrt_price_limit < self.sqrt_price
+                }
+                TradeDirection::BtoA => {
+                    sqrt_price_limit > self.sqrt_price && sqrt_price_limit <= self.sqrt_max_price
+                }
+            };
+        require!(is_valid, PoolError::InvalidSqrtPriceLimit);
+        Ok(())
+    }
+
     pub fn get_swap_result_from_exact_input(
         &self,
         amount_in: u64,
@@ -690,6 +876,7 @@ impl Pool {
         let mut actual_claiming_fee = 0;
         let mut actual_compounding_fee = 0;
         let mut actual_referral_fee = 0;
+        let mut actual_creator_fee = 0;
 
         let liquidity_handler = self.get_liquidity_handler()?;
 
@@ -708,6 +895,7 @@ impl Pool {
                 trade_direction,
                 max_fee_numerator,
                 self.sqrt_price,
+                self.rolling_volume,
             )?;
 
         let actual_amount_in = if fee_mode.fees_on_input {
@@ -717,16 +905,19 @@ impl Pool {
                 compounding_fee,
                 protocol_fee,
                 referral_fee,
+                creator_fee,
             } = self.pool_fees.get_fee_on_amount(
                 amount_in,
                 trade_fee_numerator,
                 fee_mode.has_referral,
+                self.is_creator_fee_tracked()?,
             )?;
 
             actual_claiming_fee = claiming_fee;
             actual_compounding_fee = compounding_fee;
             actual_protocol_fee = protocol_fee;
             actual_referral_fee = referral_fee;
+            actual_creator_fee = creator_fee;
 
             amount
         } else {
@@ -755,16 +946,19 @@ impl Pool {
                 compounding_fee,
                 protocol_fee,
                 referral_fee,
+                creator_fee,
             } = self.pool_fees.get_fee_on_amount(
                 output_amount,
                 trade_fee_numerator,
                 fee_mode.has_referral,
+                self.is_creator_fee_tracked()?,
             )?;
 
             actual_claiming_fee = claiming_fee;
             actual_compounding_fee = compounding_fee;
             actual_protocol_fee = protocol_fee;
             actual_referral_fee = referral_fee;
+            actual_creator_fee = creator_fee;
 
             amount
         };
@@ -779,6 +973,7 @@ impl Pool {
             compounding_fee: actual_compounding_fee,
             protocol_fee: actual_protocol_fee,
             referral_fee: actual_referral_fee,
+            creator_fee: actual_creator_fee,
         })
     }
 
@@ -797,9 +992,13 @@ impl Pool {
             next_sqrt_price,
             protocol_fee,
             referral_fee,
+            creator_fee,
             ..
         } = swap_result;
 
+        // accumulate the price before it is moved by the swap
+        self.update_oracle_cumulatives(current_timestamp)?;
+
         let old_sqrt_price = self.sqrt_price;
 
         let fee_per_token_stored =
@@ -808,6 +1007,8 @@ impl Pool {
 
         let trading_fee = claiming_fee.safe_add(compounding_fee)?;
 
+        self.accumulate_creator_fee(creator_fee, fee_mode.fees_on_token_a)?;
+
         if fee_mode.fees_on_token_a {
             self.protocol_a_fee = self.protocol_a_fee.safe_add(protocol_fee)?;
             self.fee_a_per_liquidity = self
@@ -835,14 +1036,17 @@ impl Pool {
                 .safe_add(trading_fee)?
                 .safe_add(protocol_fee)?
                 .safe_add(referral_fee)?
+                .safe_add(creator_fee)?
         };
 
         if trade_direction == TradeDirection::AtoB {
             self.token_a_amount = self.token_a_amount.safe_add(excluded_fee_input_amount)?;
             self.token_b_amount = self.token_b_amount.safe_sub(included_fee_output_amount)?;
+            self.accumulate_rolling_volume(included_fee_output_amount);
         } else {
             self.token_b_amount = self.token_b_amount.safe_add(excluded_fee_input_amount)?;
             self.token_a_amount = self.token_a_amount.safe_sub(included_fee_output_amount)?;
+            self.accumulate_rolling_volume(excluded_fee_input_amount);
         }
 
         // compounding fees are always accumulated in token b
@@ -857,6 +1061,71 @@ impl Pool {
         Ok(())
     }
 
+    /// Distribute the flash loan fee, which is paid in the borrowed token
+    pub fn apply_flash_loan_fee(
+        &mut self,
+        fee_amount: u64,
+        is_token_a: bool,
+        current_timestamp: u64,
+    ) -> Result<SplitFees> {
+        // accumulate the price before it is moved by the compounding fee
+        self.update_oracle_cumulatives(current_timestamp)?;
+
+        let SplitFees {
+            mut claiming_fee,
+            mut compounding_fee,
+            protocol_fee,
+            referral_fee,
+            creator_fee,
+        } = self
+            .pool_fees
+            .split_fees(fee_amount, false, self.is_creator_fee_tracked()?)?;
+
+        // compounding fees are always accumulated in token b, so LPs claim the share in token a instead
+        if is_token_a {
+            claiming_fee = claiming_fee.safe_add(compounding_fee)?;
+            compounding_fee = 0;
+        }
+
+        let fee_per_token_stored =
+            shl_div_256(claiming_fee.into(), self.liquidity, LIQUIDITY_SCALE)
+                .ok_or_else(|| PoolError::MathOverflow)?;
+
+        let trading_fee = claiming_fee.safe_add(compounding_fee)?;
+
+        self.accumulate_creator_fee(creator_fee, is_token_a)?;
+
+        if is_token_a {
+            self.protocol_a_fee = self.protocol_a_fee.safe_add(protocol_fee)?;
+            self.fee_a_per_liquidity = self
+                .fee_a_per_liquidity()
+                .safe_add(fee_per_token_stored)?
+                .to_le_bytes();
+        } else {
+            self.protocol_b_fee = self.protocol_b_fee.safe_add(protocol_fee)?;
+            self.fee_b_per_liquidity = self
+                .fee_b_per_liquidity()
+                .safe_add(fee_per_token_stored)?
+                .to_le_bytes();
+        }
+        self.metrics
+            .accumulate_fee(trading_fee, protocol_fee, is_token_a)?;
+
+        if compounding_fee > 0 {
+            self.token_b_amount = self.token_b_amount.safe_add(compounding_fee)?;
+            let liquidity_handler = self.get_liquidity_handler()?;
+            self.sqrt_price = liquidity_handler.get_next_sqrt_price(self.sqrt_price)?;
+        }
+
+        Ok(SplitFees {
+            claiming_fee,
+            compounding_fee,
+            protocol_fee,
+            referral_fee,
+            creator_fee,
+        })
+    }
+
     pub fn apply_add_liquidity(
         &mut self,
         position: &mut Position,
@@ -1011,34 +1280,103 @@ impl Pool {
         })
     }
 
+    pub fn apply_merge_position(
+        &self,
+        position: &mut Position,
+        source_position: &mut Position,
+    ) -> Result<SplitAmountInfo2> {
+        position.update_fee(self.fee_a_per_liquidity(), self.fee_b_per_liquidity())?;
+        source_position.update_fee(self.fee_a_per_liquidity(), self.fee_b_per_liquidity())?;
+
+        position.merge(source_position)
+    }
+
     pub fn update_pre_swap(&mut self, current_timestamp: u64) -> Result<()> {
         if self.pool_fees.dynamic_fee.is_dynamic_fee_enable() {
             self.pool_fees
                 .dynamic_fee
                 .update_references(self.sqrt_price, current_timestamp)?;
         }
+        if let Ok(fee_volume_tier) = self.pool_fees.base_fee.to_fee_volume_tier() {
+            self.rolling_volume = fee_volume_tier.get_decayed_rolling_volume(
+                self.rolling_volume,
+                self.rolling_volume_last_update_timestamp,
+                current_timestamp,
+            )?;
+            self.rolling_volume_last_update_timestamp = current_timestamp;
+        }
         Ok(())
     }
 
+    /// Replace the base fee, the rolling volume starts from zero when the pool switches into
+    /// BaseFeeMode::FeeVolumeTier, as it may hold the legacy padding_1 bytes
+    pub fn update_base_fee(&mut self, base_fee: BaseFeeStruct) {
+        let was_volume_tier = self.pool_fees.base_fee.to_fee_volume_tier().is_ok();
+        self.pool_fees.base_fee = base_fee;
+        self.reset_rolling_volume_if_volume_tier_enabled(was_volume_tier);
+    }
+
+    fn reset_rolling_volume_if_volume_tier_enabled(&mut self, was_volume_tier: bool) {
+        if !was_volume_tier && self.pool_fees.base_fee.to_fee_volume_tier().is_ok() {
+            self.rolling_volume = 0;
+            self.rolling_volume_last_update_timestamp = 0;
+        }
+    }
+
+    /// Accumulate the traded volume in token b, the swap is charged with the volume before it
+    fn accumulate_rolling_volume(&mut self, volume: u64) {
+        if self.pool_fees.base_fee.to_fee_volume_tier().is_ok() {
+            self.rolling_volume = self.rolling_volume.saturating_add(volume);
+        }
+    }
+
     pub fn update_post_swap(&mut self, old_sqrt_price: u128, current_timestamp: u64) -> Result<()> {
         if self.pool_fees.dynamic_fee.is_dynamic_fee_enable() {
-            self.pool_fees
-                .dynamic_fee
-                .update_volatility_accumulator(self.sqrt_price)?;
-
-            // update only last_update_timestamp if bin is crossed
-            let delta_price = DynamicFeeStruct::get_delta_bin_id(
-                self.pool_fees.dynamic_fee.bin_step_u128,
+            self.pool_fees.dynamic_fee.update_volatility_accumulator(
                 old_sqrt_price,
                 self.sqrt_price,
+                current_timestamp,
             )?;
-            if delta_price > 0 {
-                self.pool_fees.dynamic_fee.last_update_timestamp = current_timestamp;
-            }
         }
         Ok(())
     }
 
+    pub fn get_oracle_observation(&self) -> Observation {
+        Observation {
+            timestamp: self.oracle_last_update_timestamp,
+            log_sqrt_price_cumulative: self.log_sqrt_price_cumulative,
+            seconds_per_liquidity_cumulative: self.seconds_per_liquidity_cumulative,
+        }
+    }
+
+    /// Must be called before sqrt_price or liquidity is changed
+    pub fn update_oracle_cumulatives(&mut self, current_timestamp: u64) -> Result<()> {
+        if self.oracle_last_update_timestamp == 0 {
+            self.oracle_last_update_timestamp = current_timestamp;
+            return Ok(());
+        }
+
+        if current_timestamp <= self.oracle_last_update_timestamp {
+            return Ok(());
+        }
+
+        let Observation {
+            timestamp,
+            log_sqrt_price_cumulative,
+            seconds_per_liquidity_cumulative,
+        } = self.get_oracle_observation().transform(
+            current_timestamp,
+            self.sqrt_price,
+            self.liquidity,
+        )?;
+
+        self.oracle_last_update_timestamp = timestamp;
+        self.log_sqrt_price_cumulative = log_sqrt_price_cumulative;
+        self.seconds_per_liquidity_cumulative = seconds_per_liquidity_cumulative;
+
+        Ok(())
+    }
+
     pub fn accumulate_permanent_locked_liquidity(
         &mut self,
         permanent_locked_liquidity: u128,
@@ -1050,6 +1388,10 @@ impl Pool {
         Ok(())
     }
 
+    pub fn is_protocol_fee_distribution_enabled(&self) -> bool {
+        self.protocol_fee_distribution_enabled != 0
+    }
+
     pub fn claim_protocol_fee(
         &mut self,
         max_amount_a: u64,
@@ -1062,30 +1404,108 @@ impl Pool {
         Ok((token_a_amount, token_b_amount))
     }
 
+    pub fn claim_creator_fee(
+        &mut self,
+        max_amount_a: u64,
+        max_amount_b: u64,
+    ) -> Result<(u64, u64)> {
+        require!(
+            self.is_creator_fee_tracked()?,
+            PoolError::CreatorFeeNotTracked
+        );
+        let token_a_amount = self.creator_a_fee.min(max_amount_a);
+        let token_b_amount = self.creator_b_fee.min(max_amount_b);
+        self.creator_a_fee = self.creator_a_fee.safe_sub(token_a_amount)?;
+        self.creator_b_fee = self.creator_b_fee.safe_sub(token_b_amount)?;
+        Ok((token_a_amount, token_b_amount))
+    }
+
+    fn accumulate_creator_fee(&mut self, creator_fee: u64, is_token_a: bool) -> Result<()> {
+        if creator_fee == 0 {
+            return Ok(());
+        }
+        if is_token_a {
+            self.creator_a_fee = self.creator_a_fee.safe_add(creator_fee)?;
+        } else {
+            self.creator_b_fee = self.creator_b_fee.safe_add(creator_fee)?;
+        }
+        Ok(())
+    }
+
     /// Update the rewards per token stored.
     pub fn update_rewards(&mut self, current_time: u64) -> Result<()> {
         for reward_idx in 0..NUM_REWARDS {
+            let liquidity_supply = self.get_reward_liquidity_supply(reward_idx)?;
             let reward_info = &mut self.reward_infos[reward_idx];
-            reward_info.update_rewards(self.liquidity, current_time)?;
+            reward_info.update_rewards(liquidity_supply, current_time)?;
         }
 
         Ok(())
     }
 
-    pub fn claim_ineligible_reward(&mut self, reward_index: usize) -> Result<u64> {
-        // calculate ineligible reward
-        let reward_info = &mut self.reward_infos[reward_index];
-        let ineligible_reward: u64 = safe_mul_shr_cast(
-            reward_info
-                .cumulative_seconds_with_empty_liquidity_reward
-                .into(),
-            reward_info.reward_rate,
-            REWARD_RATE_SCALE,
-        )?;
+    /// Total liquidity earning the reward, including the boost of vested and permanent locked liquidity
+    pub fn get_reward_liquidity_supply(&self, reward_index: usize) -> Result<u128> {
+        let vested_liquidity = if self.is_vested_liquidity_tracked()? {
+            self.vested_liquidity
+        } else {
+            0
+        };
+        self.reward_infos[reward_index].get_reward_liquidity(
+            self.liquidity,
+            vested_liquidity,
+            self.permanent_lock_liquidity,
+        )
+    }
 
-        reward_info.cumulative_seconds_with_empty_liquidity_reward = 0;
+    pub fn is_vested_liquidity_tracked(&self) -> Result<bool> {
+        let layout_version: LayoutVersion = self.layout_version.safe_cast()?;
+        Ok(layout_version == LayoutVersion::V2)
+    }
 
-        Ok(ineligible_reward)
+    pub fn is_creator_fee_tracked(&self) -> Result<bool> {
+        let layout_version: LayoutVersion = self.layout_version.safe_cast()?;
+        Ok(layout_version == LayoutVersion::V2)
+    }
+
+    /// Refresh inner vesting of the position, the released liquidity is no longer vested liquidity of the pool.
+    /// Rewards must be updated before
+    pub fn refresh_position_inner_vesting(
+        &mut self,
+        position: &mut Position,
+        current_point: u64,
+    ) -> Result<()> {
+        let vested_liquidity = position.vested_liquidity;
+        position.refresh_inner_vesting(current_point)?;
+        self.update_vested_liquidity(vested_liquidity, position.vested_liquidity)
+    }
+
+    /// Track the change of vested liquidity of positions, from `vested_liquidity_before` to `vested_liquidity_after`.
+    /// Rewards must be updated before vested liquidity changes
+    pub fn update_vested_liquidity(
+        &mut self,
+        vested_liquidity_before: u128,
+        vested_liquidity_after: u128,
+    ) -> Result<()> {
+        if self.is_vested_liquidity_tracked()? {
+            self.vested_liquidity = self
+                .vested_liquidity
+                .safe_add(vested_liquidity_after)?
+                .safe_sub(vested_liquidity_before)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_extended_reward_initialized(&self) -> bool {
+        self.extended_reward_initialized != 0
+    }
+
+    pub fn set_extended_reward_initialized(&mut self) {
+        self.extended_reward_initialized = 1;
+    }
+
+    pub fn is_permissioned(&self) -> bool {
+        self.pool_type == Into::<u8>::into(PoolType::Permissioned)
     }
 
     pub fn fee_a_per_liquidity(&self) -> U256 {
@@ -1116,6 +1536,8 @@ impl Pool {
         &mut self,
         params: &UpdatePoolFeesParameters,
     ) -> Result<()> {
+        let was_volume_tier = self.pool_fees.base_fee.to_fee_volume_tier().is_ok();
+
         // update cliff_fee_numerator
         match params.get_base_fee_update_mode() {
             BaseFeeUpdateMode::Update(cliff_fee_numerator) => {
@@ -1164,6 +1586,7 @@ impl Pool {
                 // skip update, so we don't do anything
             }
         }
+        self.reset_rolling_volume_if_volume_tier_enabled(was_volume_tier);
 
         // update dynamic fee
         match params.get_dynamic_fee_update_mode() {
@@ -1190,7 +1613,8 @@ impl Pool {
                 let collect_fee_mode = CollectFeeMode::try_from(self.collect_fee_mode)
                     .map_err(|_| PoolError::InvalidCollectFeeMode)?;
                 require!(
-                    collect_fee_mode == CollectFeeMode::Compounding,
+                    collect_fee_mode == CollectFeeMode::Compounding
+                        || collect_fee_mode == CollectFeeMode::StableSwap,
                     PoolError::InvalidCollectFeeMode
                 );
                 self.pool_fees.compounding_fee_bps = compounding_fee_bps;
@@ -1205,19 +1629,26 @@ impl Pool {
 
     pub fn get_liquidity_handler(&self) -> Result<Box<dyn LiquidityHandler>> {
         let collect_fee_mode: CollectFeeMode = self.collect_fee_mode.safe_cast()?;
-        if collect_fee_mode == CollectFeeMode::Compounding {
-            Ok(Box::new(CompoundingLiquidity {
+        match collect_fee_mode {
+            CollectFeeMode::Compounding => Ok(Box::new(CompoundingLiquidity {
                 token_a_amount: self.token_a_amount,
                 token_b_amount: self.token_b_amount,
                 liquidity: self.liquidity,
-            }))
-        } else {
-            Ok(Box::new(ConcentratedLiquidity {
-                sqrt_max_price: self.sqrt_max_price,
-                sqrt_min_price: self.sqrt_min_price,
+            })),
+            CollectFeeMode::StableSwap => Ok(Box::new(StableSwapLiquidity {
+                token_a_amount: self.token_a_amount,
+                token_b_amount: self.token_b_amount,
                 liquidity: self.liquidity,
-                sqrt_price: self.sqrt_price,
-            }))
+                amplification_coefficient: self.amplification_coefficient,
+            })),
+            CollectFeeMode::BothToken | CollectFeeMode::OnlyB => {
+                Ok(Box::new(ConcentratedLiquidity {
+                    sqrt_max_price: self.sqrt_max_price,
+                    sqrt_min_price: self.sqrt_min_price,
+                    liquidity: self.liquidity,
+                    sqrt_price: self.sqrt_price,
+                }))
+            }
         }
     }
 }
@@ -1234,6 +1665,7 @@ pub struct SwapResult2 {
     pub protocol_fee: u64,
     pub compounding_fee: u64, // previous is partner_fee, now will be reused for compounding_fee
     pub referral_fee: u64,
+    pub creator_fee: u64,
 }
 
 pub struct SwapAmountFromInput {
@@ -9,7 +9,7 @@ use crate::{
         TOTAL_REWARD_SCALE,
     },
     safe_math::SafeMath,
-    state::{InnerVesting, Pool},
+    state::{InnerVesting, Pool, SplitAmountInfo2},
     u128x128_math::Rounding,
     utils_math::{safe_mul_div_cast_u128, safe_mul_div_cast_u64, safe_mul_shr_256_cast},
     PoolError,
@@ -78,8 +78,8 @@ pub struct Position {
     pub reward_infos: [UserRewardInfo; NUM_REWARDS],
     /// inner vesting info
     pub inner_vesting: InnerVesting,
-    /// padding for future usage
-    pub padding: u128,
+    /// fee compounded into liquidity metrics, PositionMetrics can't be extended without breaking the layout
+    pub compounded_fee_metrics: PositionCompoundedFeeMetrics,
 }
 
 const_assert_eq!(Position::INIT_SPACE, 400);
@@ -105,6 +105,27 @@ impl PositionMetrics {
     }
 }
 
+#[zero_copy]
+#[derive(Debug, InitSpace, Default)]
+pub struct PositionCompoundedFeeMetrics {
+    pub total_compounded_a_fee: u64,
+    pub total_compounded_b_fee: u64,
+}
+
+const_assert_eq!(PositionCompoundedFeeMetrics::INIT_SPACE, 16);
+
+impl PositionCompoundedFeeMetrics {
+    pub fn accumulate_compounded_fee(
+        &mut self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<()> {
+        self.total_compounded_a_fee = self.total_compounded_a_fee.safe_add(token_a_amount)?;
+        self.total_compounded_b_fee = self.total_compounded_b_fee.safe_add(token_b_amount)?;
+        Ok(())
+    }
+}
+
 impl Position {
     pub fn initialize(
         &mut self,
@@ -255,6 +276,13 @@ impl Position {
         Ok(())
     }
 
+    /// Pending fee deposited back into the position as liquidity
+    pub fn compound_pending_fee(&mut self, token_a_amount: u64, token_b_amount: u64) -> Result<()> {
+        self.remove_fee_pending(token_a_amount, token_b_amount)?;
+        self.compounded_fee_metrics
+            .accumulate_compounded_fee(token_a_amount, token_b_amount)
+    }
+
     pub fn reset_pending_fee(&mut self) {
         self.fee_a_pending = 0;
         self.fee_b_pending = 0;
@@ -274,15 +302,19 @@ impl Position {
 
     pub fn update_position_reward(&mut self, pool: &Pool) -> Result<()> {
         let position_liquidity = self.get_total_liquidity()?;
-        let position_reward_infos = &mut self.reward_infos;
         for reward_idx in 0..NUM_REWARDS {
             let pool_reward_info = pool.reward_infos[reward_idx];
 
             if pool_reward_info.initialized() {
+                let reward_liquidity = pool_reward_info.get_reward_liquidity(
+                    position_liquidity,
+                    self.vested_liquidity,
+                    self.permanent_locked_liquidity,
+                )?;
                 let reward_per_token_stored =
                     U256::from_le_bytes(pool_reward_info.reward_per_token_stored);
-                position_reward_infos[reward_idx]
-                    .update_rewards(position_liquidity, reward_per_token_stored)?;
+                self.reward_infos[reward_idx]
+                    .update_rewards(reward_liquidity, reward_per_token_stored)?;
             }
         }
 
@@ -481,6 +513,62 @@ impl Position {
         Ok(destination_position.vested_liquidity)
     }
 
+    /// Move everything of `source_position` to this position, `source_position` is empty afterward.
+    /// Fee and reward of both positions must be updated, and inner vesting must be refreshed before merging.
+    pub fn merge(&mut self, source_position: &mut Position) -> Result<SplitAmountInfo2> {
+        // vesting accounts of source position can't be released after it is closed
+        source_position.validate_no_external_vesting()?;
+
+        self.inner_vesting.merge(&source_position.inner_vesting)?;
+        self.vested_liquidity = self
+            .vested_liquidity
+            .safe_add(source_position.vested_liquidity)?;
+        self.add_liquidity(source_position.unlocked_liquidity)?;
+        self.add_permanent_locked_liquidity(source_position.permanent_locked_liquidity)?;
+        self.add_fee_pending(source_position.fee_a_pending, source_position.fee_b_pending)?;
+        self.metrics.accumulate_claimed_fee(
+            source_position.metrics.total_claimed_a_fee,
+            source_position.metrics.total_claimed_b_fee,
+        )?;
+        self.compounded_fee_metrics.accumulate_compounded_fee(
+            source_position
+                .compounded_fee_metrics
+                .total_compounded_a_fee,
+            source_position
+                .compounded_fee_metrics
+                .total_compounded_b_fee,
+        )?;
+        for reward_index in 0..NUM_REWARDS {
+            let source_reward_info = source_position.reward_infos[reward_index];
+            self.add_reward_pending(reward_index, source_reward_info.reward_pendings)?;
+            self.accumulate_total_claimed_rewards(
+                reward_index,
+                source_reward_info.total_claimed_rewards,
+            );
+        }
+
+        let merged_amount_info = SplitAmountInfo2 {
+            permanent_locked_liquidity: source_position.permanent_locked_liquidity,
+            unlocked_liquidity: source_position.unlocked_liquidity,
+            vested_liquidity: source_position.vested_liquidity,
+            fee_a: source_position.fee_a_pending,
+            fee_b: source_position.fee_b_pending,
+            reward_0: source_position.reward_infos[REWARD_INDEX_0].reward_pendings,
+            reward_1: source_position.reward_infos[REWARD_INDEX_1].reward_pendings,
+        };
+
+        source_position.unlocked_liquidity = 0;
+        source_position.vested_liquidity = 0;
+        source_position.permanent_locked_liquidity = 0;
+        source_position.reset_pending_fee();
+        for reward_index in 0..NUM_REWARDS {
+            source_position.reset_all_pending_reward(reward_index);
+        }
+        source_position.inner_vesting = InnerVesting::default();
+
+        Ok(merged_amount_info)
+    }
+
     pub fn to_split_info(&self) -> SplitPositionInfo2 {
         SplitPositionInfo2 {
             unlocked_liquidity: self.unlocked_liquidity,
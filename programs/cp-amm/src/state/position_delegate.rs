@@ -0,0 +1,62 @@
+use std::ops::BitAnd;
+
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use static_assertions::const_assert_eq;
+
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum PositionDelegatePermission {
+    ClaimPositionFee,    // 0
+    ClaimReward,         // 1
+    CompoundPositionFee, // 2
+}
+
+/// Delegate of a position set by the position owner. It's only valid while the owner still holds the position nft,
+/// a new owner must set its own delegate
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct PositionDelegate {
+    pub position: Pubkey,
+    /// position owner which set the delegate
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    /// wallet receiving fees and rewards claimed by the delegate
+    pub payout_destination: Pubkey,
+    /// bitmask of PositionDelegatePermission
+    pub permission: u64,
+    pub padding: [u64; 3], // padding for future use
+}
+
+const_assert_eq!(PositionDelegate::INIT_SPACE, 160);
+
+impl PositionDelegate {
+    pub fn initialize(
+        &mut self,
+        position: Pubkey,
+        owner: Pubkey,
+        delegate: Pubkey,
+        payout_destination: Pubkey,
+        permission: u64,
+    ) {
+        self.position = position;
+        self.owner = owner;
+        self.delegate = delegate;
+        self.payout_destination = payout_destination;
+        self.permission = permission;
+    }
+
+    pub fn is_permission_allow(&self, permission: PositionDelegatePermission) -> bool {
+        let result: u64 = self.permission.bitand(1u64 << Into::<u8>::into(permission));
+        result != 0
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{fee::MAX_BASIS_POINT, MAX_PROTOCOL_FEE_RECEIVERS},
+    safe_math::SafeMath,
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u64,
+    PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
+pub struct ProtocolFeeReceiverParameters {
+    /// owner of the receiver token accounts
+    pub wallet: Pubkey,
+    pub weight_bps: u16,
+}
+
+#[zero_copy]
+#[derive(InitSpace, Debug, Default)]
+pub struct ProtocolFeeReceiver {
+    /// owner of the receiver token accounts
+    pub wallet: Pubkey,
+    /// share of the protocol fee, weights of all receivers sum to MAX_BASIS_POINT
+    pub weight_bps: u16,
+    pub padding: [u8; 6],
+}
+
+/// Receivers of the protocol fee of a pool, protocol fees are split by `distribute_protocol_fee`
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct ProtocolFeeDistribution {
+    pub pool: Pubkey,
+    pub receivers: [ProtocolFeeReceiver; MAX_PROTOCOL_FEE_RECEIVERS],
+    pub number_of_receivers: u8,
+    pub padding_0: [u8; 7],
+    pub padding: [u64; 4], // padding for future use
+}
+
+const_assert_eq!(ProtocolFeeDistribution::INIT_SPACE, 272);
+
+impl ProtocolFeeDistribution {
+    pub fn initialize(
+        &mut self,
+        pool: Pubkey,
+        receivers: &[ProtocolFeeReceiverParameters],
+    ) -> Result<()> {
+        self.pool = pool;
+        self.set_receivers(receivers)
+    }
+
+    pub fn set_receivers(&mut self, receivers: &[ProtocolFeeReceiverParameters]) -> Result<()> {
+        require!(
+            !receivers.is_empty() && receivers.len() <= MAX_PROTOCOL_FEE_RECEIVERS,
+            PoolError::InvalidProtocolFeeDistribution
+        );
+
+        let mut total_weight_bps = 0u16;
+        for receiver in receivers {
+            require!(
+                receiver.weight_bps > 0 && receiver.wallet != Pubkey::default(),
+                PoolError::InvalidProtocolFeeDistribution
+            );
+            total_weight_bps = total_weight_bps.safe_add(receiver.weight_bps)?;
+        }
+        require!(
+            total_weight_bps == MAX_BASIS_POINT,
+            PoolError::InvalidProtocolFeeDistribution
+        );
+
+        self.receivers = Default::default();
+        for (receiver, params) in self.receivers.iter_mut().zip(receivers) {
+            receiver.wallet = params.wallet;
+            receiver.weight_bps = params.weight_bps;
+        }
+        self.number_of_receivers = receivers.len() as u8;
+
+        Ok(())
+    }
+
+    pub fn get_receivers(&self) -> &[ProtocolFeeReceiver] {
+        &self.receivers[..usize::from(self.number_of_receivers)]
+    }
+
+    /// Split `amount` by the receiver weights, rounding down. The last receiver gets the remainder
+    pub fn get_receiver_amounts(&self, amount: u64) -> Result<Vec<u64>> {
+        let receivers = self.get_receivers();
+        let mut receiver_amounts = Vec::with_capacity(receivers.len());
+        let mut remaining_amount = amount;
+        for (i, receiver) in receivers.iter().enumerate() {
+            let receiver_amount = if i + 1 == receivers.len() {
+                remaining_amount
+            } else {
+                safe_mul_div_cast_u64::<u64>(
+                    amount,
+                    receiver.weight_bps.into(),
+                    MAX_BASIS_POINT.into(),
+                    Rounding::Down,
+                )?
+            };
+            remaining_amount = remaining_amount.safe_sub(receiver_amount)?;
+            receiver_amounts.push(receiver_amount);
+        }
+        Ok(receiver_amounts)
+    }
+}
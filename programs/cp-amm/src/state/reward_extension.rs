@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use ruint::aliases::U256;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    safe_math::SafeMath,
+    state::{Pool, RewardInfo, UserRewardInfo},
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u128,
+    PoolError,
+};
+
+/// Additional rewards of a pool, reward index NUM_REWARDS onward
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct PoolRewardExtension {
+    pub pool: Pubkey,
+    /// Farming reward information
+    pub reward_infos: [RewardInfo; NUM_EXTENDED_REWARDS],
+}
+
+const_assert_eq!(PoolRewardExtension::INIT_SPACE, 1184);
+
+impl PoolRewardExtension {
+    pub fn initialize(&mut self, pool: Pubkey) {
+        self.pool = pool;
+    }
+
+    pub fn update_rewards(&mut self, liquidity_supply: u128, current_time: u64) -> Result<()> {
+        for reward_info in self.reward_infos.iter_mut() {
+            reward_info.update_rewards(liquidity_supply, current_time)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Additional rewards of a position, reward index NUM_REWARDS onward
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct PositionRewardExtension {
+    pub position: Pubkey,
+    /// Farming reward information
+    pub reward_infos: [UserRewardInfo; NUM_EXTENDED_REWARDS],
+}
+
+const_assert_eq!(PositionRewardExtension::INIT_SPACE, 320);
+
+impl PositionRewardExtension {
+    /// Reward checkpoints start from zero. The position liquidity can't change without the extension once an extended
+    /// reward is initialized, so the position earns with its current liquidity since then
+    pub fn initialize(&mut self, position: Pubkey) {
+        self.position = position;
+    }
+
+    pub fn update_position_reward(
+        &mut self,
+        position_liquidity: u128,
+        pool_reward_extension: &PoolRewardExtension,
+    ) -> Result<()> {
+        for (reward_info, pool_reward_info) in self
+            .reward_infos
+            .iter_mut()
+            .zip(pool_reward_extension.reward_infos.iter())
+        {
+            if pool_reward_info.initialized() {
+                reward_info.update_rewards(
+                    position_liquidity,
+                    U256::from_le_bytes(pool_reward_info.reward_per_token_stored),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn claim_reward(&mut self, extended_reward_index: usize) -> Result<u64> {
+        let reward_info = &mut self.reward_infos[extended_reward_index];
+        let total_reward = reward_info.reward_pendings;
+        reward_info.total_claimed_rewards =
+            reward_info.total_claimed_rewards.wrapping_add(total_reward);
+        reward_info.reward_pendings = 0;
+
+        Ok(total_reward)
+    }
+
+    /// Move pending rewards of the source position, which is closed by the merge
+    pub fn merge(&mut self, source: &mut PositionRewardExtension) -> Result<()> {
+        for (reward_info, source_reward_info) in self
+            .reward_infos
+            .iter_mut()
+            .zip(source.reward_infos.iter_mut())
+        {
+            reward_info.reward_pendings = reward_info
+                .reward_pendings
+                .safe_add(source_reward_info.reward_pendings)?;
+            reward_info.total_claimed_rewards = reward_info
+                .total_claimed_rewards
+                .wrapping_add(source_reward_info.total_claimed_rewards);
+            source_reward_info.reward_pendings = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Move pending rewards to the second position of a split, in proportion of the liquidity moved to it
+    pub fn split(
+        &mut self,
+        second: &mut PositionRewardExtension,
+        liquidity_split: u128,
+        liquidity_before_split: u128,
+    ) -> Result<()> {
+        if liquidity_split == 0 {
+            return Ok(());
+        }
+
+        for (reward_info, second_reward_info) in self
+            .reward_infos
+            .iter_mut()
+            .zip(second.reward_infos.iter_mut())
+        {
+            let reward_split: u64 = safe_mul_div_cast_u128(
+                reward_info.reward_pendings.into(),
+                liquidity_split,
+                liquidity_before_split,
+                Rounding::Down,
+            )?
+            .try_into()
+            .map_err(|_| PoolError::TypeCastFailed)?;
+
+            reward_info.reward_pendings = reward_info.reward_pendings.safe_sub(reward_split)?;
+            second_reward_info.reward_pendings =
+                second_reward_info.reward_pendings.safe_add(reward_split)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reward_infos
+            .iter()
+            .all(|reward_info| reward_info.reward_pendings == 0)
+    }
+}
+
+/// Index of the reward in the pool reward extension, none for rewards stored in the pool
+pub fn get_extended_reward_index(reward_index: usize) -> Result<Option<usize>> {
+    require!(
+        reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+        PoolError::InvalidRewardIndex
+    );
+    Ok(reward_index.checked_sub(NUM_REWARDS))
+}
+
+/// Reward info of `reward_index` in the pool or the pool reward extension
+pub fn get_reward_info_mut<'a>(
+    pool: &'a mut Pool,
+    pool_reward_extension: Option<&'a mut PoolRewardExtension>,
+    reward_index: usize,
+) -> Result<&'a mut RewardInfo> {
+    match get_extended_reward_index(reward_index)? {
+        Some(extended_reward_index) => {
+            let pool_reward_extension =
+                pool_reward_extension.ok_or(PoolError::MissingRewardExtension)?;
+            Ok(&mut pool_reward_extension.reward_infos[extended_reward_index])
+        }
+        None => Ok(&mut pool.reward_infos[reward_index]),
+    }
+}
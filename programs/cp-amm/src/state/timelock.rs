@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::MAX_PENDING_OPERATOR_ACTION_AGE, state::OperatorPermission, DynamicConfigParameters,
+    PoolError, StableSwapConfigParameters, StaticConfigParameters, UpdatePoolFeesParameters,
+};
+
+/// Maximum size of a borsh serialized [OperatorAction]
+pub const MAX_OPERATOR_ACTION_SIZE: usize = 192;
+
+/// Operator action which can be timelocked by the delay of its permission
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub enum OperatorAction {
+    SetPoolStatus {
+        status: u8,
+    },
+    UpdateRewardFunder {
+        reward_index: u8,
+        new_funder: Pubkey,
+    },
+    UpdatePoolFees {
+        params: UpdatePoolFeesParameters,
+    },
+    CreateConfig {
+        index: u64,
+        config_parameters: StaticConfigParameters,
+    },
+    CreateDynamicConfig {
+        index: u64,
+        config_parameters: DynamicConfigParameters,
+    },
+    CreateStableSwapConfig {
+        index: u64,
+        config_parameters: StableSwapConfigParameters,
+    },
+}
+
+impl OperatorAction {
+    pub fn get_permission(&self) -> OperatorPermission {
+        match self {
+            OperatorAction::SetPoolStatus { .. } => OperatorPermission::SetPoolStatus,
+            OperatorAction::UpdateRewardFunder { .. } => OperatorPermission::UpdateRewardFunder,
+            OperatorAction::UpdatePoolFees { .. } => OperatorPermission::UpdatePoolFees,
+            OperatorAction::CreateConfig { .. }
+            | OperatorAction::CreateDynamicConfig { .. }
+            | OperatorAction::CreateStableSwapConfig { .. } => OperatorPermission::CreateConfigKey,
+        }
+    }
+
+    /// Index of the config created by config actions, which target the config instead of a pool
+    pub fn get_config_index(&self) -> Option<u64> {
+        match self {
+            OperatorAction::CreateConfig { index, .. }
+            | OperatorAction::CreateDynamicConfig { index, .. }
+            | OperatorAction::CreateStableSwapConfig { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            OperatorAction::UpdatePoolFees { params } => params.validate(),
+            OperatorAction::CreateConfig {
+                config_parameters, ..
+            } => config_parameters.validate(),
+            OperatorAction::CreateDynamicConfig {
+                config_parameters, ..
+            } => config_parameters.validate(),
+            OperatorAction::CreateStableSwapConfig {
+                config_parameters, ..
+            } => config_parameters.validate(),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Permissions of the operator actions which can be timelocked
+pub fn is_timelocked_permission(permission: OperatorPermission) -> bool {
+    matches!(
+        permission,
+        OperatorPermission::SetPoolStatus
+            | OperatorPermission::UpdateRewardFunder
+            | OperatorPermission::UpdatePoolFees
+            | OperatorPermission::CreateConfigKey
+    )
+}
+
+/// Delays of operator actions set by the admin. An operator action with a non-zero delay must be proposed first,
+/// and can only be executed once the delay has passed
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct TimelockConfig {
+    /// delay in seconds, indexed by [OperatorPermission]
+    pub delays: [u64; 16],
+    pub padding: [u64; 4], // padding for future use
+}
+
+const_assert_eq!(TimelockConfig::INIT_SPACE, 160);
+
+impl TimelockConfig {
+    pub fn get_delay(&self, permission: OperatorPermission) -> u64 {
+        self.delays[usize::from(Into::<u8>::into(permission))]
+    }
+
+    pub fn set_delay(&mut self, permission: OperatorPermission, delay: u64) {
+        self.delays[usize::from(Into::<u8>::into(permission))] = delay;
+    }
+}
+
+/// Operator action proposed for a pool, anyone can execute it from `earliest_execution_timestamp` until
+/// [MAX_PENDING_OPERATOR_ACTION_AGE] after it. The account is derived from the pool and the permission, so a pool
+/// has at most one pending action per permission, which must be executed or cancelled before the next proposal
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+pub struct PendingOperatorAction {
+    /// pool of the action, or the config created by config actions
+    pub pool: Pubkey,
+    /// operator which proposed the action, receives the rent once the action is executed or cancelled
+    pub proposer: Pubkey,
+    pub earliest_execution_timestamp: u64,
+    /// refer [OperatorPermission]
+    pub permission: u8,
+    pub padding_0: [u8; 7],
+    /// borsh serialized [OperatorAction]
+    pub action: [u8; MAX_OPERATOR_ACTION_SIZE],
+    pub padding: [u64; 4], // padding for future use
+}
+
+const_assert_eq!(PendingOperatorAction::INIT_SPACE, 304);
+
+impl PendingOperatorAction {
+    pub fn initialize(
+        &mut self,
+        pool: Pubkey,
+        proposer: Pubkey,
+        earliest_execution_timestamp: u64,
+        action: &OperatorAction,
+    ) -> Result<()> {
+        action
+            .serialize(&mut &mut self.action[..])
+            .map_err(|_| PoolError::InvalidOperatorAction)?;
+        self.pool = pool;
+        self.proposer = proposer;
+        self.earliest_execution_timestamp = earliest_execution_timestamp;
+        self.permission = action.get_permission().into();
+        Ok(())
+    }
+
+    pub fn get_action(&self) -> Result<OperatorAction> {
+        let action = OperatorAction::deserialize(&mut &self.action[..])
+            .map_err(|_| PoolError::InvalidOperatorAction)?;
+        Ok(action)
+    }
+
+    pub fn is_executable(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.earliest_execution_timestamp
+    }
+
+    /// Stale actions must be proposed again, so a forgotten proposal can't be executed long after the delay
+    pub fn is_expired(&self, current_timestamp: u64) -> bool {
+        current_timestamp
+            > self
+                .earliest_execution_timestamp
+                .saturating_add(MAX_PENDING_OPERATOR_ACTION_AGE)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::safe_math::SafeMath;
+use crate::{safe_math::SafeMath, PoolError};
 use anchor_lang::prelude::{
     borsh::{BorshDeserialize, BorshSerialize},
     *,
@@ -90,6 +90,37 @@ impl InnerVesting {
         let remaining_vested_liquidity = self.get_new_release_liquidity(u64::MAX)?;
         Ok(remaining_vested_liquidity)
     }
+
+    /// Both vestings must be refreshed at the same point. Only schedules with the same cliff point, period frequency and number of period can be merged
+    pub fn merge(&mut self, other: &InnerVesting) -> Result<()> {
+        if other.is_empty() {
+            return Ok(());
+        }
+
+        if self.is_empty() {
+            *self = *other;
+            return Ok(());
+        }
+
+        require!(
+            self.cliff_point == other.cliff_point
+                && self.period_frequency == other.period_frequency
+                && self.number_of_period == other.number_of_period,
+            PoolError::UnsupportedInnerVestingMerge
+        );
+
+        self.cliff_unlock_liquidity = self
+            .cliff_unlock_liquidity
+            .safe_add(other.cliff_unlock_liquidity)?;
+        self.liquidity_per_period = self
+            .liquidity_per_period
+            .safe_add(other.liquidity_per_period)?;
+        self.total_released_liquidity = self
+            .total_released_liquidity
+            .safe_add(other.total_released_liquidity)?;
+
+        Ok(())
+    }
 }
 
 const_assert_eq!(InnerVesting::INIT_SPACE, 80);
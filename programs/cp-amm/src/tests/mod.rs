@@ -4,6 +4,12 @@ pub const LIQUIDITY_MAX: u128 = 34028236692093846346337460743;
 #[cfg(test)]
 mod test_swap;
 
+#[cfg(test)]
+mod test_swap_price_limit;
+
+#[cfg(test)]
+mod test_oracle;
+
 #[cfg(test)]
 mod test_modify_liquidity;
 
@@ -43,14 +49,59 @@ mod test_base_fee_serde;
 #[cfg(test)]
 mod test_split_inner_vesting;
 
+#[cfg(test)]
+mod test_merge_position;
+
 #[cfg(test)]
 mod test_collect_fee_mode;
 
 #[cfg(test)]
 mod test_liquidity_compounding;
 
+#[cfg(test)]
+mod test_liquidity_stable_swap;
+
 #[cfg(test)]
 mod test_safe_math;
 
 #[cfg(test)]
 mod test_const_pda;
+
+#[cfg(test)]
+mod test_flash_loan;
+
+#[cfg(test)]
+mod test_fee_volume_tier;
+
+#[cfg(test)]
+mod test_asymmetric_fee_time_scheduler;
+
+#[cfg(test)]
+mod test_dynamic_fee_ema;
+
+#[cfg(test)]
+mod test_compound_position_fee;
+
+#[cfg(test)]
+mod test_position_delegate;
+
+#[cfg(test)]
+mod test_reward_extension;
+
+#[cfg(test)]
+mod test_reward_boost;
+
+#[cfg(test)]
+mod test_allowlist;
+
+#[cfg(test)]
+mod test_pool_pause;
+
+#[cfg(test)]
+mod test_timelock;
+
+#[cfg(test)]
+mod test_protocol_fee_distribution;
+
+#[cfg(test)]
+mod test_creator_fee;
@@ -0,0 +1,79 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    is_wallet_allowlisted,
+    state::{AllowlistEntry, Pool, PoolType},
+};
+
+fn get_allowlist_entry_data(pool: Pubkey, wallet: Pubkey) -> Vec<u8> {
+    let mut allowlist_entry = AllowlistEntry::default();
+    allowlist_entry.initialize(pool, wallet);
+
+    let mut data = AllowlistEntry::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&allowlist_entry));
+    data
+}
+
+fn get_pool(pool_type: PoolType) -> Pool {
+    Pool {
+        pool_type: pool_type.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_is_wallet_allowlisted() {
+    let pool_key = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let allowlist_entry_key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = get_allowlist_entry_data(pool_key, wallet);
+    let allowlist_entry = AccountInfo::new(
+        &allowlist_entry_key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &crate::ID,
+        false,
+        0,
+    );
+    let remaining_accounts = [allowlist_entry];
+
+    let pool = get_pool(PoolType::Permissioned);
+    assert!(is_wallet_allowlisted(&remaining_accounts, pool_key, &pool, wallet).unwrap());
+    assert!(
+        !is_wallet_allowlisted(&remaining_accounts, pool_key, &pool, Pubkey::new_unique()).unwrap()
+    );
+    assert!(
+        !is_wallet_allowlisted(&remaining_accounts, Pubkey::new_unique(), &pool, wallet).unwrap()
+    );
+    assert!(!is_wallet_allowlisted(&[], pool_key, &pool, wallet).unwrap());
+
+    // allowlist is only looked up for permissioned pools
+    let pool = get_pool(PoolType::Customizable);
+    assert!(!is_wallet_allowlisted(&remaining_accounts, pool_key, &pool, wallet).unwrap());
+}
+
+#[test]
+fn test_allowlist_entry_owned_by_other_program() {
+    let pool_key = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let allowlist_entry_key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = get_allowlist_entry_data(pool_key, wallet);
+    let allowlist_entry = AccountInfo::new(
+        &allowlist_entry_key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let pool = get_pool(PoolType::Permissioned);
+    assert!(!is_wallet_allowlisted(&[allowlist_entry], pool_key, &pool, wallet).unwrap());
+}
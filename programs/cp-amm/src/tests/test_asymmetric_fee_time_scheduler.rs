@@ -0,0 +1,224 @@
+use crate::{
+    activation_handler::ActivationType,
+    base_fee::{
+        fee_asymmetric_time_scheduler::PodAlignedAsymmetricFeeTimeScheduler, BaseFeeHandler,
+    },
+    constants::fee::{MAX_FEE_NUMERATOR_V1, MIN_FEE_NUMERATOR},
+    params::swap::TradeDirection,
+    state::{fee::BaseFeeMode, CollectFeeMode, PoolFeesStruct},
+};
+
+fn get_asymmetric_fee_time_scheduler() -> PodAlignedAsymmetricFeeTimeScheduler {
+    PodAlignedAsymmetricFeeTimeScheduler {
+        cliff_fee_numerator: 500_000_000, // 50%
+        number_of_period: 45,
+        period_frequency: 60,
+        reduction_factor: 10_000_000,       // 1%
+        constant_fee_numerator: 10_000_000, // 1%
+        scheduled_trade_direction: TradeDirection::AtoB.into(),
+        base_fee_mode: BaseFeeMode::AsymmetricFeeTimeSchedulerLinear.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_validate_asymmetric_fee_time_scheduler() {
+    let fee_scheduler = get_asymmetric_fee_time_scheduler();
+    assert!(fee_scheduler
+        .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+        .is_ok());
+
+    // invalid scheduled trade direction
+    {
+        let mut fee_scheduler = get_asymmetric_fee_time_scheduler();
+        fee_scheduler.scheduled_trade_direction = 2;
+        assert!(fee_scheduler
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+
+    // constant fee is out of range
+    {
+        let mut fee_scheduler = get_asymmetric_fee_time_scheduler();
+        fee_scheduler.constant_fee_numerator = (MIN_FEE_NUMERATOR - 1) as u32;
+        assert!(fee_scheduler
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+
+        let mut fee_scheduler = get_asymmetric_fee_time_scheduler();
+        fee_scheduler.constant_fee_numerator = (MAX_FEE_NUMERATOR_V1 + 1) as u32;
+        assert!(fee_scheduler
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+
+    // scheduler reduces below the minimum fee
+    {
+        let mut fee_scheduler = get_asymmetric_fee_time_scheduler();
+        fee_scheduler.number_of_period = 50;
+        assert!(fee_scheduler
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+
+    // partial scheduler
+    {
+        let mut fee_scheduler = get_asymmetric_fee_time_scheduler();
+        fee_scheduler.period_frequency = 0;
+        assert!(fee_scheduler
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_asymmetric_fee_time_scheduler_by_direction() {
+    let fee_scheduler = get_asymmetric_fee_time_scheduler();
+    let activation_point = 1_000;
+
+    let get_fee_numerator = |current_point: u64, trade_direction: TradeDirection| {
+        fee_scheduler
+            .get_base_fee_numerator_from_included_fee_amount(
+                current_point,
+                activation_point,
+                trade_direction,
+                1_000_000,
+                0,
+                0,
+                0,
+            )
+            .unwrap()
+    };
+
+    // scheduled direction
+    assert_eq!(
+        get_fee_numerator(activation_point, TradeDirection::AtoB),
+        500_000_000
+    );
+    assert_eq!(
+        get_fee_numerator(activation_point + 60 * 10, TradeDirection::AtoB),
+        400_000_000
+    );
+    assert_eq!(
+        get_fee_numerator(u64::MAX, TradeDirection::AtoB),
+        50_000_000
+    );
+
+    // opposite direction
+    assert_eq!(
+        get_fee_numerator(activation_point, TradeDirection::BtoA),
+        10_000_000
+    );
+    assert_eq!(
+        get_fee_numerator(u64::MAX, TradeDirection::BtoA),
+        10_000_000
+    );
+
+    assert_eq!(fee_scheduler.get_min_fee_numerator().unwrap(), 10_000_000);
+    assert_eq!(fee_scheduler.get_max_fee_numerator().unwrap(), 500_000_000);
+
+    assert!(!fee_scheduler
+        .validate_base_fee_is_static(activation_point + 60 * 45, activation_point)
+        .unwrap());
+    assert!(fee_scheduler
+        .validate_base_fee_is_static(activation_point + 60 * 45 + 1, activation_point)
+        .unwrap());
+}
+
+#[test]
+fn test_asymmetric_fee_time_scheduler_exponential() {
+    let mut fee_scheduler = get_asymmetric_fee_time_scheduler();
+    fee_scheduler.base_fee_mode = BaseFeeMode::AsymmetricFeeTimeSchedulerExponential.into();
+    fee_scheduler.reduction_factor = 1_000; // 10%
+    fee_scheduler.scheduled_trade_direction = TradeDirection::BtoA.into();
+
+    assert!(fee_scheduler
+        .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+        .is_ok());
+
+    let fee_numerator = fee_scheduler
+        .get_base_fee_numerator_from_excluded_fee_amount(60, 0, TradeDirection::BtoA, 0, 0, 0, 0)
+        .unwrap();
+    assert_eq!(
+        fee_numerator,
+        fee_scheduler
+            .to_fee_time_scheduler()
+            .unwrap()
+            .get_base_fee_numerator(60, 0)
+            .unwrap()
+    );
+    assert!(fee_numerator < fee_scheduler.cliff_fee_numerator);
+
+    let fee_numerator = fee_scheduler
+        .get_base_fee_numerator_from_excluded_fee_amount(60, 0, TradeDirection::AtoB, 0, 0, 0, 0)
+        .unwrap();
+    assert_eq!(fee_numerator, 10_000_000);
+}
+
+#[test]
+fn test_asymmetric_fee_time_scheduler_exact_out_inversion() {
+    let fee_scheduler = get_asymmetric_fee_time_scheduler();
+    let mut pool_fees = PoolFeesStruct::default();
+    pool_fees
+        .base_fee
+        .base_fee_info
+        .data
+        .copy_from_slice(bytemuck::bytes_of(&fee_scheduler));
+
+    let current_point = 60 * 10;
+    let excluded_fee_amount = 1_000_000_000;
+
+    for trade_direction in [TradeDirection::AtoB, TradeDirection::BtoA] {
+        // exact out: find the included fee amount from the excluded fee amount
+        let trade_fee_numerator = pool_fees
+            .get_total_trading_fee_from_excluded_fee_amount(
+                current_point,
+                0,
+                excluded_fee_amount,
+                trade_direction,
+                MAX_FEE_NUMERATOR_V1,
+                0,
+                0,
+            )
+            .unwrap();
+        let (included_fee_amount, fee_amount) =
+            PoolFeesStruct::get_included_fee_amount(trade_fee_numerator, excluded_fee_amount)
+                .unwrap();
+
+        // exact in with the included fee amount charges the same fee numerator
+        let trade_fee_numerator_from_included = pool_fees
+            .get_total_trading_fee_from_included_fee_amount(
+                current_point,
+                0,
+                included_fee_amount,
+                trade_direction,
+                MAX_FEE_NUMERATOR_V1,
+                0,
+                0,
+            )
+            .unwrap();
+        assert_eq!(trade_fee_numerator, trade_fee_numerator_from_included);
+
+        let (amount, trading_fee) =
+            PoolFeesStruct::get_excluded_fee_amount(trade_fee_numerator, included_fee_amount)
+                .unwrap();
+        assert!(amount >= excluded_fee_amount);
+        assert!(trading_fee <= fee_amount);
+    }
+}
+
+#[test]
+fn test_asymmetric_fee_time_scheduler_u32_ranges() {
+    let mut fee_scheduler = get_asymmetric_fee_time_scheduler();
+    fee_scheduler.period_frequency = u32::MAX;
+    fee_scheduler.reduction_factor = u32::MAX;
+
+    // narrowed fields are widened without loss for the scheduled direction
+    let time_scheduler = fee_scheduler.to_fee_time_scheduler().unwrap();
+    assert_eq!(time_scheduler.period_frequency, u64::from(u32::MAX));
+    assert_eq!(time_scheduler.reduction_factor, u64::from(u32::MAX));
+    assert_eq!(
+        time_scheduler.base_fee_mode,
+        u8::from(BaseFeeMode::FeeTimeSchedulerLinear)
+    );
+}
@@ -1,9 +1,13 @@
+use crate::base_fee::fee_asymmetric_time_scheduler::{
+    BorshAsymmetricFeeTimeScheduler, PodAlignedAsymmetricFeeTimeScheduler,
+};
 use crate::base_fee::fee_market_cap_scheduler::{
     BorshFeeMarketCapScheduler, PodAlignedFeeMarketCapScheduler,
 };
 use crate::base_fee::fee_rate_limiter::{BorshFeeRateLimiter, PodAlignedFeeRateLimiter};
 use crate::base_fee::fee_time_scheduler::BorshFeeTimeScheduler;
 use crate::base_fee::fee_time_scheduler::PodAlignedFeeTimeScheduler;
+use crate::base_fee::fee_volume_tier::{BorshFeeVolumeTier, PodAlignedFeeVolumeTier};
 use crate::base_fee::{
     base_fee_info_to_base_fee_parameters, base_fee_parameters_to_base_fee_info, BaseFeeEnumReader,
 };
@@ -190,6 +194,107 @@ fn test_base_fee_serde_market_cap_scheduler() {
     assert_eq!(base_fee_params.data, reverse_base_fee_params.data);
 }
 
+#[test]
+fn test_base_fee_serde_volume_tier() {
+    let fee = BorshFeeVolumeTier {
+        cliff_fee_numerator: 10_000_000,
+        number_of_tiers: 5,
+        volume_decay_period: 86_400,
+        reduction_factor: 1_000_000,
+        tier_volume_step: 1_000_000_000_000,
+        base_fee_mode: BaseFeeMode::FeeVolumeTier.into(),
+    };
+
+    // convert to base fee params
+    let mut base_fee_params = BaseFeeParameters::default();
+    let bytes = borsh::to_vec(&fee).unwrap();
+    base_fee_params.data.copy_from_slice(&bytes);
+
+    let deserialized = BorshFeeVolumeTier::try_from_slice(&base_fee_params.data).unwrap();
+    assert_eq!(fee, deserialized);
+    assert_eq!(
+        base_fee_params.get_base_fee_mode().unwrap(),
+        BaseFeeMode::FeeVolumeTier
+    );
+
+    // convert to base fee struct
+    let base_fee_info_struct = base_fee_parameters_to_base_fee_info(&base_fee_params).unwrap();
+
+    let deserialized =
+        *bytemuck::from_bytes::<PodAlignedFeeVolumeTier>(base_fee_info_struct.data.as_slice());
+    assert_eq!(fee.base_fee_mode, deserialized.base_fee_mode);
+    assert_eq!(fee.cliff_fee_numerator, deserialized.cliff_fee_numerator);
+    assert_eq!(fee.number_of_tiers, deserialized.number_of_tiers);
+    assert_eq!(fee.volume_decay_period, deserialized.volume_decay_period);
+    assert_eq!(fee.reduction_factor, deserialized.reduction_factor);
+    assert_eq!(fee.tier_volume_step, deserialized.tier_volume_step);
+    assert_eq!(
+        base_fee_info_struct.get_base_fee_mode().unwrap(),
+        BaseFeeMode::FeeVolumeTier
+    );
+
+    // convert back to base fee params
+    let reverse_base_fee_params =
+        base_fee_info_to_base_fee_parameters(&base_fee_info_struct).unwrap();
+    assert_eq!(base_fee_params.data, reverse_base_fee_params.data);
+}
+
+#[test]
+fn test_base_fee_serde_asymmetric_time_scheduler() {
+    let fee = BorshAsymmetricFeeTimeScheduler {
+        cliff_fee_numerator: 500_000_000,
+        number_of_period: 45,
+        period_frequency: 60,
+        reduction_factor: 10_000_000,
+        constant_fee_numerator: 10_000_000,
+        scheduled_trade_direction: 1,
+        base_fee_mode: BaseFeeMode::AsymmetricFeeTimeSchedulerLinear.into(),
+        ..Default::default()
+    };
+
+    // convert to base fee params
+    let mut base_fee_params = BaseFeeParameters::default();
+    let bytes = borsh::to_vec(&fee).unwrap();
+    base_fee_params.data.copy_from_slice(&bytes);
+
+    let deserialized =
+        BorshAsymmetricFeeTimeScheduler::try_from_slice(&base_fee_params.data).unwrap();
+    assert_eq!(fee, deserialized);
+    assert_eq!(
+        base_fee_params.get_base_fee_mode().unwrap(),
+        BaseFeeMode::AsymmetricFeeTimeSchedulerLinear
+    );
+
+    // convert to base fee struct
+    let base_fee_info_struct = base_fee_parameters_to_base_fee_info(&base_fee_params).unwrap();
+
+    let deserialized = *bytemuck::from_bytes::<PodAlignedAsymmetricFeeTimeScheduler>(
+        base_fee_info_struct.data.as_slice(),
+    );
+    assert_eq!(fee.base_fee_mode, deserialized.base_fee_mode);
+    assert_eq!(fee.cliff_fee_numerator, deserialized.cliff_fee_numerator);
+    assert_eq!(fee.number_of_period, deserialized.number_of_period);
+    assert_eq!(fee.period_frequency, deserialized.period_frequency);
+    assert_eq!(fee.reduction_factor, deserialized.reduction_factor);
+    assert_eq!(
+        fee.constant_fee_numerator,
+        deserialized.constant_fee_numerator
+    );
+    assert_eq!(
+        fee.scheduled_trade_direction,
+        deserialized.scheduled_trade_direction
+    );
+    assert_eq!(
+        base_fee_info_struct.get_base_fee_mode().unwrap(),
+        BaseFeeMode::AsymmetricFeeTimeSchedulerLinear
+    );
+
+    // convert back to base fee params
+    let reverse_base_fee_params =
+        base_fee_info_to_base_fee_parameters(&base_fee_info_struct).unwrap();
+    assert_eq!(base_fee_params.data, reverse_base_fee_params.data);
+}
+
 #[test]
 fn test_base_fee_params_base_fee_mode_offset_valid() {
     let borsh_fee_params_0 = BorshFeeMarketCapScheduler {
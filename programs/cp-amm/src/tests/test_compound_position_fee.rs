@@ -0,0 +1,51 @@
+use crate::state::Position;
+
+#[test]
+fn test_compound_pending_fee() {
+    let mut position = Position {
+        fee_a_pending: 1_000,
+        fee_b_pending: 2_000,
+        ..Default::default()
+    };
+
+    position.compound_pending_fee(600, 2_000).unwrap();
+    assert_eq!(position.fee_a_pending, 400);
+    assert_eq!(position.fee_b_pending, 0);
+    assert_eq!(position.compounded_fee_metrics.total_compounded_a_fee, 600);
+    assert_eq!(
+        position.compounded_fee_metrics.total_compounded_b_fee,
+        2_000
+    );
+    // compounded fee isn't claimed fee
+    assert_eq!(position.metrics.total_claimed_a_fee, 0);
+    assert_eq!(position.metrics.total_claimed_b_fee, 0);
+
+    position.compound_pending_fee(400, 0).unwrap();
+    assert_eq!(position.fee_a_pending, 0);
+    assert_eq!(
+        position.compounded_fee_metrics.total_compounded_a_fee,
+        1_000
+    );
+
+    // can't compound more than pending fee
+    assert!(position.compound_pending_fee(1, 0).is_err());
+}
+
+#[test]
+fn test_merge_compounded_fee_metrics() {
+    let mut position = Position::default();
+    position.compounded_fee_metrics.total_compounded_a_fee = 100;
+    position.compounded_fee_metrics.total_compounded_b_fee = 200;
+
+    let mut source_position = Position::default();
+    source_position
+        .compounded_fee_metrics
+        .total_compounded_a_fee = 300;
+    source_position
+        .compounded_fee_metrics
+        .total_compounded_b_fee = 400;
+
+    position.merge(&mut source_position).unwrap();
+    assert_eq!(position.compounded_fee_metrics.total_compounded_a_fee, 400);
+    assert_eq!(position.compounded_fee_metrics.total_compounded_b_fee, 600);
+}
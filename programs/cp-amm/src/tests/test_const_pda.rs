@@ -1,6 +1,6 @@
 use anchor_lang::solana_program::pubkey::Pubkey;
 
-use crate::const_pda::{pool_authority, protocol_fee_authority};
+use crate::const_pda::{pool_authority, protocol_fee_authority, timelock_config};
 
 // Potential optimization on event authority too since anchor internally do Pubkey::find_program_address during runtime.
 #[test]
@@ -23,3 +23,13 @@ fn test_const_protocol_fee_authority() {
     assert_eq!(protocol_fee_authority::ID, derived_authority);
     assert_eq!(protocol_fee_authority::BUMP, derived_bump);
 }
+
+#[test]
+fn test_const_timelock_config() {
+    let (derived_timelock_config, derived_bump) = Pubkey::find_program_address(
+        &[crate::constants::seeds::TIMELOCK_CONFIG_PREFIX],
+        &crate::ID,
+    );
+    assert_eq!(timelock_config::ID, derived_timelock_config);
+    assert_eq!(timelock_config::BUMP, derived_bump);
+}
@@ -0,0 +1,118 @@
+use crate::{
+    base_fee::fee_time_scheduler::PodAlignedFeeTimeScheduler,
+    constants::{MAX_SQRT_PRICE, MIN_SQRT_PRICE},
+    params::swap::TradeDirection,
+    state::{
+        fee::{FeeMode, PoolFeesStruct},
+        CollectFeeMode, LayoutVersion, Pool,
+    },
+};
+
+fn get_pool(layout_version: LayoutVersion) -> Pool {
+    let mut pool = Pool {
+        liquidity: 1_000_000u128 << 64,
+        collect_fee_mode: CollectFeeMode::BothToken.into(),
+        token_a_amount: 1_000_000,
+        token_b_amount: 1_000_000,
+        sqrt_price: 1u128 << 64,
+        sqrt_min_price: MIN_SQRT_PRICE,
+        sqrt_max_price: MAX_SQRT_PRICE,
+        layout_version: layout_version.into(),
+        ..Default::default()
+    };
+    let fee_scheduler = PodAlignedFeeTimeScheduler {
+        cliff_fee_numerator: 10_000_000, // 1%
+        ..Default::default()
+    };
+    pool.pool_fees
+        .base_fee
+        .base_fee_info
+        .data
+        .copy_from_slice(bytemuck::bytes_of(&fee_scheduler));
+    pool.pool_fees.protocol_fee_percent = 20;
+    pool.pool_fees.creator_fee_percent = 10;
+    pool
+}
+
+#[test]
+fn test_split_fees_with_creator_fee() {
+    let pool_fees = PoolFeesStruct {
+        protocol_fee_percent: 20,
+        creator_fee_percent: 10,
+        referral_fee_percent: 20,
+        ..Default::default()
+    };
+
+    let split_fees = pool_fees.split_fees(1_000, true, true).unwrap();
+    assert_eq!(split_fees.referral_fee, 40);
+    assert_eq!(split_fees.protocol_fee, 160);
+    // creator fee is taken from the trading fee after protocol fee
+    assert_eq!(split_fees.creator_fee, 80);
+    assert_eq!(split_fees.claiming_fee, 720);
+    assert_eq!(split_fees.compounding_fee, 0);
+
+    // creator_fee_percent is the legacy padding of pools before layout version 2
+    let split_fees = pool_fees.split_fees(1_000, true, false).unwrap();
+    assert_eq!(split_fees.creator_fee, 0);
+    assert_eq!(split_fees.claiming_fee, 800);
+}
+
+#[test]
+fn test_claim_creator_fee() {
+    let mut pool = get_pool(LayoutVersion::V2);
+
+    let split_fees = pool.apply_flash_loan_fee(1_000, true, 0).unwrap();
+    assert_eq!(split_fees.creator_fee, 80);
+    assert_eq!(pool.creator_a_fee, 80);
+    assert_eq!(pool.creator_b_fee, 0);
+    assert_eq!(pool.protocol_a_fee, 200);
+
+    let (token_a_amount, token_b_amount) = pool.claim_creator_fee(50, u64::MAX).unwrap();
+    assert_eq!(token_a_amount, 50);
+    assert_eq!(token_b_amount, 0);
+    assert_eq!(pool.creator_a_fee, 30);
+}
+
+#[test]
+fn test_creator_fee_not_tracked_by_previous_layout() {
+    let mut pool = get_pool(LayoutVersion::V1);
+    pool.pool_fees.creator_fee_percent = 0;
+    // previously partner pubkey
+    pool.creator_a_fee = 1;
+
+    pool.apply_flash_loan_fee(1_000, true, 0).unwrap();
+    assert_eq!(pool.creator_a_fee, 1);
+    assert!(pool.claim_creator_fee(u64::MAX, u64::MAX).is_err());
+}
+
+#[test]
+fn test_swap_ignores_legacy_creator_fee_byte() {
+    for layout_version in [LayoutVersion::V0, LayoutVersion::V1] {
+        // non-zero legacy byte in place of creator_fee_percent
+        let mut pool = get_pool(layout_version);
+
+        let fee_mode =
+            FeeMode::get_fee_mode(CollectFeeMode::BothToken, TradeDirection::AtoB, false);
+        let swap_result = pool
+            .get_swap_result_from_exact_input(100_000, &fee_mode, TradeDirection::AtoB, 0)
+            .unwrap();
+        assert!(swap_result.claiming_fee > 0);
+        assert_eq!(swap_result.creator_fee, 0);
+
+        pool.apply_swap_result(&swap_result, &fee_mode, TradeDirection::AtoB, 0)
+            .unwrap();
+        assert_eq!(pool.creator_a_fee, 0);
+        assert_eq!(pool.creator_b_fee, 0);
+    }
+
+    let mut pool = get_pool(LayoutVersion::V2);
+    let fee_mode = FeeMode::get_fee_mode(CollectFeeMode::BothToken, TradeDirection::AtoB, false);
+    let swap_result = pool
+        .get_swap_result_from_exact_input(100_000, &fee_mode, TradeDirection::AtoB, 0)
+        .unwrap();
+    assert!(swap_result.creator_fee > 0);
+
+    pool.apply_swap_result(&swap_result, &fee_mode, TradeDirection::AtoB, 0)
+        .unwrap();
+    assert_eq!(pool.creator_b_fee, swap_result.creator_fee);
+}
@@ -0,0 +1,202 @@
+use anchor_lang::AnchorSerialize;
+
+use crate::{
+    constants::{fee::MAX_BASIS_POINT, BIN_STEP_BPS_DEFAULT, BIN_STEP_BPS_U128_DEFAULT, U24_MAX},
+    dynamic_fee::fee_ema_price_return::EmaPriceReturnDynamicFee,
+    params::fee_parameters::DynamicFeeParameters,
+    state::{
+        fee::{DynamicFeeMode, DynamicFeeStruct},
+        DynamicFeeConfig,
+    },
+    tests::price_math::get_price_from_id,
+};
+
+fn get_ema_dynamic_fee_parameters() -> DynamicFeeParameters {
+    DynamicFeeParameters {
+        filter_period: 10,
+        decay_period: 120,
+        reduction_factor: 2_500, // 25% weight of the latest price return
+        max_volatility_accumulator: 10_000_000, // 10% price return
+        variable_fee_control: 5_000, // 0.5x of the ema
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_dynamic_fee_mode_layout_backward_compatible() {
+    // existing pools and configs have zero padding, which is the volatility accumulator model
+    assert_eq!(
+        DynamicFeeMode::try_from(0u8).unwrap(),
+        DynamicFeeMode::VolatilityAccumulator
+    );
+    assert_eq!(std::mem::offset_of!(DynamicFeeStruct, dynamic_fee_mode), 1);
+    assert_eq!(std::mem::offset_of!(DynamicFeeConfig, dynamic_fee_mode), 1);
+    assert_eq!(
+        std::mem::offset_of!(DynamicFeeStruct, max_volatility_accumulator),
+        8
+    );
+    assert_eq!(
+        std::mem::offset_of!(DynamicFeeConfig, max_volatility_accumulator),
+        8
+    );
+
+    // the model is inferred from the bin step, so the instruction arguments keep their layout
+    let parameters = DynamicFeeParameters::default();
+    assert_eq!(parameters.try_to_vec().unwrap().len(), 32);
+}
+
+#[test]
+fn test_dynamic_fee_mode_inferred_from_bin_step() {
+    let parameters = get_ema_dynamic_fee_parameters();
+    assert_eq!(
+        parameters.get_dynamic_fee_mode(),
+        DynamicFeeMode::EmaPriceReturn
+    );
+    assert_eq!(
+        parameters.to_dynamic_fee_struct().dynamic_fee_mode,
+        u8::from(DynamicFeeMode::EmaPriceReturn)
+    );
+
+    let parameters = DynamicFeeParameters {
+        bin_step: BIN_STEP_BPS_DEFAULT,
+        bin_step_u128: BIN_STEP_BPS_U128_DEFAULT,
+        ..get_ema_dynamic_fee_parameters()
+    };
+    assert_eq!(
+        parameters.get_dynamic_fee_mode(),
+        DynamicFeeMode::VolatilityAccumulator
+    );
+    assert_eq!(
+        parameters.to_dynamic_fee_struct().dynamic_fee_mode,
+        u8::from(DynamicFeeMode::VolatilityAccumulator)
+    );
+}
+
+#[test]
+fn test_validate_ema_dynamic_fee_parameters() {
+    let parameters = get_ema_dynamic_fee_parameters();
+    assert!(parameters.validate().is_ok());
+
+    // default bin steps select the volatility accumulator model
+    {
+        let mut parameters = get_ema_dynamic_fee_parameters();
+        parameters.bin_step = BIN_STEP_BPS_DEFAULT;
+        parameters.bin_step_u128 = BIN_STEP_BPS_U128_DEFAULT;
+        assert!(parameters.validate().is_ok());
+    }
+
+    {
+        let mut parameters = get_ema_dynamic_fee_parameters();
+        parameters.reduction_factor = 0;
+        assert!(parameters.validate().is_err());
+
+        parameters.reduction_factor = MAX_BASIS_POINT + 1;
+        assert!(parameters.validate().is_err());
+    }
+
+    {
+        let mut parameters = get_ema_dynamic_fee_parameters();
+        parameters.filter_period = parameters.decay_period;
+        assert!(parameters.validate().is_err());
+    }
+
+    {
+        let mut parameters = get_ema_dynamic_fee_parameters();
+        parameters.max_volatility_accumulator = U24_MAX + 1;
+        assert!(parameters.validate().is_err());
+    }
+
+    // bin steps of neither model
+    {
+        let mut parameters = get_ema_dynamic_fee_parameters();
+        parameters.bin_step = BIN_STEP_BPS_DEFAULT;
+        assert!(parameters.validate().is_err());
+    }
+}
+
+#[test]
+fn test_ema_price_return() {
+    let sqrt_price = get_price_from_id(0, BIN_STEP_BPS_DEFAULT).unwrap();
+    // 100 bins of 1 bps in sqrt price is ~2% in price
+    let upper_sqrt_price = get_price_from_id(100, BIN_STEP_BPS_DEFAULT).unwrap();
+
+    let price_return =
+        EmaPriceReturnDynamicFee::get_price_return(upper_sqrt_price, sqrt_price, u128::MAX)
+            .unwrap();
+    let reverse_price_return =
+        EmaPriceReturnDynamicFee::get_price_return(sqrt_price, upper_sqrt_price, u128::MAX)
+            .unwrap();
+    assert_eq!(price_return, reverse_price_return);
+    // (1.0001^100)^2 - 1 = 2.0201..%
+    assert!(price_return > 2_020_000 && price_return < 2_021_000);
+
+    assert_eq!(
+        EmaPriceReturnDynamicFee::get_price_return(sqrt_price, sqrt_price, u128::MAX).unwrap(),
+        0
+    );
+
+    // capped
+    assert_eq!(
+        EmaPriceReturnDynamicFee::get_price_return(u128::MAX / 2, 1, 10_000_000).unwrap(),
+        10_000_000
+    );
+}
+
+#[test]
+fn test_ema_dynamic_fee_update() {
+    let parameters = get_ema_dynamic_fee_parameters();
+    let mut dynamic_fee = parameters.to_dynamic_fee_struct();
+
+    let sqrt_price = get_price_from_id(0, BIN_STEP_BPS_DEFAULT).unwrap();
+    let moved_sqrt_price = get_price_from_id(100, BIN_STEP_BPS_DEFAULT).unwrap();
+    let price_return =
+        EmaPriceReturnDynamicFee::get_price_return(moved_sqrt_price, sqrt_price, u128::MAX)
+            .unwrap();
+
+    // first swap moves the price, ema takes 25% of the return
+    let current_timestamp = 1_000;
+    dynamic_fee
+        .update_references(sqrt_price, current_timestamp)
+        .unwrap();
+    dynamic_fee
+        .update_volatility_accumulator(sqrt_price, moved_sqrt_price, current_timestamp)
+        .unwrap();
+    let ema = price_return * 2_500 / 10_000;
+    assert_eq!(dynamic_fee.volatility_accumulator, ema);
+    assert_eq!(dynamic_fee.last_update_timestamp, current_timestamp);
+    assert_eq!(
+        dynamic_fee.get_variable_fee().unwrap(),
+        (ema * 5_000).div_ceil(1_000)
+    );
+
+    // swap within the filter period is measured against the same reference and previous ema
+    dynamic_fee
+        .update_references(moved_sqrt_price, current_timestamp + 5)
+        .unwrap();
+    dynamic_fee
+        .update_volatility_accumulator(moved_sqrt_price, moved_sqrt_price, current_timestamp + 5)
+        .unwrap();
+    assert_eq!(dynamic_fee.volatility_accumulator, ema);
+    assert_eq!(dynamic_fee.sqrt_price_reference, sqrt_price);
+
+    // swap after the filter period without price change decays the ema
+    dynamic_fee
+        .update_references(moved_sqrt_price, current_timestamp + 20)
+        .unwrap();
+    dynamic_fee
+        .update_volatility_accumulator(moved_sqrt_price, moved_sqrt_price, current_timestamp + 20)
+        .unwrap();
+    let decayed_ema = ema * 7_500 / 10_000;
+    assert_eq!(dynamic_fee.volatility_accumulator, decayed_ema);
+    assert_eq!(dynamic_fee.sqrt_price_reference, moved_sqrt_price);
+
+    // ema is reset after the decay period
+    dynamic_fee
+        .update_references(moved_sqrt_price, current_timestamp + 200)
+        .unwrap();
+    dynamic_fee
+        .update_volatility_accumulator(moved_sqrt_price, moved_sqrt_price, current_timestamp + 200)
+        .unwrap();
+    assert_eq!(dynamic_fee.volatility_accumulator, 0);
+    assert_eq!(dynamic_fee.get_variable_fee().unwrap(), 0);
+}
@@ -0,0 +1,244 @@
+use crate::{
+    activation_handler::ActivationType,
+    base_fee::{fee_volume_tier::PodAlignedFeeVolumeTier, BaseFeeHandler},
+    constants::fee::{MAX_FEE_NUMERATOR_V1, MIN_FEE_NUMERATOR},
+    params::swap::TradeDirection,
+    state::{
+        fee::{BaseFeeMode, BaseFeeStruct},
+        CollectFeeMode, Pool,
+    },
+};
+
+fn get_fee_volume_tier() -> PodAlignedFeeVolumeTier {
+    PodAlignedFeeVolumeTier {
+        cliff_fee_numerator: 10_000_000, // 1%
+        number_of_tiers: 5,
+        volume_decay_period: 3600,       // 1 hour
+        reduction_factor: 1_000_000,     // 0.1%
+        tier_volume_step: 1_000_000_000, // 1000 USDC
+        base_fee_mode: BaseFeeMode::FeeVolumeTier.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_validate_fee_volume_tier() {
+    let fee_volume_tier = get_fee_volume_tier();
+    assert!(fee_volume_tier
+        .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+        .is_ok());
+
+    // zero fields
+    {
+        let mut fee_volume_tier = get_fee_volume_tier();
+        fee_volume_tier.reduction_factor = 0;
+        assert!(fee_volume_tier
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+
+        let mut fee_volume_tier = get_fee_volume_tier();
+        fee_volume_tier.number_of_tiers = 0;
+        assert!(fee_volume_tier
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+
+        let mut fee_volume_tier = get_fee_volume_tier();
+        fee_volume_tier.tier_volume_step = 0;
+        assert!(fee_volume_tier
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+
+        let mut fee_volume_tier = get_fee_volume_tier();
+        fee_volume_tier.volume_decay_period = 0;
+        assert!(fee_volume_tier
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+
+    // min fee is lower than the minimum
+    {
+        let mut fee_volume_tier = get_fee_volume_tier();
+        fee_volume_tier.cliff_fee_numerator = MIN_FEE_NUMERATOR;
+        fee_volume_tier.reduction_factor = 1;
+        assert!(fee_volume_tier
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+
+    // reduce below zero
+    {
+        let mut fee_volume_tier = get_fee_volume_tier();
+        fee_volume_tier.reduction_factor = 2_000_001;
+        assert!(fee_volume_tier
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+
+    // max fee is higher than the maximum
+    {
+        let mut fee_volume_tier = get_fee_volume_tier();
+        fee_volume_tier.cliff_fee_numerator = MAX_FEE_NUMERATOR_V1 + 1;
+        assert!(fee_volume_tier
+            .validate(CollectFeeMode::OnlyB, ActivationType::Timestamp)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_fee_volume_tier_step() {
+    let fee_volume_tier = get_fee_volume_tier();
+    let step = fee_volume_tier.tier_volume_step;
+    let cliff_fee_numerator = fee_volume_tier.cliff_fee_numerator;
+    let reduction_factor = u64::from(fee_volume_tier.reduction_factor);
+
+    let get_fee_numerator = |rolling_volume: u64| {
+        fee_volume_tier
+            .get_base_fee_numerator_from_included_fee_amount(
+                0,
+                0,
+                TradeDirection::AtoB,
+                1_000_000,
+                0,
+                0,
+                rolling_volume,
+            )
+            .unwrap()
+    };
+
+    assert_eq!(get_fee_numerator(0), cliff_fee_numerator);
+    assert_eq!(get_fee_numerator(step - 1), cliff_fee_numerator);
+    assert_eq!(
+        get_fee_numerator(step),
+        cliff_fee_numerator - reduction_factor
+    );
+    assert_eq!(
+        get_fee_numerator(step * 3 + 1),
+        cliff_fee_numerator - reduction_factor * 3
+    );
+    // capped at the last tier
+    assert_eq!(
+        get_fee_numerator(u64::MAX),
+        fee_volume_tier.get_min_fee_numerator().unwrap()
+    );
+    assert_eq!(
+        fee_volume_tier.get_min_fee_numerator().unwrap(),
+        cliff_fee_numerator - reduction_factor * 5
+    );
+
+    // same fee with excluded fee amount
+    assert_eq!(
+        fee_volume_tier
+            .get_base_fee_numerator_from_excluded_fee_amount(
+                0,
+                0,
+                TradeDirection::BtoA,
+                1_000_000,
+                0,
+                0,
+                step * 2,
+            )
+            .unwrap(),
+        get_fee_numerator(step * 2)
+    );
+
+    // never static
+    assert!(!fee_volume_tier
+        .validate_base_fee_is_static(u64::MAX, 0)
+        .unwrap());
+}
+
+#[test]
+fn test_fee_volume_tier_decay() {
+    let fee_volume_tier = get_fee_volume_tier();
+    let rolling_volume = 1_000_000;
+
+    assert_eq!(
+        fee_volume_tier
+            .get_decayed_rolling_volume(rolling_volume, 100, 100)
+            .unwrap(),
+        rolling_volume
+    );
+    assert_eq!(
+        fee_volume_tier
+            .get_decayed_rolling_volume(rolling_volume, 100, 100 + 1800)
+            .unwrap(),
+        rolling_volume / 2
+    );
+    assert_eq!(
+        fee_volume_tier
+            .get_decayed_rolling_volume(rolling_volume, 100, 100 + 3600)
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        fee_volume_tier
+            .get_decayed_rolling_volume(rolling_volume, 100, u64::MAX)
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_pool_update_pre_swap_decay_rolling_volume() {
+    let fee_volume_tier = get_fee_volume_tier();
+    let mut pool = Pool {
+        rolling_volume: 1_000_000,
+        rolling_volume_last_update_timestamp: 100,
+        ..Default::default()
+    };
+    pool.pool_fees
+        .base_fee
+        .base_fee_info
+        .data
+        .copy_from_slice(bytemuck::bytes_of(&fee_volume_tier));
+
+    pool.update_pre_swap(100 + 900).unwrap();
+    assert_eq!(pool.rolling_volume, 750_000);
+    assert_eq!(pool.rolling_volume_last_update_timestamp, 1000);
+
+    pool.update_pre_swap(1000 + 3600).unwrap();
+    assert_eq!(pool.rolling_volume, 0);
+    assert_eq!(pool.rolling_volume_last_update_timestamp, 4600);
+}
+
+#[test]
+fn test_pool_update_pre_swap_ignore_rolling_volume() {
+    // other base fee modes don't track rolling volume
+    let mut pool = Pool {
+        rolling_volume: 1_000_000,
+        rolling_volume_last_update_timestamp: 100,
+        ..Default::default()
+    };
+
+    pool.update_pre_swap(100 + 900).unwrap();
+    assert_eq!(pool.rolling_volume, 1_000_000);
+    assert_eq!(pool.rolling_volume_last_update_timestamp, 100);
+}
+
+#[test]
+fn test_pool_switch_to_fee_volume_tier_reset_rolling_volume() {
+    let fee_volume_tier = get_fee_volume_tier();
+    let mut volume_tier_base_fee = BaseFeeStruct::default();
+    volume_tier_base_fee
+        .base_fee_info
+        .data
+        .copy_from_slice(bytemuck::bytes_of(&fee_volume_tier));
+
+    // legacy pool holds the previous padding_1 bytes in rolling_volume
+    let mut pool = Pool {
+        rolling_volume: 1_000_000,
+        rolling_volume_last_update_timestamp: 100,
+        ..Default::default()
+    };
+    pool.update_base_fee(volume_tier_base_fee);
+    assert!(pool.pool_fees.base_fee.to_fee_volume_tier().is_ok());
+    assert_eq!(pool.rolling_volume, 0);
+    assert_eq!(pool.rolling_volume_last_update_timestamp, 0);
+
+    // the traded volume is kept while the pool stays in volume tier mode
+    pool.rolling_volume = 1_000_000;
+    pool.rolling_volume_last_update_timestamp = 100;
+    pool.update_base_fee(volume_tier_base_fee);
+    assert_eq!(pool.rolling_volume, 1_000_000);
+    assert_eq!(pool.rolling_volume_last_update_timestamp, 100);
+}
@@ -0,0 +1,99 @@
+use ruint::aliases::U256;
+
+use crate::{
+    constants::LIQUIDITY_SCALE,
+    state::{CollectFeeMode, Pool},
+};
+
+fn get_pool(collect_fee_mode: CollectFeeMode, compounding_fee_bps: u16) -> Pool {
+    let mut pool = Pool {
+        liquidity: 1_000_000u128 << 64,
+        collect_fee_mode: collect_fee_mode.into(),
+        token_a_amount: 1_000_000,
+        token_b_amount: 1_000_000,
+        sqrt_price: 1u128 << 64,
+        ..Default::default()
+    };
+    pool.pool_fees.protocol_fee_percent = 20;
+    pool.pool_fees.compounding_fee_bps = compounding_fee_bps;
+    pool
+}
+
+#[test]
+fn test_flash_loan_fee_split() {
+    let mut pool = get_pool(CollectFeeMode::BothToken, 0);
+
+    let split_fees = pool.apply_flash_loan_fee(1_000, true, 0).unwrap();
+    assert_eq!(split_fees.protocol_fee, 200);
+    assert_eq!(split_fees.claiming_fee, 800);
+    assert_eq!(split_fees.compounding_fee, 0);
+    assert_eq!(split_fees.referral_fee, 0);
+
+    assert_eq!(pool.protocol_a_fee, 200);
+    assert_eq!(pool.protocol_b_fee, 0);
+    assert_eq!(
+        pool.fee_a_per_liquidity(),
+        (U256::from(800) << LIQUIDITY_SCALE as usize) / U256::from(pool.liquidity)
+    );
+    assert_eq!(pool.fee_b_per_liquidity(), U256::ZERO);
+    assert_eq!(pool.metrics.total_lp_a_fee, 800);
+    assert_eq!(pool.metrics.total_protocol_a_fee, 200);
+    // reserves are unchanged without compounding fee
+    assert_eq!(pool.token_a_amount, 1_000_000);
+    assert_eq!(pool.token_b_amount, 1_000_000);
+}
+
+#[test]
+fn test_flash_loan_fee_compounding_token_b() {
+    let mut pool = get_pool(CollectFeeMode::Compounding, 5_000);
+    let sqrt_price = pool.sqrt_price;
+
+    let split_fees = pool.apply_flash_loan_fee(1_000, false, 0).unwrap();
+    assert_eq!(split_fees.protocol_fee, 200);
+    assert_eq!(split_fees.compounding_fee, 400);
+    assert_eq!(split_fees.claiming_fee, 400);
+
+    assert_eq!(pool.protocol_b_fee, 200);
+    assert_eq!(pool.token_b_amount, 1_000_400);
+    assert_eq!(pool.metrics.total_lp_b_fee, 800);
+    // compounding fee moves the price up
+    assert!(pool.sqrt_price > sqrt_price);
+}
+
+#[test]
+fn test_flash_loan_fee_compounding_token_a() {
+    let mut pool = get_pool(CollectFeeMode::Compounding, 5_000);
+    let sqrt_price = pool.sqrt_price;
+
+    let split_fees = pool.apply_flash_loan_fee(1_000, true, 0).unwrap();
+    // compounding fees are only accumulated in token b, the share is claimable instead
+    assert_eq!(split_fees.compounding_fee, 0);
+    assert_eq!(split_fees.claiming_fee, 800);
+
+    assert_eq!(pool.protocol_a_fee, 200);
+    assert_eq!(pool.token_a_amount, 1_000_000);
+    assert_eq!(pool.token_b_amount, 1_000_000);
+    assert_eq!(pool.sqrt_price, sqrt_price);
+}
+
+#[test]
+fn test_flash_loan_fee_accumulates_oracle_before_price_change() {
+    let mut pool = get_pool(CollectFeeMode::Compounding, 5_000);
+    pool.update_oracle_cumulatives(1_000).unwrap();
+
+    let mut expected_pool = pool;
+    expected_pool.update_oracle_cumulatives(1_100).unwrap();
+
+    pool.apply_flash_loan_fee(1_000, false, 1_100).unwrap();
+    assert!(pool.sqrt_price > expected_pool.sqrt_price);
+    // the elapsed period is accumulated with the price before the compounding fee
+    assert_eq!(pool.oracle_last_update_timestamp, 1_100);
+    assert_eq!(
+        pool.log_sqrt_price_cumulative,
+        expected_pool.log_sqrt_price_cumulative
+    );
+    assert_eq!(
+        pool.seconds_per_liquidity_cumulative,
+        expected_pool.seconds_per_liquidity_cumulative
+    );
+}
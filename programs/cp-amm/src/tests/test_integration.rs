@@ -130,6 +130,7 @@ proptest! {
             0,
             sqrt_price,
             liquidity,
+            0,
         ).unwrap();
 
         let mut pool = Pool {
@@ -77,6 +77,7 @@ proptest! {
             0,
             sqrt_price,
             liquidity,
+            0,
         ).unwrap();
 
         let mut pool = Pool {
@@ -152,6 +153,7 @@ proptest! {
             0,
             sqrt_price,
             liquidity,
+            0,
         ).unwrap();
 
         let mut pool = Pool {
@@ -215,6 +217,7 @@ proptest! {
             0,
             sqrt_price,
             liquidity,
+            0,
         ).unwrap();
 
         let mut pool = Pool {
@@ -254,6 +257,45 @@ proptest! {
 
 }
 
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10000, .. ProptestConfig::default()
+    })]
+    #[test]
+    fn test_compounding_max_liquidity_delta_from_amounts_wont_exceed_amounts(
+        token_a_amount in 0..=u64::MAX,
+        token_b_amount in 0..=u64::MAX,
+        a in 1..u64::MAX,
+        b in 1..u64::MAX,
+    ) {
+        let Ok((sqrt_price, liquidity)) = get_sqrt_price_and_liquidity_from_amounts(a, b) else {
+            return Ok(());
+        };
+        let InitialPoolInformation {
+            token_a_amount: reserve_a_amount,
+            token_b_amount: reserve_b_amount,
+            ..
+        } = get_initial_pool_information(CollectFeeMode::Compounding, 0, 0, sqrt_price, liquidity, 0)
+            .unwrap();
+
+        let liquidity_handler = CompoundingLiquidity {
+            token_a_amount: reserve_a_amount,
+            token_b_amount: reserve_b_amount,
+            liquidity,
+        };
+        let liquidity_delta = liquidity_handler
+            .get_max_liquidity_delta_from_amounts(token_a_amount, token_b_amount)
+            .unwrap();
+
+        let (deposit_a_amount, deposit_b_amount) = liquidity_handler
+            .get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)
+            .unwrap();
+
+        assert!(deposit_a_amount <= token_a_amount);
+        assert!(deposit_b_amount <= token_b_amount);
+    }
+}
+
 #[test]
 fn test_compounding_swap_basic() {
     let a = 100_000_000;
@@ -267,7 +309,7 @@ fn test_compounding_swap_basic() {
         initial_liquidity: _,
         sqrt_min_price: _,
         sqrt_max_price: _,
-    } = get_initial_pool_information(CollectFeeMode::Compounding, 0, 0, sqrt_price, liquidity)
+    } = get_initial_pool_information(CollectFeeMode::Compounding, 0, 0, sqrt_price, liquidity, 0)
         .unwrap();
 
     let mut pool = Pool {
@@ -0,0 +1,381 @@
+use crate::constants::{MAX_AMPLIFICATION_COEFFICIENT, ONE_Q64};
+use crate::params::swap::TradeDirection;
+use crate::safe_math::SafeCast;
+use crate::state::fee::FeeMode;
+use crate::state::SwapAmountFromInput;
+use crate::tests::test_liquidity_compounding::get_sqrt_price_and_liquidity_from_amounts;
+use crate::utils_math::sqrt_u256;
+use crate::{
+    get_initial_pool_information,
+    state::{CollectFeeMode, Pool, Position},
+    u128x128_math::Rounding,
+    InitialPoolInformation,
+};
+use crate::{
+    get_stable_swap_invariant, get_stable_swap_sqrt_price, validate_amplification_coefficient,
+    LiquidityHandler, StableSwapConfigParameters, StableSwapLiquidity, StaticConfigParameters,
+};
+use anchor_lang::prelude::Pubkey;
+use proptest::prelude::*;
+use ruint::aliases::U256;
+
+fn get_stable_swap_pool(
+    token_a_amount: u64,
+    token_b_amount: u64,
+    amplification_coefficient: u64,
+) -> Pool {
+    let sqrt_price =
+        get_stable_swap_sqrt_price(token_a_amount, token_b_amount, amplification_coefficient)
+            .unwrap();
+    Pool {
+        collect_fee_mode: CollectFeeMode::StableSwap.into(),
+        token_a_amount,
+        token_b_amount,
+        liquidity: ONE_Q64,
+        sqrt_price,
+        sqrt_max_price: u128::MAX,
+        amplification_coefficient,
+        ..Default::default()
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10000, .. ProptestConfig::default()
+    })]
+    #[test]
+    fn test_stable_swap_liquidity_initialization(
+        a in 1..u64::MAX,
+        b in 1..u64::MAX,
+        amplification_coefficient in 1..=MAX_AMPLIFICATION_COEFFICIENT,
+    ) {
+        let result = get_sqrt_price_and_liquidity_from_amounts(a, b);
+        if result.is_err() {
+            return Ok(());
+        }
+        let (sqrt_price, liquidity) = result.unwrap();
+        let InitialPoolInformation {
+            token_a_amount,
+            token_b_amount,
+            initial_liquidity,
+            sqrt_price: initial_sqrt_price,
+            sqrt_min_price,
+            sqrt_max_price,
+        } = get_initial_pool_information(
+            CollectFeeMode::StableSwap,
+            0,
+            0,
+            sqrt_price,
+            liquidity,
+            amplification_coefficient,
+        ).unwrap();
+        assert_eq!(sqrt_min_price, 0);
+        assert_eq!(sqrt_max_price, u128::MAX);
+
+        // stable swap price is between 1 and the constant product price
+        let constant_product_sqrt_price: u128 = sqrt_u256(
+            (U256::from(token_b_amount) << 128) / U256::from(token_a_amount),
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let lower = ONE_Q64.min(constant_product_sqrt_price);
+        let upper = ONE_Q64.max(constant_product_sqrt_price);
+        assert!(initial_sqrt_price >= lower.saturating_sub(1));
+        assert!(initial_sqrt_price <= upper.saturating_add(1));
+
+        let mut pool = Pool {
+            collect_fee_mode: CollectFeeMode::StableSwap.into(),
+            token_a_amount,
+            token_b_amount,
+            liquidity,
+            amplification_coefficient,
+            ..Default::default()
+        };
+
+        let mut position = Position {
+            unlocked_liquidity: initial_liquidity,
+            ..Default::default()
+        };
+        let unlocked_liquidity = position.unlocked_liquidity;
+
+        let liquidity_handler = pool.get_liquidity_handler().unwrap();
+        let (removed_token_a_amount, removed_token_b_amount) = liquidity_handler.get_amounts_for_modify_liquidity(unlocked_liquidity, Rounding::Down).unwrap();
+        pool.apply_remove_liquidity(&mut position, unlocked_liquidity, removed_token_a_amount, removed_token_b_amount).unwrap();
+        assert!(pool.liquidity > 0); // there is a deadshare in pool
+        assert!(pool.token_a_amount > 0 && pool.token_b_amount > 0);
+        assert_eq!(position.unlocked_liquidity, 0);
+    }
+
+    #[test]
+    fn test_stable_swap_reserve_wont_lost_when_swap_round_trip(
+        amount_in in 1..=u64::MAX,
+        a in 1..u64::MAX,
+        b in 1..u64::MAX,
+        amplification_coefficient in 1..=MAX_AMPLIFICATION_COEFFICIENT,
+        a_to_b in any::<bool>(),
+    ) {
+        let (trade_direction, reverse_trade_direction, input_reserve) = if a_to_b {
+            (TradeDirection::AtoB, TradeDirection::BtoA, a)
+        } else {
+            (TradeDirection::BtoA, TradeDirection::AtoB, b)
+        };
+        if input_reserve.checked_add(amount_in).is_none() {
+            return Ok(());
+        }
+        let mut pool = get_stable_swap_pool(a, b, amplification_coefficient);
+        let invariant = get_stable_swap_invariant(a, b, amplification_coefficient).unwrap();
+
+        let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast().unwrap();
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+        let swap_result_0 = pool
+            .get_swap_result_from_exact_input(amount_in, &fee_mode, trade_direction, 0)
+            .unwrap();
+        pool.apply_swap_result(&swap_result_0, &fee_mode, trade_direction, 0).unwrap();
+
+        // invariant never decreases after swap
+        let next_invariant = get_stable_swap_invariant(pool.token_a_amount, pool.token_b_amount, amplification_coefficient).unwrap();
+        assert!(next_invariant + U256::from(1) >= invariant);
+
+        // swap back
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, reverse_trade_direction, false);
+        let swap_result_1 = pool
+            .get_swap_result_from_exact_input(swap_result_0.output_amount, &fee_mode, reverse_trade_direction, 0)
+            .unwrap();
+
+        assert!(swap_result_1.output_amount <= amount_in);
+    }
+
+    #[test]
+    fn test_stable_swap_exact_out_wont_undercharge(
+        amount_out in 1..u64::MAX,
+        a in 1..u64::MAX,
+        b in 1..u64::MAX,
+        amplification_coefficient in 1..=MAX_AMPLIFICATION_COEFFICIENT,
+        a_to_b in any::<bool>(),
+    ) {
+        let liquidity_handler = StableSwapLiquidity {
+            token_a_amount: a,
+            token_b_amount: b,
+            liquidity: ONE_Q64,
+            amplification_coefficient,
+        };
+        let output_reserve = if a_to_b { b } else { a };
+        if amount_out >= output_reserve {
+            return Ok(());
+        }
+
+        let result = if a_to_b {
+            liquidity_handler.calculate_a_to_b_from_amount_out(amount_out)
+        } else {
+            liquidity_handler.calculate_b_to_a_from_amount_out(amount_out)
+        };
+        let Ok(swap_amount) = result else {
+            // input amount doesn't fit in u64
+            return Ok(());
+        };
+
+        // selling the bought amount back at the same reserves can't return more than what was paid
+        let sold_amount = if a_to_b {
+            liquidity_handler.calculate_b_to_a_from_amount_in(amount_out)
+        } else {
+            liquidity_handler.calculate_a_to_b_from_amount_in(amount_out)
+        }
+        .unwrap()
+        .output_amount;
+        assert!(sold_amount <= swap_amount.input_amount);
+    }
+
+    #[test]
+    fn test_stable_swap_partial_fill_wont_cross_price_limit(
+        amount_in in 1..u64::MAX / 2,
+        a in 1_000..u64::MAX / 2,
+        b in 1_000..u64::MAX / 2,
+        amplification_coefficient in 1..=MAX_AMPLIFICATION_COEFFICIENT,
+        price_move_bps in 1..5_000u128,
+        a_to_b in any::<bool>(),
+    ) {
+        let liquidity_handler = StableSwapLiquidity {
+            token_a_amount: a,
+            token_b_amount: b,
+            liquidity: ONE_Q64,
+            amplification_coefficient,
+        };
+        let sqrt_price = liquidity_handler.get_next_sqrt_price(0).unwrap();
+
+        let (sqrt_price_limit, result) = if a_to_b {
+            let sqrt_price_limit = sqrt_price * (10_000 - price_move_bps) / 10_000;
+            (sqrt_price_limit, liquidity_handler.calculate_a_to_b_from_partial_amount_in(amount_in, Some(sqrt_price_limit)).unwrap())
+        } else {
+            let sqrt_price_limit = sqrt_price * (10_000 + price_move_bps) / 10_000;
+            (sqrt_price_limit, liquidity_handler.calculate_b_to_a_from_partial_amount_in(amount_in, Some(sqrt_price_limit)).unwrap())
+        };
+        let SwapAmountFromInput { amount_left, output_amount, .. } = result;
+        let consumed_in_amount = amount_in - amount_left;
+
+        let (token_a_amount, token_b_amount) = if a_to_b {
+            (a + consumed_in_amount, b - output_amount)
+        } else {
+            (a - output_amount, b + consumed_in_amount)
+        };
+        let next_sqrt_price = get_stable_swap_sqrt_price(token_a_amount, token_b_amount, amplification_coefficient).unwrap();
+        if a_to_b {
+            assert!(next_sqrt_price >= sqrt_price_limit);
+        } else {
+            assert!(next_sqrt_price <= sqrt_price_limit);
+        }
+
+        if amount_left > 0 {
+            // one more token in would cross the limit
+            let output_amount = if a_to_b {
+                liquidity_handler.calculate_a_to_b_from_amount_in(consumed_in_amount + 1)
+            } else {
+                liquidity_handler.calculate_b_to_a_from_amount_in(consumed_in_amount + 1)
+            }
+            .unwrap()
+            .output_amount;
+            let next_sqrt_price = if a_to_b {
+                get_stable_swap_sqrt_price(a + consumed_in_amount + 1, b - output_amount, amplification_coefficient).unwrap()
+            } else {
+                get_stable_swap_sqrt_price(a - output_amount, b + consumed_in_amount + 1, amplification_coefficient).unwrap()
+            };
+            if a_to_b {
+                assert!(next_sqrt_price < sqrt_price_limit);
+            } else {
+                assert!(next_sqrt_price > sqrt_price_limit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stable_swap_modify_liquidity_proportional(
+        a in 1..u64::MAX,
+        b in 1..u64::MAX,
+        liquidity in 1..u128::MAX,
+        liquidity_delta in 1..u128::MAX,
+    ) {
+        if liquidity_delta > liquidity {
+            return Ok(());
+        }
+        let liquidity_handler = StableSwapLiquidity {
+            token_a_amount: a,
+            token_b_amount: b,
+            liquidity,
+            amplification_coefficient: 100,
+        };
+        let (amount_a_down, amount_b_down) = liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Down).unwrap();
+        let (amount_a_up, amount_b_up) = liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up).unwrap();
+        assert!(amount_a_down <= a && amount_b_down <= b);
+        assert!(amount_a_up >= amount_a_down && amount_a_up - amount_a_down <= 1);
+        assert!(amount_b_up >= amount_b_down && amount_b_up - amount_b_down <= 1);
+    }
+}
+
+#[test]
+fn test_stable_swap_balanced_reserves_price() {
+    for amplification_coefficient in [1, 100, MAX_AMPLIFICATION_COEFFICIENT] {
+        let liquidity_handler = StableSwapLiquidity {
+            token_a_amount: 1_000_000_000,
+            token_b_amount: 1_000_000_000,
+            liquidity: ONE_Q64,
+            amplification_coefficient,
+        };
+        assert_eq!(liquidity_handler.get_next_sqrt_price(0).unwrap(), ONE_Q64);
+    }
+}
+
+#[test]
+fn test_stable_swap_higher_amplification_flatter_curve() {
+    let token_a_amount = 1_000_000_000;
+    let token_b_amount = 2_000_000_000;
+    let amount_in = 100_000_000;
+
+    let mut prev_sqrt_price = u128::MAX;
+    let mut prev_output_amount = 0;
+    for amplification_coefficient in [1, 10, 100, 1000, MAX_AMPLIFICATION_COEFFICIENT] {
+        let liquidity_handler = StableSwapLiquidity {
+            token_a_amount,
+            token_b_amount,
+            liquidity: ONE_Q64,
+            amplification_coefficient,
+        };
+        let sqrt_price = liquidity_handler.get_next_sqrt_price(0).unwrap();
+        let output_amount = liquidity_handler
+            .calculate_b_to_a_from_amount_in(amount_in)
+            .unwrap()
+            .output_amount;
+
+        // price is pulled toward 1, so token a is cheaper and swap out more token a
+        assert!(sqrt_price > ONE_Q64 && sqrt_price < prev_sqrt_price);
+        assert!(output_amount > prev_output_amount && output_amount < amount_in);
+        prev_sqrt_price = sqrt_price;
+        prev_output_amount = output_amount;
+    }
+}
+
+#[test]
+fn test_stable_swap_basic() {
+    let mut pool = get_stable_swap_pool(100_000_000_000, 100_000_000_000, 100);
+
+    let amount_in = 1_000_000_000;
+    let trade_direction = TradeDirection::AtoB;
+
+    let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast().unwrap();
+    let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+    let swap_result = pool
+        .get_swap_result_from_exact_input(amount_in, &fee_mode, trade_direction, 0)
+        .unwrap();
+
+    // 1% of the reserves at a balanced pool is swapped at almost 1:1
+    assert!(swap_result.output_amount < amount_in);
+    assert!(swap_result.output_amount > amount_in * 999 / 1000);
+
+    pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, 0)
+        .unwrap();
+    assert!(pool.sqrt_price < ONE_Q64);
+
+    let swap_result_reverse = pool
+        .get_swap_result_from_exact_input(
+            swap_result.output_amount,
+            &fee_mode,
+            TradeDirection::BtoA,
+            0,
+        )
+        .unwrap();
+
+    assert!(swap_result_reverse.output_amount <= amount_in);
+}
+
+#[test]
+fn test_validate_amplification_coefficient() {
+    assert!(validate_amplification_coefficient(0).is_err());
+    assert!(validate_amplification_coefficient(1).is_ok());
+    assert!(validate_amplification_coefficient(MAX_AMPLIFICATION_COEFFICIENT).is_ok());
+    assert!(validate_amplification_coefficient(MAX_AMPLIFICATION_COEFFICIENT + 1).is_err());
+}
+
+#[test]
+fn test_stable_swap_config_requires_amplification_coefficient() {
+    // stable swap configs can't be created without an amplification coefficient
+    let static_config_parameters = StaticConfigParameters {
+        pool_fees: Default::default(),
+        sqrt_min_price: 0,
+        sqrt_max_price: u128::MAX,
+        vault_config_key: Pubkey::default(),
+        pool_creator_authority: Pubkey::default(),
+        activation_type: 0,
+        collect_fee_mode: CollectFeeMode::StableSwap.into(),
+    };
+    assert!(static_config_parameters.validate().is_err());
+
+    let stable_swap_config_parameters = StableSwapConfigParameters {
+        pool_fees: Default::default(),
+        vault_config_key: Pubkey::default(),
+        pool_creator_authority: Pubkey::default(),
+        activation_type: 0,
+        amplification_coefficient: 0,
+    };
+    assert!(stable_swap_config_parameters.validate().is_err());
+}
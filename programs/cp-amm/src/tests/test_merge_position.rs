@@ -0,0 +1,180 @@
+use proptest::prelude::*;
+
+use crate::{
+    constants::NUM_REWARDS,
+    state::{InnerVesting, Pool, Position},
+};
+
+fn build_vesting_position(
+    number_of_period: u16,
+    cliff_unlock_liquidity: u128,
+    liquidity_per_period: u128,
+    current_point: u64,
+) -> Position {
+    let mut position = Position::default();
+    position.inner_vesting = InnerVesting {
+        cliff_point: 0,
+        period_frequency: 1,
+        number_of_period,
+        cliff_unlock_liquidity,
+        liquidity_per_period,
+        ..Default::default()
+    };
+    position.vested_liquidity = position.inner_vesting.get_total_lock_amount().unwrap();
+    position.refresh_inner_vesting(current_point).unwrap();
+    position
+}
+
+proptest! {
+    #[test]
+    fn test_merge_inner_vesting(
+        number_of_period in 1..=1000u16,
+        current_point in 0..=1000u64,
+        later_point in 0..=2000u64,
+        cliff_unlock_liquidity_0 in 0..=u64::MAX,
+        liquidity_per_period_0 in 1..=u64::MAX,
+        cliff_unlock_liquidity_1 in 0..=u64::MAX,
+        liquidity_per_period_1 in 1..=u64::MAX,
+        unlocked_liquidity_1 in 0..=u64::MAX,
+    ) {
+        let mut position_0 = build_vesting_position(
+            number_of_period,
+            cliff_unlock_liquidity_0.into(),
+            liquidity_per_period_0.into(),
+            current_point,
+        );
+        let mut position_1 = build_vesting_position(
+            number_of_period,
+            cliff_unlock_liquidity_1.into(),
+            liquidity_per_period_1.into(),
+            current_point,
+        );
+        position_1.add_liquidity(unlocked_liquidity_1.into()).unwrap();
+
+        let source_liquidity = position_1.get_total_liquidity().unwrap();
+        let total_liquidity = position_0.get_total_liquidity().unwrap() + source_liquidity;
+        let unlocked_liquidity = position_0.unlocked_liquidity + position_1.unlocked_liquidity;
+
+        let amount_merged = position_0.merge(&mut position_1).unwrap();
+        prop_assert!(position_1.is_empty().unwrap());
+        prop_assert_eq!(
+            amount_merged.unlocked_liquidity + amount_merged.vested_liquidity,
+            source_liquidity
+        );
+        prop_assert_eq!(position_0.get_total_liquidity().unwrap(), total_liquidity);
+        prop_assert_eq!(position_0.unlocked_liquidity, unlocked_liquidity);
+        position_0.validate_no_external_vesting().unwrap();
+
+        // merged schedule releases the same liquidity as both schedules
+        let later_point = current_point.max(later_point);
+        position_0.refresh_inner_vesting(later_point).unwrap();
+        let expected_inner_vesting = InnerVesting {
+            cliff_point: 0,
+            period_frequency: 1,
+            number_of_period,
+            cliff_unlock_liquidity: u128::from(cliff_unlock_liquidity_0)
+                + u128::from(cliff_unlock_liquidity_1),
+            liquidity_per_period: u128::from(liquidity_per_period_0)
+                + u128::from(liquidity_per_period_1),
+            ..Default::default()
+        };
+        prop_assert_eq!(
+            position_0.unlocked_liquidity,
+            expected_inner_vesting
+                .get_max_unlocked_liquidity(later_point)
+                .unwrap()
+                + u128::from(unlocked_liquidity_1)
+        );
+        prop_assert_eq!(position_0.get_total_liquidity().unwrap(), total_liquidity);
+    }
+}
+
+#[test]
+fn test_merge_different_inner_vesting_schedule() {
+    let mut position_0 = build_vesting_position(10, 100, 100, 0);
+    let mut position_1 = build_vesting_position(20, 100, 100, 0);
+
+    assert!(position_0.merge(&mut position_1).is_err());
+}
+
+#[test]
+fn test_merge_inner_vesting_into_empty_position() {
+    let mut position_0 = Position {
+        unlocked_liquidity: 1_000,
+        ..Default::default()
+    };
+    let mut position_1 = build_vesting_position(10, 100, 100, 5);
+    let inner_vesting = position_1.inner_vesting;
+    let vested_liquidity = position_1.vested_liquidity;
+
+    position_0.merge(&mut position_1).unwrap();
+    assert_eq!(position_0.inner_vesting, inner_vesting);
+    assert_eq!(position_0.vested_liquidity, vested_liquidity);
+    assert!(position_1.inner_vesting.is_empty());
+}
+
+#[test]
+fn test_merge_source_position_with_external_vesting() {
+    let mut position_0 = Position::default();
+    // locked by a vesting account
+    let mut position_1 = Position {
+        vested_liquidity: 1_000,
+        ..Default::default()
+    };
+
+    assert!(position_0.merge(&mut position_1).is_err());
+}
+
+#[test]
+fn test_merge_position_fee_and_reward() {
+    let pool = Pool::default();
+    let mut position_0 = Position {
+        unlocked_liquidity: 1_000,
+        permanent_locked_liquidity: 2_000,
+        fee_a_pending: 10,
+        fee_b_pending: 20,
+        ..Default::default()
+    };
+    let mut position_1 = Position {
+        unlocked_liquidity: 3_000,
+        permanent_locked_liquidity: 4_000,
+        fee_a_pending: 30,
+        fee_b_pending: 40,
+        ..Default::default()
+    };
+    for reward_index in 0..NUM_REWARDS {
+        position_0.reward_infos[reward_index].reward_pendings = 100;
+        position_0.reward_infos[reward_index].total_claimed_rewards = 1;
+        position_1.reward_infos[reward_index].reward_pendings = 200;
+        position_1.reward_infos[reward_index].total_claimed_rewards = 2;
+    }
+    position_1.metrics.total_claimed_a_fee = 5;
+    position_1.metrics.total_claimed_b_fee = 6;
+
+    let amount_merged = pool
+        .apply_merge_position(&mut position_0, &mut position_1)
+        .unwrap();
+
+    assert_eq!(amount_merged.unlocked_liquidity, 3_000);
+    assert_eq!(amount_merged.permanent_locked_liquidity, 4_000);
+    assert_eq!(amount_merged.fee_a, 30);
+    assert_eq!(amount_merged.fee_b, 40);
+    assert_eq!(amount_merged.reward_0, 200);
+    assert_eq!(amount_merged.reward_1, 200);
+
+    assert_eq!(position_0.unlocked_liquidity, 4_000);
+    assert_eq!(position_0.permanent_locked_liquidity, 6_000);
+    assert_eq!(position_0.fee_a_pending, 40);
+    assert_eq!(position_0.fee_b_pending, 60);
+    assert_eq!(position_0.metrics.total_claimed_a_fee, 5);
+    assert_eq!(position_0.metrics.total_claimed_b_fee, 6);
+    for reward_index in 0..NUM_REWARDS {
+        assert_eq!(position_0.reward_infos[reward_index].reward_pendings, 300);
+        assert_eq!(
+            position_0.reward_infos[reward_index].total_claimed_rewards,
+            3
+        );
+    }
+
+    assert!(position_1.is_empty().unwrap());
+}
@@ -46,3 +46,34 @@ proptest! {
         assert!(result_0.1 >= result_1.1);
     }
 }
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10000, .. ProptestConfig::default()
+    })]
+    #[test]
+    fn test_max_liquidity_delta_from_amounts_wont_exceed_amounts(
+        sqrt_price in MIN_SQRT_PRICE..=MAX_SQRT_PRICE,
+        token_a_amount in 0..=u64::MAX,
+        token_b_amount in 0..=u64::MAX,
+    ) {
+        let pool = Pool {
+            sqrt_price,
+            sqrt_min_price: MIN_SQRT_PRICE,
+            sqrt_max_price: MAX_SQRT_PRICE,
+            ..Default::default()
+        };
+
+        let liquidity_handler = pool.get_liquidity_handler().unwrap();
+        let liquidity_delta = liquidity_handler
+            .get_max_liquidity_delta_from_amounts(token_a_amount, token_b_amount)
+            .unwrap();
+
+        let (deposit_a_amount, deposit_b_amount) = liquidity_handler
+            .get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)
+            .unwrap();
+
+        assert!(deposit_a_amount <= token_a_amount);
+        assert!(deposit_b_amount <= token_b_amount);
+    }
+}
@@ -1,6 +1,13 @@
+use anchor_lang::{prelude::*, Discriminator};
+
 use crate::{
     constants::MAX_OPERATION,
-    state::{Operator, OperatorPermission},
+    consume_operator_usage,
+    state::{
+        is_usage_limitable_permission, Operator, OperatorPermission, OperatorUsageLimit,
+        PermissionUsageLimit,
+    },
+    PoolError,
 };
 
 #[test]
@@ -14,51 +21,51 @@ fn test_initialize_with_full_permission() {
     };
 
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::CreateConfigKey),
+        operator.is_permission_allow(OperatorPermission::CreateConfigKey, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::RemoveConfigKey),
+        operator.is_permission_allow(OperatorPermission::RemoveConfigKey, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::CreateTokenBadge),
+        operator.is_permission_allow(OperatorPermission::CreateTokenBadge, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::CloseTokenBadge),
+        operator.is_permission_allow(OperatorPermission::CloseTokenBadge, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::SetPoolStatus),
+        operator.is_permission_allow(OperatorPermission::SetPoolStatus, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::InitializeReward),
+        operator.is_permission_allow(OperatorPermission::InitializeReward, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::UpdateRewardDuration),
+        operator.is_permission_allow(OperatorPermission::UpdateRewardDuration, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::UpdateRewardFunder),
+        operator.is_permission_allow(OperatorPermission::UpdateRewardFunder, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::UpdatePoolFees),
+        operator.is_permission_allow(OperatorPermission::UpdatePoolFees, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::ClaimProtocolFee),
+        operator.is_permission_allow(OperatorPermission::ClaimProtocolFee, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::ZapProtocolFee),
+        operator.is_permission_allow(OperatorPermission::ZapProtocolFee, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::FixPool),
+        operator.is_permission_allow(OperatorPermission::FixPool, 0),
         true
     );
 }
@@ -70,11 +77,11 @@ fn test_is_permission_allow() {
         ..Default::default()
     };
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::CreateConfigKey),
+        operator.is_permission_allow(OperatorPermission::CreateConfigKey, 0),
         false
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::RemoveConfigKey),
+        operator.is_permission_allow(OperatorPermission::RemoveConfigKey, 0),
         false
     );
 
@@ -84,15 +91,151 @@ fn test_is_permission_allow() {
     };
 
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::CreateConfigKey),
+        operator.is_permission_allow(OperatorPermission::CreateConfigKey, 0),
         true
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::RemoveConfigKey),
+        operator.is_permission_allow(OperatorPermission::RemoveConfigKey, 0),
         false
     );
     assert_eq!(
-        operator.is_permission_allow(OperatorPermission::CreateTokenBadge),
+        operator.is_permission_allow(OperatorPermission::CreateTokenBadge, 0),
         true
     );
 }
+
+#[test]
+fn test_expired_operator_permission() {
+    let mut operator = Operator::default();
+    operator.update(0b1, 1_000);
+
+    assert!(operator.is_permission_allow(OperatorPermission::CreateConfigKey, 999));
+    assert!(!operator.is_permission_allow(OperatorPermission::CreateConfigKey, 1_000));
+
+    // no expiry
+    operator.update(0b1, 0);
+    assert!(operator.is_permission_allow(OperatorPermission::CreateConfigKey, u64::MAX));
+}
+
+#[test]
+fn test_set_usage_limited_permission() {
+    let mut operator = Operator::default();
+    operator
+        .set_usage_limited(OperatorPermission::ZapProtocolFee, true)
+        .unwrap();
+    assert!(operator.is_usage_limited(OperatorPermission::ZapProtocolFee));
+    assert!(!operator.is_usage_limited(OperatorPermission::ClaimProtocolFee));
+
+    operator
+        .set_usage_limited(OperatorPermission::ZapProtocolFee, false)
+        .unwrap();
+    assert!(!operator.is_usage_limited(OperatorPermission::ZapProtocolFee));
+    assert!(is_usage_limitable_permission(
+        OperatorPermission::ClaimProtocolFee
+    ));
+    assert!(!is_usage_limitable_permission(
+        OperatorPermission::SetPoolStatus
+    ));
+    // handlers of other permissions don't consume the usage limit, so they can't be capped
+    assert!(operator
+        .set_usage_limited(OperatorPermission::SetPoolStatus, true)
+        .is_err());
+    assert!(!operator.is_usage_limited(OperatorPermission::SetPoolStatus));
+}
+
+#[test]
+fn test_consume_permission_usage_limit() {
+    let window_duration = 86_400;
+    let mut limit = PermissionUsageLimit::default();
+    limit.initialize(window_duration, 2, 1_000);
+
+    limit.consume(100, 400).unwrap();
+    limit.consume(200, 600).unwrap();
+    // max usage per window
+    assert!(limit.consume(300, 0).is_err());
+
+    // counters reset in the next window
+    let mut limit = PermissionUsageLimit::default();
+    limit.initialize(window_duration, 0, 1_000);
+    limit.consume(100, 1_000).unwrap();
+    // max amount per window
+    assert!(limit.consume(200, 1).is_err());
+    limit.consume(100 + window_duration, 1_000).unwrap();
+    assert_eq!(limit.window_start_timestamp, 100 + window_duration);
+    assert_eq!(limit.usage, 1);
+}
+
+#[test]
+fn test_consume_operator_usage() {
+    let operator_key = Pubkey::new_unique();
+    let mut operator = Operator::default();
+    operator
+        .set_usage_limited(OperatorPermission::ZapProtocolFee, true)
+        .unwrap();
+
+    let mut operator_usage_limit = OperatorUsageLimit::default();
+    operator_usage_limit.initialize(operator_key);
+    operator_usage_limit
+        .get_limit_mut(OperatorPermission::ZapProtocolFee)
+        .initialize(86_400, 1, 0);
+    let mut data = OperatorUsageLimit::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&operator_usage_limit));
+
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &crate::ID,
+        false,
+        0,
+    );
+    let remaining_accounts = [account];
+
+    // permissions which aren't usage limited don't need the operator usage limit
+    consume_operator_usage(
+        &[],
+        operator_key,
+        &operator,
+        OperatorPermission::ClaimProtocolFee,
+        100,
+        0,
+    )
+    .unwrap();
+    // usage limited permission can't be used without the operator usage limit account
+    assert_eq!(
+        consume_operator_usage(
+            &[],
+            operator_key,
+            &operator,
+            OperatorPermission::ZapProtocolFee,
+            100,
+            0
+        )
+        .unwrap_err(),
+        PoolError::MissingOperatorUsageLimit.into()
+    );
+
+    consume_operator_usage(
+        &remaining_accounts,
+        operator_key,
+        &operator,
+        OperatorPermission::ZapProtocolFee,
+        100,
+        0,
+    )
+    .unwrap();
+    // usage is written back to the account
+    assert!(consume_operator_usage(
+        &remaining_accounts,
+        operator_key,
+        &operator,
+        OperatorPermission::ZapProtocolFee,
+        200,
+        0
+    )
+    .is_err());
+}
@@ -0,0 +1,192 @@
+use crate::{
+    state::{Oracle, Pool, ORACLE_OBSERVATION_SIZE},
+    tests::LIQUIDITY_MAX,
+    utils_math::{get_log2_sqrt_price, LOG2_FRACTIONAL_BITS},
+};
+use anchor_lang::prelude::Pubkey;
+use proptest::prelude::*;
+use ruint::aliases::U256;
+
+const START_TIMESTAMP: u64 = 1_000_000;
+
+fn get_pool(sqrt_price: u128, liquidity: u128) -> Pool {
+    let mut pool = Pool {
+        sqrt_price,
+        liquidity,
+        ..Default::default()
+    };
+    pool.update_oracle_cumulatives(START_TIMESTAMP).unwrap();
+    pool
+}
+
+fn get_oracle(pool: &Pool) -> Box<Oracle> {
+    let mut oracle: Box<Oracle> = Box::new(bytemuck::Zeroable::zeroed());
+    oracle.initialize(Pubkey::new_unique(), pool);
+    oracle
+}
+
+/// Move the pool price at `timestamp` and write an observation
+fn set_sqrt_price(oracle: &mut Oracle, pool: &mut Pool, timestamp: u64, sqrt_price: u128) {
+    pool.update_oracle_cumulatives(timestamp).unwrap();
+    pool.sqrt_price = sqrt_price;
+    oracle.write_observation(pool).unwrap();
+}
+
+fn get_average_log_sqrt_price(
+    oracle: &Oracle,
+    pool: &Pool,
+    current_timestamp: u64,
+    start_seconds_ago: u64,
+    end_seconds_ago: u64,
+) -> i64 {
+    let start = oracle
+        .observe(pool, current_timestamp, start_seconds_ago)
+        .unwrap();
+    let end = oracle
+        .observe(pool, current_timestamp, end_seconds_ago)
+        .unwrap();
+    end.log_sqrt_price_cumulative
+        .wrapping_sub(start.log_sqrt_price_cumulative)
+        / (start_seconds_ago - end_seconds_ago) as i64
+}
+
+#[test]
+fn test_log2_sqrt_price() {
+    let one = 1i64 << LOG2_FRACTIONAL_BITS;
+    assert_eq!(get_log2_sqrt_price(1u128 << 64).unwrap(), 0);
+    assert_eq!(get_log2_sqrt_price(1u128 << 65).unwrap(), one);
+    assert_eq!(get_log2_sqrt_price(1u128 << 63).unwrap(), -one);
+    assert_eq!(get_log2_sqrt_price(1).unwrap(), -64 * one);
+    assert_eq!(get_log2_sqrt_price(u128::MAX).unwrap(), 64 * one - 1);
+    assert!(get_log2_sqrt_price(0).is_err());
+
+    // log2(sqrt(2)) = 0.5
+    let sqrt_2 = 26087635650665564424u128;
+    let log2 = get_log2_sqrt_price(sqrt_2).unwrap();
+    assert!((log2 - one / 2).abs() <= 1);
+}
+
+proptest! {
+    #[test]
+    fn test_log2_sqrt_price_monotonic(sqrt_price in 1..u128::MAX) {
+        let log2 = get_log2_sqrt_price(sqrt_price).unwrap();
+        let next_log2 = get_log2_sqrt_price(sqrt_price + 1).unwrap();
+        prop_assert!(log2 <= next_log2);
+    }
+}
+
+#[test]
+fn test_observe_constant_price() {
+    let sqrt_price = 1u128 << 65;
+    let mut pool = get_pool(sqrt_price, LIQUIDITY_MAX);
+    let oracle = get_oracle(&pool);
+
+    let current_timestamp = START_TIMESTAMP + 100;
+    pool.update_oracle_cumulatives(current_timestamp).unwrap();
+
+    let average = get_average_log_sqrt_price(&oracle, &pool, current_timestamp, 100, 0);
+    assert_eq!(average, get_log2_sqrt_price(sqrt_price).unwrap());
+
+    // extrapolated from the pool cumulatives
+    let average = get_average_log_sqrt_price(&oracle, &pool, current_timestamp + 50, 100, 0);
+    assert_eq!(average, get_log2_sqrt_price(sqrt_price).unwrap());
+
+    let start = oracle.observe(&pool, current_timestamp, 100).unwrap();
+    let end = oracle.observe(&pool, current_timestamp, 0).unwrap();
+    let seconds_per_liquidity = end
+        .seconds_per_liquidity_cumulative
+        .wrapping_sub(start.seconds_per_liquidity_cumulative);
+    let expected_seconds_per_liquidity: u128 = ((U256::from(100u64) << 128)
+        / U256::from(LIQUIDITY_MAX))
+    .try_into()
+    .unwrap();
+    assert_eq!(seconds_per_liquidity, expected_seconds_per_liquidity);
+}
+
+#[test]
+fn test_observe_time_weighted() {
+    let low_sqrt_price = 1u128 << 64;
+    let high_sqrt_price = 1u128 << 66;
+    let mut pool = get_pool(low_sqrt_price, LIQUIDITY_MAX);
+    let mut oracle = get_oracle(&pool);
+
+    // 100 seconds at low price, then 300 seconds at high price
+    set_sqrt_price(
+        &mut oracle,
+        &mut pool,
+        START_TIMESTAMP + 100,
+        high_sqrt_price,
+    );
+    let current_timestamp = START_TIMESTAMP + 400;
+
+    let one = 1i64 << LOG2_FRACTIONAL_BITS;
+    let average = get_average_log_sqrt_price(&oracle, &pool, current_timestamp, 400, 0);
+    assert_eq!(average, 2 * one * 300 / 400);
+
+    let average = get_average_log_sqrt_price(&oracle, &pool, current_timestamp, 300, 0);
+    assert_eq!(average, 2 * one);
+
+    // interpolated between the 2 observations
+    let average = get_average_log_sqrt_price(&oracle, &pool, current_timestamp, 400, 350);
+    assert_eq!(average, 0);
+}
+
+#[test]
+fn test_observe_ring_buffer_wrap() {
+    let mut pool = get_pool(1u128 << 64, LIQUIDITY_MAX);
+    let mut oracle = get_oracle(&pool);
+
+    let total_observations = ORACLE_OBSERVATION_SIZE as u64 * 2 + 10;
+    for i in 1..=total_observations {
+        let sqrt_price = if i % 2 == 0 { 1u128 << 64 } else { 1u128 << 65 };
+        set_sqrt_price(&mut oracle, &mut pool, START_TIMESTAMP + i * 10, sqrt_price);
+    }
+    assert_eq!(oracle.observation_count, ORACLE_OBSERVATION_SIZE as u64);
+
+    let current_timestamp = START_TIMESTAMP + total_observations * 10;
+    let oldest_timestamp = oracle.get_oldest_observation().unwrap().timestamp;
+    assert_eq!(
+        oldest_timestamp,
+        current_timestamp - (ORACLE_OBSERVATION_SIZE as u64 - 1) * 10
+    );
+
+    // price alternates every 10 seconds, take an even number of intervals
+    let one = 1i64 << LOG2_FRACTIONAL_BITS;
+    let oldest_seconds_ago = current_timestamp - oldest_timestamp;
+    let average = get_average_log_sqrt_price(
+        &oracle,
+        &pool,
+        current_timestamp,
+        oldest_seconds_ago - 10,
+        0,
+    );
+    assert_eq!(average, one / 2);
+
+    // binary search hits the observation in the middle of the buffer
+    let start = oracle.observe(&pool, current_timestamp, 1005).unwrap();
+    let end = oracle.observe(&pool, current_timestamp, 995).unwrap();
+    assert_eq!(start.timestamp, current_timestamp - 1005);
+    assert_eq!(
+        end.log_sqrt_price_cumulative
+            .wrapping_sub(start.log_sqrt_price_cumulative),
+        5 * one
+    );
+
+    assert!(oracle
+        .observe(&pool, current_timestamp, oldest_seconds_ago)
+        .is_ok());
+    assert!(oracle
+        .observe(&pool, current_timestamp, oldest_seconds_ago + 1)
+        .is_err());
+}
+
+#[test]
+fn test_write_at_most_one_observation_per_second() {
+    let mut pool = get_pool(1u128 << 64, LIQUIDITY_MAX);
+    let mut oracle = get_oracle(&pool);
+
+    set_sqrt_price(&mut oracle, &mut pool, START_TIMESTAMP + 1, 1u128 << 65);
+    set_sqrt_price(&mut oracle, &mut pool, START_TIMESTAMP + 1, 1u128 << 66);
+    assert_eq!(oracle.observation_count, 2);
+    assert_eq!(oracle.observation_index, 1);
+}
@@ -0,0 +1,75 @@
+use crate::state::{is_pool_action_paused, PoolPauseAction};
+
+const ACTIONS: [PoolPauseAction; 7] = [
+    PoolPauseAction::Swap,
+    PoolPauseAction::AddLiquidity,
+    PoolPauseAction::RemoveLiquidity,
+    PoolPauseAction::CreatePosition,
+    PoolPauseAction::LockPosition,
+    PoolPauseAction::SplitMergePosition,
+    PoolPauseAction::FlashLoan,
+];
+
+fn get_pool_status(actions: &[PoolPauseAction]) -> u8 {
+    actions.iter().fold(0, |status, &action| {
+        status | 1u8 << Into::<u8>::into(action)
+    })
+}
+
+#[test]
+fn test_enabled_pool() {
+    for action in ACTIONS {
+        assert!(!is_pool_action_paused(0, action));
+    }
+}
+
+#[test]
+fn test_disabled_pool_allows_remove_liquidity() {
+    // the previous binary pool status
+    let pool_status = get_pool_status(&[PoolPauseAction::Disable]);
+    assert_eq!(pool_status, 1);
+
+    for action in ACTIONS {
+        assert_eq!(
+            is_pool_action_paused(pool_status, action),
+            action != PoolPauseAction::RemoveLiquidity
+        );
+    }
+}
+
+#[test]
+fn test_pause_swap_only() {
+    let pool_status = get_pool_status(&[PoolPauseAction::Swap]);
+
+    for action in ACTIONS {
+        assert_eq!(
+            is_pool_action_paused(pool_status, action),
+            action == PoolPauseAction::Swap
+        );
+    }
+}
+
+#[test]
+fn test_pause_deposits_and_withdrawals() {
+    let pool_status = get_pool_status(&[
+        PoolPauseAction::AddLiquidity,
+        PoolPauseAction::RemoveLiquidity,
+    ]);
+
+    assert!(is_pool_action_paused(
+        pool_status,
+        PoolPauseAction::AddLiquidity
+    ));
+    assert!(is_pool_action_paused(
+        pool_status,
+        PoolPauseAction::RemoveLiquidity
+    ));
+    assert!(!is_pool_action_paused(pool_status, PoolPauseAction::Swap));
+
+    // remove liquidity is only paused by its own bit
+    let pool_status = pool_status | get_pool_status(&[PoolPauseAction::Disable]);
+    assert!(is_pool_action_paused(
+        pool_status,
+        PoolPauseAction::RemoveLiquidity
+    ));
+}
@@ -0,0 +1,33 @@
+use crate::{
+    constants::MAX_POSITION_DELEGATE_PERMISSION,
+    state::{PositionDelegate, PositionDelegatePermission},
+};
+
+#[test]
+fn test_position_delegate_permission() {
+    let mut position_delegate = PositionDelegate::default();
+    let permission = (1 << u8::from(PositionDelegatePermission::ClaimPositionFee))
+        | (1 << u8::from(PositionDelegatePermission::CompoundPositionFee));
+    position_delegate.initialize(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        permission,
+    );
+
+    assert!(position_delegate.is_permission_allow(PositionDelegatePermission::ClaimPositionFee));
+    assert!(position_delegate.is_permission_allow(PositionDelegatePermission::CompoundPositionFee));
+    assert!(!position_delegate.is_permission_allow(PositionDelegatePermission::ClaimReward));
+}
+
+#[test]
+fn test_max_position_delegate_permission() {
+    let max_permission =
+        PositionDelegatePermission::try_from(MAX_POSITION_DELEGATE_PERMISSION - 1).unwrap();
+    assert_eq!(
+        max_permission,
+        PositionDelegatePermission::CompoundPositionFee
+    );
+    assert!(PositionDelegatePermission::try_from(MAX_POSITION_DELEGATE_PERMISSION).is_err());
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_PROTOCOL_FEE_RECEIVERS,
+    state::{ProtocolFeeDistribution, ProtocolFeeReceiverParameters},
+};
+
+fn get_receivers(weights_bps: &[u16]) -> Vec<ProtocolFeeReceiverParameters> {
+    weights_bps
+        .iter()
+        .map(|&weight_bps| ProtocolFeeReceiverParameters {
+            wallet: Pubkey::new_unique(),
+            weight_bps,
+        })
+        .collect()
+}
+
+#[test]
+fn test_set_protocol_fee_receivers() {
+    let mut protocol_fee_distribution = ProtocolFeeDistribution::default();
+    protocol_fee_distribution
+        .initialize(Pubkey::new_unique(), &get_receivers(&[7_000, 3_000]))
+        .unwrap();
+    assert_eq!(protocol_fee_distribution.get_receivers().len(), 2);
+
+    // weights must sum to 100%
+    assert!(protocol_fee_distribution
+        .set_receivers(&get_receivers(&[7_000, 2_000]))
+        .is_err());
+    assert!(protocol_fee_distribution
+        .set_receivers(&get_receivers(&[10_000, 0]))
+        .is_err());
+    assert!(protocol_fee_distribution.set_receivers(&[]).is_err());
+    assert!(protocol_fee_distribution
+        .set_receivers(&get_receivers(&[1_000; MAX_PROTOCOL_FEE_RECEIVERS + 1]))
+        .is_err());
+
+    // fewer receivers clear the previous ones
+    let receivers = get_receivers(&[10_000]);
+    protocol_fee_distribution.set_receivers(&receivers).unwrap();
+    assert_eq!(protocol_fee_distribution.get_receivers().len(), 1);
+    assert_eq!(
+        protocol_fee_distribution.get_receivers()[0].wallet,
+        receivers[0].wallet
+    );
+    assert_eq!(
+        protocol_fee_distribution.receivers[1].wallet,
+        Pubkey::default()
+    );
+}
+
+#[test]
+fn test_get_receiver_amounts() {
+    let mut protocol_fee_distribution = ProtocolFeeDistribution::default();
+    protocol_fee_distribution
+        .initialize(Pubkey::new_unique(), &get_receivers(&[3_333, 3_333, 3_334]))
+        .unwrap();
+
+    let receiver_amounts = protocol_fee_distribution.get_receiver_amounts(100).unwrap();
+    // rounding down, the last receiver gets the remainder
+    assert_eq!(receiver_amounts, vec![33, 33, 34]);
+
+    let receiver_amounts = protocol_fee_distribution.get_receiver_amounts(1).unwrap();
+    assert_eq!(receiver_amounts, vec![0, 0, 1]);
+
+    let receiver_amounts = protocol_fee_distribution
+        .get_receiver_amounts(u64::MAX)
+        .unwrap();
+    assert_eq!(
+        receiver_amounts
+            .iter()
+            .map(|&a| u128::from(a))
+            .sum::<u128>(),
+        u128::from(u64::MAX)
+    );
+}
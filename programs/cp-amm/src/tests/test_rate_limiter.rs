@@ -179,6 +179,7 @@ fn calculate_output_amount(rate_limiter: &PodAlignedFeeRateLimiter, input_amount
             input_amount,
             0,
             0,
+            0,
         )
         .unwrap();
     let trading_fee: u64 = safe_mul_div_cast_u64(
@@ -246,6 +247,7 @@ fn test_rate_limiter_base_fee_numerator() {
                 2_000_000_000,
                 0,
                 0,
+                0,
             )
             .unwrap();
 
@@ -262,6 +264,7 @@ fn test_rate_limiter_base_fee_numerator() {
                 2_000_000_000,
                 0,
                 0,
+                0,
             )
             .unwrap();
 
@@ -278,6 +281,7 @@ fn test_rate_limiter_base_fee_numerator() {
                 2_000_000_000,
                 0,
                 0,
+                0,
             )
             .unwrap();
 
@@ -0,0 +1,114 @@
+use crate::{
+    constants::{fee::MAX_BASIS_POINT, MAX_REWARD_LIQUIDITY_BOOST_BPS},
+    state::{LayoutVersion, Pool, Position, RewardInfo},
+};
+
+const REWARD_DURATION: u64 = 1_000;
+const FUNDING_AMOUNT: u64 = 1_000_000;
+
+fn get_boosted_pool(
+    vested_liquidity_boost_bps: u16,
+    permanent_lock_liquidity_boost_bps: u16,
+) -> Pool {
+    let mut pool = Pool {
+        layout_version: LayoutVersion::V2.into(),
+        ..Default::default()
+    };
+    let reward_info = &mut pool.reward_infos[0];
+    reward_info.init_reward(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        REWARD_DURATION,
+        0,
+        vested_liquidity_boost_bps,
+        permanent_lock_liquidity_boost_bps,
+    );
+    reward_info
+        .update_rate_after_funding(0, FUNDING_AMOUNT)
+        .unwrap();
+    pool
+}
+
+#[test]
+fn test_get_reward_liquidity() {
+    let reward_info = RewardInfo {
+        vested_liquidity_boost_bps: MAX_BASIS_POINT,
+        permanent_lock_liquidity_boost_bps: MAX_REWARD_LIQUIDITY_BOOST_BPS,
+        ..Default::default()
+    };
+    assert_eq!(reward_info.get_reward_liquidity(100, 20, 30).unwrap(), 260);
+
+    // no boost by default
+    let reward_info = RewardInfo::default();
+    assert_eq!(reward_info.get_reward_liquidity(100, 20, 30).unwrap(), 100);
+}
+
+#[test]
+fn test_permanent_locked_position_earns_boosted_reward() {
+    let mut pool = get_boosted_pool(0, MAX_BASIS_POINT);
+    let liquidity = 1u128 << 64;
+
+    let mut unlocked_position = Position {
+        unlocked_liquidity: liquidity,
+        ..Default::default()
+    };
+    let mut permanent_locked_position = Position {
+        permanent_locked_liquidity: liquidity,
+        ..Default::default()
+    };
+    pool.liquidity = liquidity * 2;
+    pool.permanent_lock_liquidity = liquidity;
+
+    pool.update_rewards(REWARD_DURATION).unwrap();
+    unlocked_position.update_position_reward(&pool).unwrap();
+    permanent_locked_position
+        .update_position_reward(&pool)
+        .unwrap();
+
+    let unlocked_reward = unlocked_position.reward_infos[0].reward_pendings;
+    let permanent_locked_reward = permanent_locked_position.reward_infos[0].reward_pendings;
+
+    // rounding down
+    assert!(unlocked_reward + permanent_locked_reward <= FUNDING_AMOUNT);
+    assert!(unlocked_reward + permanent_locked_reward >= FUNDING_AMOUNT - 2);
+    assert!(permanent_locked_reward >= unlocked_reward * 2);
+}
+
+#[test]
+fn test_vested_liquidity_boost_follows_refreshed_vesting() {
+    let mut pool = get_boosted_pool(MAX_BASIS_POINT, 0);
+    let liquidity = 1u128 << 64;
+    pool.liquidity = liquidity;
+
+    let mut position = Position {
+        unlocked_liquidity: liquidity,
+        ..Default::default()
+    };
+    position.lock(liquidity).unwrap();
+    pool.update_vested_liquidity(0, liquidity).unwrap();
+    assert_eq!(pool.vested_liquidity, liquidity);
+    assert_eq!(pool.get_reward_liquidity_supply(0).unwrap(), liquidity * 2);
+
+    // no inner vesting schedule, nothing is released
+    pool.refresh_position_inner_vesting(&mut position, 0)
+        .unwrap();
+    assert_eq!(pool.vested_liquidity, liquidity);
+
+    pool.update_vested_liquidity(liquidity, 0).unwrap();
+    assert_eq!(pool.vested_liquidity, 0);
+    assert_eq!(pool.get_reward_liquidity_supply(0).unwrap(), liquidity);
+}
+
+#[test]
+fn test_vested_liquidity_not_tracked_by_previous_layout() {
+    let mut pool = get_boosted_pool(MAX_BASIS_POINT, 0);
+    pool.layout_version = LayoutVersion::V1.into();
+    pool.liquidity = 100;
+    // previously partner pubkey
+    pool.vested_liquidity = 1;
+
+    pool.update_vested_liquidity(0, 50).unwrap();
+    assert_eq!(pool.vested_liquidity, 1);
+    assert_eq!(pool.get_reward_liquidity_supply(0).unwrap(), 100);
+}
@@ -0,0 +1,170 @@
+use crate::{
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    state::{get_extended_reward_index, PoolRewardExtension, PositionRewardExtension},
+};
+
+const REWARD_DURATION: u64 = 1_000;
+const FUNDING_AMOUNT: u64 = 1_000_000;
+
+fn get_funded_pool_reward_extension(extended_reward_index: usize) -> PoolRewardExtension {
+    let mut pool_reward_extension = PoolRewardExtension::default();
+    let reward_info = &mut pool_reward_extension.reward_infos[extended_reward_index];
+    reward_info.init_reward(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        REWARD_DURATION,
+        0,
+        0,
+        0,
+    );
+    reward_info
+        .update_rate_after_funding(0, FUNDING_AMOUNT)
+        .unwrap();
+    pool_reward_extension
+}
+
+#[test]
+fn test_get_extended_reward_index() {
+    assert_eq!(get_extended_reward_index(0).unwrap(), None);
+    assert_eq!(get_extended_reward_index(NUM_REWARDS - 1).unwrap(), None);
+    assert_eq!(get_extended_reward_index(NUM_REWARDS).unwrap(), Some(0));
+    assert_eq!(
+        get_extended_reward_index(NUM_REWARDS + NUM_EXTENDED_REWARDS - 1).unwrap(),
+        Some(NUM_EXTENDED_REWARDS - 1)
+    );
+    assert!(get_extended_reward_index(NUM_REWARDS + NUM_EXTENDED_REWARDS).is_err());
+}
+
+#[test]
+fn test_claim_extended_reward() {
+    let extended_reward_index = NUM_EXTENDED_REWARDS - 1;
+    let mut pool_reward_extension = get_funded_pool_reward_extension(extended_reward_index);
+    let mut first_position_reward_extension = PositionRewardExtension::default();
+    let mut second_position_reward_extension = PositionRewardExtension::default();
+
+    let first_liquidity = 1u128 << 64;
+    let second_liquidity = 3u128 << 64;
+    let liquidity_supply = first_liquidity + second_liquidity;
+
+    pool_reward_extension
+        .update_rewards(liquidity_supply, REWARD_DURATION)
+        .unwrap();
+    first_position_reward_extension
+        .update_position_reward(first_liquidity, &pool_reward_extension)
+        .unwrap();
+    second_position_reward_extension
+        .update_position_reward(second_liquidity, &pool_reward_extension)
+        .unwrap();
+
+    let first_reward = first_position_reward_extension
+        .claim_reward(extended_reward_index)
+        .unwrap();
+    let second_reward = second_position_reward_extension
+        .claim_reward(extended_reward_index)
+        .unwrap();
+
+    // rounding down
+    assert!(first_reward + second_reward <= FUNDING_AMOUNT);
+    assert!(first_reward + second_reward >= FUNDING_AMOUNT - 2);
+    assert!(second_reward >= first_reward * 3);
+
+    assert!(first_position_reward_extension.is_empty());
+    assert_eq!(
+        first_position_reward_extension.reward_infos[extended_reward_index].total_claimed_rewards,
+        first_reward
+    );
+
+    // nothing left after the reward duration end
+    pool_reward_extension
+        .update_rewards(liquidity_supply, REWARD_DURATION * 2)
+        .unwrap();
+    first_position_reward_extension
+        .update_position_reward(first_liquidity, &pool_reward_extension)
+        .unwrap();
+    assert_eq!(
+        first_position_reward_extension
+            .claim_reward(extended_reward_index)
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_merge_position_reward_extension() {
+    let extended_reward_index = 0;
+    let mut pool_reward_extension = get_funded_pool_reward_extension(extended_reward_index);
+    let mut position_reward_extension = PositionRewardExtension::default();
+    let mut source_position_reward_extension = PositionRewardExtension::default();
+
+    let liquidity = 1u128 << 64;
+    pool_reward_extension
+        .update_rewards(liquidity * 2, REWARD_DURATION / 2)
+        .unwrap();
+    position_reward_extension
+        .update_position_reward(liquidity, &pool_reward_extension)
+        .unwrap();
+    source_position_reward_extension
+        .update_position_reward(liquidity, &pool_reward_extension)
+        .unwrap();
+
+    let reward_pendings =
+        position_reward_extension.reward_infos[extended_reward_index].reward_pendings;
+    let source_reward_pendings =
+        source_position_reward_extension.reward_infos[extended_reward_index].reward_pendings;
+    assert!(source_reward_pendings > 0);
+    assert!(!source_position_reward_extension.is_empty());
+
+    position_reward_extension
+        .merge(&mut source_position_reward_extension)
+        .unwrap();
+
+    assert!(source_position_reward_extension.is_empty());
+    assert_eq!(
+        position_reward_extension.reward_infos[extended_reward_index].reward_pendings,
+        reward_pendings + source_reward_pendings
+    );
+}
+
+#[test]
+fn test_split_position_reward_extension() {
+    let extended_reward_index = 1;
+    let mut pool_reward_extension = get_funded_pool_reward_extension(extended_reward_index);
+    let mut first_position_reward_extension = PositionRewardExtension::default();
+    let mut second_position_reward_extension = PositionRewardExtension::default();
+
+    let liquidity = 4u128 << 64;
+    pool_reward_extension
+        .update_rewards(liquidity, REWARD_DURATION / 2)
+        .unwrap();
+    first_position_reward_extension
+        .update_position_reward(liquidity, &pool_reward_extension)
+        .unwrap();
+    let reward_pendings =
+        first_position_reward_extension.reward_infos[extended_reward_index].reward_pendings;
+    assert!(reward_pendings > 0);
+
+    // no liquidity moved, nothing split
+    first_position_reward_extension
+        .split(&mut second_position_reward_extension, 0, liquidity)
+        .unwrap();
+    assert!(second_position_reward_extension.is_empty());
+
+    first_position_reward_extension
+        .split(
+            &mut second_position_reward_extension,
+            liquidity / 4,
+            liquidity,
+        )
+        .unwrap();
+
+    let first_reward_pendings =
+        first_position_reward_extension.reward_infos[extended_reward_index].reward_pendings;
+    let second_reward_pendings =
+        second_position_reward_extension.reward_infos[extended_reward_index].reward_pendings;
+    assert_eq!(second_reward_pendings, reward_pendings / 4);
+    assert_eq!(
+        first_reward_pendings + second_reward_pendings,
+        reward_pendings
+    );
+}
@@ -0,0 +1,265 @@
+use crate::{
+    base_fee::fee_time_scheduler::PodAlignedFeeTimeScheduler,
+    constants::{MAX_SQRT_PRICE, MIN_SQRT_PRICE},
+    get_initial_pool_information,
+    params::swap::TradeDirection,
+    safe_math::SafeCast,
+    state::{
+        fee::{BaseFeeStruct, FeeMode, PoolFeesStruct},
+        CollectFeeMode, Pool,
+    },
+    tests::{test_liquidity_compounding::get_sqrt_price_and_liquidity_from_amounts, LIQUIDITY_MAX},
+    ConcentratedLiquidity, InitialPoolInformation, SwapMode, SwapParameters2,
+};
+use proptest::prelude::*;
+
+fn get_concentrated_pool(sqrt_price: u128, liquidity: u128) -> Pool {
+    let InitialPoolInformation {
+        token_a_amount,
+        token_b_amount,
+        sqrt_price: _,
+        initial_liquidity: _,
+        sqrt_min_price,
+        sqrt_max_price,
+    } = ConcentratedLiquidity::get_initial_pool_information(
+        MIN_SQRT_PRICE,
+        MAX_SQRT_PRICE,
+        sqrt_price,
+        liquidity,
+    )
+    .unwrap();
+
+    Pool {
+        liquidity,
+        sqrt_max_price,
+        sqrt_min_price,
+        sqrt_price,
+        token_a_amount,
+        token_b_amount,
+        ..Default::default()
+    }
+}
+
+fn get_compounding_pool(a: u64, b: u64) -> Pool {
+    let (sqrt_price, liquidity) = get_sqrt_price_and_liquidity_from_amounts(a, b).unwrap();
+
+    let InitialPoolInformation {
+        token_a_amount,
+        token_b_amount,
+        sqrt_price,
+        initial_liquidity: _,
+        sqrt_min_price,
+        sqrt_max_price,
+    } = get_initial_pool_information(CollectFeeMode::Compounding, 0, 0, sqrt_price, liquidity, 0)
+        .unwrap();
+
+    let fee_scheduler = PodAlignedFeeTimeScheduler {
+        cliff_fee_numerator: 100_000_000, // 10%
+        ..Default::default()
+    };
+    let mut base_fee = BaseFeeStruct::default();
+    base_fee
+        .base_fee_info
+        .data
+        .copy_from_slice(bytemuck::bytes_of(&fee_scheduler));
+
+    // the compounding fee is added to the token b reserve after the swap, so it must be accounted for the price limit
+    let pool_fees = PoolFeesStruct {
+        base_fee,
+        protocol_fee_percent: 20,
+        compounding_fee_bps: 5000, // 50%
+        ..Default::default()
+    };
+
+    Pool {
+        collect_fee_mode: CollectFeeMode::Compounding.into(),
+        pool_fees,
+        token_a_amount,
+        token_b_amount,
+        liquidity,
+        sqrt_price,
+        sqrt_min_price,
+        sqrt_max_price,
+        ..Default::default()
+    }
+}
+
+fn swap_with_price_limit(
+    pool: &mut Pool,
+    amount_in: u64,
+    trade_direction: TradeDirection,
+    sqrt_price_limit: u128,
+) {
+    let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast().unwrap();
+    let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+    let swap_result = pool
+        .get_swap_result_from_partial_input(
+            amount_in,
+            &fee_mode,
+            trade_direction,
+            0,
+            Some(sqrt_price_limit),
+        )
+        .unwrap();
+
+    assert_eq!(
+        swap_result.included_fee_input_amount + swap_result.amount_left,
+        amount_in
+    );
+
+    pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, 0)
+        .unwrap();
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10000, .. ProptestConfig::default()
+    })]
+    #[test]
+    fn test_compounding_swap_wont_cross_price_limit(
+        a in 1_000_000..=1_000_000_000_000_000u64,
+        b in 1_000_000..=1_000_000_000_000_000u64,
+        amount_in in 1..=u64::MAX / 2,
+        limit_bps in 1..10_000u128,
+        a_to_b: bool,
+    ) {
+        let mut pool = get_compounding_pool(a, b);
+
+        let (trade_direction, sqrt_price_limit) = if a_to_b {
+            (TradeDirection::AtoB, pool.sqrt_price * (10_000 - limit_bps) / 10_000)
+        } else {
+            (TradeDirection::BtoA, pool.sqrt_price * (10_000 + limit_bps) / 10_000)
+        };
+        prop_assume!(sqrt_price_limit != pool.sqrt_price);
+
+        swap_with_price_limit(&mut pool, amount_in, trade_direction, sqrt_price_limit);
+
+        match trade_direction {
+            TradeDirection::AtoB => assert!(pool.sqrt_price >= sqrt_price_limit),
+            TradeDirection::BtoA => assert!(pool.sqrt_price <= sqrt_price_limit),
+        }
+    }
+}
+
+#[test]
+fn test_concentrated_swap_stop_at_price_limit() {
+    let sqrt_price = 1u128 << 64;
+    let liquidity = LIQUIDITY_MAX;
+
+    let sqrt_price_limit = sqrt_price / 2;
+    let mut pool = get_concentrated_pool(sqrt_price, liquidity);
+    swap_with_price_limit(&mut pool, u64::MAX, TradeDirection::AtoB, sqrt_price_limit);
+    assert_eq!(pool.sqrt_price, sqrt_price_limit);
+
+    let sqrt_price_limit = sqrt_price * 2;
+    let mut pool = get_concentrated_pool(sqrt_price, liquidity);
+    swap_with_price_limit(&mut pool, u64::MAX, TradeDirection::BtoA, sqrt_price_limit);
+    assert_eq!(pool.sqrt_price, sqrt_price_limit);
+}
+
+#[test]
+fn test_concentrated_swap_not_reach_price_limit() {
+    let sqrt_price = 1u128 << 64;
+    let mut pool = get_concentrated_pool(sqrt_price, LIQUIDITY_MAX);
+
+    let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast().unwrap();
+    let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, TradeDirection::AtoB, false);
+
+    let swap_result = pool
+        .get_swap_result_from_partial_input(
+            1_000_000,
+            &fee_mode,
+            TradeDirection::AtoB,
+            0,
+            Some(pool.sqrt_min_price),
+        )
+        .unwrap();
+    let exact_in_swap_result = pool
+        .get_swap_result_from_exact_input(1_000_000, &fee_mode, TradeDirection::AtoB, 0)
+        .unwrap();
+    assert_eq!(swap_result.amount_left, 0);
+    assert_eq!(
+        swap_result.output_amount,
+        exact_in_swap_result.output_amount
+    );
+
+    pool.apply_swap_result(&swap_result, &fee_mode, TradeDirection::AtoB, 0)
+        .unwrap();
+    assert!(pool.sqrt_price < sqrt_price);
+}
+
+#[test]
+fn test_swap_with_invalid_price_limit() {
+    let sqrt_price = 1u128 << 64;
+    let pool = get_concentrated_pool(sqrt_price, LIQUIDITY_MAX);
+
+    let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast().unwrap();
+
+    let invalid_limits = [
+        (TradeDirection::AtoB, sqrt_price),
+        (TradeDirection::AtoB, sqrt_price + 1),
+        (TradeDirection::AtoB, pool.sqrt_min_price - 1),
+        (TradeDirection::BtoA, sqrt_price),
+        (TradeDirection::BtoA, sqrt_price - 1),
+        (TradeDirection::BtoA, pool.sqrt_max_price + 1),
+    ];
+
+    for (trade_direction, sqrt_price_limit) in invalid_limits {
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+        assert!(pool
+            .get_swap_result_from_partial_input(
+                1_000_000,
+                &fee_mode,
+                trade_direction,
+                0,
+                Some(sqrt_price_limit),
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn test_swap_with_zero_price_limit() {
+    let pool = get_compounding_pool(1_000_000_000, 1_000_000_000);
+
+    let collect_fee_mode: CollectFeeMode = pool.collect_fee_mode.safe_cast().unwrap();
+
+    // zero is rejected in both directions, no limit is expressed with none
+    let invalid_limits = [(TradeDirection::AtoB, 0), (TradeDirection::BtoA, 0)];
+
+    for (trade_direction, sqrt_price_limit) in invalid_limits {
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+        assert!(pool
+            .get_swap_result_from_partial_input(
+                1_000_000,
+                &fee_mode,
+                trade_direction,
+                0,
+                Some(sqrt_price_limit),
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn test_deserialize_swap_parameters2_without_price_limit() {
+    let params = SwapParameters2 {
+        amount_0: 100,
+        amount_1: 50,
+        swap_mode: SwapMode::PartialFillWithPriceLimit.into(),
+        sqrt_price_limit: 1u128 << 64,
+    };
+    let data = borsh::to_vec(&params).unwrap();
+
+    let decoded = SwapParameters2::deserialize_with_optional_price_limit(&mut &data[..]).unwrap();
+    assert_eq!(decoded.sqrt_price_limit, params.sqrt_price_limit);
+
+    // payload from clients built before sqrt_price_limit was added
+    let legacy_data = &data[..17];
+    let decoded =
+        SwapParameters2::deserialize_with_optional_price_limit(&mut &legacy_data[..]).unwrap();
+    assert_eq!(decoded.amount_0, 100);
+    assert_eq!(decoded.amount_1, 50);
+    assert_eq!(decoded.sqrt_price_limit, 0);
+}
@@ -0,0 +1,219 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    const_pda,
+    constants::MAX_PENDING_OPERATOR_ACTION_AGE,
+    get_timelock_delay,
+    params::fee_parameters::{DynamicFeeParameters, PoolFeeParameters},
+    require_no_timelock,
+    state::{
+        is_timelocked_permission, OperatorAction, OperatorPermission, PendingOperatorAction,
+        TimelockConfig, MAX_OPERATOR_ACTION_SIZE,
+    },
+    StableSwapConfigParameters, StaticConfigParameters, UpdatePoolFeesParameters,
+};
+
+fn get_timelock_config_data(timelock_config: &TimelockConfig) -> Vec<u8> {
+    let mut data = TimelockConfig::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(timelock_config));
+    data
+}
+
+#[test]
+fn test_largest_operator_action_fits_pending_operator_action() {
+    let action = OperatorAction::UpdatePoolFees {
+        params: UpdatePoolFeesParameters {
+            cliff_fee_numerator: Some(u64::MAX),
+            dynamic_fee: Some(DynamicFeeParameters::default()),
+            compounding_fee_bps: Some(u16::MAX),
+        },
+    };
+    assert!(borsh::to_vec(&action).unwrap().len() <= MAX_OPERATOR_ACTION_SIZE);
+
+    let pool = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let mut pending_operator_action: PendingOperatorAction = bytemuck::Zeroable::zeroed();
+    pending_operator_action
+        .initialize(pool, proposer, 1_000, &action)
+        .unwrap();
+
+    assert_eq!(
+        pending_operator_action.permission,
+        u8::from(OperatorPermission::UpdatePoolFees)
+    );
+    assert!(!pending_operator_action.is_executable(999));
+    assert!(pending_operator_action.is_executable(1_000));
+    assert!(!pending_operator_action.is_expired(1_000 + MAX_PENDING_OPERATOR_ACTION_AGE));
+    assert!(pending_operator_action.is_expired(1_000 + MAX_PENDING_OPERATOR_ACTION_AGE + 1));
+
+    let OperatorAction::UpdatePoolFees { params } = pending_operator_action.get_action().unwrap()
+    else {
+        panic!("unexpected operator action");
+    };
+    assert_eq!(params.cliff_fee_numerator, Some(u64::MAX));
+    assert_eq!(params.dynamic_fee, Some(DynamicFeeParameters::default()));
+    assert_eq!(params.compounding_fee_bps, Some(u16::MAX));
+}
+
+#[test]
+fn test_get_timelock_delay() {
+    let mut timelock_config = TimelockConfig::default();
+    timelock_config.set_delay(OperatorPermission::SetPoolStatus, 3_600);
+
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = get_timelock_config_data(&timelock_config);
+    let account = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &crate::ID,
+        false,
+        0,
+    );
+    assert_eq!(
+        get_timelock_delay(&account, OperatorPermission::SetPoolStatus).unwrap(),
+        3_600
+    );
+    assert_eq!(
+        get_timelock_delay(&account, OperatorPermission::UpdatePoolFees).unwrap(),
+        0
+    );
+
+    // uninitialized timelock config has no delay
+    let mut lamports = 0;
+    let mut data = vec![];
+    let system_program = anchor_lang::system_program::ID;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &system_program,
+        false,
+        0,
+    );
+    assert_eq!(
+        get_timelock_delay(&account, OperatorPermission::SetPoolStatus).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_require_no_timelock() {
+    let mut timelock_config = TimelockConfig::default();
+    timelock_config.set_delay(OperatorPermission::SetPoolStatus, 3_600);
+
+    let key = const_pda::timelock_config::ID;
+    let mut lamports = 0;
+    let mut data = get_timelock_config_data(&timelock_config);
+    let account = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &crate::ID,
+        false,
+        0,
+    );
+    let other_key = Pubkey::new_unique();
+    let mut other_lamports = 0;
+    let mut other_data = get_timelock_config_data(&timelock_config);
+    let other_account = AccountInfo::new(
+        &other_key,
+        false,
+        false,
+        &mut other_lamports,
+        &mut other_data,
+        &crate::ID,
+        false,
+        0,
+    );
+
+    let remaining_accounts = [other_account.clone(), account];
+    assert!(require_no_timelock(&remaining_accounts, OperatorPermission::SetPoolStatus).is_err());
+    assert!(require_no_timelock(&remaining_accounts, OperatorPermission::UpdatePoolFees).is_ok());
+
+    // clients built before the timelock don't pass the timelock config
+    assert!(require_no_timelock(&[], OperatorPermission::SetPoolStatus).is_ok());
+    // only the timelock config address is read
+    assert!(require_no_timelock(&[other_account], OperatorPermission::SetPoolStatus).is_ok());
+}
+
+#[test]
+fn test_timelocked_permissions() {
+    assert!(is_timelocked_permission(OperatorPermission::SetPoolStatus));
+    assert!(is_timelocked_permission(
+        OperatorPermission::UpdateRewardFunder
+    ));
+    assert!(is_timelocked_permission(OperatorPermission::UpdatePoolFees));
+    assert!(is_timelocked_permission(
+        OperatorPermission::CreateConfigKey
+    ));
+    assert!(!is_timelocked_permission(
+        OperatorPermission::ClaimProtocolFee
+    ));
+}
+
+#[test]
+fn test_create_config_action_fits_pending_operator_action() {
+    let action = OperatorAction::CreateConfig {
+        index: u64::MAX,
+        config_parameters: StaticConfigParameters {
+            pool_fees: PoolFeeParameters {
+                dynamic_fee: Some(DynamicFeeParameters::default()),
+                ..Default::default()
+            },
+            sqrt_min_price: 0,
+            sqrt_max_price: u128::MAX,
+            vault_config_key: Pubkey::new_unique(),
+            pool_creator_authority: Pubkey::new_unique(),
+            activation_type: 0,
+            collect_fee_mode: 0,
+        },
+    };
+    assert!(borsh::to_vec(&action).unwrap().len() <= MAX_OPERATOR_ACTION_SIZE);
+    assert_eq!(action.get_permission(), OperatorPermission::CreateConfigKey);
+    assert_eq!(action.get_config_index(), Some(u64::MAX));
+
+    let mut pending_operator_action: PendingOperatorAction = bytemuck::Zeroable::zeroed();
+    pending_operator_action
+        .initialize(Pubkey::new_unique(), Pubkey::new_unique(), 0, &action)
+        .unwrap();
+    let OperatorAction::CreateConfig {
+        index,
+        config_parameters,
+    } = pending_operator_action.get_action().unwrap()
+    else {
+        panic!("unexpected operator action");
+    };
+    assert_eq!(index, u64::MAX);
+    assert_eq!(config_parameters.sqrt_max_price, u128::MAX);
+
+    let action = OperatorAction::SetPoolStatus { status: 1 };
+    assert_eq!(action.get_config_index(), None);
+}
+
+#[test]
+fn test_create_stable_swap_config_action_fits_pending_operator_action() {
+    let action = OperatorAction::CreateStableSwapConfig {
+        index: 1,
+        config_parameters: StableSwapConfigParameters {
+            pool_fees: PoolFeeParameters {
+                dynamic_fee: Some(DynamicFeeParameters::default()),
+                ..Default::default()
+            },
+            vault_config_key: Pubkey::new_unique(),
+            pool_creator_authority: Pubkey::new_unique(),
+            activation_type: 0,
+            amplification_coefficient: 100,
+        },
+    };
+    assert!(borsh::to_vec(&action).unwrap().len() <= MAX_OPERATOR_ACTION_SIZE);
+    assert_eq!(action.get_permission(), OperatorPermission::CreateConfigKey);
+    assert_eq!(action.get_config_index(), Some(1));
+}
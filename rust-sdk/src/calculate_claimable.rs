@@ -0,0 +1,143 @@
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+};
+use anyhow::{Error, Result};
+use cp_amm::{
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    state::{Pool, PoolRewardExtension, Position, PositionRewardExtension},
+};
+
+/// Rewards stored in the pool followed by the extended rewards, indexed by reward index
+pub const MAX_REWARDS: usize = NUM_REWARDS + NUM_EXTENDED_REWARDS;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClaimableAmounts {
+    pub fee_a: u64,
+    pub fee_b: u64,
+    pub rewards: [u64; MAX_REWARDS],
+}
+
+/// Mint account data (including Token-2022 extensions) used to project the amounts received by the owner.
+/// Reward mint is `None` when the reward is not initialized.
+pub struct ClaimableMints<'a> {
+    pub token_a_mint: &'a [u8],
+    pub token_b_mint: &'a [u8],
+    pub reward_mints: [Option<&'a [u8]>; MAX_REWARDS],
+}
+
+/// Pending fee a and fee b of the position, same as `claim_position_fee` would transfer
+pub fn get_claimable_fee(pool: &Pool, position: &Position) -> Result<(u64, u64)> {
+    let mut position = *position;
+    position.update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())?;
+
+    Ok((position.fee_a_pending, position.fee_b_pending))
+}
+
+/// Pending rewards of the position per reward index, same as `claim_reward` would transfer at `current_timestamp`.
+/// `reward_extensions` are the pool and position reward extensions, required once an extended reward is initialized
+pub fn get_claimable_rewards(
+    pool: &Pool,
+    position: &Position,
+    reward_extensions: Option<(&PoolRewardExtension, &PositionRewardExtension)>,
+    current_timestamp: u64,
+) -> Result<[u64; MAX_REWARDS]> {
+    let mut pool = *pool;
+    let mut position = *position;
+
+    if pool.pool_reward_initialized() {
+        pool.update_rewards(current_timestamp)?;
+        position.update_position_reward(&pool)?;
+    }
+
+    let mut rewards = [0u64; MAX_REWARDS];
+    for (reward, reward_info) in rewards.iter_mut().zip(position.reward_infos.iter()) {
+        *reward = reward_info.reward_pendings;
+    }
+
+    match reward_extensions {
+        Some((pool_reward_extension, position_reward_extension)) => {
+            let mut pool_reward_extension = *pool_reward_extension;
+            let mut position_reward_extension = *position_reward_extension;
+            pool_reward_extension.update_rewards(pool.liquidity, current_timestamp)?;
+            position_reward_extension
+                .update_position_reward(position.get_total_liquidity()?, &pool_reward_extension)?;
+
+            for (reward, reward_info) in rewards[NUM_REWARDS..]
+                .iter_mut()
+                .zip(position_reward_extension.reward_infos.iter())
+            {
+                *reward = reward_info.reward_pendings;
+            }
+        }
+        None if pool.is_extended_reward_initialized() => {
+            return Err(Error::msg("Missing reward extensions"));
+        }
+        None => {}
+    }
+
+    Ok(rewards)
+}
+
+pub fn get_claimable_amounts(
+    pool: &Pool,
+    position: &Position,
+    reward_extensions: Option<(&PoolRewardExtension, &PositionRewardExtension)>,
+    current_timestamp: u64,
+) -> Result<ClaimableAmounts> {
+    let (fee_a, fee_b) = get_claimable_fee(pool, position)?;
+    let rewards = get_claimable_rewards(pool, position, reward_extensions, current_timestamp)?;
+
+    Ok(ClaimableAmounts {
+        fee_a,
+        fee_b,
+        rewards,
+    })
+}
+
+impl ClaimableAmounts {
+    /// Amounts received by the owner after Token-2022 transfer fee at `epoch`
+    pub fn to_transfer_fee_excluded_amounts(
+        &self,
+        mints: &ClaimableMints,
+        epoch: u64,
+    ) -> Result<ClaimableAmounts> {
+        let mut rewards = [0u64; MAX_REWARDS];
+        for (i, reward) in rewards.iter_mut().enumerate() {
+            if self.rewards[i] == 0 {
+                continue;
+            }
+            let reward_mint =
+                mints.reward_mints[i].ok_or_else(|| Error::msg("Missing reward mint"))?;
+            *reward = calculate_transfer_fee_excluded_amount(reward_mint, epoch, self.rewards[i])?;
+        }
+
+        Ok(ClaimableAmounts {
+            fee_a: calculate_transfer_fee_excluded_amount(mints.token_a_mint, epoch, self.fee_a)?,
+            fee_b: calculate_transfer_fee_excluded_amount(mints.token_b_mint, epoch, self.fee_b)?,
+            rewards,
+        })
+    }
+}
+
+/// Same as `calculate_transfer_fee_excluded_amount` of the program, with epoch provided by the caller instead of `Clock`
+pub fn calculate_transfer_fee_excluded_amount(
+    token_mint_data: &[u8],
+    epoch: u64,
+    transfer_fee_included_amount: u64,
+) -> Result<u64> {
+    let token_mint_unpacked =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(token_mint_data)?;
+
+    if let Ok(transfer_fee_config) = token_mint_unpacked.get_extension::<TransferFeeConfig>() {
+        let transfer_fee = transfer_fee_config
+            .get_epoch_fee(epoch)
+            .calculate_fee(transfer_fee_included_amount)
+            .ok_or_else(|| Error::msg("Math overflow"))?;
+        return transfer_fee_included_amount
+            .checked_sub(transfer_fee)
+            .ok_or_else(|| Error::msg("Math overflow"));
+    }
+
+    Ok(transfer_fee_included_amount)
+}
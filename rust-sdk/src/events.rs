@@ -0,0 +1,99 @@
+use anchor_lang::{event::EVENT_IX_TAG_LE, prelude::Pubkey, AnchorDeserialize, Discriminator};
+use anyhow::{bail, Result};
+
+macro_rules! cp_amm_events {
+    ($($event:ident),* $(,)?) => {
+        #[allow(deprecated)]
+        pub enum CpAmmEvent {
+            $($event(cp_amm::$event),)*
+        }
+
+        #[allow(deprecated)]
+        fn decode_event_data(data: &[u8]) -> Result<CpAmmEvent> {
+            $(
+                if let Some(mut event_data) = data.strip_prefix(cp_amm::$event::DISCRIMINATOR) {
+                    let event = cp_amm::$event::deserialize(&mut event_data)?;
+                    return Ok(CpAmmEvent::$event(event));
+                }
+            )*
+
+            bail!("Unknown event discriminator")
+        }
+    };
+}
+
+cp_amm_events!(
+    EvtCloseConfig,
+    EvtCreateConfig,
+    EvtCreateDynamicConfig,
+    EvtCreateStableSwapConfig,
+    EvtCreateTokenBadge,
+    EvtInitializePool,
+    EvtClaimPositionFee,
+    EvtCreatePosition,
+    EvtClosePosition,
+    EvtSwap2,
+    EvtLockPosition,
+    EvtPermanentLockPosition,
+    EvtClaimProtocolFee,
+    EvtClaimProtocolFee2,
+    EvtSetPoolStatus,
+    EvtInitializeReward,
+    EvtFundReward,
+    EvtClaimReward,
+    EvtUpdateRewardDuration,
+    EvtUpdateRewardFunder,
+    EvtWithdrawIneligibleReward,
+    EvtSplitPosition2,
+    EvtSplitPosition3,
+    EvtMergePosition,
+    EvtLiquidityChange,
+    EvtUpdatePoolFees,
+    EvtInitializeOracle,
+    EvtAddLiquiditySingleSided,
+    EvtRemoveLiquiditySingleToken,
+    EvtCompoundPositionFee,
+    EvtFlashBorrow,
+    EvtFlashRepay,
+    EvtSetPositionDelegate,
+    EvtRevokePositionDelegate,
+    EvtInitializePoolRewardExtension,
+    EvtInitializePositionRewardExtension,
+    EvtAddAllowlistEntry,
+    EvtRemoveAllowlistEntry,
+    EvtSetTimelockDelay,
+    EvtProposeOperatorAction,
+    EvtExecuteOperatorAction,
+    EvtCancelOperatorAction,
+    EvtSetProtocolFeeDistribution,
+    EvtCloseProtocolFeeDistribution,
+    EvtDistributeProtocolFee,
+    EvtClaimCreatorFee,
+);
+
+/// Decode the data of a self CPI instruction emitted by `emit_cpi!`, prefixed by `EVENT_IX_TAG_LE`
+pub fn decode_event(instruction_data: &[u8]) -> Result<CpAmmEvent> {
+    let Some(data) = instruction_data.strip_prefix(EVENT_IX_TAG_LE) else {
+        bail!("Not an event instruction");
+    };
+
+    decode_event_data(data)
+}
+
+/// Returns true if the inner instruction is an event emitted by cp-amm
+pub fn is_event_instruction(program_id: &Pubkey, instruction_data: &[u8]) -> bool {
+    program_id.eq(&cp_amm::ID) && instruction_data.starts_with(EVENT_IX_TAG_LE)
+}
+
+/// Decode cp-amm events from the inner instructions of a confirmed transaction, as `(program_id, data)` in execution order.
+/// Inner instructions of other programs and non-event cp-amm instructions are skipped.
+pub fn decode_events<'a, I>(inner_instructions: I) -> impl Iterator<Item = Result<CpAmmEvent>> + 'a
+where
+    I: IntoIterator<Item = (&'a Pubkey, &'a [u8])>,
+    I::IntoIter: 'a,
+{
+    inner_instructions
+        .into_iter()
+        .filter(|(program_id, instruction_data)| is_event_instruction(program_id, instruction_data))
+        .map(|(_, instruction_data)| decode_event(instruction_data))
+}
@@ -0,0 +1,215 @@
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction};
+use cp_amm::{
+    state::{OperatorPermission, ProtocolFeeReceiverParameters},
+    OperatorUsageLimitParameters, UpdateOperatorParameters,
+};
+
+use super::build_instruction;
+use crate::pda::{
+    derive_event_authority, derive_operator, derive_operator_usage_limit,
+    derive_protocol_fee_distribution, derive_timelock_config,
+};
+
+pub fn create_operator_account(
+    whitelisted_address: Pubkey,
+    signer: Pubkey,
+    payer: Pubkey,
+    permission: u128,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CreateOperatorAccountCtx {
+            operator: derive_operator(&whitelisted_address),
+            whitelisted_address,
+            signer,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CreateOperatorAccount { permission },
+        vec![],
+    )
+}
+
+pub fn close_operator_account(
+    whitelisted_address: Pubkey,
+    signer: Pubkey,
+    rent_receiver: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CloseOperatorAccountCtx {
+            operator: derive_operator(&whitelisted_address),
+            signer,
+            rent_receiver,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CloseOperatorAccount {},
+        vec![],
+    )
+}
+
+pub fn initialize_timelock_config(signer: Pubkey, payer: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::InitializeTimelockConfigCtx {
+            timelock_config: derive_timelock_config(),
+            signer,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+        },
+        cp_amm::instruction::InitializeTimelockConfig {},
+        vec![],
+    )
+}
+
+/// Only `SetPoolStatus`, `UpdateRewardFunder` and `UpdatePoolFees` can be timelocked, a zero delay disables the timelock
+pub fn set_timelock_delay(
+    signer: Pubkey,
+    permission: OperatorPermission,
+    delay: u64,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::SetTimelockDelayCtx {
+            timelock_config: derive_timelock_config(),
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::SetTimelockDelay {
+            permission: permission.into(),
+            delay,
+        },
+        vec![],
+    )
+}
+
+/// `expiry_timestamp` 0 means the permissions don't expire
+pub fn update_operator_account(
+    whitelisted_address: Pubkey,
+    signer: Pubkey,
+    permission: u128,
+    expiry_timestamp: u64,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::UpdateOperatorAccountCtx {
+            operator: derive_operator(&whitelisted_address),
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::UpdateOperatorAccount {
+            params: UpdateOperatorParameters {
+                permission,
+                expiry_timestamp,
+            },
+        },
+        vec![],
+    )
+}
+
+pub fn initialize_operator_usage_limit(
+    whitelisted_address: Pubkey,
+    signer: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    let operator = derive_operator(&whitelisted_address);
+    build_instruction(
+        cp_amm::accounts::InitializeOperatorUsageLimitCtx {
+            operator_usage_limit: derive_operator_usage_limit(&operator),
+            operator,
+            signer,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+        },
+        cp_amm::instruction::InitializeOperatorUsageLimit {},
+        vec![],
+    )
+}
+
+/// Only `ClaimProtocolFee` and `ZapProtocolFee` can be usage limited, the cap is removed when both
+/// `max_usage` and `max_amount` are 0. `max_amount` must be 0 for `ClaimProtocolFee`
+pub fn set_operator_usage_limit(
+    whitelisted_address: Pubkey,
+    signer: Pubkey,
+    permission: OperatorPermission,
+    window_duration: u64,
+    max_usage: u64,
+    max_amount: u64,
+) -> Instruction {
+    let operator = derive_operator(&whitelisted_address);
+    build_instruction(
+        cp_amm::accounts::SetOperatorUsageLimitCtx {
+            operator_usage_limit: derive_operator_usage_limit(&operator),
+            operator,
+            signer,
+        },
+        cp_amm::instruction::SetOperatorUsageLimit {
+            params: OperatorUsageLimitParameters {
+                permission: permission.into(),
+                window_duration,
+                max_usage,
+                max_amount,
+            },
+        },
+        vec![],
+    )
+}
+
+/// Receiver weights must sum to 10000 bps. The protocol fee of the pool can only be claimed by
+/// `distribute_protocol_fee` afterward
+pub fn initialize_protocol_fee_distribution(
+    pool: Pubkey,
+    signer: Pubkey,
+    payer: Pubkey,
+    receivers: Vec<ProtocolFeeReceiverParameters>,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::InitializeProtocolFeeDistributionCtx {
+            protocol_fee_distribution: derive_protocol_fee_distribution(&pool),
+            pool,
+            signer,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializeProtocolFeeDistribution { receivers },
+        vec![],
+    )
+}
+
+pub fn update_protocol_fee_distribution(
+    pool: Pubkey,
+    signer: Pubkey,
+    receivers: Vec<ProtocolFeeReceiverParameters>,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::UpdateProtocolFeeDistributionCtx {
+            protocol_fee_distribution: derive_protocol_fee_distribution(&pool),
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::UpdateProtocolFeeDistribution { receivers },
+        vec![],
+    )
+}
+
+pub fn close_protocol_fee_distribution(
+    pool: Pubkey,
+    signer: Pubkey,
+    rent_receiver: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CloseProtocolFeeDistributionCtx {
+            protocol_fee_distribution: derive_protocol_fee_distribution(&pool),
+            pool,
+            signer,
+            rent_receiver,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CloseProtocolFeeDistribution {},
+        vec![],
+    )
+}
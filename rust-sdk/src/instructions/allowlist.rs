@@ -0,0 +1,62 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+};
+
+use super::build_instruction;
+use crate::pda::{derive_allowlist_config, derive_allowlist_entry, derive_event_authority};
+
+/// `allowlist_authority` is the one set when the permissioned pool was initialized
+pub fn add_allowlist_entry(
+    pool_address: Pubkey,
+    wallet: Pubkey,
+    allowlist_authority: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::AddAllowlistEntryCtx {
+            pool: pool_address,
+            allowlist_config: derive_allowlist_config(&pool_address),
+            allowlist_entry: derive_allowlist_entry(&pool_address, &wallet),
+            wallet,
+            allowlist_authority,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::AddAllowlistEntry {},
+        vec![],
+    )
+}
+
+pub fn remove_allowlist_entry(
+    pool_address: Pubkey,
+    wallet: Pubkey,
+    allowlist_authority: Pubkey,
+    rent_receiver: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::RemoveAllowlistEntryCtx {
+            pool: pool_address,
+            allowlist_config: derive_allowlist_config(&pool_address),
+            allowlist_entry: derive_allowlist_entry(&pool_address, &wallet),
+            allowlist_authority,
+            rent_receiver,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::RemoveAllowlistEntry {},
+        vec![],
+    )
+}
+
+/// Append to the remaining accounts of swap, add liquidity and create position of a permissioned pool, `wallet` is the
+/// swap payer, the position owner or the flash loan borrower. For swaps with the rate limiter, the instructions sysvar
+/// stays the first remaining account
+pub fn get_allowlist_entry_remaining_account(
+    pool_address: &Pubkey,
+    wallet: &Pubkey,
+) -> AccountMeta {
+    AccountMeta::new_readonly(derive_allowlist_entry(pool_address, wallet), false)
+}
@@ -0,0 +1,81 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{instruction::Instruction, sysvar},
+};
+use anyhow::Result;
+use cp_amm::{
+    constants::fee::{FEE_DENOMINATOR, FLASH_LOAN_FEE_NUMERATOR},
+    state::Pool,
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u64,
+};
+
+use super::{build_instruction, get_token_programs};
+use crate::pda::{derive_event_authority, derive_pool_authority};
+
+pub struct FlashLoanAccounts {
+    pub pool: Pubkey,
+    pub borrower: Pubkey,
+    pub user_token_account: Pubkey,
+    /// Borrow token a or token b of the pool
+    pub is_token_a: bool,
+}
+
+/// Must be a top level instruction, followed by [flash_repay] in the same transaction
+pub fn flash_borrow(accounts: &FlashLoanAccounts, pool: &Pool, amount: u64) -> Result<Instruction> {
+    Ok(build_instruction(
+        get_flash_loan_accounts(accounts, pool)?,
+        cp_amm::instruction::FlashBorrow { amount },
+        vec![],
+    ))
+}
+
+/// `borrow_instruction_index` is the index of the paired [flash_borrow] in the transaction
+pub fn flash_repay(
+    accounts: &FlashLoanAccounts,
+    pool: &Pool,
+    borrow_instruction_index: u16,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        get_flash_loan_accounts(accounts, pool)?,
+        cp_amm::instruction::FlashRepay {
+            borrow_instruction_index,
+        },
+        vec![],
+    ))
+}
+
+/// Fee charged by flash_repay on top of the borrowed amount, excluding Token-2022 transfer fee
+pub fn get_flash_loan_fee(amount: u64) -> Result<u64> {
+    Ok(safe_mul_div_cast_u64(
+        amount,
+        FLASH_LOAN_FEE_NUMERATOR,
+        FEE_DENOMINATOR,
+        Rounding::Up,
+    )?)
+}
+
+fn get_flash_loan_accounts(
+    accounts: &FlashLoanAccounts,
+    pool: &Pool,
+) -> Result<cp_amm::accounts::FlashLoanCtx> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+    let (token_vault, token_mint, token_program) = if accounts.is_token_a {
+        (pool.token_a_vault, pool.token_a_mint, token_a_program)
+    } else {
+        (pool.token_b_vault, pool.token_b_mint, token_b_program)
+    };
+
+    Ok(cp_amm::accounts::FlashLoanCtx {
+        pool_authority: derive_pool_authority(),
+        pool: accounts.pool,
+        token_vault,
+        user_token_account: accounts.user_token_account,
+        token_mint,
+        borrower: accounts.borrower,
+        token_program,
+        sysvar_instructions: sysvar::instructions::ID,
+        event_authority: derive_event_authority(),
+        program: cp_amm::ID,
+    })
+}
@@ -0,0 +1,175 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+};
+use cp_amm::{InitializeCustomizablePoolParameters, InitializePoolParameters};
+
+use super::build_instruction;
+use crate::pda::{
+    derive_allowlist_config, derive_customizable_pool, derive_event_authority,
+    derive_permissioned_pool, derive_pool, derive_pool_authority, derive_position,
+    derive_position_nft_account, derive_token_badge, derive_token_vault,
+};
+
+/// `position_nft_mint` is a new keypair account and must sign the transaction
+pub struct InitializePoolAccounts {
+    pub creator: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub payer: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub payer_token_a: Pubkey,
+    pub payer_token_b: Pubkey,
+    pub token_a_program: Pubkey,
+    pub token_b_program: Pubkey,
+}
+
+pub fn initialize_pool(
+    accounts: &InitializePoolAccounts,
+    config: Pubkey,
+    params: InitializePoolParameters,
+) -> Instruction {
+    let pool = derive_pool(&config, &accounts.token_a_mint, &accounts.token_b_mint);
+
+    build_instruction(
+        cp_amm::accounts::InitializePoolCtx {
+            creator: accounts.creator,
+            position_nft_mint: accounts.position_nft_mint,
+            position_nft_account: derive_position_nft_account(&accounts.position_nft_mint),
+            payer: accounts.payer,
+            config,
+            pool_authority: derive_pool_authority(),
+            pool,
+            position: derive_position(&accounts.position_nft_mint),
+            token_a_mint: accounts.token_a_mint,
+            token_b_mint: accounts.token_b_mint,
+            token_a_vault: derive_token_vault(&accounts.token_a_mint, &pool),
+            token_b_vault: derive_token_vault(&accounts.token_b_mint, &pool),
+            payer_token_a: accounts.payer_token_a,
+            payer_token_b: accounts.payer_token_b,
+            token_a_program: accounts.token_a_program,
+            token_b_program: accounts.token_b_program,
+            token_2022_program: anchor_spl::token_2022::ID,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializePool { params },
+        get_token_badge_remaining_accounts(accounts),
+    )
+}
+
+pub fn initialize_pool_with_dynamic_config(
+    accounts: &InitializePoolAccounts,
+    config: Pubkey,
+    pool_creator_authority: Pubkey,
+    params: InitializeCustomizablePoolParameters,
+) -> Instruction {
+    let pool = derive_pool(&config, &accounts.token_a_mint, &accounts.token_b_mint);
+
+    build_instruction(
+        cp_amm::accounts::InitializePoolWithDynamicConfigCtx {
+            creator: accounts.creator,
+            position_nft_mint: accounts.position_nft_mint,
+            position_nft_account: derive_position_nft_account(&accounts.position_nft_mint),
+            payer: accounts.payer,
+            pool_creator_authority,
+            config,
+            pool_authority: derive_pool_authority(),
+            pool,
+            position: derive_position(&accounts.position_nft_mint),
+            token_a_mint: accounts.token_a_mint,
+            token_b_mint: accounts.token_b_mint,
+            token_a_vault: derive_token_vault(&accounts.token_a_mint, &pool),
+            token_b_vault: derive_token_vault(&accounts.token_b_mint, &pool),
+            payer_token_a: accounts.payer_token_a,
+            payer_token_b: accounts.payer_token_b,
+            token_a_program: accounts.token_a_program,
+            token_b_program: accounts.token_b_program,
+            token_2022_program: anchor_spl::token_2022::ID,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializePoolWithDynamicConfig { params },
+        get_token_badge_remaining_accounts(accounts),
+    )
+}
+
+pub fn initialize_customizable_pool(
+    accounts: &InitializePoolAccounts,
+    params: InitializeCustomizablePoolParameters,
+) -> Instruction {
+    let pool = derive_customizable_pool(&accounts.token_a_mint, &accounts.token_b_mint);
+
+    build_instruction(
+        cp_amm::accounts::InitializeCustomizablePoolCtx {
+            creator: accounts.creator,
+            position_nft_mint: accounts.position_nft_mint,
+            position_nft_account: derive_position_nft_account(&accounts.position_nft_mint),
+            payer: accounts.payer,
+            pool_authority: derive_pool_authority(),
+            pool,
+            position: derive_position(&accounts.position_nft_mint),
+            token_a_mint: accounts.token_a_mint,
+            token_b_mint: accounts.token_b_mint,
+            token_a_vault: derive_token_vault(&accounts.token_a_mint, &pool),
+            token_b_vault: derive_token_vault(&accounts.token_b_mint, &pool),
+            payer_token_a: accounts.payer_token_a,
+            payer_token_b: accounts.payer_token_b,
+            token_a_program: accounts.token_a_program,
+            token_b_program: accounts.token_b_program,
+            token_2022_program: anchor_spl::token_2022::ID,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializeCustomizablePool { params },
+        get_token_badge_remaining_accounts(accounts),
+    )
+}
+
+/// `allowlist_authority` adds and removes allowlist entries of the pool
+pub fn initialize_permissioned_pool(
+    accounts: &InitializePoolAccounts,
+    allowlist_authority: Pubkey,
+    params: InitializeCustomizablePoolParameters,
+) -> Instruction {
+    let pool = derive_permissioned_pool(&accounts.token_a_mint, &accounts.token_b_mint);
+
+    build_instruction(
+        cp_amm::accounts::InitializePermissionedPoolCtx {
+            creator: accounts.creator,
+            allowlist_authority,
+            position_nft_mint: accounts.position_nft_mint,
+            position_nft_account: derive_position_nft_account(&accounts.position_nft_mint),
+            payer: accounts.payer,
+            pool_authority: derive_pool_authority(),
+            pool,
+            allowlist_config: derive_allowlist_config(&pool),
+            position: derive_position(&accounts.position_nft_mint),
+            token_a_mint: accounts.token_a_mint,
+            token_b_mint: accounts.token_b_mint,
+            token_a_vault: derive_token_vault(&accounts.token_a_mint, &pool),
+            token_b_vault: derive_token_vault(&accounts.token_b_mint, &pool),
+            payer_token_a: accounts.payer_token_a,
+            payer_token_b: accounts.payer_token_b,
+            token_a_program: accounts.token_a_program,
+            token_b_program: accounts.token_b_program,
+            token_2022_program: anchor_spl::token_2022::ID,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializePermissionedPool { params },
+        get_token_badge_remaining_accounts(accounts),
+    )
+}
+
+/// Token badges are only read by the program for mints which are not supported without it
+fn get_token_badge_remaining_accounts(accounts: &InitializePoolAccounts) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(derive_token_badge(&accounts.token_a_mint), false),
+        AccountMeta::new_readonly(derive_token_badge(&accounts.token_b_mint), false),
+    ]
+}
@@ -0,0 +1,248 @@
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction};
+use anyhow::{Error, Result};
+use cp_amm::{
+    params::swap::TradeDirection, state::Pool, AddLiquidityParameters,
+    AddLiquiditySingleSidedParameters, CompoundPositionFeeParameters, RemoveLiquidityParameters,
+    RemoveLiquiditySingleTokenParameters,
+};
+
+use super::{build_instruction, get_token_programs};
+use crate::pda::{derive_event_authority, derive_pool_authority};
+
+/// Accounts shared by instructions that move tokens between the position owner and the pool vaults
+pub struct ModifyLiquidityAccounts {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub owner: Pubkey,
+    pub token_a_account: Pubkey,
+    pub token_b_account: Pubkey,
+}
+
+pub fn add_liquidity(
+    accounts: &ModifyLiquidityAccounts,
+    pool: &Pool,
+    params: AddLiquidityParameters,
+) -> Result<Instruction> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::AddLiquidityCtx {
+            pool: accounts.pool,
+            position: accounts.position,
+            token_a_account: accounts.token_a_account,
+            token_b_account: accounts.token_b_account,
+            token_a_vault: pool.token_a_vault,
+            token_b_vault: pool.token_b_vault,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            token_a_program,
+            token_b_program,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::AddLiquidity { params },
+        vec![],
+    ))
+}
+
+pub fn add_liquidity_single_sided(
+    accounts: &ModifyLiquidityAccounts,
+    pool: &Pool,
+    params: AddLiquiditySingleSidedParameters,
+) -> Result<Instruction> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::AddLiquiditySingleSidedCtx {
+            pool_authority: derive_pool_authority(),
+            pool: accounts.pool,
+            position: accounts.position,
+            token_a_account: accounts.token_a_account,
+            token_b_account: accounts.token_b_account,
+            token_a_vault: pool.token_a_vault,
+            token_b_vault: pool.token_b_vault,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            token_a_program,
+            token_b_program,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::AddLiquiditySingleSided { params },
+        vec![],
+    ))
+}
+
+pub fn remove_liquidity(
+    accounts: &ModifyLiquidityAccounts,
+    pool: &Pool,
+    params: RemoveLiquidityParameters,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        get_remove_liquidity_accounts(accounts, pool)?,
+        cp_amm::instruction::RemoveLiquidity { params },
+        vec![],
+    ))
+}
+
+pub fn remove_all_liquidity(
+    accounts: &ModifyLiquidityAccounts,
+    pool: &Pool,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        get_remove_liquidity_accounts(accounts, pool)?,
+        cp_amm::instruction::RemoveAllLiquidity {
+            token_a_amount_threshold,
+            token_b_amount_threshold,
+        },
+        vec![],
+    ))
+}
+
+/// The output token account is `token_b_account` when swapping token a to token b, otherwise `token_a_account`
+pub fn remove_liquidity_single_token(
+    accounts: &ModifyLiquidityAccounts,
+    pool: &Pool,
+    params: RemoveLiquiditySingleTokenParameters,
+) -> Result<Instruction> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+    let trade_direction = TradeDirection::try_from(params.trade_direction)
+        .map_err(|_| Error::msg("Invalid trade direction"))?;
+    let output_token_account = match trade_direction {
+        TradeDirection::AtoB => accounts.token_b_account,
+        TradeDirection::BtoA => accounts.token_a_account,
+    };
+
+    Ok(build_instruction(
+        cp_amm::accounts::RemoveLiquiditySingleTokenCtx {
+            pool_authority: derive_pool_authority(),
+            pool: accounts.pool,
+            position: accounts.position,
+            output_token_account,
+            token_a_vault: pool.token_a_vault,
+            token_b_vault: pool.token_b_vault,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            token_a_program,
+            token_b_program,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::RemoveLiquiditySingleToken { params },
+        vec![],
+    ))
+}
+
+pub fn claim_position_fee(accounts: &ModifyLiquidityAccounts, pool: &Pool) -> Result<Instruction> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::ClaimPositionFeeCtx {
+            pool_authority: derive_pool_authority(),
+            pool: accounts.pool,
+            position: accounts.position,
+            token_a_account: accounts.token_a_account,
+            token_b_account: accounts.token_b_account,
+            token_a_vault: pool.token_a_vault,
+            token_b_vault: pool.token_b_vault,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            token_a_program,
+            token_b_program,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ClaimPositionFee {},
+        vec![],
+    ))
+}
+
+/// Signed by the pool creator, `token_a_account` and `token_b_account` receive the creator fees
+pub fn claim_creator_fee(
+    pool_address: Pubkey,
+    pool: &Pool,
+    token_a_account: Pubkey,
+    token_b_account: Pubkey,
+    max_amount_a: u64,
+    max_amount_b: u64,
+) -> Result<Instruction> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::ClaimCreatorFeeCtx {
+            pool_authority: derive_pool_authority(),
+            pool: pool_address,
+            token_a_account,
+            token_b_account,
+            token_a_vault: pool.token_a_vault,
+            token_b_vault: pool.token_b_vault,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            creator: pool.creator,
+            token_a_program,
+            token_b_program,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ClaimCreatorFee {
+            max_amount_a,
+            max_amount_b,
+        },
+        vec![],
+    ))
+}
+
+/// Token accounts of `accounts` are unused, pending fees never leave the vaults
+pub fn compound_position_fee(
+    accounts: &ModifyLiquidityAccounts,
+    params: CompoundPositionFeeParameters,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        cp_amm::accounts::CompoundPositionFeeCtx {
+            pool: accounts.pool,
+            position: accounts.position,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CompoundPositionFee { params },
+        vec![],
+    ))
+}
+
+fn get_remove_liquidity_accounts(
+    accounts: &ModifyLiquidityAccounts,
+    pool: &Pool,
+) -> Result<cp_amm::accounts::RemoveLiquidityCtx> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(cp_amm::accounts::RemoveLiquidityCtx {
+        pool_authority: derive_pool_authority(),
+        pool: accounts.pool,
+        position: accounts.position,
+        token_a_account: accounts.token_a_account,
+        token_b_account: accounts.token_b_account,
+        token_a_vault: pool.token_a_vault,
+        token_b_vault: pool.token_b_vault,
+        token_a_mint: pool.token_a_mint,
+        token_b_mint: pool.token_b_mint,
+        position_nft_account: accounts.position_nft_account,
+        owner: accounts.owner,
+        token_a_program,
+        token_b_program,
+        event_authority: derive_event_authority(),
+        program: cp_amm::ID,
+    })
+}
@@ -0,0 +1,42 @@
+pub mod admin;
+pub mod allowlist;
+pub mod flash_loan;
+pub mod initialize_pool;
+pub mod liquidity;
+pub mod operator;
+pub mod oracle;
+pub mod position;
+pub mod reward;
+pub mod swap;
+pub mod timelock;
+
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    InstructionData, ToAccountMetas,
+};
+use anyhow::Result;
+use cp_amm::{state::Pool, token::get_token_program_from_flag};
+
+fn build_instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id: cp_amm::ID,
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
+
+/// Token a and token b program of the pool
+fn get_token_programs(pool: &Pool) -> Result<(Pubkey, Pubkey)> {
+    Ok((
+        get_token_program_from_flag(pool.token_a_flag)?,
+        get_token_program_from_flag(pool.token_b_flag)?,
+    ))
+}
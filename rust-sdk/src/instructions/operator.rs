@@ -0,0 +1,344 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::{instruction::Instruction, sysvar},
+};
+use anyhow::{bail, Result};
+use cp_amm::{
+    params::fee_parameters::BaseFeeParameters, state::Pool, token::get_token_program_from_flag,
+    DynamicConfigParameters, StableSwapConfigParameters, StaticConfigParameters,
+    UpdatePoolFeesParameters,
+};
+
+use super::{
+    build_instruction, get_token_programs, timelock::get_timelock_config_remaining_account,
+};
+use crate::pda::{
+    derive_config, derive_event_authority, derive_operator, derive_operator_usage_limit,
+    derive_pool_authority, derive_protocol_fee_distribution, derive_token_badge,
+};
+
+pub fn create_config(
+    signer: Pubkey,
+    payer: Pubkey,
+    index: u64,
+    config_parameters: StaticConfigParameters,
+) -> Instruction {
+    build_instruction(
+        get_create_config_accounts(signer, payer, index),
+        cp_amm::instruction::CreateConfig {
+            index,
+            config_parameters,
+        },
+        vec![get_timelock_config_remaining_account()],
+    )
+}
+
+pub fn create_dynamic_config(
+    signer: Pubkey,
+    payer: Pubkey,
+    index: u64,
+    config_parameters: DynamicConfigParameters,
+) -> Instruction {
+    build_instruction(
+        get_create_config_accounts(signer, payer, index),
+        cp_amm::instruction::CreateDynamicConfig {
+            index,
+            config_parameters,
+        },
+        vec![get_timelock_config_remaining_account()],
+    )
+}
+
+pub fn create_stable_swap_config(
+    signer: Pubkey,
+    payer: Pubkey,
+    index: u64,
+    config_parameters: StableSwapConfigParameters,
+) -> Instruction {
+    build_instruction(
+        get_create_config_accounts(signer, payer, index),
+        cp_amm::instruction::CreateStableSwapConfig {
+            index,
+            config_parameters,
+        },
+        vec![get_timelock_config_remaining_account()],
+    )
+}
+
+pub fn close_config(config: Pubkey, signer: Pubkey, rent_receiver: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CloseConfigCtx {
+            config,
+            operator: derive_operator(&signer),
+            signer,
+            rent_receiver,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CloseConfig {},
+        vec![],
+    )
+}
+
+pub fn create_token_badge(token_mint: Pubkey, signer: Pubkey, payer: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CreateTokenBadgeCtx {
+            token_badge: derive_token_badge(&token_mint),
+            token_mint,
+            operator: derive_operator(&signer),
+            signer,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CreateTokenBadge {},
+        vec![],
+    )
+}
+
+pub fn close_token_badge(token_mint: Pubkey, signer: Pubkey, rent_receiver: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CloseTokenBadgeCtx {
+            token_badge: derive_token_badge(&token_mint),
+            operator: derive_operator(&signer),
+            signer,
+            rent_receiver,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CloseTokenBadge {},
+        vec![],
+    )
+}
+
+/// `status` is the bitmask of paused actions, refer `cp_amm::state::PoolPauseAction`. Fails when the permission
+/// is timelocked, use `timelock::propose_operator_action` instead
+pub fn set_pool_status(pool: Pubkey, signer: Pubkey, status: u8) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::SetPoolStatusCtx {
+            pool,
+            operator: derive_operator(&signer),
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::SetPoolStatus { status },
+        vec![get_timelock_config_remaining_account()],
+    )
+}
+
+pub fn update_pool_fees(
+    pool: Pubkey,
+    signer: Pubkey,
+    params: UpdatePoolFeesParameters,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::UpdatePoolFeesCtx {
+            pool,
+            operator: derive_operator(&signer),
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::UpdatePoolFees { params },
+        vec![get_timelock_config_remaining_account()],
+    )
+}
+
+pub fn fix_pool_fee_params(pool: Pubkey, signer: Pubkey, params: BaseFeeParameters) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::FixPoolFeeParamsCtx {
+            pool,
+            operator: derive_operator(&signer),
+            signer,
+        },
+        cp_amm::instruction::FixPoolFeeParams { params },
+        vec![],
+    )
+}
+
+pub fn fix_config_fee_params(
+    config: Pubkey,
+    signer: Pubkey,
+    params: BaseFeeParameters,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::FixConfigFeeParamsCtx {
+            config,
+            operator: derive_operator(&signer),
+            signer,
+        },
+        cp_amm::instruction::FixConfigFeeParams { params },
+        vec![],
+    )
+}
+
+pub fn fix_pool_layout_version(pool: Pubkey, signer: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::FixPoolLayoutVersionCtx {
+            pool,
+            operator: derive_operator(&signer),
+            signer,
+        },
+        cp_amm::instruction::FixPoolLayoutVersion {},
+        vec![],
+    )
+}
+
+#[allow(deprecated)]
+pub fn claim_protocol_fee(
+    pool_address: Pubkey,
+    pool: &Pool,
+    signer: Pubkey,
+    token_a_account: Pubkey,
+    token_b_account: Pubkey,
+    max_amount_a: u64,
+    max_amount_b: u64,
+) -> Result<Instruction> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::ClaimProtocolFeesCtx {
+            pool_authority: derive_pool_authority(),
+            pool: pool_address,
+            token_a_vault: pool.token_a_vault,
+            token_b_vault: pool.token_b_vault,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            token_a_account,
+            token_b_account,
+            operator: derive_operator(&signer),
+            signer,
+            token_a_program,
+            token_b_program,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ClaimProtocolFee {
+            max_amount_a,
+            max_amount_b,
+        },
+        vec![],
+    ))
+}
+
+/// `token_mint` selects which side of the pool protocol fee is zapped out
+#[allow(deprecated)]
+pub fn zap_protocol_fee(
+    pool_address: Pubkey,
+    pool: &Pool,
+    token_mint: Pubkey,
+    signer: Pubkey,
+    receiver_token: Pubkey,
+    max_amount: u64,
+) -> Result<Instruction> {
+    let (token_vault, token_program_flag) = if token_mint == pool.token_a_mint {
+        (pool.token_a_vault, pool.token_a_flag)
+    } else if token_mint == pool.token_b_mint {
+        (pool.token_b_vault, pool.token_b_flag)
+    } else {
+        bail!("token mint is not in the pool");
+    };
+
+    Ok(build_instruction(
+        cp_amm::accounts::ZapProtocolFee {
+            pool_authority: derive_pool_authority(),
+            pool: pool_address,
+            token_vault,
+            token_mint,
+            receiver_token,
+            operator: derive_operator(&signer),
+            signer,
+            token_program: get_token_program_from_flag(token_program_flag)?,
+            sysvar_instructions: sysvar::instructions::ID,
+        },
+        cp_amm::instruction::ZapProtocolFee { max_amount },
+        vec![],
+    ))
+}
+
+/// Only callable through the protocol fee program, which signs as the protocol fee authority
+pub fn claim_protocol_fee2(
+    pool_address: Pubkey,
+    pool: &Pool,
+    receiver_token_account: Pubkey,
+    max_amount: u64,
+) -> Result<Instruction> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::ClaimProtocolFee2Ctx {
+            receiver_token_account,
+            token_a_mint: pool.token_a_mint,
+            token_b_mint: pool.token_b_mint,
+            token_a_program,
+            token_b_program,
+            pool: pool_address,
+            token_a_vault: pool.token_a_vault,
+            token_b_vault: pool.token_b_vault,
+            pool_authority: derive_pool_authority(),
+            signer: cp_amm::const_pda::protocol_fee_authority::ID,
+        },
+        cp_amm::instruction::ClaimProtocolFee2 { max_amount },
+        vec![],
+    ))
+}
+
+/// Permissionless. `receiver_token_accounts` are the `token_mint` token accounts of the distribution receivers,
+/// in the receivers order
+pub fn distribute_protocol_fee(
+    pool_address: Pubkey,
+    pool: &Pool,
+    token_mint: Pubkey,
+    receiver_token_accounts: &[Pubkey],
+    max_amount: u64,
+) -> Result<Instruction> {
+    let (token_vault, token_program_flag) = if token_mint == pool.token_a_mint {
+        (pool.token_a_vault, pool.token_a_flag)
+    } else if token_mint == pool.token_b_mint {
+        (pool.token_b_vault, pool.token_b_flag)
+    } else {
+        bail!("token mint is not in the pool");
+    };
+
+    Ok(build_instruction(
+        cp_amm::accounts::DistributeProtocolFeeCtx {
+            pool_authority: derive_pool_authority(),
+            pool: pool_address,
+            protocol_fee_distribution: derive_protocol_fee_distribution(&pool_address),
+            token_vault,
+            token_mint,
+            token_program: get_token_program_from_flag(token_program_flag)?,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::DistributeProtocolFee { max_amount },
+        receiver_token_accounts
+            .iter()
+            .map(|receiver_token_account| AccountMeta::new(*receiver_token_account, false))
+            .collect(),
+    ))
+}
+
+/// Append to the remaining accounts of `claim_protocol_fee` and `zap_protocol_fee` when the permission of the
+/// operator is usage limited
+pub fn get_operator_usage_limit_remaining_account(signer: &Pubkey) -> AccountMeta {
+    AccountMeta::new(derive_operator_usage_limit(&derive_operator(signer)), false)
+}
+
+fn get_create_config_accounts(
+    signer: Pubkey,
+    payer: Pubkey,
+    index: u64,
+) -> cp_amm::accounts::CreateConfigCtx {
+    cp_amm::accounts::CreateConfigCtx {
+        config: derive_config(index),
+        operator: derive_operator(&signer),
+        signer,
+        payer,
+        system_program: anchor_lang::system_program::ID,
+        event_authority: derive_event_authority(),
+        program: cp_amm::ID,
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+};
+
+use super::build_instruction;
+use crate::pda::{derive_event_authority, derive_oracle};
+
+pub fn initialize_oracle(pool_address: Pubkey, payer: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::InitializeOracleCtx {
+            pool: pool_address,
+            oracle: derive_oracle(&pool_address),
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializeOracle {},
+        vec![],
+    )
+}
+
+/// Read-only, the observations are returned via return data
+pub fn observe_oracle(pool_address: Pubkey, seconds_agos: Vec<u64>) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::ObserveOracleCtx {
+            pool: pool_address,
+            oracle: derive_oracle(&pool_address),
+        },
+        cp_amm::instruction::ObserveOracle { seconds_agos },
+        vec![],
+    )
+}
+
+/// Append to the remaining accounts of swap, add liquidity or remove liquidity to write an oracle observation
+pub fn get_oracle_remaining_account(pool_address: &Pubkey) -> AccountMeta {
+    AccountMeta::new(derive_oracle(pool_address), false)
+}
@@ -0,0 +1,253 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+};
+use cp_amm::{SetPositionDelegateParameters, SplitPositionParameters, VestingParameters};
+
+use super::build_instruction;
+use crate::pda::{
+    derive_event_authority, derive_pool_authority, derive_position, derive_position_delegate,
+    derive_position_nft_account,
+};
+
+/// Accounts of a position owned by `owner` through `position_nft_account`
+pub struct PositionAccounts {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// `position_nft_mint` must sign the transaction
+pub fn create_position(
+    pool: Pubkey,
+    owner: Pubkey,
+    position_nft_mint: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CreatePositionCtx {
+            owner,
+            position_nft_mint,
+            position_nft_account: derive_position_nft_account(&position_nft_mint),
+            pool,
+            position: derive_position(&position_nft_mint),
+            pool_authority: derive_pool_authority(),
+            payer,
+            token_program: anchor_spl::token_2022::ID,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CreatePosition {},
+        vec![],
+    )
+}
+
+pub fn close_position(
+    accounts: &PositionAccounts,
+    position_nft_mint: Pubkey,
+    rent_receiver: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::ClosePositionCtx {
+            position_nft_mint,
+            position_nft_account: accounts.position_nft_account,
+            pool: accounts.pool,
+            position: accounts.position,
+            pool_authority: derive_pool_authority(),
+            rent_receiver,
+            owner: accounts.owner,
+            token_program: anchor_spl::token_2022::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ClosePosition {},
+        vec![],
+    )
+}
+
+/// `vesting` is a new keypair account and must sign the transaction
+pub fn lock_position(
+    accounts: &PositionAccounts,
+    vesting: Pubkey,
+    payer: Pubkey,
+    params: VestingParameters,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::LockPositionCtx {
+            pool: accounts.pool,
+            position: accounts.position,
+            vesting,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::LockPosition { params },
+        vec![],
+    )
+}
+
+pub fn lock_inner_position(accounts: &PositionAccounts, params: VestingParameters) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::LockInnerPositionCtx {
+            pool: accounts.pool,
+            position: accounts.position,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::LockInnerPosition { params },
+        vec![],
+    )
+}
+
+/// `vestings` are the external vesting accounts of the position to be released
+pub fn refresh_vesting(accounts: &PositionAccounts, vestings: &[Pubkey]) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::RefreshVestingCtx {
+            pool: accounts.pool,
+            position: accounts.position,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+        },
+        cp_amm::instruction::RefreshVesting {},
+        vestings
+            .iter()
+            .map(|vesting| AccountMeta::new(*vesting, false))
+            .collect(),
+    )
+}
+
+pub fn permanent_lock_position(
+    accounts: &PositionAccounts,
+    permanent_lock_liquidity: u128,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::PermanentLockPositionCtx {
+            pool: accounts.pool,
+            position: accounts.position,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::PermanentLockPosition {
+            permanent_lock_liquidity,
+        },
+        vec![],
+    )
+}
+
+pub fn split_position(
+    first_position: &PositionAccounts,
+    second_position: &PositionAccounts,
+    params: SplitPositionParameters,
+) -> Instruction {
+    build_instruction(
+        get_split_position_accounts(first_position, second_position),
+        cp_amm::instruction::SplitPosition { params },
+        vec![],
+    )
+}
+
+pub fn split_position2(
+    first_position: &PositionAccounts,
+    second_position: &PositionAccounts,
+    numerator: u32,
+) -> Instruction {
+    build_instruction(
+        get_split_position_accounts(first_position, second_position),
+        cp_amm::instruction::SplitPosition2 { numerator },
+        vec![],
+    )
+}
+
+/// Merge `source_position` into `position` and close `source_position`, both positions must be owned by the same owner
+pub fn merge_position(
+    position: &PositionAccounts,
+    source_position: &PositionAccounts,
+    source_position_nft_mint: Pubkey,
+    rent_receiver: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::MergePositionCtx {
+            pool: position.pool,
+            position: position.position,
+            position_nft_account: position.position_nft_account,
+            source_position: source_position.position,
+            source_position_nft_mint,
+            source_position_nft_account: source_position.position_nft_account,
+            pool_authority: derive_pool_authority(),
+            rent_receiver,
+            owner: position.owner,
+            token_program: anchor_spl::token_2022::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::MergePosition {},
+        vec![],
+    )
+}
+
+fn get_split_position_accounts(
+    first_position: &PositionAccounts,
+    second_position: &PositionAccounts,
+) -> cp_amm::accounts::SplitPositionCtx {
+    cp_amm::accounts::SplitPositionCtx {
+        pool: first_position.pool,
+        first_position: first_position.position,
+        first_position_nft_account: first_position.position_nft_account,
+        second_position: second_position.position,
+        second_position_nft_account: second_position.position_nft_account,
+        first_owner: first_position.owner,
+        second_owner: second_position.owner,
+        event_authority: derive_event_authority(),
+        program: cp_amm::ID,
+    }
+}
+
+pub fn set_position_delegate(
+    accounts: &PositionAccounts,
+    params: SetPositionDelegateParameters,
+    payer: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::SetPositionDelegateCtx {
+            position: accounts.position,
+            position_nft_account: accounts.position_nft_account,
+            position_delegate: derive_position_delegate(&accounts.position, &accounts.owner),
+            owner: accounts.owner,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::SetPositionDelegate { params },
+        vec![],
+    )
+}
+
+/// `owner` is the position owner which set the delegate, and receives the rent
+pub fn revoke_position_delegate(position: Pubkey, owner: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::RevokePositionDelegateCtx {
+            position_delegate: derive_position_delegate(&position, &owner),
+            owner,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::RevokePositionDelegate {},
+        vec![],
+    )
+}
+
+/// Insert as the first remaining account of claim_position_fee, claim_reward or compound_position_fee signed by the
+/// delegate instead of `owner`. Token accounts receiving claimed fees or rewards must be owned by the payout destination
+pub fn get_position_delegate_remaining_account(position: &Pubkey, owner: &Pubkey) -> AccountMeta {
+    AccountMeta::new_readonly(derive_position_delegate(position, owner), false)
+}
@@ -0,0 +1,374 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    InstructionData,
+};
+use anyhow::{Context, Result};
+use cp_amm::{
+    state::{get_extended_reward_index, Pool, PoolRewardExtension, RewardInfo},
+    token::get_token_program_from_flag,
+    InitializeRewardParameters,
+};
+
+use super::{
+    build_instruction, position::PositionAccounts, timelock::get_timelock_config_remaining_account,
+};
+use crate::pda::{
+    derive_event_authority, derive_operator, derive_pool_authority, derive_pool_reward_extension,
+    derive_position_reward_extension, derive_reward_vault, derive_token_badge,
+};
+
+/// Token badge is only read by the program when the reward mint is not supported without it
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_reward(
+    pool_address: Pubkey,
+    pool: &Pool,
+    reward_index: u8,
+    reward_mint: Pubkey,
+    reward_token_program: Pubkey,
+    signer: Pubkey,
+    payer: Pubkey,
+    reward_duration: u64,
+    funder: Pubkey,
+) -> Result<Instruction> {
+    build_initialize_reward(
+        pool_address,
+        pool,
+        reward_index,
+        reward_mint,
+        reward_token_program,
+        signer,
+        payer,
+        cp_amm::instruction::InitializeReward {
+            reward_index,
+            reward_duration,
+            funder,
+        },
+    )
+}
+
+/// Same as `initialize_reward`, vested and permanent locked liquidity can be boosted by `params`
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_reward2(
+    pool_address: Pubkey,
+    pool: &Pool,
+    reward_index: u8,
+    reward_mint: Pubkey,
+    reward_token_program: Pubkey,
+    signer: Pubkey,
+    payer: Pubkey,
+    params: InitializeRewardParameters,
+) -> Result<Instruction> {
+    build_initialize_reward(
+        pool_address,
+        pool,
+        reward_index,
+        reward_mint,
+        reward_token_program,
+        signer,
+        payer,
+        cp_amm::instruction::InitializeReward2 {
+            reward_index,
+            params,
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_initialize_reward(
+    pool_address: Pubkey,
+    pool: &Pool,
+    reward_index: u8,
+    reward_mint: Pubkey,
+    reward_token_program: Pubkey,
+    signer: Pubkey,
+    payer: Pubkey,
+    data: impl InstructionData,
+) -> Result<Instruction> {
+    let mut remaining_accounts = vec![AccountMeta::new_readonly(
+        derive_token_badge(&reward_mint),
+        false,
+    )];
+    remaining_accounts.extend(get_operator_remaining_account(pool, reward_index, signer));
+    remaining_accounts.extend(get_pool_reward_extension_remaining_account(
+        &pool_address,
+        reward_index,
+    )?);
+
+    Ok(build_instruction(
+        cp_amm::accounts::InitializeRewardCtx {
+            pool_authority: derive_pool_authority(),
+            pool: pool_address,
+            reward_vault: derive_reward_vault(&pool_address, reward_index),
+            reward_mint,
+            signer,
+            payer,
+            token_program: reward_token_program,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        data,
+        remaining_accounts,
+    ))
+}
+
+/// `pool_reward_extension` is required for reward index `NUM_REWARDS` onward
+#[allow(clippy::too_many_arguments)]
+pub fn fund_reward(
+    pool_address: Pubkey,
+    pool: &Pool,
+    pool_reward_extension: Option<&PoolRewardExtension>,
+    reward_index: u8,
+    funder: Pubkey,
+    funder_token_account: Pubkey,
+    amount: u64,
+    carry_forward: bool,
+) -> Result<Instruction> {
+    let reward_info = get_reward_info(pool, pool_reward_extension, reward_index)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::FundRewardCtx {
+            pool: pool_address,
+            reward_vault: reward_info.vault,
+            reward_mint: reward_info.mint,
+            funder_token_account,
+            funder,
+            token_program: get_token_program_from_flag(reward_info.reward_token_flag)?,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::FundReward {
+            reward_index,
+            amount,
+            carry_forward,
+        },
+        get_pool_reward_extension_remaining_account(&pool_address, reward_index)?,
+    ))
+}
+
+/// `pool_reward_extension` is required for reward index `NUM_REWARDS` onward
+pub fn withdraw_ineligible_reward(
+    pool_address: Pubkey,
+    pool: &Pool,
+    pool_reward_extension: Option<&PoolRewardExtension>,
+    reward_index: u8,
+    funder: Pubkey,
+    funder_token_account: Pubkey,
+) -> Result<Instruction> {
+    let reward_info = get_reward_info(pool, pool_reward_extension, reward_index)?;
+
+    Ok(build_instruction(
+        cp_amm::accounts::WithdrawIneligibleRewardCtx {
+            pool_authority: derive_pool_authority(),
+            pool: pool_address,
+            reward_vault: reward_info.vault,
+            reward_mint: reward_info.mint,
+            funder_token_account,
+            funder,
+            token_program: get_token_program_from_flag(reward_info.reward_token_flag)?,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::WithdrawIneligibleReward { reward_index },
+        get_pool_reward_extension_remaining_account(&pool_address, reward_index)?,
+    ))
+}
+
+pub fn update_reward_funder(
+    pool_address: Pubkey,
+    pool: &Pool,
+    reward_index: u8,
+    signer: Pubkey,
+    new_funder: Pubkey,
+) -> Result<Instruction> {
+    let mut remaining_accounts = get_operator_remaining_account(pool, reward_index, signer);
+    remaining_accounts.extend(get_pool_reward_extension_remaining_account(
+        &pool_address,
+        reward_index,
+    )?);
+    remaining_accounts.push(get_timelock_config_remaining_account());
+
+    Ok(build_instruction(
+        cp_amm::accounts::UpdateRewardFunderCtx {
+            pool: pool_address,
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::UpdateRewardFunder {
+            reward_index,
+            new_funder,
+        },
+        remaining_accounts,
+    ))
+}
+
+pub fn update_reward_duration(
+    pool_address: Pubkey,
+    pool: &Pool,
+    reward_index: u8,
+    signer: Pubkey,
+    new_duration: u64,
+) -> Result<Instruction> {
+    let mut remaining_accounts = get_operator_remaining_account(pool, reward_index, signer);
+    remaining_accounts.extend(get_pool_reward_extension_remaining_account(
+        &pool_address,
+        reward_index,
+    )?);
+
+    Ok(build_instruction(
+        cp_amm::accounts::UpdateRewardDurationCtx {
+            pool: pool_address,
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::UpdateRewardDuration {
+            reward_index,
+            new_duration,
+        },
+        remaining_accounts,
+    ))
+}
+
+/// `pool_reward_extension` is required for reward index `NUM_REWARDS` onward
+pub fn claim_reward(
+    accounts: &PositionAccounts,
+    pool: &Pool,
+    pool_reward_extension: Option<&PoolRewardExtension>,
+    user_token_account: Pubkey,
+    reward_index: u8,
+    skip_reward: bool,
+) -> Result<Instruction> {
+    let reward_info = get_reward_info(pool, pool_reward_extension, reward_index)?;
+    let mut remaining_accounts =
+        get_pool_reward_extension_remaining_account(&accounts.pool, reward_index)?;
+    if !remaining_accounts.is_empty() {
+        remaining_accounts.push(AccountMeta::new(
+            derive_position_reward_extension(&accounts.position),
+            false,
+        ));
+    }
+
+    Ok(build_instruction(
+        cp_amm::accounts::ClaimRewardCtx {
+            pool_authority: derive_pool_authority(),
+            pool: accounts.pool,
+            position: accounts.position,
+            reward_vault: reward_info.vault,
+            reward_mint: reward_info.mint,
+            user_token_account,
+            position_nft_account: accounts.position_nft_account,
+            owner: accounts.owner,
+            token_program: get_token_program_from_flag(reward_info.reward_token_flag)?,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ClaimReward {
+            reward_index,
+            skip_reward: skip_reward.into(),
+        },
+        remaining_accounts,
+    ))
+}
+
+pub fn initialize_pool_reward_extension(pool_address: Pubkey, payer: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::InitializePoolRewardExtensionCtx {
+            pool: pool_address,
+            pool_reward_extension: derive_pool_reward_extension(&pool_address),
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializePoolRewardExtension {},
+        vec![],
+    )
+}
+
+pub fn initialize_position_reward_extension(position: Pubkey, payer: Pubkey) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::InitializePositionRewardExtensionCtx {
+            position,
+            position_reward_extension: derive_position_reward_extension(&position),
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::InitializePositionRewardExtension {},
+        vec![],
+    )
+}
+
+/// Once an extended reward is initialized, append to the remaining accounts of instructions changing the liquidity of
+/// `positions`: add or remove liquidity, compound_position_fee, split_position, merge_position. Empty before that
+pub fn get_reward_extension_remaining_accounts(
+    pool_address: &Pubkey,
+    pool: &Pool,
+    positions: &[Pubkey],
+) -> Vec<AccountMeta> {
+    if !pool.is_extended_reward_initialized() {
+        return vec![];
+    }
+
+    let mut remaining_accounts = vec![AccountMeta::new(
+        derive_pool_reward_extension(pool_address),
+        false,
+    )];
+    remaining_accounts.extend(
+        positions
+            .iter()
+            .map(|position| AccountMeta::new(derive_position_reward_extension(position), false)),
+    );
+    remaining_accounts
+}
+
+fn get_reward_info<'a>(
+    pool: &'a Pool,
+    pool_reward_extension: Option<&'a PoolRewardExtension>,
+    reward_index: u8,
+) -> Result<&'a RewardInfo> {
+    let reward_index = usize::from(reward_index);
+    match get_extended_reward_index(reward_index).context("invalid reward index")? {
+        Some(extended_reward_index) => {
+            let pool_reward_extension =
+                pool_reward_extension.context("missing pool reward extension")?;
+            Ok(&pool_reward_extension.reward_infos[extended_reward_index])
+        }
+        None => Ok(&pool.reward_infos[reward_index]),
+    }
+}
+
+/// Extended rewards are stored in the pool reward extension
+pub(crate) fn get_pool_reward_extension_remaining_account(
+    pool_address: &Pubkey,
+    reward_index: u8,
+) -> Result<Vec<AccountMeta>> {
+    if get_extended_reward_index(usize::from(reward_index))
+        .context("invalid reward index")?
+        .is_none()
+    {
+        return Ok(vec![]);
+    }
+
+    Ok(vec![AccountMeta::new(
+        derive_pool_reward_extension(pool_address),
+        false,
+    )])
+}
+
+/// Anyone other than the pool creator editing reward 0 must be an operator
+fn get_operator_remaining_account(
+    pool: &Pool,
+    reward_index: u8,
+    signer: Pubkey,
+) -> Vec<AccountMeta> {
+    if pool.check_pool_creator_to_edit_reward(usize::from(reward_index), signer) {
+        return vec![];
+    }
+
+    vec![AccountMeta::new_readonly(derive_operator(&signer), false)]
+}
@@ -0,0 +1,149 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::{instruction::Instruction, sysvar},
+};
+use anyhow::Result;
+use cp_amm::{
+    params::swap::TradeDirection, state::Pool, SwapParameters, SwapParameters2, SwapRouteParameters,
+};
+
+use super::{build_instruction, get_token_programs, oracle::get_oracle_remaining_account};
+use crate::pda::{derive_event_authority, derive_pool_authority};
+
+pub struct SwapAccounts {
+    pub pool: Pubkey,
+    pub payer: Pubkey,
+    pub input_token_account: Pubkey,
+    pub output_token_account: Pubkey,
+    pub referral_token_account: Option<Pubkey>,
+}
+
+pub fn swap(
+    accounts: &SwapAccounts,
+    pool: &Pool,
+    trade_direction: TradeDirection,
+    current_point: u64,
+    params: SwapParameters,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        get_swap_accounts(accounts, pool)?,
+        cp_amm::instruction::Swap { _params: params },
+        get_swap_remaining_accounts(pool, trade_direction, current_point)?,
+    ))
+}
+
+pub fn swap2(
+    accounts: &SwapAccounts,
+    pool: &Pool,
+    trade_direction: TradeDirection,
+    current_point: u64,
+    params: SwapParameters2,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        get_swap_accounts(accounts, pool)?,
+        cp_amm::instruction::Swap2 { _params: params },
+        get_swap_remaining_accounts(pool, trade_direction, current_point)?,
+    ))
+}
+
+pub struct SwapRouteHopAccounts<'a> {
+    pub pool: Pubkey,
+    pub pool_state: &'a Pool,
+    /// User token account receiving the hop output, also the input token account of the next hop
+    pub output_token_account: Pubkey,
+    /// Write an observation to the pool oracle, which must be initialized
+    pub has_oracle: bool,
+}
+
+/// Hops must be ordered, the input token of each hop is the output token of the previous one
+pub fn swap_route(
+    payer: Pubkey,
+    input_token_account: Pubkey,
+    hops: &[SwapRouteHopAccounts],
+    params: SwapRouteParameters,
+) -> Result<Instruction> {
+    let accounts = cp_amm::accounts::SwapRouteCtx {
+        pool_authority: derive_pool_authority(),
+        input_token_account,
+        payer,
+        sysvar_instructions: sysvar::instructions::ID,
+        event_authority: derive_event_authority(),
+        program: cp_amm::ID,
+    };
+
+    let mut remaining_accounts = Vec::with_capacity(hops.len() * cp_amm::SWAP_ROUTE_HOP_ACCOUNTS);
+    for hop in hops {
+        let pool = hop.pool_state;
+        let (token_a_program, token_b_program) = get_token_programs(pool)?;
+        remaining_accounts.extend([
+            AccountMeta::new(hop.pool, false),
+            AccountMeta::new(hop.output_token_account, false),
+            AccountMeta::new(pool.token_a_vault, false),
+            AccountMeta::new(pool.token_b_vault, false),
+            AccountMeta::new_readonly(pool.token_a_mint, false),
+            AccountMeta::new_readonly(pool.token_b_mint, false),
+            AccountMeta::new_readonly(token_a_program, false),
+            AccountMeta::new_readonly(token_b_program, false),
+        ]);
+    }
+
+    // oracles are passed after all hops
+    remaining_accounts.extend(
+        hops.iter()
+            .filter(|hop| hop.has_oracle)
+            .map(|hop| get_oracle_remaining_account(&hop.pool)),
+    );
+
+    Ok(build_instruction(
+        accounts,
+        cp_amm::instruction::SwapRoute { params },
+        remaining_accounts,
+    ))
+}
+
+fn get_swap_accounts(accounts: &SwapAccounts, pool: &Pool) -> Result<cp_amm::accounts::SwapCtx> {
+    let (token_a_program, token_b_program) = get_token_programs(pool)?;
+
+    Ok(cp_amm::accounts::SwapCtx {
+        pool_authority: derive_pool_authority(),
+        pool: accounts.pool,
+        input_token_account: accounts.input_token_account,
+        output_token_account: accounts.output_token_account,
+        token_a_vault: pool.token_a_vault,
+        token_b_vault: pool.token_b_vault,
+        token_a_mint: pool.token_a_mint,
+        token_b_mint: pool.token_b_mint,
+        payer: accounts.payer,
+        token_a_program,
+        token_b_program,
+        referral_token_account: accounts.referral_token_account,
+        event_authority: derive_event_authority(),
+        program: cp_amm::ID,
+    })
+}
+
+/// Rate limiter requires the instructions sysvar to validate there is a single swap to the pool in the transaction
+fn get_swap_remaining_accounts(
+    pool: &Pool,
+    trade_direction: TradeDirection,
+    current_point: u64,
+) -> Result<Vec<AccountMeta>> {
+    let Ok(rate_limiter) = pool.pool_fees.base_fee.to_fee_rate_limiter() else {
+        return Ok(vec![]);
+    };
+
+    // the transaction might land after activation even if it is built before
+    let current_point = current_point.max(pool.activation_point);
+    if rate_limiter.is_rate_limiter_applied(
+        current_point,
+        pool.activation_point,
+        trade_direction,
+    )? {
+        return Ok(vec![AccountMeta::new_readonly(
+            sysvar::instructions::ID,
+            false,
+        )]);
+    }
+
+    Ok(vec![])
+}
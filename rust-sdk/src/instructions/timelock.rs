@@ -0,0 +1,135 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+};
+use anyhow::{Context, Result};
+use cp_amm::state::{OperatorAction, OperatorPermission};
+
+use super::{build_instruction, reward::get_pool_reward_extension_remaining_account};
+use crate::pda::{
+    derive_config, derive_event_authority, derive_operator, derive_pending_operator_action,
+    derive_timelock_config,
+};
+
+/// Append to the remaining accounts of `set_pool_status`, `update_pool_fees`, `update_reward_funder`,
+/// `create_config`, `create_dynamic_config` and `create_stable_swap_config`, so the program rejects the action when its permission is timelocked
+pub fn get_timelock_config_remaining_account() -> AccountMeta {
+    AccountMeta::new_readonly(derive_timelock_config(), false)
+}
+
+/// `signer` is the operator proposing the action, it can be executed by anyone once the delay has passed.
+/// `pool_address` is the config created by config actions, see `get_operator_action_address`
+pub fn propose_operator_action(
+    pool_address: Pubkey,
+    signer: Pubkey,
+    payer: Pubkey,
+    action: OperatorAction,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::ProposeOperatorActionCtx {
+            pending_operator_action: derive_pending_operator_action(
+                &pool_address,
+                action.get_permission(),
+            ),
+            pool: pool_address,
+            timelock_config: derive_timelock_config(),
+            operator: derive_operator(&signer),
+            signer,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ProposeOperatorAction { action },
+        vec![],
+    )
+}
+
+/// `signer` can be any operator with the permission of the pending action
+pub fn cancel_operator_action(
+    pool_address: Pubkey,
+    permission: OperatorPermission,
+    proposer: Pubkey,
+    signer: Pubkey,
+) -> Instruction {
+    build_instruction(
+        cp_amm::accounts::CancelOperatorActionCtx {
+            pending_operator_action: derive_pending_operator_action(&pool_address, permission),
+            proposer,
+            operator: derive_operator(&signer),
+            signer,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::CancelOperatorAction {},
+        vec![],
+    )
+}
+
+/// `action` is the pending action, extended reward funder updates need the pool reward extension
+pub fn execute_operator_action(
+    pool_address: Pubkey,
+    proposer: Pubkey,
+    action: &OperatorAction,
+) -> Result<Instruction> {
+    let remaining_accounts = match action {
+        OperatorAction::UpdateRewardFunder { reward_index, .. } => {
+            get_pool_reward_extension_remaining_account(&pool_address, *reward_index)?
+        }
+        _ => vec![],
+    };
+
+    Ok(build_instruction(
+        cp_amm::accounts::ExecuteOperatorActionCtx {
+            pending_operator_action: derive_pending_operator_action(
+                &pool_address,
+                action.get_permission(),
+            ),
+            pool: pool_address,
+            proposer,
+            operator: derive_operator(&proposer),
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ExecuteOperatorAction {},
+        remaining_accounts,
+    ))
+}
+
+/// `action` is the pending config action, `payer` pays the rent of the created config
+pub fn execute_config_operator_action(
+    proposer: Pubkey,
+    payer: Pubkey,
+    action: &OperatorAction,
+) -> Result<Instruction> {
+    let index = action
+        .get_config_index()
+        .context("Not a config operator action")?;
+    let config = derive_config(index);
+
+    Ok(build_instruction(
+        cp_amm::accounts::ExecuteConfigOperatorActionCtx {
+            pending_operator_action: derive_pending_operator_action(
+                &config,
+                action.get_permission(),
+            ),
+            config,
+            proposer,
+            operator: derive_operator(&proposer),
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            event_authority: derive_event_authority(),
+            program: cp_amm::ID,
+        },
+        cp_amm::instruction::ExecuteConfigOperatorAction { index },
+        vec![],
+    ))
+}
+
+/// Address the pending action is derived from, the pool of the action or the config created by config actions
+pub fn get_operator_action_address(pool_address: Pubkey, action: &OperatorAction) -> Pubkey {
+    action
+        .get_config_index()
+        .map(derive_config)
+        .unwrap_or(pool_address)
+}
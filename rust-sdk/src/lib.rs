@@ -1,7 +1,16 @@
+pub mod calculate_claimable;
 pub mod calculate_initial_sqrt_price;
+pub mod events;
+pub mod instructions;
+pub mod pda;
+pub mod quote_add_liquidity_single_sided;
+pub mod quote_compound_position_fee;
 pub mod quote_exact_in;
 pub mod quote_exact_out;
+pub mod quote_liquidity;
 pub mod quote_partial_fill_in;
+pub mod quote_remove_liquidity_single_token;
+pub mod twap;
 mod utils;
 
 #[cfg(test)]
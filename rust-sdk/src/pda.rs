@@ -0,0 +1,179 @@
+use anchor_lang::prelude::Pubkey;
+use cp_amm::{
+    const_pda,
+    constants::seeds::{
+        ALLOWLIST_CONFIG_PREFIX, ALLOWLIST_ENTRY_PREFIX, CONFIG_PREFIX, CUSTOMIZABLE_POOL_PREFIX,
+        OPERATOR_PREFIX, OPERATOR_USAGE_LIMIT_PREFIX, ORACLE_PREFIX,
+        PENDING_OPERATOR_ACTION_PREFIX, PERMISSIONED_POOL_PREFIX, POOL_PREFIX,
+        POOL_REWARD_EXTENSION_PREFIX, POSITION_DELEGATE_PREFIX, POSITION_NFT_ACCOUNT_PREFIX,
+        POSITION_PREFIX, POSITION_REWARD_EXTENSION_PREFIX, PROTOCOL_FEE_DISTRIBUTION_PREFIX,
+        REWARD_VAULT_PREFIX, TOKEN_BADGE_PREFIX, TOKEN_VAULT_PREFIX,
+    },
+    max_key, min_key,
+    state::OperatorPermission,
+};
+
+pub fn derive_pool_authority() -> Pubkey {
+    const_pda::pool_authority::ID
+}
+
+pub fn derive_event_authority() -> Pubkey {
+    Pubkey::new_from_array(const_pda::EVENT_AUTHORITY_AND_BUMP.0)
+}
+
+pub fn derive_config(index: u64) -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_PREFIX, &index.to_le_bytes()], &cp_amm::ID).0
+}
+
+pub fn derive_pool(config: &Pubkey, token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            POOL_PREFIX,
+            config.as_ref(),
+            &max_key(token_a_mint, token_b_mint),
+            &min_key(token_a_mint, token_b_mint),
+        ],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_customizable_pool(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            CUSTOMIZABLE_POOL_PREFIX,
+            &max_key(token_a_mint, token_b_mint),
+            &min_key(token_a_mint, token_b_mint),
+        ],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_permissioned_pool(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PERMISSIONED_POOL_PREFIX,
+            &max_key(token_a_mint, token_b_mint),
+            &min_key(token_a_mint, token_b_mint),
+        ],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_position(position_nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POSITION_PREFIX, position_nft_mint.as_ref()], &cp_amm::ID).0
+}
+
+pub fn derive_position_nft_account(position_nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[POSITION_NFT_ACCOUNT_PREFIX, position_nft_mint.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_token_vault(token_mint: &Pubkey, pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[TOKEN_VAULT_PREFIX, token_mint.as_ref(), pool.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_reward_vault(pool: &Pubkey, reward_index: u8) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            REWARD_VAULT_PREFIX,
+            pool.as_ref(),
+            &reward_index.to_le_bytes(),
+        ],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_token_badge(token_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TOKEN_BADGE_PREFIX, token_mint.as_ref()], &cp_amm::ID).0
+}
+
+pub fn derive_operator(whitelisted_address: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[OPERATOR_PREFIX, whitelisted_address.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_oracle(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ORACLE_PREFIX, pool.as_ref()], &cp_amm::ID).0
+}
+
+/// Delegate of `position` set by `owner`, the position owner at the time it's set
+pub fn derive_position_delegate(position: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[POSITION_DELEGATE_PREFIX, position.as_ref(), owner.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_pool_reward_extension(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POOL_REWARD_EXTENSION_PREFIX, pool.as_ref()], &cp_amm::ID).0
+}
+
+pub fn derive_position_reward_extension(position: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[POSITION_REWARD_EXTENSION_PREFIX, position.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+/// Allowlist entry of `wallet` in the permissioned `pool`
+pub fn derive_allowlist_entry(pool: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ALLOWLIST_ENTRY_PREFIX, pool.as_ref(), wallet.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_allowlist_config(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ALLOWLIST_CONFIG_PREFIX, pool.as_ref()], &cp_amm::ID).0
+}
+
+pub fn derive_timelock_config() -> Pubkey {
+    const_pda::timelock_config::ID
+}
+
+/// Pending operator action of `pool`, at most one per permission
+pub fn derive_pending_operator_action(pool: &Pubkey, permission: OperatorPermission) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PENDING_OPERATOR_ACTION_PREFIX,
+            pool.as_ref(),
+            &[u8::from(permission)],
+        ],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+/// Usage limit of the `operator` account
+pub fn derive_operator_usage_limit(operator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[OPERATOR_USAGE_LIMIT_PREFIX, operator.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
+
+pub fn derive_protocol_fee_distribution(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PROTOCOL_FEE_DISTRIBUTION_PREFIX, pool.as_ref()],
+        &cp_amm::ID,
+    )
+    .0
+}
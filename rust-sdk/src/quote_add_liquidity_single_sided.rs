@@ -0,0 +1,245 @@
+use crate::utils::*;
+use anyhow::{ensure, Context, Error, Result};
+use cp_amm::{
+    constants::fee::MAX_BASIS_POINT,
+    params::swap::TradeDirection,
+    state::{fee::FeeMode, CollectFeeMode, Pool, SwapResult2},
+    u128x128_math::Rounding,
+    AddLiquiditySingleSidedParameters,
+};
+use ruint::aliases::U256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddLiquiditySingleSidedQuote {
+    /// Amount of the deposit token swapped into the other token
+    pub swap_amount_in: u64,
+    pub swap_result: SwapResult2,
+    pub liquidity_delta: u128,
+    /// Token a amount deposited into the position after the swap
+    pub token_a_amount: u64,
+    /// Token b amount deposited into the position after the swap
+    pub token_b_amount: u64,
+    /// Swap output which can't be deposited, refunded to the user
+    pub refund_amount_out: u64,
+    /// Maximum amount of the deposit token transferred from the user, before Token-2022 transfer fee
+    pub max_amount_in: u64,
+    pub minimum_liquidity_delta: u128,
+}
+
+impl AddLiquiditySingleSidedQuote {
+    /// For Token-2022 deposit token with transfer fee, the transfer fee must be added to `max_amount_in`
+    pub fn to_add_liquidity_single_sided_parameters(
+        &self,
+        a_to_b: bool,
+    ) -> AddLiquiditySingleSidedParameters {
+        let trade_direction = if a_to_b {
+            TradeDirection::AtoB
+        } else {
+            TradeDirection::BtoA
+        };
+        AddLiquiditySingleSidedParameters {
+            swap_amount_in: self.swap_amount_in,
+            max_amount_in: self.max_amount_in,
+            minimum_liquidity_delta: self.minimum_liquidity_delta,
+            trade_direction: trade_direction.into(),
+        }
+    }
+}
+
+struct SimulatedDeposit {
+    swap_result: SwapResult2,
+    liquidity_from_input: u128,
+    liquidity_from_output: u128,
+    pool: Pool,
+}
+
+impl SimulatedDeposit {
+    fn liquidity_delta(&self) -> u128 {
+        self.liquidity_from_input.min(self.liquidity_from_output)
+    }
+}
+
+/// Find the swap amount which maximizes the liquidity minted from `amount_in` of a single token.
+/// `a_to_b` deposits token a, otherwise token b. `amount_in` excludes Token-2022 transfer fee
+pub fn get_quote(
+    pool: &Pool,
+    current_timestamp: u64,
+    current_slot: u64,
+    amount_in: u64,
+    a_to_b: bool,
+    slippage_bps: u16,
+) -> Result<AddLiquiditySingleSidedQuote> {
+    ensure!(amount_in > 1, "amount is too small");
+    ensure!(slippage_bps <= MAX_BASIS_POINT, "invalid slippage bps");
+
+    let current_point = get_current_point(pool.activation_type, current_slot, current_timestamp)?;
+    ensure!(is_swap_enable(pool, current_point)?, "Swap is disabled");
+
+    let trade_direction = if a_to_b {
+        TradeDirection::AtoB
+    } else {
+        TradeDirection::BtoA
+    };
+
+    if let Ok(rate_limiter) = pool.pool_fees.base_fee.to_fee_rate_limiter() {
+        ensure!(
+            !rate_limiter.is_rate_limiter_applied(
+                current_point,
+                pool.activation_point,
+                trade_direction
+            )?,
+            "Single sided add liquidity is disabled while rate limiter is applied"
+        );
+    }
+
+    let collect_fee_mode = CollectFeeMode::try_from(pool.collect_fee_mode)
+        .map_err(|_| Error::msg("Invalid collect fee mode"))?;
+    let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+    let (swap_amount_in, swap_result, post_swap_pool) = get_optimal_swap_amount(
+        pool,
+        current_timestamp,
+        current_point,
+        amount_in,
+        0,
+        &fee_mode,
+        trade_direction,
+    )?
+    .context("No valid swap amount")?;
+
+    let (available_token_a_amount, available_token_b_amount) = match trade_direction {
+        TradeDirection::AtoB => (amount_in - swap_amount_in, swap_result.output_amount),
+        TradeDirection::BtoA => (swap_result.output_amount, amount_in - swap_amount_in),
+    };
+
+    let liquidity_handler = post_swap_pool.get_liquidity_handler()?;
+    let liquidity_delta = liquidity_handler
+        .get_max_liquidity_delta_from_amounts(available_token_a_amount, available_token_b_amount)?;
+    ensure!(liquidity_delta > 0, "liquidity delta is zero");
+
+    let (token_a_amount, token_b_amount) =
+        liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)?;
+
+    let refund_amount_out = match trade_direction {
+        TradeDirection::AtoB => available_token_b_amount.checked_sub(token_b_amount),
+        TradeDirection::BtoA => available_token_a_amount.checked_sub(token_a_amount),
+    }
+    .ok_or_else(|| Error::msg("Math overflow"))?;
+
+    let minimum_liquidity_delta = U256::from(liquidity_delta)
+        .checked_mul(U256::from(MAX_BASIS_POINT - slippage_bps))
+        .and_then(|v| v.checked_div(U256::from(MAX_BASIS_POINT)))
+        .ok_or_else(|| Error::msg("Math overflow"))?;
+    let minimum_liquidity_delta =
+        u128::try_from(minimum_liquidity_delta).context("Type cast failed")?;
+
+    Ok(AddLiquiditySingleSidedQuote {
+        swap_amount_in,
+        swap_result,
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        refund_amount_out,
+        // the whole amount is spent at most, price movement is guarded by minimum_liquidity_delta
+        max_amount_in: amount_in,
+        minimum_liquidity_delta,
+    })
+}
+
+/// Swap amount out of `amount_in` which maximizes the liquidity minted from the remaining `amount_in`
+/// and `amount_out` plus the swap output. Returns the swap amount, swap result and the pool after the swap
+pub(crate) fn get_optimal_swap_amount(
+    pool: &Pool,
+    current_timestamp: u64,
+    current_point: u64,
+    amount_in: u64,
+    amount_out: u64,
+    fee_mode: &FeeMode,
+    trade_direction: TradeDirection,
+) -> Result<Option<(u64, SwapResult2, Pool)>> {
+    // liquidity minted from the remaining input decreases with the swap amount while liquidity minted from the output increases,
+    // so the optimal swap amount is where both sides cross
+    let mut best: Option<(u64, SimulatedDeposit)> = None;
+    let mut low = 1u64;
+    let mut high = amount_in;
+    while low <= high {
+        let swap_amount_in = low + (high - low) / 2;
+        let Ok(deposit) = simulate_deposit(
+            pool,
+            current_timestamp,
+            current_point,
+            amount_in,
+            amount_out,
+            swap_amount_in,
+            fee_mode,
+            trade_direction,
+        ) else {
+            // swap amount exceeds the pool's liquidity
+            high = swap_amount_in - 1;
+            continue;
+        };
+
+        let is_better = best.as_ref().map_or(true, |(_, best_deposit)| {
+            deposit.liquidity_delta() > best_deposit.liquidity_delta()
+        });
+        let swap_more = deposit.liquidity_from_input > deposit.liquidity_from_output;
+        if is_better {
+            best = Some((swap_amount_in, deposit));
+        }
+
+        if swap_more {
+            low = swap_amount_in + 1;
+        } else {
+            high = swap_amount_in - 1;
+        }
+    }
+
+    Ok(best.map(|(swap_amount_in, deposit)| (swap_amount_in, deposit.swap_result, deposit.pool)))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn simulate_deposit(
+    pool: &Pool,
+    current_timestamp: u64,
+    current_point: u64,
+    amount_in: u64,
+    amount_out: u64,
+    swap_amount_in: u64,
+    fee_mode: &FeeMode,
+    trade_direction: TradeDirection,
+) -> Result<SimulatedDeposit> {
+    let mut pool = *pool;
+    let mut swap_result = pool.get_swap_result_from_exact_input(
+        swap_amount_in,
+        fee_mode,
+        trade_direction,
+        current_point,
+    )?;
+    pool.apply_swap_result(&swap_result, fee_mode, trade_direction, current_timestamp)?;
+    swap_result.next_sqrt_price = pool.sqrt_price;
+
+    let remaining_amount_in = amount_in - swap_amount_in;
+    let total_amount_out = amount_out
+        .checked_add(swap_result.output_amount)
+        .ok_or_else(|| Error::msg("Math overflow"))?;
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let (liquidity_from_input, liquidity_from_output) = match trade_direction {
+        TradeDirection::AtoB => (
+            liquidity_handler
+                .get_max_liquidity_delta_from_amounts(remaining_amount_in, u64::MAX)?,
+            liquidity_handler.get_max_liquidity_delta_from_amounts(u64::MAX, total_amount_out)?,
+        ),
+        TradeDirection::BtoA => (
+            liquidity_handler
+                .get_max_liquidity_delta_from_amounts(u64::MAX, remaining_amount_in)?,
+            liquidity_handler.get_max_liquidity_delta_from_amounts(total_amount_out, u64::MAX)?,
+        ),
+    };
+
+    Ok(SimulatedDeposit {
+        swap_result,
+        liquidity_from_input,
+        liquidity_from_output,
+        pool,
+    })
+}
@@ -0,0 +1,147 @@
+use crate::{quote_add_liquidity_single_sided::get_optimal_swap_amount, utils::*};
+use anyhow::{ensure, Context, Error, Result};
+use cp_amm::{
+    constants::fee::MAX_BASIS_POINT,
+    params::swap::TradeDirection,
+    state::{fee::FeeMode, CollectFeeMode, Pool, Position, SwapResult2},
+    u128x128_math::Rounding,
+    CompoundPositionFeeParameters,
+};
+use ruint::aliases::U256;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompoundPositionFeeQuote {
+    /// Amount of pending fee swapped through the pool, zero when no swap is needed
+    pub swap_amount_in: u64,
+    /// Pending fee token swapped, none when no swap is needed
+    pub trade_direction: Option<TradeDirection>,
+    pub swap_result: Option<SwapResult2>,
+    pub liquidity_delta: u128,
+    /// Token a pending fee deposited into the position
+    pub token_a_amount: u64,
+    /// Token b pending fee deposited into the position
+    pub token_b_amount: u64,
+    pub minimum_liquidity_delta: u128,
+}
+
+impl CompoundPositionFeeQuote {
+    pub fn to_compound_position_fee_parameters(&self) -> CompoundPositionFeeParameters {
+        let trade_direction = self.trade_direction.unwrap_or(TradeDirection::AtoB);
+        CompoundPositionFeeParameters {
+            swap_amount_in: self.swap_amount_in,
+            trade_direction: trade_direction.into(),
+            minimum_liquidity_delta: self.minimum_liquidity_delta,
+        }
+    }
+}
+
+/// Quote for `compound_position_fee`. The surplus pending fee token is swapped when it mints more liquidity
+/// than depositing the pending fees as is. The position's share of the swap fee is not included
+pub fn get_quote(
+    pool: &Pool,
+    position: &Position,
+    current_timestamp: u64,
+    current_slot: u64,
+    slippage_bps: u16,
+) -> Result<CompoundPositionFeeQuote> {
+    ensure!(slippage_bps <= MAX_BASIS_POINT, "invalid slippage bps");
+
+    let mut position = *position;
+    position.update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())?;
+    let (fee_a_pending, fee_b_pending) = (position.fee_a_pending, position.fee_b_pending);
+    ensure!(
+        fee_a_pending > 0 || fee_b_pending > 0,
+        "No pending fee to compound"
+    );
+
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let liquidity_from_fee_a =
+        liquidity_handler.get_max_liquidity_delta_from_amounts(fee_a_pending, u64::MAX)?;
+    let liquidity_from_fee_b =
+        liquidity_handler.get_max_liquidity_delta_from_amounts(u64::MAX, fee_b_pending)?;
+    let mut liquidity_delta = liquidity_from_fee_a.min(liquidity_from_fee_b);
+    let mut post_deposit_pool = *pool;
+    let mut swap = None;
+
+    let current_point = get_current_point(pool.activation_type, current_slot, current_timestamp)?;
+    // the surplus side is swapped into the deficit side
+    let trade_direction = if liquidity_from_fee_a > liquidity_from_fee_b {
+        TradeDirection::AtoB
+    } else {
+        TradeDirection::BtoA
+    };
+    let is_rate_limited = match pool.pool_fees.base_fee.to_fee_rate_limiter() {
+        Ok(rate_limiter) => rate_limiter.is_rate_limiter_applied(
+            current_point,
+            pool.activation_point,
+            trade_direction,
+        )?,
+        Err(_) => false,
+    };
+
+    if liquidity_from_fee_a != liquidity_from_fee_b
+        && !is_rate_limited
+        && is_swap_enable(pool, current_point)?
+    {
+        let collect_fee_mode = CollectFeeMode::try_from(pool.collect_fee_mode)
+            .map_err(|_| Error::msg("Invalid collect fee mode"))?;
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+        let (amount_in, amount_out) = match trade_direction {
+            TradeDirection::AtoB => (fee_a_pending, fee_b_pending),
+            TradeDirection::BtoA => (fee_b_pending, fee_a_pending),
+        };
+
+        if let Some((swap_amount_in, swap_result, post_swap_pool)) = get_optimal_swap_amount(
+            pool,
+            current_timestamp,
+            current_point,
+            amount_in,
+            amount_out,
+            &fee_mode,
+            trade_direction,
+        )? {
+            let remaining_amount_in = amount_in - swap_amount_in;
+            let total_amount_out = amount_out
+                .checked_add(swap_result.output_amount)
+                .ok_or_else(|| Error::msg("Math overflow"))?;
+            let (available_token_a_amount, available_token_b_amount) = match trade_direction {
+                TradeDirection::AtoB => (remaining_amount_in, total_amount_out),
+                TradeDirection::BtoA => (total_amount_out, remaining_amount_in),
+            };
+            let swapped_liquidity_delta = post_swap_pool
+                .get_liquidity_handler()?
+                .get_max_liquidity_delta_from_amounts(
+                    available_token_a_amount,
+                    available_token_b_amount,
+                )?;
+
+            if swapped_liquidity_delta > liquidity_delta {
+                liquidity_delta = swapped_liquidity_delta;
+                post_deposit_pool = post_swap_pool;
+                swap = Some((swap_amount_in, trade_direction, swap_result));
+            }
+        }
+    }
+    ensure!(liquidity_delta > 0, "liquidity delta is zero");
+
+    let (token_a_amount, token_b_amount) = post_deposit_pool
+        .get_liquidity_handler()?
+        .get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)?;
+
+    let minimum_liquidity_delta = U256::from(liquidity_delta)
+        .checked_mul(U256::from(MAX_BASIS_POINT - slippage_bps))
+        .and_then(|v| v.checked_div(U256::from(MAX_BASIS_POINT)))
+        .ok_or_else(|| Error::msg("Math overflow"))?;
+    let minimum_liquidity_delta =
+        u128::try_from(minimum_liquidity_delta).context("Type cast failed")?;
+
+    Ok(CompoundPositionFeeQuote {
+        swap_amount_in: swap.map_or(0, |(swap_amount_in, _, _)| swap_amount_in),
+        trade_direction: swap.map(|(_, trade_direction, _)| trade_direction),
+        swap_result: swap.map(|(_, _, swap_result)| swap_result),
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        minimum_liquidity_delta,
+    })
+}
@@ -0,0 +1,224 @@
+use crate::utils::*;
+use anyhow::{ensure, Context, Error, Ok, Result};
+use cp_amm::{
+    constants::fee::MAX_BASIS_POINT,
+    liquidity_handler::{concentrated_liquidity, get_liquidity_delta_from_reserve},
+    state::{CollectFeeMode, Pool, Position},
+    u128x128_math::Rounding,
+    AddLiquidityParameters, RemoveLiquidityParameters,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiquidityQuote {
+    pub liquidity_delta: u128,
+    /// Token a amount transferred by the program, before Token-2022 transfer fee
+    pub token_a_amount: u64,
+    /// Token b amount transferred by the program, before Token-2022 transfer fee
+    pub token_b_amount: u64,
+    /// When adding liquidity, this is the maximum token a amount. When removing, this is the minimum token a amount
+    pub token_a_amount_threshold: u64,
+    /// When adding liquidity, this is the maximum token b amount. When removing, this is the minimum token b amount
+    pub token_b_amount_threshold: u64,
+}
+
+impl LiquidityQuote {
+    pub fn to_add_liquidity_parameters(&self) -> AddLiquidityParameters {
+        AddLiquidityParameters {
+            liquidity_delta: self.liquidity_delta,
+            token_a_amount_threshold: self.token_a_amount_threshold,
+            token_b_amount_threshold: self.token_b_amount_threshold,
+        }
+    }
+
+    pub fn to_remove_liquidity_parameters(&self) -> RemoveLiquidityParameters {
+        RemoveLiquidityParameters {
+            liquidity_delta: self.liquidity_delta,
+            token_a_amount_threshold: self.token_a_amount_threshold,
+            token_b_amount_threshold: self.token_b_amount_threshold,
+        }
+    }
+}
+
+/// Maximum liquidity delta can be minted by depositing at most `token_a_amount` of token a
+pub fn get_liquidity_delta_from_amount_a(pool: &Pool, token_a_amount: u64) -> Result<u128> {
+    let collect_fee_mode = CollectFeeMode::try_from(pool.collect_fee_mode)
+        .map_err(|_| Error::msg("Invalid collect fee mode"))?;
+
+    // compounding and stable swap liquidity are shares of the reserves
+    if matches!(
+        collect_fee_mode,
+        CollectFeeMode::Compounding | CollectFeeMode::StableSwap
+    ) {
+        ensure!(pool.token_a_amount > 0, "token a reserve is zero");
+        return Ok(get_liquidity_delta_from_reserve(
+            token_a_amount,
+            pool.token_a_amount,
+            pool.liquidity,
+        )?);
+    }
+
+    ensure!(
+        pool.sqrt_max_price > pool.sqrt_price,
+        "token a is not required at current price"
+    );
+    Ok(concentrated_liquidity::get_liquidity_delta_from_amount_a(
+        pool.sqrt_price,
+        pool.sqrt_max_price,
+        token_a_amount,
+    )?)
+}
+
+/// Maximum liquidity delta can be minted by depositing at most `token_b_amount` of token b
+pub fn get_liquidity_delta_from_amount_b(pool: &Pool, token_b_amount: u64) -> Result<u128> {
+    let collect_fee_mode = CollectFeeMode::try_from(pool.collect_fee_mode)
+        .map_err(|_| Error::msg("Invalid collect fee mode"))?;
+
+    if matches!(
+        collect_fee_mode,
+        CollectFeeMode::Compounding | CollectFeeMode::StableSwap
+    ) {
+        ensure!(pool.token_b_amount > 0, "token b reserve is zero");
+        return Ok(get_liquidity_delta_from_reserve(
+            token_b_amount,
+            pool.token_b_amount,
+            pool.liquidity,
+        )?);
+    }
+
+    ensure!(
+        pool.sqrt_price > pool.sqrt_min_price,
+        "token b is not required at current price"
+    );
+    Ok(concentrated_liquidity::get_liquidity_delta_from_amount_b(
+        pool.sqrt_min_price,
+        pool.sqrt_price,
+        token_b_amount,
+    )?)
+}
+
+/// Maximum liquidity delta can be minted without spending more than `max_token_a_amount` and `max_token_b_amount`
+pub fn get_liquidity_delta(
+    pool: &Pool,
+    max_token_a_amount: u64,
+    max_token_b_amount: u64,
+) -> Result<u128> {
+    let collect_fee_mode = CollectFeeMode::try_from(pool.collect_fee_mode)
+        .map_err(|_| Error::msg("Invalid collect fee mode"))?;
+
+    // concentrated pool at the edge of price range only requires a single token
+    if !matches!(
+        collect_fee_mode,
+        CollectFeeMode::Compounding | CollectFeeMode::StableSwap
+    ) {
+        if pool.sqrt_price >= pool.sqrt_max_price {
+            return get_liquidity_delta_from_amount_b(pool, max_token_b_amount);
+        }
+        if pool.sqrt_price <= pool.sqrt_min_price {
+            return get_liquidity_delta_from_amount_a(pool, max_token_a_amount);
+        }
+    }
+
+    let liquidity_delta_from_a = get_liquidity_delta_from_amount_a(pool, max_token_a_amount)?;
+    let liquidity_delta_from_b = get_liquidity_delta_from_amount_b(pool, max_token_b_amount)?;
+
+    Ok(liquidity_delta_from_a.min(liquidity_delta_from_b))
+}
+
+pub fn get_add_liquidity_quote(
+    pool: &Pool,
+    liquidity_delta: u128,
+    slippage_bps: u16,
+) -> Result<LiquidityQuote> {
+    ensure!(liquidity_delta > 0, "liquidity delta is zero");
+    ensure!(slippage_bps <= MAX_BASIS_POINT, "invalid slippage bps");
+
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let (token_a_amount, token_b_amount) =
+        liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)?;
+
+    ensure!(token_a_amount > 0 || token_b_amount > 0, "amount is zero");
+
+    Ok(LiquidityQuote {
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        token_a_amount_threshold: get_maximum_amount_with_slippage(token_a_amount, slippage_bps)?,
+        token_b_amount_threshold: get_maximum_amount_with_slippage(token_b_amount, slippage_bps)?,
+    })
+}
+
+pub fn get_remove_liquidity_quote(
+    pool: &Pool,
+    liquidity_delta: u128,
+    slippage_bps: u16,
+) -> Result<LiquidityQuote> {
+    ensure!(liquidity_delta > 0, "liquidity delta is zero");
+    ensure!(
+        liquidity_delta <= pool.liquidity,
+        "liquidity delta exceeds pool liquidity"
+    );
+    ensure!(slippage_bps <= MAX_BASIS_POINT, "invalid slippage bps");
+
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let (token_a_amount, token_b_amount) =
+        liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Down)?;
+
+    ensure!(token_a_amount > 0 || token_b_amount > 0, "amount is zero");
+
+    Ok(LiquidityQuote {
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        token_a_amount_threshold: get_minimum_amount_with_slippage(token_a_amount, slippage_bps)?,
+        token_b_amount_threshold: get_minimum_amount_with_slippage(token_b_amount, slippage_bps)?,
+    })
+}
+
+/// Quote for `remove_all_liquidity`, inner vesting is refreshed before the unlocked liquidity is read, same as on-chain
+pub fn get_remove_all_liquidity_quote(
+    pool: &Pool,
+    position: &Position,
+    current_timestamp: u64,
+    current_slot: u64,
+    slippage_bps: u16,
+) -> Result<LiquidityQuote> {
+    let current_point = get_current_point(pool.activation_type, current_slot, current_timestamp)?;
+    ensure!(
+        current_point >= pool.activation_point,
+        "Remove liquidity is disabled"
+    );
+
+    let mut position = *position;
+    position.refresh_inner_vesting(current_point)?;
+
+    get_remove_liquidity_quote(pool, position.unlocked_liquidity, slippage_bps)
+}
+
+/// Token amounts backing the total liquidity (unlocked, vested and permanent locked) of a position, excluding pending fees and rewards
+pub fn get_position_value(pool: &Pool, position: &Position) -> Result<(u64, u64)> {
+    let total_liquidity = position.get_total_liquidity()?;
+    if total_liquidity == 0 {
+        return Ok((0, 0));
+    }
+
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    Ok(liquidity_handler.get_amounts_for_modify_liquidity(total_liquidity, Rounding::Down)?)
+}
+
+fn get_maximum_amount_with_slippage(amount: u64, slippage_bps: u16) -> Result<u64> {
+    let max_basis_point = u128::from(MAX_BASIS_POINT);
+    let amount = u128::from(amount)
+        .checked_mul(max_basis_point + u128::from(slippage_bps))
+        .ok_or_else(|| Error::msg("Math overflow"))?
+        .div_ceil(max_basis_point);
+    Ok(u64::try_from(amount).unwrap_or(u64::MAX))
+}
+
+fn get_minimum_amount_with_slippage(amount: u64, slippage_bps: u16) -> Result<u64> {
+    let max_basis_point = u128::from(MAX_BASIS_POINT);
+    let amount = u128::from(amount)
+        .checked_mul(max_basis_point - u128::from(slippage_bps))
+        .ok_or_else(|| Error::msg("Math overflow"))?
+        / max_basis_point;
+    u64::try_from(amount).context("Type cast failed")
+}
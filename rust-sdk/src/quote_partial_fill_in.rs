@@ -12,6 +12,27 @@ pub fn get_quote(
     actual_amount_in: u64,
     a_to_b: bool,
     has_referral: bool,
+) -> Result<SwapResult2> {
+    get_quote_with_sqrt_price_limit(
+        pool,
+        current_timestamp,
+        current_slot,
+        actual_amount_in,
+        a_to_b,
+        has_referral,
+        None,
+    )
+}
+
+/// Quote for [cp_amm::SwapMode::PartialFillWithPriceLimit], the swap stops when the price reaches `sqrt_price_limit`
+pub fn get_quote_with_sqrt_price_limit(
+    pool: &Pool,
+    current_timestamp: u64,
+    current_slot: u64,
+    actual_amount_in: u64,
+    a_to_b: bool,
+    has_referral: bool,
+    sqrt_price_limit: Option<u128>,
 ) -> Result<SwapResult2> {
     ensure!(actual_amount_in > 0, "amount is zero");
 
@@ -34,6 +55,7 @@ pub fn get_quote(
         &fee_mode,
         trade_direction,
         current_point,
+        sqrt_price_limit,
     )?;
 
     swap_result.next_sqrt_price = apply_next_sqrt_price(
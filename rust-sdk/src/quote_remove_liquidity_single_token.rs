@@ -0,0 +1,145 @@
+use crate::utils::*;
+use anyhow::{ensure, Context, Error, Result};
+use cp_amm::{
+    constants::fee::MAX_BASIS_POINT,
+    params::swap::TradeDirection,
+    state::{fee::FeeMode, CollectFeeMode, Pool, Position, SwapResult2},
+    u128x128_math::Rounding,
+    RemoveLiquiditySingleTokenParameters,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoveLiquiditySingleTokenQuote {
+    pub liquidity_delta: u128,
+    /// Token a amount withdrawn from the position
+    pub token_a_amount: u64,
+    /// Token b amount withdrawn from the position
+    pub token_b_amount: u64,
+    /// Swap of the withdrawn input token, none when no input token is withdrawn
+    pub swap_result: Option<SwapResult2>,
+    /// Output token amount transferred by the program, before Token-2022 transfer fee
+    pub amount_out: u64,
+    pub minimum_amount_out: u64,
+}
+
+impl RemoveLiquiditySingleTokenQuote {
+    /// For Token-2022 output token with transfer fee, the transfer fee must be deducted from `minimum_amount_out`
+    pub fn to_remove_liquidity_single_token_parameters(
+        &self,
+        a_to_b: bool,
+    ) -> RemoveLiquiditySingleTokenParameters {
+        let trade_direction = if a_to_b {
+            TradeDirection::AtoB
+        } else {
+            TradeDirection::BtoA
+        };
+        RemoveLiquiditySingleTokenParameters {
+            liquidity_delta: self.liquidity_delta,
+            minimum_amount_out: self.minimum_amount_out,
+            trade_direction: trade_direction.into(),
+        }
+    }
+}
+
+/// Quote for `remove_liquidity_single_token`. `a_to_b` swaps the withdrawn token a and receives token b,
+/// otherwise swaps the withdrawn token b and receives token a
+pub fn get_quote(
+    pool: &Pool,
+    current_timestamp: u64,
+    current_slot: u64,
+    liquidity_delta: u128,
+    a_to_b: bool,
+    slippage_bps: u16,
+) -> Result<RemoveLiquiditySingleTokenQuote> {
+    ensure!(liquidity_delta > 0, "liquidity delta is zero");
+    ensure!(
+        liquidity_delta <= pool.liquidity,
+        "liquidity delta exceeds pool liquidity"
+    );
+    ensure!(slippage_bps <= MAX_BASIS_POINT, "invalid slippage bps");
+
+    let current_point = get_current_point(pool.activation_type, current_slot, current_timestamp)?;
+    ensure!(is_swap_enable(pool, current_point)?, "Swap is disabled");
+
+    let trade_direction = if a_to_b {
+        TradeDirection::AtoB
+    } else {
+        TradeDirection::BtoA
+    };
+
+    if let Ok(rate_limiter) = pool.pool_fees.base_fee.to_fee_rate_limiter() {
+        ensure!(
+            !rate_limiter.is_rate_limiter_applied(
+                current_point,
+                pool.activation_point,
+                trade_direction
+            )?,
+            "Single token remove liquidity is disabled while rate limiter is applied"
+        );
+    }
+
+    let mut pool = *pool;
+    let liquidity_handler = pool.get_liquidity_handler()?;
+    let (token_a_amount, token_b_amount) =
+        liquidity_handler.get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Down)?;
+    ensure!(token_a_amount > 0 || token_b_amount > 0, "amount is zero");
+
+    let mut position = Position {
+        unlocked_liquidity: liquidity_delta,
+        ..Default::default()
+    };
+    pool.apply_remove_liquidity(
+        &mut position,
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+    )?;
+
+    let (swap_amount_in, withdrawn_amount_out) = match trade_direction {
+        TradeDirection::AtoB => (token_a_amount, token_b_amount),
+        TradeDirection::BtoA => (token_b_amount, token_a_amount),
+    };
+
+    let swap_result = if swap_amount_in > 0 {
+        let collect_fee_mode = CollectFeeMode::try_from(pool.collect_fee_mode)
+            .map_err(|_| Error::msg("Invalid collect fee mode"))?;
+        let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+
+        let mut swap_result = pool.get_swap_result_from_exact_input(
+            swap_amount_in,
+            &fee_mode,
+            trade_direction,
+            current_point,
+        )?;
+        swap_result.next_sqrt_price = apply_next_sqrt_price(
+            &pool,
+            &swap_result,
+            &fee_mode,
+            trade_direction,
+            collect_fee_mode,
+        )?;
+        Some(swap_result)
+    } else {
+        None
+    };
+
+    let swap_amount_out = swap_result.map_or(0, |swap_result| swap_result.output_amount);
+    let amount_out = withdrawn_amount_out
+        .checked_add(swap_amount_out)
+        .ok_or_else(|| Error::msg("Math overflow"))?;
+
+    let max_basis_point = u128::from(MAX_BASIS_POINT);
+    let minimum_amount_out = u128::from(amount_out)
+        .checked_mul(max_basis_point - u128::from(slippage_bps))
+        .ok_or_else(|| Error::msg("Math overflow"))?
+        / max_basis_point;
+
+    Ok(RemoveLiquiditySingleTokenQuote {
+        liquidity_delta,
+        token_a_amount,
+        token_b_amount,
+        swap_result,
+        amount_out,
+        minimum_amount_out: u64::try_from(minimum_amount_out).context("Type cast failed")?,
+    })
+}
@@ -1,8 +1,16 @@
+pub mod test_calculate_claimable;
 pub mod test_calculate_compounding_liquidity;
 pub mod test_calculate_concentrated_initial_sqrt_price;
+pub mod test_events;
+pub mod test_instructions;
+pub mod test_quote_add_liquidity_single_sided;
+pub mod test_quote_compound_position_fee;
 pub mod test_quote_exact_in;
 pub mod test_quote_exact_out;
+pub mod test_quote_liquidity;
 pub mod test_quote_partial_fill_in;
+pub mod test_quote_remove_liquidity_single_token;
+pub mod test_twap;
 
 use cp_amm::{
     get_initial_pool_information,
@@ -36,7 +44,7 @@ fn get_compounding_pool(token_a_amount: u64, token_b_amount: u64) -> Pool {
         token_b_amount,
         sqrt_price,
         ..
-    } = get_initial_pool_information(CollectFeeMode::Compounding, 0, 0, sqrt_price, liquidity)
+    } = get_initial_pool_information(CollectFeeMode::Compounding, 0, 0, sqrt_price, liquidity, 0)
         .expect("Failed to get initial pool information");
 
     Pool {
@@ -0,0 +1,124 @@
+use cp_amm::{
+    constants::NUM_REWARDS,
+    state::{Pool, PoolRewardExtension, Position, PositionRewardExtension, RewardInfo},
+};
+use ruint::aliases::U256;
+
+use crate::calculate_claimable;
+
+const LIQUIDITY: u128 = 1_000_000u128 << 64;
+
+fn get_pool_with_reward(reward_rate_per_second: u64, reward_duration_end: u64) -> Pool {
+    let mut pool = Pool {
+        liquidity: LIQUIDITY,
+        ..Default::default()
+    };
+    pool.reward_infos[0] = RewardInfo {
+        initialized: 1,
+        reward_duration: reward_duration_end,
+        reward_duration_end,
+        reward_rate: u128::from(reward_rate_per_second) << 64,
+        ..Default::default()
+    };
+    pool
+}
+
+#[test]
+fn test_claimable_fee_matches_checkpoint_delta() {
+    let mut pool = Pool {
+        liquidity: LIQUIDITY,
+        ..Default::default()
+    };
+    // 1000 token a and 2000 token b per 2^64 liquidity
+    pool.fee_a_per_liquidity = (U256::from(1_000u64) << 64).to_le_bytes();
+    pool.fee_b_per_liquidity = (U256::from(2_000u64) << 64).to_le_bytes();
+
+    let position = Position {
+        unlocked_liquidity: LIQUIDITY / 4,
+        permanent_locked_liquidity: LIQUIDITY / 4,
+        fee_a_pending: 7,
+        ..Default::default()
+    };
+
+    let (fee_a, fee_b) = calculate_claimable::get_claimable_fee(&pool, &position).unwrap();
+    let total_liquidity = (LIQUIDITY / 2) >> 64;
+    assert_eq!(fee_a, (total_liquidity * 1_000) as u64 + 7);
+    assert_eq!(fee_b, (total_liquidity * 2_000) as u64);
+
+    // position already checkpointed at the latest pool state only has its pending fee
+    let mut checkpointed_position = position;
+    checkpointed_position.fee_a_per_token_checkpoint = pool.fee_a_per_liquidity;
+    checkpointed_position.fee_b_per_token_checkpoint = pool.fee_b_per_liquidity;
+    let (fee_a, fee_b) =
+        calculate_claimable::get_claimable_fee(&pool, &checkpointed_position).unwrap();
+    assert_eq!(fee_a, 7);
+    assert_eq!(fee_b, 0);
+}
+
+#[test]
+fn test_claimable_rewards_single_position() {
+    let pool = get_pool_with_reward(1_000, 100);
+    let position = Position {
+        unlocked_liquidity: LIQUIDITY,
+        ..Default::default()
+    };
+
+    let rewards = calculate_claimable::get_claimable_rewards(&pool, &position, None, 50).unwrap();
+    assert!(rewards[0] <= 50_000 && 50_000 - rewards[0] <= 1);
+    assert_eq!(rewards[1], 0);
+
+    // reward stops accruing after reward duration end
+    let rewards_at_end =
+        calculate_claimable::get_claimable_rewards(&pool, &position, None, 100).unwrap();
+    let rewards_after_end =
+        calculate_claimable::get_claimable_rewards(&pool, &position, None, 1_000).unwrap();
+    assert_eq!(rewards_at_end, rewards_after_end);
+    assert!(rewards_at_end[0] <= 100_000 && 100_000 - rewards_at_end[0] <= 1);
+}
+
+#[test]
+fn test_claimable_amounts_does_not_mutate_input() {
+    let pool = get_pool_with_reward(1_000, 100);
+    let position = Position {
+        unlocked_liquidity: LIQUIDITY / 2,
+        ..Default::default()
+    };
+
+    let claimable =
+        calculate_claimable::get_claimable_amounts(&pool, &position, None, 100).unwrap();
+    assert!(claimable.rewards[0] > 0);
+
+    // replaying twice from the same snapshot returns the same amounts
+    let claimable_again =
+        calculate_claimable::get_claimable_amounts(&pool, &position, None, 100).unwrap();
+    assert_eq!(claimable, claimable_again);
+    assert_eq!(pool.reward_infos[0].last_update_time, 0);
+    assert_eq!(position.reward_infos[0].reward_pendings, 0);
+}
+
+#[test]
+fn test_claimable_extended_rewards() {
+    let mut pool = get_pool_with_reward(1_000, 100);
+    pool.set_extended_reward_initialized();
+    let mut pool_reward_extension = PoolRewardExtension::default();
+    pool_reward_extension.reward_infos[1] = pool.reward_infos[0];
+    let position_reward_extension = PositionRewardExtension::default();
+    let position = Position {
+        unlocked_liquidity: LIQUIDITY,
+        ..Default::default()
+    };
+
+    // extended rewards can't be projected without the reward extensions
+    assert!(calculate_claimable::get_claimable_rewards(&pool, &position, None, 50).is_err());
+
+    let rewards = calculate_claimable::get_claimable_rewards(
+        &pool,
+        &position,
+        Some((&pool_reward_extension, &position_reward_extension)),
+        50,
+    )
+    .unwrap();
+    assert_eq!(rewards[NUM_REWARDS], 0);
+    assert_eq!(rewards[NUM_REWARDS + 1], rewards[0]);
+    assert!(rewards[NUM_REWARDS + 1] > 0);
+}
@@ -30,6 +30,7 @@ proptest! {
             0,
             sqrt_price,
             liquidity,
+            0,
         ).unwrap();
 
         println!("a {} {}", token_a_amount, a);
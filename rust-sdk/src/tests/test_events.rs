@@ -0,0 +1,123 @@
+use anchor_lang::{event::EVENT_IX_TAG_LE, prelude::Pubkey, Event};
+use cp_amm::{EvtClaimPositionFee, EvtFlashBorrow, EvtFlashRepay, EvtSetPoolStatus};
+
+use crate::events::{self, CpAmmEvent};
+
+fn to_event_instruction_data(event: &impl Event) -> Vec<u8> {
+    let mut data = EVENT_IX_TAG_LE.to_vec();
+    data.extend(event.data());
+    data
+}
+
+#[test]
+fn test_decode_event() {
+    let pool = Pubkey::new_unique();
+    let data = to_event_instruction_data(&EvtClaimPositionFee {
+        pool,
+        position: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        fee_a_claimed: 100,
+        fee_b_claimed: 200,
+    });
+
+    let CpAmmEvent::EvtClaimPositionFee(event) = events::decode_event(&data).unwrap() else {
+        panic!("Expected EvtClaimPositionFee");
+    };
+    assert_eq!(event.pool, pool);
+    assert_eq!(event.fee_a_claimed, 100);
+    assert_eq!(event.fee_b_claimed, 200);
+
+    // missing event tag
+    assert!(events::decode_event(&data[EVENT_IX_TAG_LE.len()..]).is_err());
+}
+
+#[test]
+fn test_decode_flash_loan_events() {
+    let pool = Pubkey::new_unique();
+    let borrower = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+
+    let data = to_event_instruction_data(&EvtFlashBorrow {
+        pool,
+        borrower,
+        token_mint,
+        amount: 1_000,
+    });
+    let CpAmmEvent::EvtFlashBorrow(event) = events::decode_event(&data).unwrap() else {
+        panic!("Expected EvtFlashBorrow");
+    };
+    assert_eq!(event.pool, pool);
+    assert_eq!(event.borrower, borrower);
+    assert_eq!(event.token_mint, token_mint);
+    assert_eq!(event.amount, 1_000);
+
+    let data = to_event_instruction_data(&EvtFlashRepay {
+        pool,
+        borrower,
+        token_mint,
+        amount: 1_000,
+        fee: 10,
+        claiming_fee: 6,
+        compounding_fee: 0,
+        protocol_fee: 2,
+        creator_fee: 2,
+        transfer_fee_included_repay_amount: 1_010,
+    });
+    let CpAmmEvent::EvtFlashRepay(event) = events::decode_event(&data).unwrap() else {
+        panic!("Expected EvtFlashRepay");
+    };
+    assert_eq!(event.pool, pool);
+    assert_eq!(event.amount, 1_000);
+    assert_eq!(event.fee, 10);
+    assert_eq!(event.transfer_fee_included_repay_amount, 1_010);
+}
+
+#[test]
+fn test_decode_events_from_inner_instructions() {
+    let pool = Pubkey::new_unique();
+    let other_program = Pubkey::new_unique();
+
+    let set_pool_status_data = to_event_instruction_data(&EvtSetPoolStatus {
+        pool,
+        status: 1,
+        previous_status: 0,
+        changed_actions: 1,
+    });
+    let claim_fee_data = to_event_instruction_data(&EvtClaimPositionFee {
+        pool,
+        position: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        fee_a_claimed: 1,
+        fee_b_claimed: 2,
+    });
+    let token_transfer_data = vec![3u8, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    let inner_instructions: Vec<(Pubkey, Vec<u8>)> = vec![
+        (other_program, set_pool_status_data.clone()),
+        (cp_amm::ID, set_pool_status_data),
+        (other_program, token_transfer_data),
+        (cp_amm::ID, claim_fee_data),
+    ];
+
+    let decoded_events = events::decode_events(
+        inner_instructions
+            .iter()
+            .map(|(program_id, data)| (program_id, data.as_slice())),
+    )
+    .collect::<anyhow::Result<Vec<_>>>()
+    .unwrap();
+
+    assert_eq!(decoded_events.len(), 2);
+    assert!(matches!(
+        decoded_events[0],
+        CpAmmEvent::EvtSetPoolStatus(EvtSetPoolStatus { status: 1, .. })
+    ));
+    assert!(matches!(
+        decoded_events[1],
+        CpAmmEvent::EvtClaimPositionFee(EvtClaimPositionFee {
+            fee_a_claimed: 1,
+            fee_b_claimed: 2,
+            ..
+        })
+    ));
+}
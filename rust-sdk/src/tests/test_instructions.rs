@@ -0,0 +1,538 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{instruction::Instruction, sysvar},
+};
+use cp_amm::{
+    base_fee::fee_rate_limiter::PodAlignedFeeRateLimiter,
+    constants::NUM_REWARDS,
+    params::swap::TradeDirection,
+    state::{fee::BaseFeeMode, OperatorAction, OperatorPermission, Pool, PoolRewardExtension},
+    DynamicConfigParameters, SetPositionDelegateParameters, SwapParameters2, SwapRouteParameters,
+    SWAP_ROUTE_HOP_ACCOUNTS,
+};
+
+use crate::{
+    instructions::{admin, allowlist, liquidity, operator, position, reward, swap, timelock},
+    pda,
+    tests::{get_pool_account, SOL_USDC_CL_ADDRESS},
+};
+
+fn get_swap_accounts() -> swap::SwapAccounts {
+    swap::SwapAccounts {
+        pool: Pubkey::new_unique(),
+        payer: Pubkey::new_unique(),
+        input_token_account: Pubkey::new_unique(),
+        output_token_account: Pubkey::new_unique(),
+        referral_token_account: None,
+    }
+}
+
+fn get_rate_limiter_pool() -> Pool {
+    let mut pool = Pool {
+        activation_point: 1_000,
+        ..Default::default()
+    };
+    let rate_limiter = PodAlignedFeeRateLimiter {
+        cliff_fee_numerator: 10_000_000,
+        reference_amount: 1_000_000_000,
+        max_limiter_duration: 60,
+        max_fee_bps: 5000,
+        fee_increment_bps: 10,
+        base_fee_mode: BaseFeeMode::RateLimiter.into(),
+        ..Default::default()
+    };
+    pool.pool_fees
+        .base_fee
+        .base_fee_info
+        .data
+        .copy_from_slice(bytemuck::bytes_of(&rate_limiter));
+    pool
+}
+
+fn has_instructions_sysvar(instruction: &Instruction) -> bool {
+    instruction
+        .accounts
+        .iter()
+        .any(|account| account.pubkey == sysvar::instructions::ID)
+}
+
+#[test]
+fn test_swap_accounts_derived_from_pool() {
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    let accounts = get_swap_accounts();
+
+    let instruction = swap::swap2(
+        &accounts,
+        &pool,
+        TradeDirection::AtoB,
+        0,
+        SwapParameters2::default(),
+    )
+    .unwrap();
+
+    assert_eq!(instruction.program_id, cp_amm::ID);
+    assert_eq!(instruction.accounts[0].pubkey, pda::derive_pool_authority());
+    assert_eq!(instruction.accounts[1].pubkey, accounts.pool);
+    assert_eq!(instruction.accounts[4].pubkey, pool.token_a_vault);
+    assert_eq!(instruction.accounts[5].pubkey, pool.token_b_vault);
+    // referral token account is optional, and replaced by program id
+    assert_eq!(instruction.accounts[11].pubkey, cp_amm::ID);
+    assert_eq!(
+        instruction.accounts[12].pubkey,
+        pda::derive_event_authority()
+    );
+    assert!(!has_instructions_sysvar(&instruction));
+}
+
+#[test]
+fn test_swap_append_instructions_sysvar_for_rate_limiter() {
+    let pool = get_rate_limiter_pool();
+    let accounts = get_swap_accounts();
+
+    let swap_with = |trade_direction, current_point| {
+        swap::swap2(
+            &accounts,
+            &pool,
+            trade_direction,
+            current_point,
+            SwapParameters2::default(),
+        )
+        .unwrap()
+    };
+
+    // rate limiter only applies to B to A
+    assert!(!has_instructions_sysvar(&swap_with(
+        TradeDirection::AtoB,
+        1_000
+    )));
+    assert!(has_instructions_sysvar(&swap_with(
+        TradeDirection::BtoA,
+        1_000
+    )));
+    // built before activation but might be landed after activation
+    assert!(has_instructions_sysvar(&swap_with(TradeDirection::BtoA, 0)));
+    // rate limiter is over
+    assert!(!has_instructions_sysvar(&swap_with(
+        TradeDirection::BtoA,
+        1_061
+    )));
+}
+
+#[test]
+fn test_swap_route_hop_accounts() {
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    let payer = Pubkey::new_unique();
+    let input_token_account = Pubkey::new_unique();
+    let hops = [
+        swap::SwapRouteHopAccounts {
+            pool: Pubkey::new_unique(),
+            pool_state: &pool,
+            output_token_account: Pubkey::new_unique(),
+            has_oracle: false,
+        },
+        swap::SwapRouteHopAccounts {
+            pool: Pubkey::new_unique(),
+            pool_state: &pool,
+            output_token_account: Pubkey::new_unique(),
+            has_oracle: true,
+        },
+    ];
+
+    let instruction = swap::swap_route(
+        payer,
+        input_token_account,
+        &hops,
+        SwapRouteParameters {
+            amount_in: 1_000,
+            minimum_amount_out: 0,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(instruction.accounts[1].pubkey, input_token_account);
+    assert!(instruction.accounts[2].is_signer);
+    assert!(has_instructions_sysvar(&instruction));
+
+    // 6 accounts of the context, followed by the hops and the oracles
+    let (hop_accounts, oracle_accounts) =
+        instruction.accounts[6..].split_at(hops.len() * SWAP_ROUTE_HOP_ACCOUNTS);
+    assert_eq!(oracle_accounts.len(), 1);
+    assert_eq!(oracle_accounts[0].pubkey, pda::derive_oracle(&hops[1].pool));
+    assert!(oracle_accounts[0].is_writable);
+    for (hop, accounts) in hops
+        .iter()
+        .zip(hop_accounts.chunks(SWAP_ROUTE_HOP_ACCOUNTS))
+    {
+        assert_eq!(accounts[0].pubkey, hop.pool);
+        assert!(accounts[0].is_writable);
+        assert_eq!(accounts[1].pubkey, hop.output_token_account);
+        assert_eq!(accounts[2].pubkey, pool.token_a_vault);
+        assert_eq!(accounts[5].pubkey, pool.token_b_mint);
+    }
+}
+
+#[test]
+fn test_create_position_derive_pda() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let position_nft_mint = Pubkey::new_unique();
+
+    let instruction = position::create_position(pool, owner, position_nft_mint, owner);
+
+    let position_nft_account = Pubkey::find_program_address(
+        &[b"position_nft_account", position_nft_mint.as_ref()],
+        &cp_amm::ID,
+    )
+    .0;
+    let position =
+        Pubkey::find_program_address(&[b"position", position_nft_mint.as_ref()], &cp_amm::ID).0;
+
+    assert_eq!(instruction.accounts[1].pubkey, position_nft_mint);
+    assert!(instruction.accounts[1].is_signer);
+    assert_eq!(instruction.accounts[2].pubkey, position_nft_account);
+    assert_eq!(instruction.accounts[4].pubkey, position);
+}
+
+#[test]
+fn test_position_delegate_derive_pda() {
+    let accounts = position::PositionAccounts {
+        pool: Pubkey::new_unique(),
+        position: Pubkey::new_unique(),
+        position_nft_account: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+    };
+    let params = SetPositionDelegateParameters {
+        delegate: Pubkey::new_unique(),
+        payout_destination: Pubkey::new_unique(),
+        permission: 0b111,
+    };
+
+    let position_delegate = Pubkey::find_program_address(
+        &[
+            b"position_delegate",
+            accounts.position.as_ref(),
+            accounts.owner.as_ref(),
+        ],
+        &cp_amm::ID,
+    )
+    .0;
+
+    let instruction = position::set_position_delegate(&accounts, params, accounts.owner);
+    assert_eq!(instruction.accounts[2].pubkey, position_delegate);
+
+    let instruction = position::revoke_position_delegate(accounts.position, accounts.owner);
+    assert_eq!(instruction.accounts[0].pubkey, position_delegate);
+    assert!(instruction.accounts[0].is_writable);
+
+    let remaining_account =
+        position::get_position_delegate_remaining_account(&accounts.position, &accounts.owner);
+    assert_eq!(remaining_account.pubkey, position_delegate);
+    assert!(!remaining_account.is_writable);
+}
+
+#[test]
+fn test_claim_extended_reward_remaining_accounts() {
+    let accounts = position::PositionAccounts {
+        pool: Pubkey::new_unique(),
+        position: Pubkey::new_unique(),
+        position_nft_account: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+    };
+    let pool = Pool::default();
+    let mut pool_reward_extension = PoolRewardExtension::default();
+    let reward_vault = Pubkey::new_unique();
+    pool_reward_extension.reward_infos[0].vault = reward_vault;
+    let reward_index = NUM_REWARDS as u8;
+
+    // extended rewards are read from the pool reward extension
+    assert!(reward::claim_reward(
+        &accounts,
+        &pool,
+        None,
+        Pubkey::new_unique(),
+        reward_index,
+        false
+    )
+    .is_err());
+
+    let instruction = reward::claim_reward(
+        &accounts,
+        &pool,
+        Some(&pool_reward_extension),
+        Pubkey::new_unique(),
+        reward_index,
+        false,
+    )
+    .unwrap();
+    assert_eq!(instruction.accounts[3].pubkey, reward_vault);
+
+    let pool_reward_extension_address = Pubkey::find_program_address(
+        &[b"pool_reward_extension", accounts.pool.as_ref()],
+        &cp_amm::ID,
+    )
+    .0;
+    let position_reward_extension_address = Pubkey::find_program_address(
+        &[b"position_reward_extension", accounts.position.as_ref()],
+        &cp_amm::ID,
+    )
+    .0;
+    let remaining_accounts = &instruction.accounts[instruction.accounts.len() - 2..];
+    assert_eq!(remaining_accounts[0].pubkey, pool_reward_extension_address);
+    assert_eq!(
+        remaining_accounts[1].pubkey,
+        position_reward_extension_address
+    );
+
+    // reward extensions are only required once an extended reward is initialized
+    assert!(reward::get_reward_extension_remaining_accounts(
+        &accounts.pool,
+        &pool,
+        &[accounts.position]
+    )
+    .is_empty());
+    let pool = Pool {
+        extended_reward_initialized: 1,
+        ..Default::default()
+    };
+    let remaining_accounts = reward::get_reward_extension_remaining_accounts(
+        &accounts.pool,
+        &pool,
+        &[accounts.position],
+    );
+    assert_eq!(remaining_accounts[0].pubkey, pool_reward_extension_address);
+    assert_eq!(
+        remaining_accounts[1].pubkey,
+        position_reward_extension_address
+    );
+}
+
+#[test]
+fn test_allowlist_entry_derive_pda() {
+    let pool = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let allowlist_authority = Pubkey::new_unique();
+
+    let allowlist_entry = Pubkey::find_program_address(
+        &[b"allowlist_entry", pool.as_ref(), wallet.as_ref()],
+        &cp_amm::ID,
+    )
+    .0;
+    let allowlist_config =
+        Pubkey::find_program_address(&[b"allowlist_config", pool.as_ref()], &cp_amm::ID).0;
+
+    let instruction =
+        allowlist::add_allowlist_entry(pool, wallet, allowlist_authority, allowlist_authority);
+    assert_eq!(instruction.accounts[1].pubkey, allowlist_config);
+    assert_eq!(instruction.accounts[2].pubkey, allowlist_entry);
+    assert_eq!(instruction.accounts[4].pubkey, allowlist_authority);
+    assert!(instruction.accounts[4].is_signer);
+
+    let instruction =
+        allowlist::remove_allowlist_entry(pool, wallet, allowlist_authority, allowlist_authority);
+    assert_eq!(instruction.accounts[1].pubkey, allowlist_config);
+    assert_eq!(instruction.accounts[2].pubkey, allowlist_entry);
+    assert!(instruction.accounts[2].is_writable);
+    assert!(instruction.accounts[3].is_signer);
+
+    let remaining_account = allowlist::get_allowlist_entry_remaining_account(&pool, &wallet);
+    assert_eq!(remaining_account.pubkey, allowlist_entry);
+    assert!(!remaining_account.is_writable);
+}
+
+#[test]
+fn test_pending_operator_action_derive_pda() {
+    let pool = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let action = OperatorAction::SetPoolStatus { status: 1 };
+
+    let pending_operator_action = Pubkey::find_program_address(
+        &[b"pending_operator_action", pool.as_ref(), &[4]],
+        &cp_amm::ID,
+    )
+    .0;
+    let timelock_config = Pubkey::find_program_address(&[b"timelock_config"], &cp_amm::ID).0;
+
+    let instruction = timelock::execute_operator_action(pool, proposer, &action).unwrap();
+    assert_eq!(instruction.accounts[0].pubkey, pending_operator_action);
+    assert!(instruction.accounts[2].is_writable);
+    assert_eq!(
+        instruction.accounts[3].pubkey,
+        pda::derive_operator(&proposer)
+    );
+
+    let instruction = timelock::propose_operator_action(pool, proposer, proposer, action);
+    assert_eq!(instruction.accounts[0].pubkey, pending_operator_action);
+    assert_eq!(instruction.accounts[2].pubkey, timelock_config);
+
+    // extended reward funder updates carry the pool reward extension
+    let action = OperatorAction::UpdateRewardFunder {
+        reward_index: NUM_REWARDS as u8,
+        new_funder: Pubkey::new_unique(),
+    };
+    let instruction = timelock::execute_operator_action(pool, proposer, &action).unwrap();
+    assert_eq!(
+        instruction.accounts.last().unwrap().pubkey,
+        pda::derive_pool_reward_extension(&pool)
+    );
+}
+
+#[test]
+fn test_timelock_config_is_a_remaining_account() {
+    let pool = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+    let timelock_config = pda::derive_timelock_config();
+
+    // accounts before the timelock are unchanged, the timelock config follows the event cpi accounts
+    let instruction = operator::set_pool_status(pool, signer, 1);
+    assert_eq!(instruction.accounts.len(), 6);
+    assert_eq!(
+        instruction.accounts[3].pubkey,
+        pda::derive_event_authority()
+    );
+    assert_eq!(instruction.accounts[5].pubkey, timelock_config);
+    assert!(!instruction.accounts[5].is_writable);
+
+    let instruction = operator::create_dynamic_config(
+        signer,
+        signer,
+        0,
+        DynamicConfigParameters {
+            pool_creator_authority: Pubkey::new_unique(),
+        },
+    );
+    assert_eq!(instruction.accounts.last().unwrap().pubkey, timelock_config);
+}
+
+#[test]
+fn test_config_operator_action_derive_pda() {
+    let pool = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let index = 7u64;
+    let action = OperatorAction::CreateDynamicConfig {
+        index,
+        config_parameters: DynamicConfigParameters {
+            pool_creator_authority: Pubkey::new_unique(),
+        },
+    };
+
+    let config = pda::derive_config(index);
+    assert_eq!(timelock::get_operator_action_address(pool, &action), config);
+    let pending_operator_action = Pubkey::find_program_address(
+        &[
+            b"pending_operator_action",
+            config.as_ref(),
+            &[u8::from(OperatorPermission::CreateConfigKey)],
+        ],
+        &cp_amm::ID,
+    )
+    .0;
+
+    let instruction =
+        timelock::execute_config_operator_action(proposer, proposer, &action).unwrap();
+    assert_eq!(instruction.accounts[0].pubkey, pending_operator_action);
+    assert_eq!(instruction.accounts[1].pubkey, config);
+    assert_eq!(
+        instruction.accounts[3].pubkey,
+        pda::derive_operator(&proposer)
+    );
+
+    // pool actions are executed by execute_operator_action
+    let action = OperatorAction::SetPoolStatus { status: 1 };
+    assert_eq!(timelock::get_operator_action_address(pool, &action), pool);
+    assert!(timelock::execute_config_operator_action(proposer, proposer, &action).is_err());
+}
+
+#[test]
+fn test_operator_usage_limit_derive_pda() {
+    let whitelisted_address = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+    let operator_address = pda::derive_operator(&whitelisted_address);
+    let operator_usage_limit = Pubkey::find_program_address(
+        &[b"operator_usage_limit", operator_address.as_ref()],
+        &cp_amm::ID,
+    )
+    .0;
+
+    let instruction = admin::initialize_operator_usage_limit(whitelisted_address, signer, signer);
+    assert_eq!(instruction.accounts[0].pubkey, operator_usage_limit);
+    assert_eq!(instruction.accounts[1].pubkey, operator_address);
+
+    let remaining_account =
+        operator::get_operator_usage_limit_remaining_account(&whitelisted_address);
+    assert_eq!(remaining_account.pubkey, operator_usage_limit);
+    assert!(remaining_account.is_writable);
+}
+
+#[test]
+fn test_distribute_protocol_fee() {
+    let pool_address = Pubkey::new_unique();
+    let pool = Pool {
+        token_a_mint: Pubkey::new_unique(),
+        token_b_mint: Pubkey::new_unique(),
+        token_a_vault: Pubkey::new_unique(),
+        token_b_vault: Pubkey::new_unique(),
+        ..Default::default()
+    };
+    let receiver_token_accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let instruction = operator::distribute_protocol_fee(
+        pool_address,
+        &pool,
+        pool.token_b_mint,
+        &receiver_token_accounts,
+        u64::MAX,
+    )
+    .unwrap();
+    assert_eq!(
+        instruction.accounts[2].pubkey,
+        pda::derive_protocol_fee_distribution(&pool_address)
+    );
+    assert_eq!(instruction.accounts[3].pubkey, pool.token_b_vault);
+
+    let remaining_accounts = &instruction.accounts[instruction.accounts.len() - 2..];
+    for (account, receiver_token_account) in remaining_accounts.iter().zip(receiver_token_accounts)
+    {
+        assert_eq!(account.pubkey, receiver_token_account);
+        assert!(account.is_writable);
+    }
+
+    assert!(operator::distribute_protocol_fee(
+        pool_address,
+        &pool,
+        Pubkey::new_unique(),
+        &receiver_token_accounts,
+        u64::MAX,
+    )
+    .is_err());
+}
+
+#[test]
+fn test_claim_creator_fee_signed_by_pool_creator() {
+    let pool_address = Pubkey::new_unique();
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    let token_a_account = Pubkey::new_unique();
+    let token_b_account = Pubkey::new_unique();
+
+    let instruction = liquidity::claim_creator_fee(
+        pool_address,
+        &pool,
+        token_a_account,
+        token_b_account,
+        u64::MAX,
+        u64::MAX,
+    )
+    .unwrap();
+
+    assert_eq!(instruction.accounts[1].pubkey, pool_address);
+    assert!(instruction.accounts[1].is_writable);
+    assert_eq!(instruction.accounts[2].pubkey, token_a_account);
+    assert_eq!(instruction.accounts[3].pubkey, token_b_account);
+    assert_eq!(instruction.accounts[8].pubkey, pool.creator);
+    assert!(instruction.accounts[8].is_signer);
+}
+
+#[test]
+fn test_const_event_authority() {
+    let event_authority = Pubkey::find_program_address(&[b"__event_authority"], &cp_amm::ID).0;
+    assert_eq!(pda::derive_event_authority(), event_authority);
+}
@@ -0,0 +1,99 @@
+use cp_amm::{
+    params::swap::TradeDirection,
+    state::{fee::FeeMode, CollectFeeMode},
+    u128x128_math::Rounding,
+};
+
+use crate::{
+    quote_add_liquidity_single_sided,
+    tests::{get_compounding_pool, get_pool_account, SOL_USDC_CL_ADDRESS},
+    utils::get_current_point,
+};
+
+#[test]
+fn test_quote_add_liquidity_single_sided_compounding() {
+    let pool = get_compounding_pool(1_000_000_000, 5_000_000_000);
+
+    let amount_in = 100_000_000;
+    let quote =
+        quote_add_liquidity_single_sided::get_quote(&pool, 0, 0, amount_in, true, 100).unwrap();
+
+    assert!(quote.swap_amount_in > 0 && quote.swap_amount_in < amount_in);
+    assert!(quote.swap_amount_in + quote.token_a_amount <= amount_in);
+    assert_eq!(
+        quote.swap_result.output_amount,
+        quote.token_b_amount + quote.refund_amount_out
+    );
+    assert!(quote.minimum_liquidity_delta <= quote.liquidity_delta);
+    assert_eq!(
+        quote.minimum_liquidity_delta,
+        quote.liquidity_delta * 9_900 / 10_000
+    );
+
+    // almost nothing is left over when the swap amount is optimal
+    let unused_amount_a = amount_in - quote.swap_amount_in - quote.token_a_amount;
+    assert!(unused_amount_a <= amount_in / 10_000);
+    assert!(quote.refund_amount_out <= quote.swap_result.output_amount / 10_000);
+}
+
+#[test]
+fn test_quote_add_liquidity_single_sided_is_optimal() {
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    let current_timestamp: u64 = 1_753_751_761;
+    let current_slot: u64 = 356410171;
+
+    let amount_in = 1_000_000_000; // 1000 USDC
+    let quote = quote_add_liquidity_single_sided::get_quote(
+        &pool,
+        current_timestamp,
+        current_slot,
+        amount_in,
+        false,
+        0,
+    )
+    .unwrap();
+
+    assert!(quote.swap_amount_in + quote.token_b_amount <= amount_in);
+    assert_eq!(quote.minimum_liquidity_delta, quote.liquidity_delta);
+
+    // swapping a different amount mints no more liquidity
+    let trade_direction = TradeDirection::BtoA;
+    let collect_fee_mode = CollectFeeMode::try_from(pool.collect_fee_mode).unwrap();
+    let fee_mode = FeeMode::get_fee_mode(collect_fee_mode, trade_direction, false);
+    let current_point =
+        get_current_point(pool.activation_type, current_slot, current_timestamp).unwrap();
+
+    for swap_amount_in in [
+        quote.swap_amount_in / 2,
+        quote.swap_amount_in * 99 / 100,
+        quote.swap_amount_in * 101 / 100,
+        (quote.swap_amount_in + amount_in) / 2,
+    ] {
+        let mut pool = pool;
+        let swap_result = pool
+            .get_swap_result_from_exact_input(
+                swap_amount_in,
+                &fee_mode,
+                trade_direction,
+                current_point,
+            )
+            .unwrap();
+        pool.apply_swap_result(&swap_result, &fee_mode, trade_direction, current_timestamp)
+            .unwrap();
+
+        let liquidity_handler = pool.get_liquidity_handler().unwrap();
+        let liquidity_delta = liquidity_handler
+            .get_max_liquidity_delta_from_amounts(
+                swap_result.output_amount,
+                amount_in - swap_amount_in,
+            )
+            .unwrap();
+        assert!(liquidity_delta <= quote.liquidity_delta);
+
+        let (token_a_amount, token_b_amount) = liquidity_handler
+            .get_amounts_for_modify_liquidity(liquidity_delta, Rounding::Up)
+            .unwrap();
+        assert!(token_a_amount <= swap_result.output_amount);
+        assert!(token_b_amount <= amount_in - swap_amount_in);
+    }
+}
@@ -0,0 +1,44 @@
+use cp_amm::{params::swap::TradeDirection, state::Position};
+
+use crate::{quote_compound_position_fee, tests::get_compounding_pool};
+
+#[test]
+fn test_quote_compound_position_fee_swaps_surplus_fee() {
+    let pool = get_compounding_pool(1_000_000_000, 5_000_000_000);
+    let position = Position {
+        fee_a_pending: 10_000_000,
+        fee_b_pending: 1_000_000,
+        ..Default::default()
+    };
+
+    let quote = quote_compound_position_fee::get_quote(&pool, &position, 0, 0, 100).unwrap();
+
+    assert_eq!(quote.trade_direction, Some(TradeDirection::AtoB));
+    let swap_result = quote.swap_result.unwrap();
+    assert!(quote.swap_amount_in > 0 && quote.swap_amount_in < position.fee_a_pending);
+    assert!(quote.swap_amount_in + quote.token_a_amount <= position.fee_a_pending);
+    assert!(quote.token_b_amount <= position.fee_b_pending + swap_result.output_amount);
+    assert_eq!(
+        quote.minimum_liquidity_delta,
+        quote.liquidity_delta * 9_900 / 10_000
+    );
+
+    // swapping the surplus mints more liquidity than depositing the pending fees as is
+    let liquidity_handler = pool.get_liquidity_handler().unwrap();
+    let liquidity_without_swap = liquidity_handler
+        .get_max_liquidity_delta_from_amounts(position.fee_a_pending, position.fee_b_pending)
+        .unwrap();
+    assert!(quote.liquidity_delta > liquidity_without_swap);
+
+    let params = quote.to_compound_position_fee_parameters();
+    assert_eq!(params.swap_amount_in, quote.swap_amount_in);
+    assert_eq!(params.trade_direction, u8::from(TradeDirection::AtoB));
+}
+
+#[test]
+fn test_quote_compound_position_fee_without_pending_fee() {
+    let pool = get_compounding_pool(1_000_000_000, 5_000_000_000);
+    let position = Position::default();
+
+    assert!(quote_compound_position_fee::get_quote(&pool, &position, 0, 0, 100).is_err());
+}
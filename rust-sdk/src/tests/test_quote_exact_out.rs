@@ -1,5 +1,10 @@
+use cp_amm::{
+    base_fee::fee_asymmetric_time_scheduler::PodAlignedAsymmetricFeeTimeScheduler,
+    params::swap::TradeDirection, state::fee::BaseFeeMode,
+};
+
 use crate::{
-    quote_exact_out,
+    quote_exact_in, quote_exact_out,
     tests::{get_compounding_pool, get_pool_account, MACK_USDC_ADDRESS},
 };
 
@@ -57,3 +62,44 @@ fn test_quote_exact_out_compounding_next_sqrt_price() {
         "next_sqrt_price should differ from initial sqrt_price after swap"
     );
 }
+
+#[test]
+fn test_quote_exact_out_asymmetric_base_fee() {
+    let mut pool = get_compounding_pool(1_000_000_000, 1_000_000_000);
+    let fee_scheduler = PodAlignedAsymmetricFeeTimeScheduler {
+        cliff_fee_numerator: 300_000_000, // 30% sell tax
+        number_of_period: 20,
+        period_frequency: 60,
+        reduction_factor: 10_000_000,
+        constant_fee_numerator: 10_000_000, // 1% buy fee
+        scheduled_trade_direction: TradeDirection::AtoB.into(),
+        base_fee_mode: BaseFeeMode::AsymmetricFeeTimeSchedulerLinear.into(),
+        ..Default::default()
+    };
+    pool.pool_fees
+        .base_fee
+        .base_fee_info
+        .data
+        .copy_from_slice(bytemuck::bytes_of(&fee_scheduler));
+
+    let amount_out = 100_000;
+    let sell_result = quote_exact_out::get_quote(&pool, 0, 0, amount_out, true, false).unwrap();
+    let buy_result = quote_exact_out::get_quote(&pool, 0, 0, amount_out, false, false).unwrap();
+    assert_eq!(sell_result.output_amount, amount_out);
+    assert_eq!(buy_result.output_amount, amount_out);
+    assert!(sell_result.included_fee_input_amount > buy_result.included_fee_input_amount);
+
+    // exact in with the quoted input covers the requested output in both directions
+    for (a_to_b, exact_out_result) in [(true, sell_result), (false, buy_result)] {
+        let exact_in_result = quote_exact_in::get_quote(
+            &pool,
+            0,
+            0,
+            exact_out_result.included_fee_input_amount,
+            a_to_b,
+            false,
+        )
+        .unwrap();
+        assert!(exact_in_result.output_amount >= amount_out);
+    }
+}
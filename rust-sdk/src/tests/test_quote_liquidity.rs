@@ -0,0 +1,97 @@
+use cp_amm::state::Position;
+
+use crate::{
+    quote_liquidity,
+    tests::{get_compounding_pool, get_pool_account, SOL_USDC_CL_ADDRESS},
+};
+
+#[test]
+fn test_concentrated_liquidity_delta_wont_exceed_deposit() {
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+
+    let max_token_a_amount = 1_000_000_000; // 1 SOL
+    let max_token_b_amount = 1_000_000_000; // 1000 USDC
+
+    let liquidity_delta =
+        quote_liquidity::get_liquidity_delta(&pool, max_token_a_amount, max_token_b_amount)
+            .unwrap();
+    assert!(liquidity_delta > 0);
+
+    let quote = quote_liquidity::get_add_liquidity_quote(&pool, liquidity_delta, 0).unwrap();
+
+    assert!(
+        quote.token_a_amount <= max_token_a_amount && quote.token_b_amount <= max_token_b_amount,
+        "Deposit must not exceed the target amounts"
+    );
+    assert_eq!(quote.token_a_amount_threshold, quote.token_a_amount);
+    assert_eq!(quote.token_b_amount_threshold, quote.token_b_amount);
+}
+
+#[test]
+fn test_compounding_liquidity_delta_wont_exceed_deposit() {
+    let pool = get_compounding_pool(1_000_000_000, 5_000_000_000);
+
+    let max_token_a_amount = 123_456_789;
+    let max_token_b_amount = u64::MAX;
+
+    let liquidity_delta =
+        quote_liquidity::get_liquidity_delta(&pool, max_token_a_amount, max_token_b_amount)
+            .unwrap();
+
+    let quote = quote_liquidity::get_add_liquidity_quote(&pool, liquidity_delta, 0).unwrap();
+
+    assert!(quote.token_a_amount <= max_token_a_amount);
+    assert!(
+        max_token_a_amount - quote.token_a_amount <= 1,
+        "Token a should be the binding side"
+    );
+}
+
+#[test]
+fn test_remove_liquidity_quote_with_slippage() {
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+
+    let liquidity_delta = pool.liquidity / 1000;
+    let quote = quote_liquidity::get_remove_liquidity_quote(&pool, liquidity_delta, 100).unwrap();
+
+    assert!(quote.token_a_amount_threshold <= quote.token_a_amount);
+    assert!(quote.token_b_amount_threshold <= quote.token_b_amount);
+    assert_eq!(
+        quote.token_a_amount_threshold,
+        (u128::from(quote.token_a_amount) * 9_900 / 10_000) as u64
+    );
+
+    // add then remove the same liquidity must not return more than deposited
+    let add_quote = quote_liquidity::get_add_liquidity_quote(&pool, liquidity_delta, 100).unwrap();
+    assert!(add_quote.token_a_amount >= quote.token_a_amount);
+    assert!(add_quote.token_b_amount >= quote.token_b_amount);
+    assert!(add_quote.token_a_amount_threshold >= add_quote.token_a_amount);
+    assert!(add_quote.token_b_amount_threshold >= add_quote.token_b_amount);
+}
+
+#[test]
+fn test_remove_all_liquidity_quote() {
+    let pool = get_compounding_pool(1_000_000_000, 1_000_000_000);
+
+    let position = Position {
+        unlocked_liquidity: pool.liquidity / 2,
+        permanent_locked_liquidity: pool.liquidity / 4,
+        ..Default::default()
+    };
+
+    let quote =
+        quote_liquidity::get_remove_all_liquidity_quote(&pool, &position, 0, 0, 50).unwrap();
+    assert_eq!(quote.liquidity_delta, position.unlocked_liquidity);
+
+    let (value_a, value_b) = quote_liquidity::get_position_value(&pool, &position).unwrap();
+    assert!(value_a > quote.token_a_amount);
+    assert!(value_b > quote.token_b_amount);
+}
+
+#[test]
+fn test_add_liquidity_quote_invalid_slippage() {
+    let pool = get_compounding_pool(1_000_000_000, 1_000_000_000);
+
+    let result = quote_liquidity::get_add_liquidity_quote(&pool, pool.liquidity / 10, 10_001);
+    assert!(result.is_err(), "Expected error when slippage exceeds 100%");
+}
@@ -62,3 +62,41 @@ fn test_quote_partial_fill_in_compounding_next_sqrt_price() {
         "next_sqrt_price should differ from initial sqrt_price after swap"
     );
 }
+
+#[test]
+fn test_quote_partial_fill_in_with_sqrt_price_limit() {
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+
+    let current_timestamp: u64 = 1_753_751_761;
+    let current_slot: u64 = 356410171;
+
+    let sqrt_price_limit = pool.sqrt_price + (pool.sqrt_max_price - pool.sqrt_price) / 2;
+    let amount_in = u64::MAX;
+
+    let swap_result = quote_partial_fill_in::get_quote_with_sqrt_price_limit(
+        &pool,
+        current_timestamp,
+        current_slot,
+        amount_in,
+        false,
+        false,
+        Some(sqrt_price_limit),
+    )
+    .unwrap();
+
+    assert!(swap_result.output_amount > 0);
+    assert!(swap_result.amount_left > 0);
+    assert_eq!(swap_result.next_sqrt_price, sqrt_price_limit);
+
+    // limit must be above current price for b to a
+    assert!(quote_partial_fill_in::get_quote_with_sqrt_price_limit(
+        &pool,
+        current_timestamp,
+        current_slot,
+        amount_in,
+        false,
+        false,
+        Some(pool.sqrt_price),
+    )
+    .is_err());
+}
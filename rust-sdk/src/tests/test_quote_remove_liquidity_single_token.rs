@@ -0,0 +1,64 @@
+use crate::{
+    quote_exact_in, quote_liquidity, quote_remove_liquidity_single_token,
+    tests::{get_compounding_pool, get_pool_account, SOL_USDC_CL_ADDRESS},
+};
+
+#[test]
+fn test_quote_remove_liquidity_single_token_compounding() {
+    let pool = get_compounding_pool(1_000_000_000, 5_000_000_000);
+
+    let liquidity_delta = pool.liquidity / 10;
+    let quote =
+        quote_remove_liquidity_single_token::get_quote(&pool, 0, 0, liquidity_delta, true, 100)
+            .unwrap();
+
+    let remove_quote =
+        quote_liquidity::get_remove_liquidity_quote(&pool, liquidity_delta, 0).unwrap();
+    assert_eq!(quote.token_a_amount, remove_quote.token_a_amount);
+    assert_eq!(quote.token_b_amount, remove_quote.token_b_amount);
+
+    let swap_result = quote.swap_result.unwrap();
+    assert_eq!(
+        quote.amount_out,
+        quote.token_b_amount + swap_result.output_amount
+    );
+    assert_eq!(
+        quote.minimum_amount_out,
+        (u128::from(quote.amount_out) * 9_900 / 10_000) as u64
+    );
+
+    // swapping after the withdrawal gets less than swapping before it because the reserves are smaller
+    let swap_before_withdrawal =
+        quote_exact_in::get_quote(&pool, 0, 0, quote.token_a_amount, true, false).unwrap();
+    assert!(swap_result.output_amount < swap_before_withdrawal.output_amount);
+}
+
+#[test]
+fn test_quote_remove_liquidity_single_token_concentrated() {
+    let pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    let current_timestamp: u64 = 1_753_751_761;
+    let current_slot: u64 = 356410171;
+
+    let liquidity_delta = pool.liquidity / 1000;
+    let quote = quote_remove_liquidity_single_token::get_quote(
+        &pool,
+        current_timestamp,
+        current_slot,
+        liquidity_delta,
+        false,
+        0,
+    )
+    .unwrap();
+
+    assert!(quote.token_a_amount > 0 && quote.token_b_amount > 0);
+    let swap_result = quote.swap_result.unwrap();
+    assert_eq!(
+        quote.amount_out,
+        quote.token_a_amount + swap_result.output_amount
+    );
+    assert_eq!(quote.minimum_amount_out, quote.amount_out);
+
+    let params = quote.to_remove_liquidity_single_token_parameters(false);
+    assert_eq!(params.liquidity_delta, liquidity_delta);
+    assert_eq!(params.trade_direction, 1);
+}
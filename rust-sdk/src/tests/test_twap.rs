@@ -0,0 +1,70 @@
+use anchor_lang::prelude::Pubkey;
+use cp_amm::{
+    state::{Oracle, Pool},
+    utils_math::get_log2_sqrt_price,
+};
+use proptest::prelude::*;
+
+use crate::{
+    tests::{get_pool_account, SOL_USDC_CL_ADDRESS},
+    twap::{get_sqrt_price_from_log2, get_twap},
+};
+
+proptest! {
+    #[test]
+    fn test_sqrt_price_from_log2_round_trip(sqrt_price in 1u128 << 32..u128::MAX >> 1) {
+        let log_sqrt_price = get_log2_sqrt_price(sqrt_price).unwrap();
+        let result = get_sqrt_price_from_log2(log_sqrt_price).unwrap();
+
+        // log2 is rounded down in Q32.32, so the relative error is at most 2^-32
+        prop_assert!(result <= sqrt_price);
+        prop_assert!(sqrt_price - result <= (sqrt_price >> 31) + 1);
+    }
+}
+
+#[test]
+fn test_get_twap() {
+    let start_timestamp = 1_000_000;
+    let mut pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    pool.oracle_last_update_timestamp = 0;
+    pool.update_oracle_cumulatives(start_timestamp).unwrap();
+
+    let mut oracle: Box<Oracle> = Box::new(bytemuck::Zeroable::zeroed());
+    oracle.initialize(Pubkey::new_unique(), &pool);
+
+    // price is doubled after 100 seconds
+    let sqrt_price = pool.sqrt_price;
+    pool.update_oracle_cumulatives(start_timestamp + 100)
+        .unwrap();
+    pool.sqrt_price = sqrt_price * 2;
+    oracle.write_observation(&pool).unwrap();
+
+    let current_timestamp = start_timestamp + 200;
+    let twap = get_twap(&oracle, &pool, current_timestamp, 200, 0).unwrap();
+    let expected_sqrt_price = sqrt_price as f64 * 2f64.sqrt();
+    let relative_error = (twap.sqrt_price as f64 - expected_sqrt_price).abs() / expected_sqrt_price;
+    assert!(relative_error < 1e-9);
+    assert!(twap.liquidity.abs_diff(pool.liquidity) <= 1);
+
+    let twap = get_twap(&oracle, &pool, current_timestamp, 100, 0).unwrap();
+    assert!(twap.sqrt_price.abs_diff(pool.sqrt_price) <= pool.sqrt_price >> 31);
+
+    // window is reversed
+    assert!(get_twap(&oracle, &pool, current_timestamp, 0, 100).is_err());
+}
+
+#[test]
+fn test_get_twap_without_liquidity() {
+    let mut pool = Pool {
+        sqrt_price: 1u128 << 64,
+        ..Default::default()
+    };
+    pool.update_oracle_cumulatives(1_000).unwrap();
+
+    let mut oracle: Box<Oracle> = Box::new(bytemuck::Zeroable::zeroed());
+    oracle.initialize(Pubkey::new_unique(), &pool);
+
+    let twap = get_twap(&oracle, &pool, 1_100, 100, 0).unwrap();
+    assert_eq!(twap.sqrt_price, 1u128 << 64);
+    assert_eq!(twap.liquidity, 0);
+}
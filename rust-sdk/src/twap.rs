@@ -0,0 +1,80 @@
+use anyhow::{ensure, Context, Result};
+use cp_amm::{
+    state::{Oracle, Pool},
+    utils_math::{sqrt_u256, LOG2_FRACTIONAL_BITS},
+};
+use ruint::aliases::U256;
+
+pub struct TwapResult {
+    /// time weighted geometric mean of sqrt_price, in Q64.64
+    pub sqrt_price: u128,
+    /// time weighted harmonic mean of liquidity. Zero when the pool has no liquidity for the whole window
+    pub liquidity: u128,
+}
+
+/// Time weighted average over [current_timestamp - start_seconds_ago, current_timestamp - end_seconds_ago]
+pub fn get_twap(
+    oracle: &Oracle,
+    pool: &Pool,
+    current_timestamp: u64,
+    start_seconds_ago: u64,
+    end_seconds_ago: u64,
+) -> Result<TwapResult> {
+    ensure!(
+        start_seconds_ago > end_seconds_ago,
+        "Start of the window must be before the end"
+    );
+
+    let start = oracle.observe(pool, current_timestamp, start_seconds_ago)?;
+    let end = oracle.observe(pool, current_timestamp, end_seconds_ago)?;
+    let window = start_seconds_ago - end_seconds_ago;
+
+    let log_sqrt_price_delta = end
+        .log_sqrt_price_cumulative
+        .wrapping_sub(start.log_sqrt_price_cumulative);
+    // round toward negative infinity, so the price is always rounded down
+    let average_log_sqrt_price =
+        i64::try_from(i128::from(log_sqrt_price_delta).div_euclid(i128::from(window)))?;
+
+    let seconds_per_liquidity_delta = end
+        .seconds_per_liquidity_cumulative
+        .wrapping_sub(start.seconds_per_liquidity_cumulative);
+    let liquidity = if seconds_per_liquidity_delta == 0 {
+        0
+    } else {
+        let liquidity = (U256::from(window) << 128) / U256::from(seconds_per_liquidity_delta);
+        u128::try_from(liquidity).unwrap_or(u128::MAX)
+    };
+
+    Ok(TwapResult {
+        sqrt_price: get_sqrt_price_from_log2(average_log_sqrt_price)?,
+        liquidity,
+    })
+}
+
+/// Inverse of [cp_amm::utils_math::get_log2_sqrt_price], sqrt_price = 2^64 * 2^(log_sqrt_price / 2^32)
+pub fn get_sqrt_price_from_log2(log_sqrt_price: i64) -> Result<u128> {
+    let integer_part = log_sqrt_price >> LOG2_FRACTIONAL_BITS;
+    let fractional_part = log_sqrt_price & ((1i64 << LOG2_FRACTIONAL_BITS) - 1);
+
+    // 2^(fractional_part / 2^32) in Q64.64, from 2^(1/2), 2^(1/4), ... 2^(1/2^32)
+    let one = U256::from(1u128 << 64);
+    let mut result = one;
+    let mut factor = one << 1;
+    for bit in (0..LOG2_FRACTIONAL_BITS).rev() {
+        factor = sqrt_u256(factor << 64).context("Math overflow")?;
+        if fractional_part & (1 << bit) != 0 {
+            result = (result * factor) >> 64;
+        }
+    }
+
+    let result = if integer_part >= 0 {
+        result.checked_shl(usize::try_from(integer_part)?)
+    } else {
+        Some(result >> usize::try_from(-integer_part)?)
+    };
+
+    result
+        .and_then(|result| u128::try_from(result).ok())
+        .context("Math overflow")
+}
@@ -1,8 +1,10 @@
 use anyhow::{Context, Error, Result};
 use cp_amm::{
     params::swap::TradeDirection,
-    state::{fee::FeeMode, CollectFeeMode, Pool, PoolStatus, SwapResult2},
-    ActivationType, CompoundingLiquidity, LiquidityHandler,
+    state::{
+        fee::FeeMode, is_pool_action_paused, CollectFeeMode, Pool, PoolPauseAction, SwapResult2,
+    },
+    ActivationType, CompoundingLiquidity, LiquidityHandler, StableSwapLiquidity,
 };
 
 pub fn get_current_point(
@@ -22,8 +24,10 @@ pub fn get_current_point(
 }
 
 pub fn is_swap_enable(pool: &Pool, current_point: u64) -> Result<bool> {
-    let pool_status = PoolStatus::try_from(pool.pool_status).context("invalid pool status")?;
-    Ok(pool_status == PoolStatus::Enable && current_point >= pool.activation_point)
+    Ok(
+        !is_pool_action_paused(pool.pool_status, PoolPauseAction::Swap)
+            && current_point >= pool.activation_point,
+    )
 }
 
 pub fn apply_next_sqrt_price(
@@ -33,7 +37,10 @@ pub fn apply_next_sqrt_price(
     trade_direction: TradeDirection,
     collect_fee_mode: CollectFeeMode,
 ) -> Result<u128> {
-    if collect_fee_mode != CollectFeeMode::Compounding {
+    if !matches!(
+        collect_fee_mode,
+        CollectFeeMode::Compounding | CollectFeeMode::StableSwap
+    ) {
         return Ok(swap_result.next_sqrt_price);
     }
 
@@ -50,6 +57,7 @@ pub fn apply_next_sqrt_price(
             .checked_add(trading_fee)
             .and_then(|v| v.checked_add(swap_result.protocol_fee))
             .and_then(|v| v.checked_add(swap_result.referral_fee))
+            .and_then(|v| v.checked_add(swap_result.creator_fee))
             .ok_or_else(|| Error::msg("Math overflow"))?
     };
 
@@ -82,11 +90,22 @@ pub fn apply_next_sqrt_price(
         .checked_add(swap_result.compounding_fee)
         .ok_or_else(|| Error::msg("Math overflow"))?;
 
-    let handler = CompoundingLiquidity {
-        token_a_amount: new_token_a,
-        token_b_amount: new_token_b,
-        liquidity: pool.liquidity,
+    let next_sqrt_price = if collect_fee_mode == CollectFeeMode::StableSwap {
+        StableSwapLiquidity {
+            token_a_amount: new_token_a,
+            token_b_amount: new_token_b,
+            liquidity: pool.liquidity,
+            amplification_coefficient: pool.amplification_coefficient,
+        }
+        .get_next_sqrt_price(0)?
+    } else {
+        CompoundingLiquidity {
+            token_a_amount: new_token_a,
+            token_b_amount: new_token_b,
+            liquidity: pool.liquidity,
+        }
+        .get_next_sqrt_price(0)?
     };
 
-    Ok(handler.get_next_sqrt_price(0)?)
+    Ok(next_sqrt_price)
 }
@@ -81,7 +81,7 @@ describe("Add liquidity", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -257,7 +257,7 @@ describe("Add liquidity", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
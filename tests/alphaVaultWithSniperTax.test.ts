@@ -220,7 +220,7 @@ const alphaVaultWithSniperTaxFullFlow = async (
     poolFees: {
       baseFee,
       compoundingFeeBps: 0,
-      padding: 0,
+      creatorFeePercent: 0,
       dynamicFee: null,
     },
     activationType: 0, // slot
@@ -77,7 +77,7 @@ describe("Claim position fee", () => {
           data: Array.from(data),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: null,
       },
       sqrtMinPrice: new BN(MIN_SQRT_PRICE),
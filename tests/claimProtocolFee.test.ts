@@ -92,7 +92,7 @@ describe("Claim Protocol Fee", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -304,7 +304,7 @@ describe("Claim Protocol Fee", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
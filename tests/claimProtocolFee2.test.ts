@@ -81,7 +81,7 @@ describe("Claim Protocol Fee 2", () => {
           data: Array.from(data),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: null,
       },
       sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -79,7 +79,7 @@ describe("Compounding liquidity", () => {
                     data: Array.from(data),
                 },
                 compoundingFeeBps: 5000,
-                padding: 0,
+                creatorFeePercent: 0,
                 dynamicFee: null,
             },
             sqrtMinPrice: new BN(0),
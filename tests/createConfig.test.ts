@@ -53,7 +53,7 @@ describe("Admin function: Create config", () => {
           data: Array.from(data),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: null,
       },
       sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -120,7 +120,7 @@ describe("Admin function: Create config", () => {
           data: Array.from(data),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: {
           binStep: binStep.toNumber(),
           binStepU128,
@@ -172,7 +172,7 @@ describe("Admin function: Create config", () => {
           data: Array.from(data),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: null,
       },
       sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -71,7 +71,7 @@ describe("Initialize customizable pool", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         activationType: 0,
@@ -155,7 +155,7 @@ describe("Initialize customizable pool", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         activationType: 0,
@@ -74,7 +74,7 @@ describe("Initialize pool", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -202,7 +202,7 @@ describe("Initialize pool", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -81,7 +81,7 @@ describe("Create position", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -195,7 +195,7 @@ describe("Create position", () => {
             data: Array.from(data),
           },
           compoundingFeeBps: 0,
-          padding: 0,
+          creatorFeePercent: 0,
           dynamicFee: null,
         },
         sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -94,7 +94,7 @@ describe("Dynamic config test", () => {
           data: Array.from(data),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: null,
       },
       activationType: 0,
@@ -96,7 +96,7 @@ describe("Frozen reward vault", () => {
           data: Array.from(data),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: null,
       },
       sqrtMinPrice: new BN(MIN_SQRT_PRICE),
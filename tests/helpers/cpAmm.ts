@@ -134,7 +134,7 @@ export type BaseFee = {
 export type PoolFees = {
   baseFee: BaseFee;
   compoundingFeeBps: number;
-  padding: number;
+  creatorFeePercent: number;
   dynamicFee: DynamicFee | null;
 };
 
@@ -909,7 +909,7 @@ export async function setPoolStatus(svm: LiteSVM, params: SetPoolStatusParams) {
 export type PoolFeesParams = {
   baseFee: BaseFee;
   compoundingFeeBps: number;
-  padding: number;
+  creatorFeePercent: number;
   dynamicFee: DynamicFee | null;
 };
 
@@ -1933,6 +1933,7 @@ export enum SwapMode {
   ExactIn,
   PartialFillIn,
   ExactOut,
+  PartialFillWithPriceLimit,
 }
 
 export type Swap2Params = {
@@ -1943,6 +1944,7 @@ export type Swap2Params = {
   amount0: BN;
   amount1: BN;
   swapMode: SwapMode;
+  sqrtPriceLimit?: BN;
   referralTokenAccount: PublicKey | null;
 };
 
@@ -1955,6 +1957,7 @@ export async function swap2Instruction(svm: LiteSVM, params: Swap2Params) {
     amount0,
     amount1,
     swapMode,
+    sqrtPriceLimit,
     referralTokenAccount,
   } = params;
 
@@ -1987,6 +1990,7 @@ export async function swap2Instruction(svm: LiteSVM, params: Swap2Params) {
       amount0,
       amount1,
       swapMode,
+      sqrtPriceLimit: sqrtPriceLimit ?? new BN(0),
     })
     .accountsPartial({
       poolAuthority,
@@ -7,10 +7,16 @@ type PodAlignedFeeTimeScheduler = IdlTypes<CpAmm>["podAlignedFeeTimeScheduler"];
 type PodAlignedFeeMarketCapScheduler =
   IdlTypes<CpAmm>["podAlignedFeeMarketCapScheduler"];
 type PodAlignedRateLimiter = IdlTypes<CpAmm>["podAlignedFeeRateLimiter"];
+type PodAlignedFeeVolumeTier = IdlTypes<CpAmm>["podAlignedFeeVolumeTier"];
+type PodAlignedAsymmetricFeeTimeScheduler =
+  IdlTypes<CpAmm>["podAlignedAsymmetricFeeTimeScheduler"];
 
 type BorshFeeTimeScheduler = IdlTypes<CpAmm>["borshFeeTimeScheduler"];
 type BorshFeeMarketCapScheduler = IdlTypes<CpAmm>["borshFeeMarketCapScheduler"];
 type BorshRateLimiter = IdlTypes<CpAmm>["borshFeeRateLimiter"];
+type BorshFeeVolumeTier = IdlTypes<CpAmm>["borshFeeVolumeTier"];
+type BorshAsymmetricFeeTimeScheduler =
+  IdlTypes<CpAmm>["borshAsymmetricFeeTimeScheduler"];
 
 export enum BaseFeeMode {
   FeeTimeSchedulerLinear,
@@ -18,6 +24,14 @@ export enum BaseFeeMode {
   RateLimiter,
   FeeMarketCapSchedulerLinear,
   FeeMarketCapSchedulerExponential,
+  FeeVolumeTier,
+  AsymmetricFeeTimeSchedulerLinear,
+  AsymmetricFeeTimeSchedulerExponential,
+}
+
+export enum TradeDirection {
+  AtoB,
+  BtoA,
 }
 
 export function encodeFeeTimeSchedulerParams(
@@ -126,3 +140,81 @@ export function decodePodAlignedFeeRateLimiter(
   const program = createCpAmmProgram();
   return program.coder.types.decode("podAlignedFeeRateLimiter", data);
 }
+
+export function encodeFeeVolumeTierParams(
+  cliffFeeNumerator: bigint,
+  numberOfTiers: number,
+  volumeDecayPeriod: number,
+  reductionFactor: number,
+  tierVolumeStep: bigint
+): Buffer {
+  const feeVolumeTier: BorshFeeVolumeTier = {
+    cliffFeeNumerator: new BN(cliffFeeNumerator.toString()),
+    numberOfTiers,
+    volumeDecayPeriod,
+    reductionFactor,
+    tierVolumeStep: new BN(tierVolumeStep.toString()),
+    baseFeeMode: BaseFeeMode.FeeVolumeTier,
+  };
+
+  const program = createCpAmmProgram();
+  return program.coder.types.encode("borshFeeVolumeTier", feeVolumeTier);
+}
+
+export function decodeFeeVolumeTierParams(data: Buffer): BorshFeeVolumeTier {
+  const program = createCpAmmProgram();
+  return program.coder.types.decode("borshFeeVolumeTier", data);
+}
+
+export function decodePodAlignedFeeVolumeTier(
+  data: Buffer
+): PodAlignedFeeVolumeTier {
+  const program = createCpAmmProgram();
+  return program.coder.types.decode("podAlignedFeeVolumeTier", data);
+}
+
+export function encodeAsymmetricFeeTimeSchedulerParams(
+  cliffFeeNumerator: bigint,
+  numberOfPeriod: number,
+  periodFrequency: number,
+  reductionFactor: number,
+  constantFeeNumerator: number,
+  scheduledTradeDirection: TradeDirection,
+  baseFeeMode:
+    | BaseFeeMode.AsymmetricFeeTimeSchedulerLinear
+    | BaseFeeMode.AsymmetricFeeTimeSchedulerExponential
+): Buffer {
+  const asymmetricFeeTimeScheduler: BorshAsymmetricFeeTimeScheduler = {
+    cliffFeeNumerator: new BN(cliffFeeNumerator.toString()),
+    numberOfPeriod,
+    periodFrequency,
+    reductionFactor,
+    constantFeeNumerator,
+    scheduledTradeDirection,
+    padding: [0, 0, 0],
+    baseFeeMode,
+  };
+
+  const program = createCpAmmProgram();
+  return program.coder.types.encode(
+    "borshAsymmetricFeeTimeScheduler",
+    asymmetricFeeTimeScheduler
+  );
+}
+
+export function decodeAsymmetricFeeTimeSchedulerParams(
+  data: Buffer
+): BorshAsymmetricFeeTimeScheduler {
+  const program = createCpAmmProgram();
+  return program.coder.types.decode("borshAsymmetricFeeTimeScheduler", data);
+}
+
+export function decodePodAlignedAsymmetricFeeTimeScheduler(
+  data: Buffer
+): PodAlignedAsymmetricFeeTimeScheduler {
+  const program = createCpAmmProgram();
+  return program.coder.types.decode(
+    "podAlignedAsymmetricFeeTimeScheduler",
+    data
+  );
+}
@@ -646,7 +646,7 @@ export async function createCustomizableDammV2Pool(params: {
         data: Array.from(data),
       },
       compoundingFeeBps: 0,
-      padding: 0,
+      creatorFeePercent: 0,
       dynamicFee: null,
     },
     activationType: 0, // slot
@@ -80,7 +80,7 @@ describe("Test max fee 99%", () => {
           data: Array.from([]),
         },
         compoundingFeeBps: 0,
-        padding: 0,
+        creatorFeePercent: 0,
         dynamicFee: null,
       },
       sqrtMinPrice: new BN(MIN_SQRT_PRICE),
@@ -736,7 +736,7 @@ async function createPool(
         data: Array.from(baseFeeData),
       },
       compoundingFeeBps: 0,
-      padding: 0,
+      creatorFeePercent: 0,
       dynamicFee,
     },
     activationType: 0,
@@ -772,7 +772,7 @@ async function createCompoundingPool(
         data: Array.from(baseFeeData),
       },
       compoundingFeeBps,
-      padding: 0,
+      creatorFeePercent: 0,
       dynamicFee: null,
     },
     activationType: 0,